@@ -0,0 +1,73 @@
+//! Functions for hashing data and verifying signatures on the host, rather than in Wasm.
+
+use casperlabs_types::account::{
+    Ed25519Bytes, PublicKey, Secp256k1Bytes, ED25519_SIGNATURE_LENGTH, SECP256K1_LENGTH,
+    SECP256K1_RECOVERABLE_SIGNATURE_LENGTH,
+};
+
+use crate::ext_ffi;
+
+/// The length in bytes of the digests returned by the hash functions.
+pub const DIGEST_LENGTH: usize = 32;
+
+/// Returns the BLAKE2b digest of `data`, with a 256-bit output.
+pub fn blake2b256(data: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut digest = [0; DIGEST_LENGTH];
+    unsafe { ext_ffi::blake2b256(data.as_ptr(), data.len(), digest.as_mut_ptr()) };
+    digest
+}
+
+/// Returns the SHA-256 digest of `data`.
+pub fn sha256(data: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut digest = [0; DIGEST_LENGTH];
+    unsafe { ext_ffi::sha256(data.as_ptr(), data.len(), digest.as_mut_ptr()) };
+    digest
+}
+
+/// Returns the Keccak-256 digest of `data`, as used by Ethereum.
+pub fn keccak256(data: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut digest = [0; DIGEST_LENGTH];
+    unsafe { ext_ffi::keccak256(data.as_ptr(), data.len(), digest.as_mut_ptr()) };
+    digest
+}
+
+/// Returns `true` if `signature` is a valid Ed25519 signature of `message` by `public_key`.
+pub fn verify_ed25519(
+    message: &[u8],
+    signature: &[u8; ED25519_SIGNATURE_LENGTH],
+    public_key: &Ed25519Bytes,
+) -> bool {
+    let result = unsafe {
+        ext_ffi::verify_ed25519(
+            message.as_ptr(),
+            message.len(),
+            signature.as_ptr(),
+            public_key.as_ptr(),
+        )
+    };
+    result != 0
+}
+
+/// Recovers the Secp256k1 public key which produced `signature` over `message_hash`.
+///
+/// `signature` is the 64-byte compact signature followed by the recovery ID, which may be given
+/// either as 0 to 3, or offset by 27 as in Ethereum signatures.  Returns `None` if no public key
+/// can be recovered.
+pub fn recover_secp256k1(
+    message_hash: &[u8; DIGEST_LENGTH],
+    signature: &[u8; SECP256K1_RECOVERABLE_SIGNATURE_LENGTH],
+) -> Option<PublicKey> {
+    let mut public_key: Secp256k1Bytes = [0; SECP256K1_LENGTH];
+    let result = unsafe {
+        ext_ffi::recover_secp256k1(
+            message_hash.as_ptr(),
+            signature.as_ptr(),
+            public_key.as_mut_ptr(),
+        )
+    };
+    if result != 0 {
+        Some(PublicKey::secp256k1_from(public_key))
+    } else {
+        None
+    }
+}
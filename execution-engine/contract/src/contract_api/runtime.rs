@@ -267,6 +267,17 @@ pub fn put_key(name: &str, key: Key) {
     unsafe { ext_ffi::put_key(name_ptr, name_size, key_ptr, key_size) };
 }
 
+/// Emits an event named `name` carrying the given [`CLValue`].
+///
+/// Events are returned in order in the execution results of the deploy, tagged with the hash of the
+/// emitting contract and the current [`Phase`].  They are not written to global state, and are
+/// discarded if the deploy's execution fails.
+pub fn emit_event(name: &str, value: CLValue) {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let (value_ptr, value_size, _bytes2) = contract_api::to_ptr(value);
+    unsafe { ext_ffi::emit_event(name_ptr, name_size, value_ptr, value_size) }
+}
+
 /// Removes the [`Key`] stored under `name` in the current context's named keys.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -298,6 +309,37 @@ pub fn list_named_keys() -> NamedKeys {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns up to `limit` of the named keys of the current context, ordered by name and starting
+/// after the name `start_after` if given.
+///
+/// Unlike [`list_named_keys`], only the requested page is copied into Wasm memory.  The call is
+/// charged per byte of the page returned, and further pages can be listed by passing the last name
+/// of the previous page as `start_after`; a page with fewer than `limit` entries is the last one.
+pub fn list_named_keys_page(start_after: Option<&str>, limit: u32) -> NamedKeys {
+    let (cursor_ptr, cursor_size, _bytes) = contract_api::to_ptr(start_after);
+    let page_size = {
+        let mut page_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::list_named_keys_page(cursor_ptr, cursor_size, limit, page_size.as_mut_ptr())
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { page_size.assume_init() }
+    };
+    let bytes = read_host_buffer(page_size).unwrap_or_revert();
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// Returns `count` deterministic pseudorandom bytes.
+///
+/// The bytes are derived from the deploy hash and the current [`Phase`], so they are the same for
+/// every validator executing the deploy, and are predictable by the deployer.  Drawing them doesn't
+/// affect the addresses of URefs created later in the deploy.
+pub fn random_bytes(count: usize) -> Vec<u8> {
+    let mut bytes = vec![0; count];
+    unsafe { ext_ffi::random_bytes(bytes.as_mut_ptr(), bytes.len()) };
+    bytes
+}
+
 /// Validates uref against named keys.
 pub fn is_valid_uref(uref: URef) -> bool {
     let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(uref);
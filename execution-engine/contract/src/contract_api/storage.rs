@@ -43,6 +43,25 @@ pub fn read_or_revert<T: CLTyped + FromBytes>(uref: URef) -> T {
         .unwrap_or_revert_with(ApiError::ValueNotFound)
 }
 
+/// Reads the values under each of `urefs` in the global state in a single call to the host.
+///
+/// The values are returned in the same order as `urefs`, with `None` in place of any which aren't
+/// found.
+pub fn read_many(urefs: &[URef]) -> Vec<Option<CLValue>> {
+    let keys: Vec<Key> = urefs.iter().copied().map(Key::from).collect();
+    let (keys_ptr, keys_size, _bytes) = contract_api::to_ptr(keys);
+
+    let values_size = {
+        let mut values_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::read_many(keys_ptr, keys_size, values_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { values_size.assume_init() }
+    };
+
+    let values_bytes = runtime::read_host_buffer(values_size).unwrap_or_revert();
+    bytesrepr::deserialize(values_bytes).unwrap_or_revert()
+}
+
 /// Reads the value under `key` in the context-local partition of global state.
 pub fn read_local<K: ToBytes, V: CLTyped + FromBytes>(
     key: &K,
@@ -65,6 +84,44 @@ pub fn read_local<K: ToBytes, V: CLTyped + FromBytes>(
     Ok(Some(bytesrepr::deserialize(value_bytes)?))
 }
 
+/// Returns up to `limit` of the keys written via [`write_local`] in the current context whose
+/// serialized forms start with `prefix`, ordered by their serialized forms and starting after
+/// `start_after` if given.
+///
+/// The local partition of global state is shared by all contexts, and keys written by other
+/// contracts under `prefix` are skipped but still charged for, so keys should be namespaced by a
+/// common prefix to keep listing cheap.  The call is charged per byte of every key visited, and
+/// further pages can be listed by passing the last key of the previous page as `start_after`; a
+/// page with fewer than `limit` keys is the last one.
+pub fn list_local_keys<K: ToBytes + FromBytes>(
+    prefix: &[u8],
+    start_after: Option<&K>,
+    limit: u32,
+) -> Result<Vec<K>, bytesrepr::Error> {
+    let start_after = start_after.map(ToBytes::to_bytes).transpose()?;
+    let (cursor_ptr, cursor_size, _bytes) = contract_api::to_ptr(start_after);
+
+    let page_size = {
+        let mut page_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::list_local_keys(
+                prefix.as_ptr(),
+                prefix.len(),
+                cursor_ptr,
+                cursor_size,
+                limit,
+                page_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { page_size.assume_init() }
+    };
+
+    let page_bytes = runtime::read_host_buffer(page_size).unwrap_or_revert();
+    let key_bytes: Vec<Vec<u8>> = bytesrepr::deserialize(page_bytes)?;
+    key_bytes.into_iter().map(bytesrepr::deserialize).collect()
+}
+
 /// Writes `value` under `uref` in the global state.
 pub fn write<T: CLTyped + ToBytes>(uref: URef, value: T) {
     let key = Key::from(uref);
@@ -78,6 +135,19 @@ pub fn write<T: CLTyped + ToBytes>(uref: URef, value: T) {
     }
 }
 
+/// Writes each value under its URef in the global state in a single call to the host.
+pub fn write_many(entries: Vec<(URef, CLValue)>) {
+    let entries: Vec<(Key, CLValue)> = entries
+        .into_iter()
+        .map(|(uref, cl_value)| (Key::from(uref), cl_value))
+        .collect();
+    let (entries_ptr, entries_size, _bytes) = contract_api::to_ptr(entries);
+
+    unsafe {
+        ext_ffi::write_many(entries_ptr, entries_size);
+    }
+}
+
 /// Writes `value` under `key` in the context-local partition of global state.
 pub fn write_local<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
     let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(key);
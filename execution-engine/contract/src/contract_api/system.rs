@@ -4,8 +4,8 @@ use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 
 use casperlabs_types::{
-    account::PublicKey, api_error, bytesrepr, ApiError, ContractHash, SystemContractType,
-    TransferResult, TransferredTo, URef, U512, UREF_SERIALIZED_LENGTH,
+    account::PublicKey, api_error, bytesrepr, ApiError, ContractHash, RuntimeArgs,
+    SystemContractType, TransferResult, TransferredTo, URef, U512, UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -93,6 +93,11 @@ pub fn get_balance(purse: URef) -> Option<U512> {
     Some(value)
 }
 
+/// Returns the total supply of motes, as recorded by the Mint contract.
+pub fn get_total_supply() -> U512 {
+    runtime::call_contract(get_mint(), "total_supply", RuntimeArgs::new())
+}
+
 /// Transfers `amount` of motes from the default purse of the account to `target`
 /// account.  If `target` does not exist it will be created.
 pub fn transfer_to_account(target: PublicKey, amount: U512) -> TransferResult {
@@ -626,6 +626,144 @@ extern "C" {
         urefs_ptr: *const u8,
         urefs_size: usize,
     ) -> i32;
+    /// Emits an event, which is returned to the caller of the deploy along with its execution
+    /// results.  Events are not written to global state.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_ptr` - pointer to serialized event name
+    /// * `name_size` - size of serialized event name
+    /// * `value_ptr` - pointer to serialized event value, a `CLValue`
+    /// * `value_size` - size of serialized event value
+    pub fn emit_event(
+        name_ptr: *const u8,
+        name_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    );
+    /// Lists up to `limit` of the current context's named keys, ordered by name.  The page is
+    /// serialized as a [`NamedKeys`](casperlabs_types::contracts::NamedKeys) map and buffered in
+    /// the runtime, and can be obtained via the [`read_host_buffer`] function.  Returns standard
+    /// error code.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor_ptr` - pointer to serialized cursor, an `Option<String>` holding the name after
+    ///   which the page starts
+    /// * `cursor_size` - size of serialized cursor
+    /// * `limit` - maximum number of named keys to list
+    /// * `output_size` - pointer to a value where host will write size of the serialized page
+    pub fn list_named_keys_page(
+        cursor_ptr: *const u8,
+        cursor_size: usize,
+        limit: u32,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Lists up to `limit` of the local keys starting with the given prefix which were written in
+    /// the current context, ordered by their bytes.  The page is serialized as a `Vec<Vec<u8>>` and
+    /// buffered in the runtime, and can be obtained via the [`read_host_buffer`] function.
+    /// Returns standard error code.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix_ptr` - pointer to bytes which the listed keys start with
+    /// * `prefix_size` - size of the prefix (in bytes)
+    /// * `cursor_ptr` - pointer to serialized cursor, an `Option<Vec<u8>>` holding the key after
+    ///   which the page starts
+    /// * `cursor_size` - size of serialized cursor
+    /// * `limit` - maximum number of local keys to list
+    /// * `output_size` - pointer to a value where host will write size of the serialized page
+    pub fn list_local_keys(
+        prefix_ptr: *const u8,
+        prefix_size: usize,
+        cursor_ptr: *const u8,
+        cursor_size: usize,
+        limit: u32,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Writes the 32-byte BLAKE2b digest with a 256-bit output of the given data to `dest_ptr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_ptr` - pointer to the data to hash
+    /// * `data_size` - size of the data (in bytes)
+    /// * `dest_ptr` - pointer to 32 bytes of wasm memory where host will write the digest
+    pub fn blake2b256(data_ptr: *const u8, data_size: usize, dest_ptr: *mut u8);
+    /// Writes the 32-byte SHA-256 digest of the given data to `dest_ptr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_ptr` - pointer to the data to hash
+    /// * `data_size` - size of the data (in bytes)
+    /// * `dest_ptr` - pointer to 32 bytes of wasm memory where host will write the digest
+    pub fn sha256(data_ptr: *const u8, data_size: usize, dest_ptr: *mut u8);
+    /// Writes the 32-byte Keccak-256 digest, as used by Ethereum, of the given data to `dest_ptr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_ptr` - pointer to the data to hash
+    /// * `data_size` - size of the data (in bytes)
+    /// * `dest_ptr` - pointer to 32 bytes of wasm memory where host will write the digest
+    pub fn keccak256(data_ptr: *const u8, data_size: usize, dest_ptr: *mut u8);
+    /// Returns `1` if the signature is a valid Ed25519 signature of the message by the public key,
+    /// or `0` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_ptr` - pointer to the signed message
+    /// * `message_size` - size of the message (in bytes)
+    /// * `signature_ptr` - pointer to the 64-byte signature
+    /// * `public_key_ptr` - pointer to the 32-byte public key
+    pub fn verify_ed25519(
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        public_key_ptr: *const u8,
+    ) -> i32;
+    /// Recovers the Secp256k1 public key which signed the message hash, writing it to `dest_ptr`
+    /// in compressed form.  Returns `1` if a public key was recovered, or `0` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_hash_ptr` - pointer to the 32-byte hash of the signed message
+    /// * `signature_ptr` - pointer to the 65-byte recoverable signature: the compact signature
+    ///   followed by the recovery ID, given either as 0 to 3 or as 27 to 30
+    /// * `dest_ptr` - pointer to 33 bytes of wasm memory where host will write the public key
+    pub fn recover_secp256k1(
+        message_hash_ptr: *const u8,
+        signature_ptr: *const u8,
+        dest_ptr: *mut u8,
+    ) -> i32;
+    /// Writes deterministic pseudorandom bytes to `dest_ptr`.
+    ///
+    /// The bytes are derived from the deploy hash and the current phase, so every validator
+    /// executing the deploy sees the same sequence.  Drawing them doesn't affect the addresses of
+    /// URefs created later in the deploy.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer to wasm memory where host will write the random bytes
+    /// * `dest_size` - number of random bytes to write
+    pub fn random_bytes(dest_ptr: *mut u8, dest_size: usize);
+    /// Reads the values under each of the given global state keys in a single call.  The values are
+    /// serialized as a `Vec<Option<CLValue>>` in the same order as the keys and buffered in the
+    /// runtime, and can be obtained via the [`read_host_buffer`] function. Returns standard error
+    /// code.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys_ptr` - pointer to a serialized `Vec<Key>` of the keys to read
+    /// * `keys_size` - size of the serialized keys (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of bytes read
+    pub fn read_many(keys_ptr: *const u8, keys_size: usize, output_size: *mut usize) -> i32;
+    /// Writes each value to its global state key in a single call.  Every entry is checked in the
+    /// same way as by [`write`] before any of them is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries_ptr` - pointer to a serialized `Vec<(Key, CLValue)>` of the entries to write
+    /// * `entries_size` - size of the serialized entries (in bytes)
+    pub fn write_many(entries_ptr: *const u8, entries_size: usize);
 
     /// Prints data directly to stanadard output on the host.
     ///
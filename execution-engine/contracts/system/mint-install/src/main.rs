@@ -1,11 +1,15 @@
 #![no_std]
 #![no_main]
 
+extern crate alloc;
+
+use alloc::string::String;
+
 use contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use types::{contracts::NamedKeys, CLValue};
+use types::{contracts::NamedKeys, CLValue, U512};
 
 const HASH_KEY_NAME: &str = "mint_hash";
 const ACCESS_KEY_NAME: &str = "mint_access";
@@ -30,6 +34,21 @@ pub extern "C" fn transfer() {
     mint_token::transfer();
 }
 
+#[no_mangle]
+pub extern "C" fn mint_reward() {
+    mint_token::mint_reward();
+}
+
+#[no_mangle]
+pub extern "C" fn burn() {
+    mint_token::burn();
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    mint_token::total_supply();
+}
+
 #[no_mangle]
 pub extern "C" fn install() {
     let entry_points = mint_token::get_entry_points();
@@ -38,7 +57,11 @@ pub extern "C" fn install() {
     runtime::put_key(HASH_KEY_NAME, contract_package_hash.into());
     runtime::put_key(ACCESS_KEY_NAME, access_uref.into());
 
-    let named_keys = NamedKeys::new();
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(mint_token::TOTAL_SUPPLY_KEY),
+        storage::new_uref(U512::zero()).into(),
+    );
 
     let (contract_key, _contract_version) =
         storage::add_contract_version(contract_package_hash, entry_points, named_keys);
@@ -20,3 +20,18 @@ pub extern "C" fn balance() {
 pub extern "C" fn transfer() {
     mint_token::transfer();
 }
+
+#[no_mangle]
+pub extern "C" fn mint_reward() {
+    mint_token::mint_reward();
+}
+
+#[no_mangle]
+pub extern "C" fn burn() {
+    mint_token::burn();
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    mint_token::total_supply();
+}
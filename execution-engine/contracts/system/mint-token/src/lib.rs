@@ -9,6 +9,7 @@ use contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
+pub use mint::TOTAL_SUPPLY_KEY;
 use mint::{Mint, RuntimeProvider, StorageProvider};
 use types::{
     account::PublicKey,
@@ -23,6 +24,9 @@ pub const METHOD_MINT: &str = "mint";
 pub const METHOD_CREATE: &str = "create";
 pub const METHOD_BALANCE: &str = "balance";
 pub const METHOD_TRANSFER: &str = "transfer";
+pub const METHOD_MINT_REWARD: &str = "mint_reward";
+pub const METHOD_BURN: &str = "burn";
+pub const METHOD_TOTAL_SUPPLY: &str = "total_supply";
 
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_PURSE: &str = "purse";
@@ -36,6 +40,10 @@ impl RuntimeProvider for MintContract {
         runtime::get_caller()
     }
 
+    fn get_key(&self, name: &str) -> Option<Key> {
+        runtime::get_key(name)
+    }
+
     fn put_key(&mut self, name: &str, key: Key) {
         runtime::put_key(name, key)
     }
@@ -107,6 +115,31 @@ pub fn transfer() {
     runtime::ret(ret);
 }
 
+pub fn mint_reward() {
+    let mut mint_contract = MintContract;
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let result: Result<(), Error> = mint_contract.mint_reward(purse, amount);
+    let ret = CLValue::from_t(result).unwrap_or_revert();
+    runtime::ret(ret);
+}
+
+pub fn burn() {
+    let mut mint_contract = MintContract;
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let result: Result<(), Error> = mint_contract.burn(purse, amount);
+    let ret = CLValue::from_t(result).unwrap_or_revert();
+    runtime::ret(ret);
+}
+
+pub fn total_supply() {
+    let mut mint_contract = MintContract;
+    let total_supply: U512 = mint_contract.total_supply().unwrap_or_revert();
+    let ret = CLValue::from_t(total_supply).unwrap_or_revert();
+    runtime::ret(ret)
+}
+
 pub fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
 
@@ -156,5 +189,44 @@ pub fn get_entry_points() -> EntryPoints {
     );
     entry_points.add_entry_point(entry_point);
 
+    let entry_point = EntryPoint::new(
+        METHOD_MINT_REWARD,
+        vec![
+            Parameter::new(ARG_PURSE, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_BURN,
+        vec![
+            Parameter::new(ARG_PURSE, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_TOTAL_SUPPLY,
+        Parameters::new(),
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
     entry_points
 }
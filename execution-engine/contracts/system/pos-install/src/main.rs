@@ -11,10 +11,13 @@ use contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use pos::{
-    ARG_ACCOUNT_KEY, ARG_AMOUNT, ARG_PURSE, METHOD_BOND, METHOD_FINALIZE_PAYMENT,
-    METHOD_GET_PAYMENT_PURSE, METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE, METHOD_UNBOND,
+    ARG_ACCOUNT_KEY, ARG_AMOUNT, ARG_PURSE, ARG_REWARDS, ARG_REWARD_RATE, ARG_VALIDATOR,
+    ARG_VALIDATORS, METHOD_BOND, METHOD_DELEGATE, METHOD_DISTRIBUTE_REWARDS,
+    METHOD_DISTRIBUTE_SEIGNIORAGE, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
+    METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE, METHOD_SLASH, METHOD_STEP, METHOD_UNBOND,
+    METHOD_UNBOND_PAYOUT, METHOD_UNDELEGATE,
 };
-use proof_of_stake::Stakes;
+use proof_of_stake::{Delegations, QueueDelays, StakeLimits, Stakes};
 use types::{
     account::PublicKey,
     contracts::{
@@ -23,16 +26,21 @@ use types::{
     },
     runtime_args,
     system_contract_errors::mint,
-    CLType, CLValue, ContractPackageHash, Key, RuntimeArgs, URef, U512,
+    CLType, CLTyped, CLValue, ContractPackageHash, Key, RuntimeArgs, URef, U512,
 };
 
 const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+const POS_QUEUE_DELAYS: &str = "pos_queue_delays";
+const POS_STAKE_LIMITS: &str = "pos_stake_limits";
+const POS_DELEGATIONS: &str = "pos_delegations";
 
 const ARG_MINT_PACKAGE_HASH: &str = "mint_contract_package_hash";
 const ARG_GENESIS_VALIDATORS: &str = "genesis_validators";
+const ARG_QUEUE_DELAYS: &str = "queue_delays";
+const ARG_STAKE_LIMITS: &str = "stake_limits";
 const ENTRY_POINT_MINT: &str = "mint";
 
 const HASH_KEY_NAME: &str = "pos_hash";
@@ -48,6 +56,16 @@ pub extern "C" fn unbond() {
     pos::unbond();
 }
 
+#[no_mangle]
+pub extern "C" fn delegate() {
+    pos::delegate();
+}
+
+#[no_mangle]
+pub extern "C" fn undelegate() {
+    pos::undelegate();
+}
+
 #[no_mangle]
 pub extern "C" fn get_payment_purse() {
     pos::get_payment_purse();
@@ -68,11 +86,38 @@ pub extern "C" fn finalize_payment() {
     pos::finalize_payment();
 }
 
+#[no_mangle]
+pub extern "C" fn slash() {
+    pos::slash();
+}
+
+#[no_mangle]
+pub extern "C" fn distribute_rewards() {
+    pos::distribute_rewards();
+}
+
+#[no_mangle]
+pub extern "C" fn unbond_payout() {
+    pos::unbond_payout();
+}
+
+#[no_mangle]
+pub extern "C" fn step() {
+    pos::step();
+}
+
+#[no_mangle]
+pub extern "C" fn distribute_seigniorage() {
+    pos::distribute_seigniorage();
+}
+
 #[no_mangle]
 pub extern "C" fn install() {
     let mint_package_hash: ContractPackageHash = runtime::get_named_arg(ARG_MINT_PACKAGE_HASH);
     let genesis_validators: BTreeMap<PublicKey, U512> =
         runtime::get_named_arg(ARG_GENESIS_VALIDATORS);
+    let queue_delays: QueueDelays = runtime::get_named_arg(ARG_QUEUE_DELAYS);
+    let stake_limits: StakeLimits = runtime::get_named_arg(ARG_STAKE_LIMITS);
 
     let stakes = Stakes::new(genesis_validators);
 
@@ -97,6 +142,22 @@ pub extern "C" fn install() {
         named_keys.insert(String::from(*name), Key::URef(*uref));
     });
 
+    // Include the PoS configuration in its named_keys
+    named_keys.insert(
+        String::from(POS_QUEUE_DELAYS),
+        Key::URef(storage::new_uref(queue_delays)),
+    );
+    named_keys.insert(
+        String::from(POS_STAKE_LIMITS),
+        Key::URef(storage::new_uref(stake_limits)),
+    );
+
+    // Include the delegator books, initially empty, in its named_keys
+    named_keys.insert(
+        String::from(POS_DELEGATIONS),
+        Key::URef(storage::new_uref(Delegations::default())),
+    );
+
     let entry_points = {
         let mut entry_points = EntryPoints::new();
 
@@ -121,6 +182,31 @@ pub extern "C" fn install() {
         );
         entry_points.add_entry_point(unbond);
 
+        let delegate = EntryPoint::new(
+            METHOD_DELEGATE.to_string(),
+            vec![
+                Parameter::new(ARG_VALIDATOR, CLType::PublicKey),
+                Parameter::new(ARG_AMOUNT, CLType::U512),
+                Parameter::new(ARG_PURSE, CLType::URef),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(delegate);
+
+        let undelegate = EntryPoint::new(
+            METHOD_UNDELEGATE.to_string(),
+            vec![
+                Parameter::new(ARG_VALIDATOR, CLType::PublicKey),
+                Parameter::new(ARG_AMOUNT, CLType::Option(Box::new(CLType::U512))),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(undelegate);
+
         let get_payment_purse = EntryPoint::new(
             METHOD_GET_PAYMENT_PURSE.to_string(),
             vec![],
@@ -152,7 +238,7 @@ pub extern "C" fn install() {
             METHOD_FINALIZE_PAYMENT.to_string(),
             vec![
                 Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ACCOUNT_KEY, CLType::FixedList(Box::new(CLType::U8), 32)),
+                Parameter::new(ARG_ACCOUNT_KEY, CLType::PublicKey),
             ],
             CLType::Unit,
             EntryPointAccess::Public,
@@ -160,6 +246,57 @@ pub extern "C" fn install() {
         );
         entry_points.add_entry_point(finalize_payment);
 
+        let slash = EntryPoint::new(
+            METHOD_SLASH.to_string(),
+            vec![Parameter::new(
+                ARG_VALIDATORS,
+                BTreeMap::<PublicKey, U512>::cl_type(),
+            )],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(slash);
+
+        let distribute_rewards = EntryPoint::new(
+            METHOD_DISTRIBUTE_REWARDS.to_string(),
+            vec![Parameter::new(
+                ARG_REWARDS,
+                BTreeMap::<PublicKey, U512>::cl_type(),
+            )],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(distribute_rewards);
+
+        let unbond_payout = EntryPoint::new(
+            METHOD_UNBOND_PAYOUT.to_string(),
+            vec![],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(unbond_payout);
+
+        let step = EntryPoint::new(
+            METHOD_STEP.to_string(),
+            vec![],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(step);
+
+        let distribute_seigniorage = EntryPoint::new(
+            METHOD_DISTRIBUTE_SEIGNIORAGE.to_string(),
+            vec![Parameter::new(ARG_REWARD_RATE, CLType::U64)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(distribute_seigniorage);
+
         entry_points
     };
 
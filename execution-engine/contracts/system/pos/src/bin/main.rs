@@ -11,6 +11,16 @@ pub extern "C" fn unbond() {
     pos::unbond();
 }
 
+#[no_mangle]
+pub extern "C" fn delegate() {
+    pos::delegate();
+}
+
+#[no_mangle]
+pub extern "C" fn undelegate() {
+    pos::undelegate();
+}
+
 #[no_mangle]
 pub extern "C" fn get_payment_purse() {
     pos::get_payment_purse();
@@ -30,3 +40,28 @@ pub extern "C" fn get_refund_purse() {
 pub extern "C" fn finalize_payment() {
     pos::finalize_payment();
 }
+
+#[no_mangle]
+pub extern "C" fn slash() {
+    pos::slash();
+}
+
+#[no_mangle]
+pub extern "C" fn distribute_rewards() {
+    pos::distribute_rewards();
+}
+
+#[no_mangle]
+pub extern "C" fn unbond_payout() {
+    pos::unbond_payout();
+}
+
+#[no_mangle]
+pub extern "C" fn step() {
+    pos::step();
+}
+
+#[no_mangle]
+pub extern "C" fn distribute_seigniorage() {
+    pos::distribute_seigniorage();
+}
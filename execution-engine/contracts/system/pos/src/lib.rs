@@ -6,32 +6,53 @@ use alloc::{
     collections::{BTreeMap, BTreeSet},
     string::String,
 };
+use core::convert::TryFrom;
 
 use contract::{
     contract_api::{runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    Delegations, DelegationsProvider, MintProvider, ProofOfStake, Queue, QueueDelays,
+    QueueProvider, RuntimeProvider, StakeLimits, Stakes, StakesProvider,
 };
 use types::{
-    account::PublicKey, system_contract_errors::pos::Error, ApiError, BlockTime, CLValue, Key,
-    Phase, TransferResult, URef, U512,
+    account::{PublicKey, ED25519_LENGTH, SECP256K1_LENGTH},
+    runtime_args,
+    system_contract_errors::{mint, pos::Error},
+    ApiError, BlockTime, CLValue, Key, Phase, RuntimeArgs, TransferResult, URef, U512,
 };
 
 pub const METHOD_BOND: &str = "bond";
 pub const METHOD_UNBOND: &str = "unbond";
+pub const METHOD_DELEGATE: &str = "delegate";
+pub const METHOD_UNDELEGATE: &str = "undelegate";
 pub const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
 pub const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
 pub const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
 pub const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+pub const METHOD_SLASH: &str = "slash";
+pub const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+pub const METHOD_UNBOND_PAYOUT: &str = "unbond_payout";
+pub const METHOD_STEP: &str = "step";
+pub const METHOD_DISTRIBUTE_SEIGNIORAGE: &str = "distribute_seigniorage";
+
+const METHOD_MINT_REWARD: &str = "mint_reward";
 
 const BONDING_KEY: u8 = 1;
 const UNBONDING_KEY: u8 = 2;
 
+const QUEUE_DELAYS_KEY: &str = "pos_queue_delays";
+const STAKE_LIMITS_KEY: &str = "pos_stake_limits";
+const DELEGATIONS_KEY: &str = "pos_delegations";
+
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_PURSE: &str = "purse";
+pub const ARG_VALIDATOR: &str = "validator";
 pub const ARG_ACCOUNT_KEY: &str = "account";
+pub const ARG_VALIDATORS: &str = "validators";
+pub const ARG_REWARDS: &str = "rewards";
+pub const ARG_REWARD_RATE: &str = "reward_rate";
 
 pub struct ProofOfStakeContract;
 
@@ -57,6 +78,36 @@ impl MintProvider for ProofOfStakeContract {
     fn balance(&mut self, purse: URef) -> Option<U512> {
         system::get_balance(purse)
     }
+
+    fn mint_reward(&mut self, purse: URef, amount: U512) -> Result<(), ()> {
+        let args = runtime_args! {
+            ARG_PURSE => purse,
+            ARG_AMOUNT => amount,
+        };
+        let result: Result<(), mint::Error> =
+            runtime::call_contract(system::get_mint(), METHOD_MINT_REWARD, args);
+        result.map_err(|_| ())
+    }
+}
+
+impl DelegationsProvider for ProofOfStakeContract {
+    /// Reads the delegator books from the contract's named keys.
+    fn read_delegations(&mut self) -> Result<Delegations, Error> {
+        let uref = runtime::get_key(DELEGATIONS_KEY)
+            .and_then(Key::into_uref)
+            .ok_or(Error::DelegationsNotFound)?;
+        storage::read(uref)
+            .map_err(|_| Error::DelegationsDeserializationFailed)?
+            .ok_or(Error::DelegationsNotFound)
+    }
+
+    /// Writes the delegator books to the contract's named keys.
+    fn write_delegations(&mut self, delegations: Delegations) {
+        let uref = runtime::get_key(DELEGATIONS_KEY)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert_with(Error::DelegationsNotFound);
+        storage::write(uref, delegations);
+    }
 }
 
 impl QueueProvider for ProofOfStakeContract {
@@ -83,6 +134,16 @@ impl QueueProvider for ProofOfStakeContract {
     fn write_unbonding(&mut self, queue: Queue) {
         storage::write_local(UNBONDING_KEY, queue);
     }
+
+    /// Reads the queue delays from the contract's named keys.
+    fn read_delays(&mut self) -> Result<QueueDelays, Error> {
+        let uref = runtime::get_key(QUEUE_DELAYS_KEY)
+            .and_then(Key::into_uref)
+            .ok_or(Error::QueueDelaysNotFound)?;
+        storage::read(uref)
+            .map_err(|_| Error::QueueDelaysDeserializationFailed)?
+            .ok_or(Error::QueueDelaysNotFound)
+    }
 }
 
 impl RuntimeProvider for ProofOfStakeContract {
@@ -123,14 +184,18 @@ impl StakesProvider for ProofOfStakeContract {
             let hex_key = split_name
                 .next()
                 .ok_or(Error::StakesKeyDeserializationFailed)?;
-            if hex_key.len() != 64 {
+            let key_length = hex_key.len() / 2;
+            if hex_key.len() % 2 != 0
+                || (key_length != ED25519_LENGTH && key_length != SECP256K1_LENGTH)
+            {
                 return Err(Error::StakesKeyDeserializationFailed);
             }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+            let mut key_bytes = [0u8; SECP256K1_LENGTH];
+            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes[..key_length])
+                .map_err(|_| Error::StakesKeyDeserializationFailed)?;
+            debug_assert!(_bytes_written == key_length);
+            let pub_key = PublicKey::try_from(&key_bytes[..key_length])
                 .map_err(|_| Error::StakesKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            let pub_key = PublicKey::ed25519_from(key_bytes);
             let balance = split_name
                 .next()
                 .and_then(|b| U512::from_dec_str(b).ok())
@@ -157,6 +222,16 @@ impl StakesProvider for ProofOfStakeContract {
             runtime::put_key(&name, Key::Hash([0; 32]));
         }
     }
+
+    /// Reads the stake limits from the contract's named keys.
+    fn read_limits(&mut self) -> Result<StakeLimits, Error> {
+        let uref = runtime::get_key(STAKE_LIMITS_KEY)
+            .and_then(Key::into_uref)
+            .ok_or(Error::StakeLimitsNotFound)?;
+        storage::read(uref)
+            .map_err(|_| Error::StakeLimitsDeserializationFailed)?
+            .ok_or(Error::StakeLimitsNotFound)
+    }
 }
 
 impl ProofOfStake for ProofOfStakeContract {}
@@ -191,6 +266,38 @@ pub fn unbond() {
         .unwrap_or_revert();
 }
 
+pub fn delegate() {
+    if !cfg!(feature = "enable-bonding") {
+        runtime::revert(ApiError::Unhandled)
+    }
+
+    let delegator = runtime::get_caller();
+    let validator: PublicKey = runtime::get_named_arg(ARG_VALIDATOR);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    // source purse
+    let source: URef = runtime::get_named_arg(ARG_PURSE);
+
+    let mut pos_contract = ProofOfStakeContract;
+    pos_contract
+        .delegate(delegator, validator, amount, source)
+        .unwrap_or_revert();
+}
+
+pub fn undelegate() {
+    if !cfg!(feature = "enable-bonding") {
+        runtime::revert(ApiError::Unhandled)
+    }
+
+    let delegator = runtime::get_caller();
+    let validator: PublicKey = runtime::get_named_arg(ARG_VALIDATOR);
+    let maybe_amount = runtime::get_named_arg(ARG_AMOUNT);
+
+    let mut pos_contract = ProofOfStakeContract;
+    pos_contract
+        .undelegate(delegator, validator, maybe_amount)
+        .unwrap_or_revert();
+}
+
 pub fn get_payment_purse() {
     let pos_contract = ProofOfStakeContract;
     let rights_controlled_purse = pos_contract.get_payment_purse().unwrap_or_revert();
@@ -226,3 +333,36 @@ pub fn finalize_payment() {
         .finalize_payment(amount_spent, account)
         .unwrap_or_revert();
 }
+
+pub fn slash() {
+    let mut pos_contract = ProofOfStakeContract;
+
+    let validators: BTreeMap<PublicKey, U512> = runtime::get_named_arg(ARG_VALIDATORS);
+    pos_contract.slash(validators).unwrap_or_revert();
+}
+
+pub fn distribute_rewards() {
+    let mut pos_contract = ProofOfStakeContract;
+
+    let rewards: BTreeMap<PublicKey, U512> = runtime::get_named_arg(ARG_REWARDS);
+    pos_contract.distribute_rewards(rewards).unwrap_or_revert();
+}
+
+pub fn unbond_payout() {
+    let mut pos_contract = ProofOfStakeContract;
+    pos_contract.unbond_payout().unwrap_or_revert();
+}
+
+pub fn step() {
+    let mut pos_contract = ProofOfStakeContract;
+    pos_contract.step().unwrap_or_revert();
+}
+
+pub fn distribute_seigniorage() {
+    let mut pos_contract = ProofOfStakeContract;
+
+    let reward_rate: u64 = runtime::get_named_arg(ARG_REWARD_RATE);
+    pos_contract
+        .distribute_seigniorage(reward_rate)
+        .unwrap_or_revert();
+}
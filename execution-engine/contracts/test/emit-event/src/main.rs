@@ -0,0 +1,65 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    ApiError, CLType, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    RuntimeArgs,
+};
+
+const ENTRY_POINT_NAME: &str = "emit";
+const HASH_KEY_NAME: &str = "emit_event";
+const ACCESS_KEY_NAME: &str = "emit_event_access";
+const ARG_REVERT: &str = "revert";
+
+const EVENT_BEFORE_CALL: &str = "before_call";
+const EVENT_FROM_CONTRACT: &str = "from_contract";
+const EVENT_AFTER_CALL: &str = "after_call";
+
+#[no_mangle]
+pub extern "C" fn emit() {
+    runtime::emit_event(
+        EVENT_FROM_CONTRACT,
+        CLValue::from_t(2u64).unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let revert: bool = runtime::get_named_arg(ARG_REVERT);
+
+    runtime::emit_event(EVENT_BEFORE_CALL, CLValue::from_t(1u64).unwrap_or_revert());
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_POINT_NAME.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+    let (contract_hash, _contract_version) = storage::new_contract(
+        entry_points,
+        None,
+        Some(HASH_KEY_NAME.to_string()),
+        Some(ACCESS_KEY_NAME.to_string()),
+    );
+    runtime::call_contract::<()>(contract_hash, ENTRY_POINT_NAME, RuntimeArgs::default());
+
+    runtime::emit_event(EVENT_AFTER_CALL, CLValue::from_t(3u64).unwrap_or_revert());
+
+    if revert {
+        runtime::revert(ApiError::User(1));
+    }
+}
@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{crypto, runtime};
+
+const ARG_DATA_SIZE: &str = "data_size";
+const DATA: [u8; 1024] = [0; 1024];
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let data_size: u32 = runtime::get_named_arg(ARG_DATA_SIZE);
+    crypto::blake2b256(&DATA[..data_size as usize]);
+}
@@ -0,0 +1,57 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::{crypto, runtime};
+use types::account::{
+    PublicKey, ED25519_LENGTH, ED25519_SIGNATURE_LENGTH, SECP256K1_LENGTH,
+    SECP256K1_RECOVERABLE_SIGNATURE_LENGTH,
+};
+
+const ARG_DATA: &str = "data";
+const ARG_BLAKE2B256: &str = "blake2b256";
+const ARG_SHA256: &str = "sha256";
+const ARG_KECCAK256: &str = "keccak256";
+const ARG_ED25519_SIGNATURE: &str = "ed25519_signature";
+const ARG_ED25519_PUBLIC_KEY: &str = "ed25519_public_key";
+const ARG_SECP256K1_SIGNATURE: &str = "secp256k1_signature";
+const ARG_SECP256K1_PUBLIC_KEY: &str = "secp256k1_public_key";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let data: Vec<u8> = runtime::get_named_arg(ARG_DATA);
+
+    let expected_digest: Vec<u8> = runtime::get_named_arg(ARG_BLAKE2B256);
+    assert_eq!(&crypto::blake2b256(&data)[..], &expected_digest[..]);
+    let expected_digest: Vec<u8> = runtime::get_named_arg(ARG_SHA256);
+    assert_eq!(&crypto::sha256(&data)[..], &expected_digest[..]);
+    let expected_digest: Vec<u8> = runtime::get_named_arg(ARG_KECCAK256);
+    assert_eq!(&crypto::keccak256(&data)[..], &expected_digest[..]);
+
+    // The Ed25519 signature is of an empty message.
+    let mut signature = [0; ED25519_SIGNATURE_LENGTH];
+    signature.copy_from_slice(&runtime::get_named_arg::<Vec<u8>>(ARG_ED25519_SIGNATURE));
+    let mut public_key = [0; ED25519_LENGTH];
+    public_key.copy_from_slice(&runtime::get_named_arg::<Vec<u8>>(ARG_ED25519_PUBLIC_KEY));
+    assert!(crypto::verify_ed25519(&[], &signature, &public_key));
+    assert!(!crypto::verify_ed25519(&data, &signature, &public_key));
+
+    // The Secp256k1 signature is of the Keccak-256 digest of the data.
+    let mut signature = [0; SECP256K1_RECOVERABLE_SIGNATURE_LENGTH];
+    signature.copy_from_slice(&runtime::get_named_arg::<Vec<u8>>(ARG_SECP256K1_SIGNATURE));
+    let mut public_key = [0; SECP256K1_LENGTH];
+    public_key.copy_from_slice(&runtime::get_named_arg::<Vec<u8>>(ARG_SECP256K1_PUBLIC_KEY));
+    let expected_public_key = PublicKey::secp256k1_from(public_key);
+    let message_hash = crypto::keccak256(&data);
+    assert_eq!(
+        crypto::recover_secp256k1(&message_hash, &signature),
+        Some(expected_public_key)
+    );
+    assert_ne!(
+        crypto::recover_secp256k1(&crypto::sha256(&data), &signature),
+        Some(expected_public_key)
+    );
+}
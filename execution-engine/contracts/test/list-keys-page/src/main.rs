@@ -0,0 +1,65 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{contracts::NamedKeys, Key};
+
+const ARG_COUNT: &str = "count";
+const ARG_LIMIT: &str = "limit";
+const LOCAL_KEY_PREFIX: [u8; 2] = [7, 7];
+
+fn local_key(index: u8) -> [u8; 32] {
+    let mut key = [0; 32];
+    key[..LOCAL_KEY_PREFIX.len()].copy_from_slice(&LOCAL_KEY_PREFIX);
+    key[31] = index;
+    key
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let count: u8 = runtime::get_named_arg(ARG_COUNT);
+    let limit: u32 = runtime::get_named_arg(ARG_LIMIT);
+
+    for index in 0..count {
+        runtime::put_key(&format!("key_{}", index), Key::Hash([index; 32]));
+        storage::write_local(local_key(index), u64::from(index));
+    }
+
+    // Paging through the named keys should yield the full listing.
+    let mut named_keys = NamedKeys::new();
+    let mut start_after: Option<String> = None;
+    loop {
+        let page = runtime::list_named_keys_page(start_after.as_deref(), limit);
+        let page_len = page.len();
+        assert!(page_len <= limit as usize, "page exceeds limit");
+        start_after = page.keys().next_back().cloned();
+        named_keys.extend(page);
+        if page_len < limit as usize {
+            break;
+        }
+    }
+    assert_eq!(named_keys, runtime::list_named_keys());
+
+    // Paging through the local keys under the prefix should yield those written above, in order.
+    let mut local_keys: Vec<[u8; 32]> = Vec::new();
+    loop {
+        let page: Vec<[u8; 32]> =
+            storage::list_local_keys(&LOCAL_KEY_PREFIX, local_keys.last(), limit)
+                .unwrap_or_revert();
+        let page_len = page.len();
+        assert!(page_len <= limit as usize, "page exceeds limit");
+        local_keys.extend(page);
+        if page_len < limit as usize {
+            break;
+        }
+    }
+    let expected_local_keys: Vec<[u8; 32]> = (0..count).map(local_key).collect();
+    assert_eq!(local_keys, expected_local_keys);
+}
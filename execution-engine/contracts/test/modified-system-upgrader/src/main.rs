@@ -153,7 +153,7 @@ fn upgrade_proof_of_stake() -> (ContractHash, ContractVersion) {
             METHOD_FINALIZE_PAYMENT,
             vec![
                 Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ACCOUNT_KEY, CLType::FixedList(Box::new(CLType::U8), 32)),
+                Parameter::new(ARG_ACCOUNT_KEY, CLType::PublicKey),
             ],
             CLType::Unit,
             EntryPointAccess::Public,
@@ -0,0 +1,29 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_DRAW_RANDOM_BYTES: &str = "draw_random_bytes";
+const RANDOM_BYTES_KEY_NAME: &str = "random_bytes";
+const UREF_KEY_NAME: &str = "uref";
+const RANDOM_BYTES_COUNT: usize = 32;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let draw_random_bytes: bool = runtime::get_named_arg(ARG_DRAW_RANDOM_BYTES);
+
+    let random_bytes = if draw_random_bytes {
+        Some(runtime::random_bytes(RANDOM_BYTES_COUNT))
+    } else {
+        None
+    };
+
+    // The address of this URef mustn't depend on whether random bytes were drawn beforehand.
+    let uref = storage::new_uref(());
+    runtime::put_key(UREF_KEY_NAME, uref.into());
+
+    if let Some(random_bytes) = random_bytes {
+        let random_bytes_uref = storage::new_uref(random_bytes);
+        runtime::put_key(RANDOM_BYTES_KEY_NAME, random_bytes_uref.into());
+    }
+}
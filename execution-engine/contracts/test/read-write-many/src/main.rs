@@ -0,0 +1,39 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{AccessRights, CLValue, URef};
+
+const ARG_FORGE_UREF: &str = "forge_uref";
+const NUMBER_KEY_NAME: &str = "number";
+const TEXT_KEY_NAME: &str = "text";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let forge_uref: bool = runtime::get_named_arg(ARG_FORGE_UREF);
+
+    let number_uref = storage::new_uref(1i32);
+    let text_uref = storage::new_uref(String::from("Hello"));
+
+    let number = CLValue::from_t(2i32).unwrap_or_revert();
+    let text = CLValue::from_t(String::from("World")).unwrap_or_revert();
+    let mut entries = vec![(number_uref, number.clone()), (text_uref, text.clone())];
+    if forge_uref {
+        let forged_uref = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        entries.push((forged_uref, number.clone()));
+    }
+    storage::write_many(entries);
+
+    let values = storage::read_many(&[number_uref, text_uref]);
+    assert_eq!(values, vec![Some(number), Some(text)]);
+
+    runtime::put_key(NUMBER_KEY_NAME, number_uref.into());
+    runtime::put_key(TEXT_KEY_NAME, text_uref.into());
+}
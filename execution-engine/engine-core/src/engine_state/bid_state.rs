@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+pub enum BidStateResult {
+    RootNotFound,
+    Success { bids: HashMap<PublicKey, U512> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidStateRequest {
+    pre_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+}
+
+impl BidStateRequest {
+    pub fn new(pre_state_hash: Blake2bHash, protocol_version: ProtocolVersion) -> Self {
+        BidStateRequest {
+            pre_state_hash,
+            protocol_version,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+}
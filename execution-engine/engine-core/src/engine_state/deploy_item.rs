@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use types::account::PublicKey;
+use types::{account::PublicKey, BlockTime};
 
 use crate::{engine_state::executable_deploy_item::ExecutableDeployItem, DeployHash};
 
@@ -15,10 +15,17 @@ pub struct DeployItem {
     pub gas_price: GasPrice,
     pub authorization_keys: BTreeSet<PublicKey>,
     pub deploy_hash: DeployHash,
+    pub nonce: u64,
+    /// The earliest block time, in milliseconds, at which the deploy may be executed.
+    pub valid_from: Option<u64>,
+    /// The number of milliseconds after `valid_from` for which the deploy may be executed.  A
+    /// deploy with a `ttl` but no `valid_from` is never valid.
+    pub ttl: Option<u64>,
 }
 
 impl DeployItem {
     /// Creates a [`DeployItem`].
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         address: PublicKey,
         session: ExecutableDeployItem,
@@ -26,6 +33,9 @@ impl DeployItem {
         gas_price: GasPrice,
         authorization_keys: BTreeSet<PublicKey>,
         deploy_hash: DeployHash,
+        nonce: u64,
+        valid_from: Option<u64>,
+        ttl: Option<u64>,
     ) -> Self {
         DeployItem {
             address,
@@ -34,6 +44,81 @@ impl DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            nonce,
+            valid_from,
+            ttl,
         }
     }
+
+    /// Returns the latest block time at which the deploy may be executed, or `None` if it never
+    /// expires or has no `valid_from` for its `ttl` to count from.
+    pub fn valid_until(&self) -> Option<u64> {
+        let valid_from = self.valid_from?;
+        self.ttl.map(|ttl| valid_from.saturating_add(ttl))
+    }
+
+    /// Returns `true` if the deploy may be executed in a block with the given `block_time`.
+    pub fn is_valid_at(&self, block_time: BlockTime) -> bool {
+        if self.ttl.is_some() && self.valid_from.is_none() {
+            return false;
+        }
+        let block_time: u64 = block_time.into();
+        self.valid_from
+            .map_or(true, |valid_from| valid_from <= block_time)
+            && self
+                .valid_until()
+                .map_or(true, |valid_until| block_time <= valid_until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_deploy_item(valid_from: Option<u64>, ttl: Option<u64>) -> DeployItem {
+        let module_bytes = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![],
+            args: vec![],
+        };
+        DeployItem::new(
+            PublicKey::ed25519_from([1; 32]),
+            module_bytes.clone(),
+            module_bytes,
+            1,
+            BTreeSet::new(),
+            [2; 32],
+            0,
+            valid_from,
+            ttl,
+        )
+    }
+
+    #[test]
+    fn should_be_valid_within_window() {
+        let deploy_item = new_deploy_item(Some(100), Some(50));
+        assert_eq!(deploy_item.valid_until(), Some(150));
+        assert!(!deploy_item.is_valid_at(BlockTime::new(99)));
+        assert!(deploy_item.is_valid_at(BlockTime::new(100)));
+        assert!(deploy_item.is_valid_at(BlockTime::new(150)));
+        assert!(!deploy_item.is_valid_at(BlockTime::new(151)));
+    }
+
+    #[test]
+    fn should_treat_unset_bounds_as_unrestricted() {
+        let deploy_item = new_deploy_item(None, None);
+        assert_eq!(deploy_item.valid_until(), None);
+        assert!(deploy_item.is_valid_at(BlockTime::new(0)));
+        assert!(deploy_item.is_valid_at(BlockTime::new(std::u64::MAX)));
+
+        let deploy_item = new_deploy_item(Some(std::u64::MAX), Some(1));
+        assert_eq!(deploy_item.valid_until(), Some(std::u64::MAX));
+    }
+
+    #[test]
+    fn should_not_be_valid_with_ttl_but_no_valid_from() {
+        let deploy_item = new_deploy_item(None, Some(50));
+        assert_eq!(deploy_item.valid_until(), None);
+        assert!(!deploy_item.is_valid_at(BlockTime::new(0)));
+        assert!(!deploy_item.is_valid_at(BlockTime::new(50)));
+    }
 }
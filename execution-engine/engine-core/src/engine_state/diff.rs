@@ -0,0 +1,30 @@
+use engine_shared::{newtypes::Blake2bHash, stored_value::StoredValue};
+use types::Key;
+
+pub use engine_storage::global_state::diff_into_transforms;
+
+pub type DiffEntry = engine_storage::global_state::DiffEntry<Key, StoredValue>;
+pub type DiffResult = engine_storage::global_state::DiffResult<Key, StoredValue>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRequest {
+    old_state_hash: Blake2bHash,
+    new_state_hash: Blake2bHash,
+}
+
+impl DiffRequest {
+    pub fn new(old_state_hash: Blake2bHash, new_state_hash: Blake2bHash) -> Self {
+        DiffRequest {
+            old_state_hash,
+            new_state_hash,
+        }
+    }
+
+    pub fn old_state_hash(&self) -> Blake2bHash {
+        self.old_state_hash
+    }
+
+    pub fn new_state_hash(&self) -> Blake2bHash {
+        self.new_state_hash
+    }
+}
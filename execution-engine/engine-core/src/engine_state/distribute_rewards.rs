@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributeRewardsRequest {
+    pre_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    rewards: BTreeMap<PublicKey, U512>,
+}
+
+impl DistributeRewardsRequest {
+    pub fn new(
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        rewards: BTreeMap<PublicKey, U512>,
+    ) -> Self {
+        DistributeRewardsRequest {
+            pre_state_hash,
+            protocol_version,
+            rewards,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn rewards(&self) -> &BTreeMap<PublicKey, U512> {
+        &self.rewards
+    }
+}
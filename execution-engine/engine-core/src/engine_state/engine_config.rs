@@ -4,6 +4,7 @@ pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
     enable_bonding: bool,
+    enable_account_nonces: bool,
 }
 
 impl EngineConfig {
@@ -29,4 +30,15 @@ impl EngineConfig {
         self.enable_bonding = enable_bonding;
         self
     }
+
+    /// Whether deploys must carry the next nonce of their account, which is incremented by every
+    /// deploy executed.
+    pub fn enable_account_nonces(self) -> bool {
+        self.enable_account_nonces
+    }
+
+    pub fn with_enable_account_nonces(mut self, enable_account_nonces: bool) -> EngineConfig {
+        self.enable_account_nonces = enable_account_nonces;
+        self
+    }
 }
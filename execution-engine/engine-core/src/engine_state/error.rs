@@ -16,6 +16,8 @@ pub enum Error {
     InvalidProtocolVersion(ProtocolVersion),
     #[fail(display = "Invalid upgrade config")]
     InvalidUpgradeConfig,
+    #[fail(display = "Invalid payment costs: conv_rate must be non-zero")]
+    ZeroConvRate,
     #[fail(display = "Wasm preprocessing error: {}", _0)]
     WasmPreprocessing(engine_wasm_prep::PreprocessingError),
     #[fail(display = "Wasm serialization error: {:?}", _0)]
@@ -26,6 +28,19 @@ pub enum Error {
     Storage(engine_storage::error::Error),
     #[fail(display = "Authorization failure: not authorized.")]
     Authorization,
+    #[fail(display = "Invalid nonce: expected {}, actual {}", expected, actual)]
+    InvalidNonce { expected: u64, actual: u64 },
+    #[fail(
+        display = "Deploy not valid at block time {}: valid from {:?} until {:?}",
+        block_time, valid_from, valid_until
+    )]
+    InvalidBlockTime {
+        block_time: u64,
+        valid_from: Option<u64>,
+        valid_until: Option<u64>,
+    },
+    #[fail(display = "Deploy has a ttl of {} but no valid_from", ttl)]
+    TtlWithoutValidFrom { ttl: u64 },
     #[fail(display = "Insufficient payment")]
     InsufficientPayment,
     #[fail(display = "Deploy error")]
@@ -0,0 +1,49 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{CLValue, ProtocolVersion};
+
+use super::{deploy_item::DeployItem, error::Error, execution_result::ExecutionResult};
+
+/// A request to execute the session code of a deploy without paying for it or committing it.
+#[derive(Debug)]
+pub struct EstimateRequest {
+    pub parent_state_hash: Blake2bHash,
+    pub block_time: u64,
+    pub deploy_item: DeployItem,
+    pub protocol_version: ProtocolVersion,
+}
+
+impl EstimateRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        block_time: u64,
+        deploy_item: DeployItem,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        EstimateRequest {
+            parent_state_hash,
+            block_time,
+            deploy_item,
+            protocol_version,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EstimateResult {
+    /// The gas used by the session code and its effects, or the error it failed with.
+    pub execution_result: ExecutionResult,
+    /// The unit value if the session code succeeded, or the value it passed to `runtime::ret`,
+    /// which fails the deploy with [`Error::Ret`](crate::execution::Error::Ret) as it would on
+    /// execution.
+    pub return_value: Option<CLValue>,
+}
+
+impl EstimateResult {
+    /// Constructs an [`EstimateResult`] for a deploy whose session code couldn't be executed.
+    pub fn precondition_failure(error: Error) -> Self {
+        EstimateResult {
+            execution_result: ExecutionResult::precondition_failure(error),
+            return_value: None,
+        }
+    }
+}
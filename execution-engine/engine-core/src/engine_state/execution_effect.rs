@@ -13,4 +13,82 @@ impl ExecutionEffect {
     pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
         ExecutionEffect { ops, transforms }
     }
+
+    /// Returns `true` if every key accessed by both `self` and `other` is accessed by commuting
+    /// [`Op`]s, i.e. the two effects can be applied to the same state in either order.
+    pub fn commutes_with(&self, other: &ExecutionEffect) -> bool {
+        let (smaller, larger) = if self.ops.len() <= other.ops.len() {
+            (&self.ops, &other.ops)
+        } else {
+            (&other.ops, &self.ops)
+        };
+        smaller.iter().all(|(key, op)| match larger.get(key) {
+            Some(other_op) => op.commutes_with(*other_op),
+            None => true,
+        })
+    }
+}
+
+/// Returns the pairs of indices `(i, j)`, with `i < j`, of the effects in `effects` which don't
+/// commute with each other.
+pub fn find_conflicts<'a, I>(effects: I) -> Vec<(usize, usize)>
+where
+    I: IntoIterator<Item = &'a ExecutionEffect>,
+{
+    let effects: Vec<&ExecutionEffect> = effects.into_iter().collect();
+    let mut conflicts = Vec::new();
+    for (i, first) in effects.iter().enumerate() {
+        for (j, second) in effects.iter().enumerate().skip(i + 1) {
+            if !first.commutes_with(second) {
+                conflicts.push((i, j));
+            }
+        }
+    }
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::additive_map::AdditiveMap;
+    use types::Key;
+
+    use super::{find_conflicts, ExecutionEffect};
+    use crate::engine_state::op::Op;
+
+    fn effect_with_ops(ops: &[(Key, Op)]) -> ExecutionEffect {
+        let mut map = AdditiveMap::new();
+        for (key, op) in ops {
+            map.insert(*key, *op);
+        }
+        ExecutionEffect::new(map, AdditiveMap::new())
+    }
+
+    #[test]
+    fn should_find_conflicts() {
+        let k1 = Key::Hash([1; 32]);
+        let k2 = Key::Hash([2; 32]);
+        let k3 = Key::Hash([3; 32]);
+
+        let effects = vec![
+            effect_with_ops(&[(k1, Op::Read), (k2, Op::Add)]),
+            effect_with_ops(&[(k1, Op::Read), (k2, Op::Add)]),
+            effect_with_ops(&[(k2, Op::Read)]),
+            effect_with_ops(&[(k3, Op::Write)]),
+            effect_with_ops(&[(k1, Op::Write), (k3, Op::NoOp)]),
+        ];
+
+        let conflicts = find_conflicts(&effects);
+        assert_eq!(conflicts, vec![(0, 2), (0, 4), (1, 2), (1, 4)]);
+    }
+
+    #[test]
+    fn should_not_find_conflicts_between_disjoint_effects() {
+        let effects = vec![
+            effect_with_ops(&[(Key::Hash([1; 32]), Op::Write)]),
+            effect_with_ops(&[(Key::Hash([2; 32]), Op::Write)]),
+            ExecutionEffect::default(),
+        ];
+
+        assert!(find_conflicts(&effects).is_empty());
+    }
 }
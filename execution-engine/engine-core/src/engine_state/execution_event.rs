@@ -0,0 +1,47 @@
+use types::{CLValue, ContractHash, Phase};
+
+/// An event emitted by a contract via the `emit_event` host function.
+///
+/// Events are reported alongside the execution results of a deploy, but are never written to
+/// global state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionEvent {
+    contract_hash: Option<ContractHash>,
+    phase: Phase,
+    name: String,
+    value: CLValue,
+}
+
+impl ExecutionEvent {
+    pub fn new(
+        contract_hash: Option<ContractHash>,
+        phase: Phase,
+        name: String,
+        value: CLValue,
+    ) -> Self {
+        ExecutionEvent {
+            contract_hash,
+            phase,
+            name,
+            value,
+        }
+    }
+
+    /// The hash of the stored contract which emitted the event, or `None` if it was emitted by
+    /// session or payment code running in the context of the deploying account.
+    pub fn contract_hash(&self) -> Option<ContractHash> {
+        self.contract_hash
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &CLValue {
+        &self.value
+    }
+}
@@ -1,7 +1,7 @@
-use super::{error, execution_effect::ExecutionEffect, op::Op, CONV_RATE};
+use super::{error, execution_effect::ExecutionEffect, execution_event::ExecutionEvent, op::Op};
 use engine_shared::{
-    additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
-    stored_value::StoredValue, transform::Transform,
+    account::Account, additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
+    payment_costs::PaymentCosts, stored_value::StoredValue, transform::Transform,
 };
 use engine_storage::global_state::StateReader;
 use types::{CLValue, Key};
@@ -11,6 +11,7 @@ fn make_payment_error_effects(
     account_main_purse_balance: Motes,
     account_main_purse: Key,
     rewards_purse: Key,
+    account: Option<Account>,
 ) -> ExecutionEffect {
     let mut ops = AdditiveMap::new();
     let mut transforms = AdditiveMap::new();
@@ -35,6 +36,12 @@ fn make_payment_error_effects(
         Transform::AddUInt512(max_payment_cost.value()),
     );
 
+    if let Some(account) = account {
+        let account_key = Key::Account(account.public_key());
+        ops.insert(account_key, Op::Write);
+        transforms.insert(account_key, Transform::Write(StoredValue::Account(account)));
+    }
+
     ExecutionEffect::new(ops, transforms)
 }
 
@@ -44,10 +51,15 @@ pub enum ExecutionResult {
     Failure {
         error: error::Error,
         effect: ExecutionEffect,
+        events: Vec<ExecutionEvent>,
         cost: Gas,
     },
     /// Execution was finished successfully
-    Success { effect: ExecutionEffect, cost: Gas },
+    Success {
+        effect: ExecutionEffect,
+        events: Vec<ExecutionEvent>,
+        cost: Gas,
+    },
 }
 
 pub enum ForcedTransferResult {
@@ -65,6 +77,7 @@ impl ExecutionResult {
         ExecutionResult::Failure {
             error,
             effect: Default::default(),
+            events: Vec::new(),
             cost: Gas::default(),
         }
     }
@@ -106,25 +119,74 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns the events emitted during execution, in the order they were emitted.
+    pub fn events(&self) -> &[ExecutionEvent] {
+        match self {
+            ExecutionResult::Failure { events, .. } => events,
+            ExecutionResult::Success { events, .. } => events,
+        }
+    }
+
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
-            ExecutionResult::Failure { error, effect, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
                 error,
                 effect,
+                events,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                events,
+                cost,
+            },
+            ExecutionResult::Success { effect, events, .. } => ExecutionResult::Success {
+                effect,
+                events,
                 cost,
             },
-            ExecutionResult::Success { effect, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
     pub fn with_effect(self, effect: ExecutionEffect) -> Self {
         match self {
-            ExecutionResult::Failure { error, cost, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                events,
+                cost,
+                ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
+                events,
+                cost,
+            },
+            ExecutionResult::Success { events, cost, .. } => ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+            },
+        }
+    }
+
+    pub fn with_events(self, events: Vec<ExecutionEvent>) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                events,
+                cost,
+            },
+            ExecutionResult::Success { effect, cost, .. } => ExecutionResult::Success {
+                effect,
+                events,
                 cost,
             },
-            ExecutionResult::Success { cost, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
@@ -147,10 +209,11 @@ impl ExecutionResult {
     pub fn check_forced_transfer(
         &self,
         payment_purse_balance: Motes,
+        conv_rate: u64,
     ) -> Option<ForcedTransferResult> {
-        let payment_result_cost = match Motes::from_gas(self.cost(), CONV_RATE) {
+        let payment_result_cost = match Motes::from_gas(self.cost(), conv_rate) {
             Some(cost) => cost,
-            // Multiplying cost by CONV_RATE overflowed the U512 range
+            // Multiplying cost by conv_rate overflowed the U512 range
             None => return Some(ForcedTransferResult::InsufficientPayment),
         };
         // payment_code_spec_3_b_ii: if (balance of PoS pay purse) < (gas spent during
@@ -173,23 +236,31 @@ impl ExecutionResult {
         }
     }
 
+    /// Constructs [ExecutionResult::Failure] charging the maximum payment to the account.
+    ///
+    /// `account` is the deploying account with its nonce incremented if account nonces are
+    /// enabled, in which case it is written too.
     pub fn new_payment_code_error(
         error: error::Error,
-        max_payment_cost: Motes,
+        payment_costs: PaymentCosts,
         account_main_purse_balance: Motes,
         account_main_purse: Key,
         rewards_purse: Key,
+        account: Option<Account>,
     ) -> ExecutionResult {
+        let max_payment_cost = payment_costs.max_payment_cost_motes();
         let effect = make_payment_error_effects(
             max_payment_cost,
             account_main_purse_balance,
             account_main_purse,
             rewards_purse,
+            account,
         );
-        let cost = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+        let cost = Gas::from_motes(max_payment_cost, payment_costs.conv_rate).unwrap_or_default();
         ExecutionResult::Failure {
             error,
             effect,
+            events: Vec::new(),
             cost,
         }
     }
@@ -266,9 +337,11 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut events = Vec::new();
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
+            events: Vec::new(),
             cost,
         };
 
@@ -278,6 +351,7 @@ impl ExecutionResultBuilder {
                     return Ok(result);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    events.extend_from_slice(result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -291,6 +365,7 @@ impl ExecutionResultBuilder {
                     ret = result.with_cost(cost);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    events.extend_from_slice(result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -305,6 +380,7 @@ impl ExecutionResultBuilder {
                     ));
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    events.extend_from_slice(result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
@@ -313,7 +389,7 @@ impl ExecutionResultBuilder {
         // Remove redundant writes to allow more opportunity to commute
         let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
 
-        Ok(ret.with_effect(reduced_effect))
+        Ok(ret.with_effect(reduced_effect).with_events(events))
     }
 
     fn add_effects(
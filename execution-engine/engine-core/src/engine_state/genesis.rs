@@ -6,17 +6,30 @@ use rand::{
     Rng,
 };
 
-use engine_shared::{motes::Motes, newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{
+    motes::Motes, newtypes::Blake2bHash, payment_costs::PaymentCosts, TypeMismatch,
+};
 use engine_storage::global_state::CommitResult;
-use engine_wasm_prep::wasm_costs::WasmCosts;
-use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH},
+    wasm_costs::WasmCosts,
+};
+use types::{
+    account::{PublicKey, SECP256K1_LENGTH},
+    bytesrepr, Key, ProtocolVersion, U512,
+};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
+pub use proof_of_stake::{QueueDelays, StakeLimits};
+
 pub const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 pub const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 pub const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 pub const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+pub const POS_QUEUE_DELAYS: &str = "pos_queue_delays";
+pub const POS_STAKE_LIMITS: &str = "pos_stake_limits";
+pub const POS_DELEGATIONS: &str = "pos_delegations";
 
 pub enum GenesisResult {
     RootNotFound,
@@ -92,7 +105,13 @@ impl GenesisAccount {
 
 impl Distribution<GenesisAccount> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenesisAccount {
-        let public_key = PublicKey::ed25519_from(rng.gen());
+        let public_key = if rng.gen() {
+            PublicKey::ed25519_from(rng.gen())
+        } else {
+            let mut secp256k1_bytes = [0u8; SECP256K1_LENGTH];
+            rng.fill_bytes(secp256k1_bytes.as_mut());
+            PublicKey::secp256k1_from(secp256k1_bytes)
+        };
 
         let mut u512_array = [0u8; 64];
         rng.fill_bytes(u512_array.as_mut());
@@ -188,15 +207,26 @@ pub struct ExecConfig {
     standard_payment_installer_bytes: Vec<u8>,
     accounts: Vec<GenesisAccount>,
     wasm_costs: WasmCosts,
+    host_function_costs: HostFunctionCosts,
+    payment_costs: PaymentCosts,
+    queue_delays: QueueDelays,
+    stake_limits: StakeLimits,
+    reward_rate: u64,
 }
 
 impl ExecConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         mint_installer_bytes: Vec<u8>,
         proof_of_stake_installer_bytes: Vec<u8>,
         standard_payment_installer_bytes: Vec<u8>,
         accounts: Vec<GenesisAccount>,
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        payment_costs: PaymentCosts,
+        queue_delays: QueueDelays,
+        stake_limits: StakeLimits,
+        reward_rate: u64,
     ) -> ExecConfig {
         ExecConfig {
             mint_installer_bytes,
@@ -204,6 +234,11 @@ impl ExecConfig {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            queue_delays,
+            stake_limits,
+            reward_rate,
         }
     }
     pub fn mint_installer_bytes(&self) -> &[u8] {
@@ -222,6 +257,26 @@ impl ExecConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> HostFunctionCosts {
+        self.host_function_costs
+    }
+
+    pub fn payment_costs(&self) -> PaymentCosts {
+        self.payment_costs
+    }
+
+    pub fn queue_delays(&self) -> QueueDelays {
+        self.queue_delays
+    }
+
+    pub fn stake_limits(&self) -> StakeLimits {
+        self.stake_limits
+    }
+
+    pub fn reward_rate(&self) -> u64 {
+        self.reward_rate
+    }
+
     pub fn get_bonded_validators(&self) -> impl Iterator<Item = (PublicKey, Motes)> + '_ {
         let zero = Motes::zero();
         self.accounts.iter().filter_map(move |genesis_account| {
@@ -274,12 +329,48 @@ impl Distribution<ExecConfig> for Standard {
             opcodes_div: rng.gen(),
         };
 
+        let host_function_costs = {
+            let bytes: Vec<u8> = iter::repeat(())
+                .map(|_| rng.gen())
+                .take(HOST_FUNCTION_COSTS_SERIALIZED_LENGTH)
+                .collect();
+            bytesrepr::deserialize(bytes).expect("should deserialize host function costs")
+        };
+
+        let payment_costs = PaymentCosts {
+            conv_rate: rng.gen_range(1, std::u64::MAX),
+            max_payment_cost: rng.gen(),
+            standard_payment_cap: rng.gen(),
+        };
+
+        let queue_delays = QueueDelays {
+            bond_delay: rng.gen(),
+            unbond_delay: rng.gen(),
+        };
+
+        let stake_limits = StakeLimits {
+            min_bond: rng.gen::<u64>().into(),
+            max_validators: rng.gen(),
+            max_spread: rng.gen::<u64>().into(),
+            max_increase: rng.gen::<u64>().into(),
+            max_decrease: rng.gen::<u64>().into(),
+            max_rel_increase: rng.gen(),
+            max_rel_decrease: rng.gen(),
+        };
+
+        let reward_rate = rng.gen();
+
         ExecConfig {
             mint_installer_bytes,
             proof_of_stake_installer_bytes,
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            queue_delays,
+            stake_limits,
+            reward_rate,
         }
     }
 }
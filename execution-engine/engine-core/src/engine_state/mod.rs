@@ -1,21 +1,32 @@
+pub mod bid_state;
 pub mod deploy_item;
+pub mod diff;
+pub mod distribute_rewards;
 pub mod engine_config;
 mod error;
+pub mod estimate;
 pub mod executable_deploy_item;
 pub mod execute_request;
 pub mod execution_effect;
+pub mod execution_event;
 pub mod execution_result;
 pub mod genesis;
 pub mod op;
+pub mod prune;
 pub mod query;
 pub mod run_genesis_request;
+pub mod seigniorage;
+pub mod slash;
+pub mod step;
 pub mod system_contract_cache;
 mod transfer;
+pub mod unbond_payout;
 pub mod upgrade;
 pub mod utils;
 
 use std::{
     cell::RefCell,
+    cmp,
     collections::{BTreeMap, BTreeSet, HashMap},
     rc::Rc,
 };
@@ -23,13 +34,16 @@ use std::{
 use log::{debug, warn};
 use num_traits::Zero;
 use parity_wasm::elements::Module;
+use rayon::prelude::*;
 
+use ::mint::TOTAL_SUPPLY_KEY;
 use engine_shared::{
     account::Account,
     additive_map::AdditiveMap,
     gas::Gas,
     motes::Motes,
     newtypes::{Blake2bHash, CorrelationId},
+    payment_costs::PaymentCosts,
     stored_value::StoredValue,
     transform::Transform,
 };
@@ -37,7 +51,10 @@ use engine_storage::{
     global_state::{CommitResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
 };
-use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
+use engine_wasm_prep::{
+    host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts, Preprocessor,
+};
+use proof_of_stake::Delegations;
 use types::{
     account::PublicKey,
     bytesrepr::{self, ToBytes},
@@ -45,7 +62,7 @@ use types::{
     runtime_args,
     system_contract_errors::mint,
     system_contract_type::PROOF_OF_STAKE,
-    AccessRights, BlockTime, Contract, ContractHash, ContractPackage, ContractPackageHash,
+    AccessRights, BlockTime, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash,
     ContractVersionKey, EntryPoint, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
     U512,
 };
@@ -57,33 +74,45 @@ pub use self::{
 };
 use crate::{
     engine_state::{
+        bid_state::{BidStateRequest, BidStateResult},
         deploy_item::DeployItem,
+        diff::{DiffRequest, DiffResult},
+        distribute_rewards::DistributeRewardsRequest,
         error::Error::MissingSystemContract,
+        estimate::{EstimateRequest, EstimateResult},
         executable_deploy_item::ExecutableDeployItem,
         execute_request::ExecuteRequest,
+        execution_effect::ExecutionEffect,
         execution_result::{ExecutionResult, ForcedTransferResult},
         genesis::{
-            ExecConfig, GenesisAccount, GenesisResult, POS_PAYMENT_PURSE, POS_REWARDS_PURSE,
+            ExecConfig, GenesisAccount, GenesisResult, POS_DELEGATIONS, POS_PAYMENT_PURSE,
+            POS_QUEUE_DELAYS, POS_REWARDS_PURSE, POS_STAKE_LIMITS,
+        },
+        prune::{PruneRequest, PruneResult},
+        query::{
+            ListKeysRequest, ListKeysResult, QueryRequest, QueryResult, QueryWithProofRequest,
+            QueryWithProofResult, MAX_LIST_KEYS_LIMIT,
         },
-        query::{QueryRequest, QueryResult},
+        seigniorage::DistributeSeigniorageRequest,
+        slash::SlashRequest,
+        step::StepRequest,
         system_contract_cache::SystemContractCache,
+        unbond_payout::UnbondPayoutRequest,
         upgrade::{UpgradeConfig, UpgradeResult},
     },
     execution::{
         self, AddressGenerator, AddressGeneratorBuilder, DirectSystemContractCall, Executor,
     },
-    tracking_copy::{TrackingCopy, TrackingCopyExt},
+    tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
 };
 
-// TODO?: MAX_PAYMENT && CONV_RATE values are currently arbitrary w/ real values
-// TBD gas * CONV_RATE = motes
-pub const MAX_PAYMENT: u64 = 10_000_000;
-pub const CONV_RATE: u64 = 10;
-
 pub const SYSTEM_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
 const ARG_AMOUNT: &str = "amount";
+const ARG_VALIDATORS: &str = "validators";
+const ARG_REWARDS: &str = "rewards";
+const ARG_REWARD_RATE: &str = "reward_rate";
 
 #[derive(Debug)]
 pub struct EngineState<S> {
@@ -118,6 +147,41 @@ impl GetModuleResult {
             GetModuleResult::Contract { module, .. } => module,
         }
     }
+
+    /// Splits the result into the module to execute, the base key and named keys of the context it
+    /// is executed in, and its contract package and entry point.  Session code is executed in the
+    /// context of `account`.
+    fn into_execution_context(
+        self,
+        account: &Account,
+    ) -> (Module, Key, NamedKeys, ContractPackage, EntryPoint) {
+        match self {
+            GetModuleResult::Session {
+                module,
+                contract_package,
+                entry_point,
+            } => (
+                module,
+                Key::Account(account.public_key()),
+                account.named_keys().clone(),
+                contract_package,
+                entry_point,
+            ),
+            GetModuleResult::Contract {
+                module,
+                base_key,
+                contract,
+                contract_package,
+                entry_point,
+            } => (
+                module,
+                base_key,
+                contract.named_keys().clone(),
+                contract_package,
+                entry_point,
+            ),
+        }
+    }
 }
 
 impl<S> EngineState<S>
@@ -148,6 +212,26 @@ where
         }
     }
 
+    pub fn host_function_costs(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<HostFunctionCosts>, Error> {
+        match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => Ok(Some(*protocol_data.host_function_costs())),
+            None => Ok(None),
+        }
+    }
+
+    pub fn payment_costs(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<PaymentCosts>, Error> {
+        match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => Ok(Some(*protocol_data.payment_costs())),
+            None => Ok(None),
+        }
+    }
+
     pub fn get_protocol_data(
         &self,
         protocol_version: ProtocolVersion,
@@ -174,6 +258,12 @@ where
 
         let initial_root_hash = self.state.empty_root();
         let wasm_costs = ee_config.wasm_costs();
+        let host_function_costs = ee_config.host_function_costs();
+        let payment_costs = ee_config.payment_costs();
+        if payment_costs.conv_rate == 0 {
+            return Err(Error::ZeroConvRate);
+        }
+        let reward_rate = ee_config.reward_rate();
         let preprocessor = Preprocessor::new(wasm_costs);
 
         // Spec #3: Create "virtual system account" object.
@@ -212,6 +302,11 @@ where
             let generator = AddressGenerator::new(&genesis_config_hash.value(), phase);
             Rc::new(RefCell::new(generator))
         };
+        let random_bytes_generator = {
+            let generator =
+                AddressGenerator::new_random_bytes_generator(&genesis_config_hash.value(), phase);
+            Rc::new(RefCell::new(generator))
+        };
 
         // Spec #5: Execute the wasm code from the mint installer bytes
         let (mint_package_hash, mint_hash): (ContractPackageHash, ContractHash) = {
@@ -222,6 +317,7 @@ where
             let install_deploy_hash = genesis_config_hash.into();
             let hash_address_generator = Rc::clone(&hash_address_generator);
             let uref_address_generator = Rc::clone(&uref_address_generator);
+            let random_bytes_generator = Rc::clone(&random_bytes_generator);
             let tracking_copy = Rc::clone(&tracking_copy);
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
             let protocol_data = ProtocolData::default();
@@ -237,6 +333,7 @@ where
                 gas_limit,
                 hash_address_generator,
                 uref_address_generator,
+                random_bytes_generator,
                 protocol_version,
                 correlation_id,
                 tracking_copy,
@@ -262,6 +359,7 @@ where
             let tracking_copy = Rc::clone(&tracking_copy);
             let hash_address_generator = Rc::clone(&hash_address_generator);
             let uref_address_generator = Rc::clone(&uref_address_generator);
+            let random_bytes_generator = Rc::clone(&random_bytes_generator);
             let install_deploy_hash = genesis_config_hash.into();
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
@@ -275,6 +373,8 @@ where
             let args = runtime_args! {
                 "mint_contract_package_hash" => mint_package_hash,
                 "genesis_validators" => bonded_validators,
+                "queue_delays" => ee_config.queue_delays(),
+                "stake_limits" => ee_config.stake_limits(),
             };
             let authorization_keys: BTreeSet<PublicKey> = BTreeSet::new();
 
@@ -289,6 +389,7 @@ where
                 gas_limit,
                 hash_address_generator,
                 uref_address_generator,
+                random_bytes_generator,
                 protocol_version,
                 correlation_id,
                 tracking_copy,
@@ -304,6 +405,9 @@ where
         // specification.
         let protocol_data = ProtocolData::partial_without_standard_payment(
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            reward_rate,
             mint_hash,
             proof_of_stake_hash,
         );
@@ -327,6 +431,7 @@ where
             let install_deploy_hash = genesis_config_hash.into();
             let hash_address_generator = Rc::clone(&hash_address_generator);
             let uref_address_generator = Rc::clone(&uref_address_generator);
+            let random_bytes_generator = Rc::clone(&random_bytes_generator);
             let tracking_copy = Rc::clone(&tracking_copy);
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
@@ -341,6 +446,7 @@ where
                 gas_limit,
                 hash_address_generator,
                 uref_address_generator,
+                random_bytes_generator,
                 protocol_version,
                 correlation_id,
                 tracking_copy,
@@ -353,6 +459,9 @@ where
         // Spec #2: Associate given CostTable with given ProtocolVersion.
         let protocol_data = ProtocolData::new(
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            reward_rate,
             mint_hash,
             proof_of_stake_hash,
             standard_payment_hash,
@@ -416,9 +525,7 @@ where
                 let base_key = mint_hash;
                 let authorization_keys: BTreeSet<PublicKey> = BTreeSet::new();
                 let account_public_key = account.public_key();
-                // NOTE: As Ed25519 keys are currently supported by chainspec, PublicKey::value
-                // returns raw bytes of it
-                let purse_creation_deploy_hash = account_public_key.value();
+                let purse_creation_deploy_hash = Key::Account(account_public_key).into_seed();
                 let hash_address_generator = Rc::clone(&hash_address_generator);
                 let uref_address_generator = {
                     let generator = AddressGeneratorBuilder::new()
@@ -428,6 +535,7 @@ where
                         .build();
                     Rc::new(RefCell::new(generator))
                 };
+                let random_bytes_generator = Rc::clone(&random_bytes_generator);
                 let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
                 let mint_result: Result<URef, mint::Error> = {
@@ -445,6 +553,7 @@ where
                         gas_limit,
                         hash_address_generator,
                         uref_address_generator,
+                        random_bytes_generator,
                         protocol_version,
                         correlation_id,
                         tracking_copy_exec,
@@ -541,9 +650,32 @@ where
             None => *current_protocol_data.wasm_costs(),
         };
 
+        // resolve host function costs for new protocol version
+        let new_host_function_costs = match upgrade_config.host_function_costs() {
+            Some(new_host_function_costs) => new_host_function_costs,
+            None => *current_protocol_data.host_function_costs(),
+        };
+
+        // resolve payment costs for new protocol version
+        let new_payment_costs = match upgrade_config.payment_costs() {
+            Some(new_payment_costs) if new_payment_costs.conv_rate == 0 => {
+                return Err(Error::ZeroConvRate);
+            }
+            Some(new_payment_costs) => new_payment_costs,
+            None => *current_protocol_data.payment_costs(),
+        };
+
+        // resolve reward rate for new protocol version
+        let new_reward_rate = upgrade_config
+            .reward_rate()
+            .unwrap_or_else(|| current_protocol_data.reward_rate());
+
         // 3.1.2.2 persist wasm CostTable
         let mut new_protocol_data = ProtocolData::new(
             new_wasm_costs,
+            new_host_function_costs,
+            new_payment_costs,
+            new_reward_rate,
             current_protocol_data.mint(),
             current_protocol_data.proof_of_stake(),
             current_protocol_data.standard_payment(),
@@ -619,6 +751,13 @@ where
                     let generator = AddressGenerator::new(&pre_state_hash.value(), phase);
                     Rc::new(RefCell::new(generator))
                 };
+                let random_bytes_generator = {
+                    let generator = AddressGenerator::new_random_bytes_generator(
+                        &pre_state_hash.value(),
+                        phase,
+                    );
+                    Rc::new(RefCell::new(generator))
+                };
                 let tracking_copy = Rc::clone(&tracking_copy);
                 let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
@@ -635,6 +774,7 @@ where
                     gas_limit,
                     hash_address_generator,
                     uref_address_generator,
+                    random_bytes_generator,
                     new_protocol_version,
                     correlation_id,
                     Rc::clone(&tracking_copy),
@@ -653,6 +793,63 @@ where
             }
         }
 
+        if let Some(queue_delays) = upgrade_config.queue_delays() {
+            let uref = self.get_proof_of_stake_config_uref(
+                correlation_id,
+                &tracking_copy,
+                new_protocol_data.proof_of_stake(),
+                POS_QUEUE_DELAYS,
+            )?;
+            let value = CLValue::from_t(queue_delays).expect("should convert");
+            tracking_copy
+                .borrow_mut()
+                .write(Key::URef(uref), StoredValue::CLValue(value));
+        }
+        if let Some(stake_limits) = upgrade_config.stake_limits() {
+            let uref = self.get_proof_of_stake_config_uref(
+                correlation_id,
+                &tracking_copy,
+                new_protocol_data.proof_of_stake(),
+                POS_STAKE_LIMITS,
+            )?;
+            let value = CLValue::from_t(stake_limits).expect("should convert");
+            tracking_copy
+                .borrow_mut()
+                .write(Key::URef(uref), StoredValue::CLValue(value));
+        }
+
+        // add the named keys which system contracts installed by an earlier version lack
+        let mut uref_address_generator = AddressGeneratorBuilder::new()
+            .seed_with(&pre_state_hash.value())
+            .seed_with(&new_protocol_version.value().into_bytes()?)
+            .seed_with(&[Phase::System as u8])
+            .build();
+        self.put_system_contract_uref_if_missing(
+            correlation_id,
+            &tracking_copy,
+            &mut uref_address_generator,
+            new_protocol_data.proof_of_stake(),
+            POS_DELEGATIONS,
+            CLValue::from_t(Delegations::default()).expect("should convert"),
+        )?;
+        let mint_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, new_protocol_data.mint())?;
+        if !mint_contract.named_keys().contains_key(TOTAL_SUPPLY_KEY) {
+            // the total supply can't be derived here, so it has to be provided
+            let initial_total_supply = upgrade_config
+                .initial_total_supply()
+                .ok_or(Error::InvalidUpgradeConfig)?;
+            self.put_system_contract_uref_if_missing(
+                correlation_id,
+                &tracking_copy,
+                &mut uref_address_generator,
+                new_protocol_data.mint(),
+                TOTAL_SUPPLY_KEY,
+                CLValue::from_t(initial_total_supply).expect("should convert"),
+            )?;
+        }
+
         let effects = tracking_copy.borrow().effect();
 
         // commit
@@ -669,6 +866,60 @@ where
         Ok(UpgradeResult::from_commit_result(commit_result, effects))
     }
 
+    /// Returns the URef stored under `name` in the named keys of the proof of stake contract,
+    /// which holds one of the contract's configuration values.
+    fn get_proof_of_stake_config_uref(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &RefCell<TrackingCopy<S::Reader>>,
+        proof_of_stake_hash: ContractHash,
+        name: &str,
+    ) -> Result<URef, Error> {
+        let proof_of_stake_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, proof_of_stake_hash)?;
+        proof_of_stake_contract
+            .named_keys()
+            .get(name)
+            .and_then(|key| key.into_uref())
+            .ok_or_else(|| Error::Exec(execution::Error::NamedKeyNotFound(name.to_string())))
+    }
+
+    /// Writes `value` under a new URef and stores it as `name` in the named keys of the system
+    /// contract `contract_hash`, unless the contract already has a named key `name`.
+    fn put_system_contract_uref_if_missing(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &RefCell<TrackingCopy<S::Reader>>,
+        uref_address_generator: &mut AddressGenerator,
+        contract_hash: ContractHash,
+        name: &str,
+        value: CLValue,
+    ) -> Result<(), Error> {
+        let mut contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, contract_hash)?;
+        if contract.named_keys().contains_key(name) {
+            return Ok(());
+        }
+
+        let uref = URef::new(
+            uref_address_generator.create_address(),
+            AccessRights::READ_ADD_WRITE,
+        );
+        tracking_copy
+            .borrow_mut()
+            .write(Key::URef(uref), StoredValue::CLValue(value));
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(name.to_string(), Key::URef(uref));
+        contract.named_keys_append(&mut named_keys);
+        tracking_copy
+            .borrow_mut()
+            .write(Key::Hash(contract_hash), StoredValue::Contract(contract));
+        Ok(())
+    }
+
     pub fn tracking_copy(
         &self,
         hash: Blake2bHash,
@@ -679,6 +930,42 @@ where
         }
     }
 
+    /// Deletes the tries which are unreachable from the requested state roots, keeping the empty
+    /// root.
+    pub fn run_prune(
+        &self,
+        correlation_id: CorrelationId,
+        prune_request: PruneRequest,
+    ) -> Result<PruneResult, Error> {
+        let prune_result = self
+            .state
+            .prune(
+                correlation_id,
+                prune_request.roots_to_keep(),
+                prune_request.batch_size(),
+            )
+            .map_err(Into::into)?;
+        Ok(prune_result)
+    }
+
+    /// Returns the entries which were added, removed or changed between the requested state
+    /// roots.
+    pub fn run_diff(
+        &self,
+        correlation_id: CorrelationId,
+        diff_request: DiffRequest,
+    ) -> Result<DiffResult, Error> {
+        let diff_result = self
+            .state
+            .diff(
+                correlation_id,
+                diff_request.old_state_hash(),
+                diff_request.new_state_hash(),
+            )
+            .map_err(Into::into)?;
+        Ok(diff_result)
+    }
+
     pub fn run_query(
         &self,
         correlation_id: CorrelationId,
@@ -697,6 +984,76 @@ where
             .into())
     }
 
+    /// Reads the value stored under the requested key along with a Merkle proof of its inclusion,
+    /// which can be checked against the requested state hash without access to global state.
+    pub fn run_query_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        query_request: QueryWithProofRequest,
+    ) -> Result<QueryWithProofResult, Error> {
+        let key = query_request.key();
+        let reader = match self
+            .state
+            .checkout(query_request.state_hash())
+            .map_err(Into::into)?
+        {
+            Some(reader) => reader,
+            None => return Ok(QueryWithProofResult::RootNotFound),
+        };
+
+        match reader
+            .read_with_proof(correlation_id, &key.normalize())
+            .map_err(Into::into)?
+        {
+            Some(proof) => Ok(QueryWithProofResult::Success(proof)),
+            None => Ok(QueryWithProofResult::ValueNotFound(format!(
+                "Failed to find base key {:?}",
+                key
+            ))),
+        }
+    }
+
+    /// Lists a page of the keys of the requested variant, in order of their serialized form.
+    pub fn run_list_keys(
+        &self,
+        correlation_id: CorrelationId,
+        list_keys_request: ListKeysRequest,
+    ) -> Result<ListKeysResult, Error> {
+        let reader = match self
+            .state
+            .checkout(list_keys_request.state_hash())
+            .map_err(Into::into)?
+        {
+            Some(reader) => reader,
+            None => return Ok(ListKeysResult::RootNotFound),
+        };
+
+        let mut prefix = vec![list_keys_request.key_tag() as u8];
+        prefix.extend_from_slice(list_keys_request.prefix());
+        let limit = cmp::max(1, cmp::min(list_keys_request.limit(), MAX_LIST_KEYS_LIMIT));
+
+        let page = reader
+            .read_page(correlation_id, &prefix, list_keys_request.cursor(), limit)
+            .map_err(Into::into)?;
+
+        let next_cursor = match page.entries.last() {
+            Some((key, _)) if page.has_more => Some(key.to_bytes()?),
+            _ => None,
+        };
+
+        let include_values = list_keys_request.include_values();
+        let entries = page
+            .entries
+            .into_iter()
+            .map(|(key, value)| (key, if include_values { Some(value) } else { None }))
+            .collect();
+
+        Ok(ListKeysResult::Success {
+            entries,
+            next_cursor,
+        })
+    }
+
     pub fn run_execute(
         &self,
         correlation_id: CorrelationId,
@@ -713,29 +1070,15 @@ where
         let mut results = Vec::new();
 
         for deploy_item in exec_request.take_deploys() {
-            let result = match deploy_item {
-                Err(exec_result) => Ok(exec_result),
-                Ok(deploy_item) => match deploy_item.session {
-                    ExecutableDeployItem::Transfer { .. } => self.transfer(
-                        correlation_id,
-                        &executor,
-                        &preprocessor,
-                        exec_request.protocol_version,
-                        exec_request.parent_state_hash,
-                        BlockTime::new(exec_request.block_time),
-                        deploy_item,
-                    ),
-                    _ => self.deploy(
-                        correlation_id,
-                        &executor,
-                        &preprocessor,
-                        exec_request.protocol_version,
-                        exec_request.parent_state_hash,
-                        BlockTime::new(exec_request.block_time),
-                        deploy_item,
-                    ),
-                },
-            };
+            let result = self.execute_deploy_item(
+                correlation_id,
+                &executor,
+                &preprocessor,
+                exec_request.protocol_version,
+                exec_request.parent_state_hash,
+                BlockTime::new(exec_request.block_time),
+                deploy_item,
+            );
             match result {
                 Ok(result) => results.push(result),
                 Err(error) => {
@@ -747,15 +1090,239 @@ where
         Ok(results)
     }
 
-    pub fn get_module(
+    /// Executes the deploys of `exec_request` concurrently on a worker pool, each one against
+    /// `exec_request.parent_state_hash`.
+    ///
+    /// Returns the result of each deploy, in the order of `exec_request`, along with the pairs of
+    /// indices `(i, j)`, with `i < j`, of deploys whose effects don't commute with each other.
+    pub fn run_execute_parallel(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<(Vec<ExecutionResult>, Vec<(usize, usize)>), RootNotFound>
+    where
+        S: Sync,
+    {
+        let deploys = exec_request.take_deploys();
+        let protocol_version = exec_request.protocol_version;
+
+        let wasm_costs = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => *protocol_data.wasm_costs(),
+            Ok(None) => {
+                let results = deploys
+                    .into_iter()
+                    .map(|_| {
+                        let error = Error::InvalidProtocolVersion(protocol_version);
+                        ExecutionResult::precondition_failure(error)
+                    })
+                    .collect();
+                return Ok((results, Vec::new()));
+            }
+            Err(error) => {
+                let error: execution::Error = error.into();
+                let results = deploys
+                    .into_iter()
+                    .map(|_| ExecutionResult::precondition_failure(Error::Exec(error.clone())))
+                    .collect();
+                return Ok((results, Vec::new()));
+            }
+        };
+        let executor = Executor::new(self.config);
+        let preprocessor = Preprocessor::new(wasm_costs);
+
+        let results = deploys
+            .into_par_iter()
+            .map(|deploy_item| {
+                self.execute_deploy_item(
+                    correlation_id,
+                    &executor,
+                    &preprocessor,
+                    exec_request.protocol_version,
+                    exec_request.parent_state_hash,
+                    BlockTime::new(exec_request.block_time),
+                    deploy_item,
+                )
+            })
+            .collect::<Result<Vec<ExecutionResult>, RootNotFound>>()?;
+
+        let conflicts =
+            execution_effect::find_conflicts(results.iter().map(|result| result.effect()));
+
+        Ok((results, conflicts))
+    }
+
+    /// Executes the deploys of `exec_request` in order, threading a single [`TrackingCopy`]
+    /// through them so that each deploy observes the effects of the ones before it.
+    ///
+    /// Returns the result of each deploy along with the combined effects of all of them, which
+    /// can be committed on top of `exec_request.parent_state_hash` in a single step.
+    pub fn run_execute_chained(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<(Vec<ExecutionResult>, ExecutionEffect), RootNotFound> {
+        let deploys = exec_request.take_deploys();
+        let parent_state_hash = exec_request.parent_state_hash;
+        let protocol_version = exec_request.protocol_version;
+        let blocktime = BlockTime::new(exec_request.block_time);
+
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                let results = deploys
+                    .into_iter()
+                    .map(|_| {
+                        let error = Error::InvalidProtocolVersion(protocol_version);
+                        ExecutionResult::precondition_failure(error)
+                    })
+                    .collect();
+                return Ok((results, ExecutionEffect::default()));
+            }
+            Err(error) => {
+                let error: execution::Error = error.into();
+                let results = deploys
+                    .into_iter()
+                    .map(|_| ExecutionResult::precondition_failure(Error::Exec(error.clone())))
+                    .collect();
+                return Ok((results, ExecutionEffect::default()));
+            }
+        };
+
+        let mut tracking_copy = match self.state.checkout(parent_state_hash) {
+            Ok(Some(reader)) => TrackingCopy::new(reader),
+            Ok(None) => return Err(RootNotFound::new(parent_state_hash)),
+            Err(error) => {
+                let error: execution::Error = error.into();
+                let results = deploys
+                    .into_iter()
+                    .map(|_| ExecutionResult::precondition_failure(Error::Exec(error.clone())))
+                    .collect();
+                return Ok((results, ExecutionEffect::default()));
+            }
+        };
+
+        let executor = Executor::new(self.config);
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+
+        let mut results = Vec::with_capacity(deploys.len());
+
+        for deploy_item in deploys {
+            let result = match deploy_item {
+                Err(exec_result) => exec_result,
+                Ok(deploy_item) => {
+                    let deploy_tracking_copy = Rc::new(RefCell::new(tracking_copy.fork()));
+                    match deploy_item.session {
+                        ExecutableDeployItem::Transfer { .. } => self.transfer_using_tracking_copy(
+                            correlation_id,
+                            &executor,
+                            &preprocessor,
+                            protocol_version,
+                            protocol_data,
+                            blocktime,
+                            deploy_item,
+                            deploy_tracking_copy,
+                        ),
+                        _ => {
+                            let (execution_result, _return_value) = self
+                                .deploy_using_tracking_copy(
+                                    correlation_id,
+                                    &executor,
+                                    &preprocessor,
+                                    protocol_version,
+                                    protocol_data,
+                                    blocktime,
+                                    deploy_item,
+                                    deploy_tracking_copy,
+                                    false,
+                                );
+                            execution_result
+                        }
+                    }
+                }
+            };
+
+            // Make the effects of this deploy visible to the deploys after it
+            let result = match tracking_copy.apply_effect(correlation_id, result.effect().clone()) {
+                Ok(AddResult::Success) => result,
+                Ok(AddResult::KeyNotFound(key)) => {
+                    let error = execution::Error::KeyNotFound(key);
+                    ExecutionResult::precondition_failure(Error::Exec(error))
+                }
+                Ok(AddResult::TypeMismatch(type_mismatch)) => {
+                    let error = execution::Error::TypeMismatch(type_mismatch);
+                    ExecutionResult::precondition_failure(Error::Exec(error))
+                }
+                Ok(AddResult::Serialization(error)) => {
+                    ExecutionResult::precondition_failure(Error::Serialization(error))
+                }
+                Err(error) => ExecutionResult::precondition_failure(Error::Exec(error.into())),
+            };
+
+            results.push(result);
+        }
+
+        Ok((results, tracking_copy.effect()))
+    }
+
+    /// Executes `estimate_request.deploy_item` like [`EngineState::deploy`], but without its
+    /// payment code or the finalization of payment, so the account isn't charged, and with an
+    /// effectively unlimited gas limit for its session code.
+    ///
+    /// The effects of the deploy are returned without being committed.
+    pub fn run_estimate(
+        &self,
+        correlation_id: CorrelationId,
+        estimate_request: EstimateRequest,
+    ) -> Result<EstimateResult, RootNotFound> {
+        let EstimateRequest {
+            parent_state_hash,
+            block_time,
+            deploy_item,
+            protocol_version,
+        } = estimate_request;
+
+        let wasm_costs = match self.wasm_costs(protocol_version) {
+            Ok(Some(wasm_costs)) => wasm_costs,
+            Ok(None) => {
+                let error = Error::InvalidProtocolVersion(protocol_version);
+                return Ok(EstimateResult::precondition_failure(error));
+            }
+            Err(error) => return Ok(EstimateResult::precondition_failure(error)),
+        };
+        let executor = Executor::new(self.config);
+        let preprocessor = Preprocessor::new(wasm_costs);
+
+        let (execution_result, return_value) = self.run_deploy(
+            correlation_id,
+            &executor,
+            &preprocessor,
+            protocol_version,
+            parent_state_hash,
+            BlockTime::new(block_time),
+            deploy_item,
+            true,
+        )?;
+        debug!("Estimate result: {:?}", execution_result);
+
+        Ok(EstimateResult {
+            execution_result,
+            return_value,
+        })
+    }
+
+    pub fn get_module<R>(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         deploy_item: &ExecutableDeployItem,
         account: &Account,
         correlation_id: CorrelationId,
         preprocessor: &Preprocessor,
         protocol_version: &ProtocolVersion,
-    ) -> Result<GetModuleResult, error::Error> {
+    ) -> Result<GetModuleResult, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
         let (contract_package, contract, base_key) = match deploy_item {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
                 let module = preprocessor.preprocess(&module_bytes)?;
@@ -865,13 +1432,17 @@ where
         }
     }
 
-    fn get_module_from_contract_hash(
+    fn get_module_from_contract_hash<R>(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         contract_hash: ContractHash,
         correlation_id: CorrelationId,
         protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
         let contract = tracking_copy
             .borrow_mut()
             .get_contract(correlation_id, contract_hash)?;
@@ -895,13 +1466,17 @@ where
         Ok(module)
     }
 
-    fn get_authorized_account(
+    fn get_authorized_account<R>(
         &self,
         correlation_id: CorrelationId,
         account_public_key: PublicKey,
         authorization_keys: &BTreeSet<PublicKey>,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
-    ) -> Result<Account, Error> {
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+    ) -> Result<Account, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
         let account: Account = match tracking_copy
             .borrow_mut()
             .get_account(correlation_id, account_public_key)
@@ -925,8 +1500,66 @@ where
         Ok(account)
     }
 
+    /// Checks that `nonce` is the next nonce of `account`, if account nonces are enabled.
+    fn check_nonce(&self, account: &Account, nonce: u64) -> Result<(), Error> {
+        if !self.config.enable_account_nonces() {
+            return Ok(());
+        }
+        let expected = account.nonce() + 1;
+        if nonce != expected {
+            return Err(Error::InvalidNonce {
+                expected,
+                actual: nonce,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that `deploy_item` may be executed in a block with the given `blocktime`.
+    fn check_block_time(
+        &self,
+        deploy_item: &DeployItem,
+        blocktime: BlockTime,
+    ) -> Result<(), Error> {
+        if let (None, Some(ttl)) = (deploy_item.valid_from, deploy_item.ttl) {
+            return Err(Error::TtlWithoutValidFrom { ttl });
+        }
+        if deploy_item.is_valid_at(blocktime) {
+            return Ok(());
+        }
+        Err(Error::InvalidBlockTime {
+            block_time: blocktime.into(),
+            valid_from: deploy_item.valid_from,
+            valid_until: deploy_item.valid_until(),
+        })
+    }
+
+    /// Increments the nonce of `account` and writes it to `tracking_copy`, if account nonces are
+    /// enabled.  Returns whether the account was written.
+    ///
+    /// This is done before any code of the deploy runs, so that the increment is part of the
+    /// effects of the deploy even if it fails.
+    fn increment_nonce<R>(
+        &self,
+        account: &mut Account,
+        tracking_copy: &Rc<RefCell<TrackingCopy<R>>>,
+    ) -> bool
+    where
+        R: StateReader<Key, StoredValue>,
+    {
+        if !self.config.enable_account_nonces() {
+            return false;
+        }
+        account.increment_nonce();
+        tracking_copy.borrow_mut().write(
+            Key::Account(account.public_key()),
+            StoredValue::Account(account.clone()),
+        );
+        true
+    }
+
     #[allow(clippy::too_many_arguments)]
-    pub fn transfer(
+    fn execute_deploy_item(
         &self,
         correlation_id: CorrelationId,
         executor: &Executor,
@@ -934,53 +1567,124 @@ where
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
         blocktime: BlockTime,
-        deploy_item: DeployItem,
+        deploy_item: Result<DeployItem, ExecutionResult>,
     ) -> Result<ExecutionResult, RootNotFound> {
-        let protocol_data = match self.state.get_protocol_data(protocol_version) {
-            Ok(Some(protocol_data)) => protocol_data,
-            Ok(None) => {
-                let error = Error::InvalidProtocolVersion(protocol_version);
-                return Ok(ExecutionResult::precondition_failure(error));
-            }
-            Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(Error::Exec(
-                    error.into(),
-                )));
-            }
-        };
-
-        let tracking_copy = match self.tracking_copy(prestate_hash) {
+        match deploy_item {
+            Err(exec_result) => Ok(exec_result),
+            Ok(deploy_item) => match deploy_item.session {
+                ExecutableDeployItem::Transfer { .. } => self.transfer(
+                    correlation_id,
+                    executor,
+                    preprocessor,
+                    protocol_version,
+                    prestate_hash,
+                    blocktime,
+                    deploy_item,
+                ),
+                _ => self.deploy(
+                    correlation_id,
+                    executor,
+                    preprocessor,
+                    protocol_version,
+                    prestate_hash,
+                    blocktime,
+                    deploy_item,
+                ),
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                let error = Error::InvalidProtocolVersion(protocol_version);
+                return Ok(ExecutionResult::precondition_failure(error));
+            }
+            Err(error) => {
+                return Ok(ExecutionResult::precondition_failure(Error::Exec(
+                    error.into(),
+                )));
+            }
+        };
+
+        let tracking_copy = match self.tracking_copy(prestate_hash) {
             Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
             Ok(None) => return Err(RootNotFound::new(prestate_hash)),
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
+        Ok(self.transfer_using_tracking_copy(
+            correlation_id,
+            executor,
+            preprocessor,
+            protocol_version,
+            protocol_data,
+            blocktime,
+            deploy_item,
+            tracking_copy,
+        ))
+    }
+
+    /// Executes a transfer against `tracking_copy` rather than a fresh checkout of global state.
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_using_tracking_copy<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        if let Err(error) = self.check_block_time(&deploy_item, blocktime) {
+            return ExecutionResult::precondition_failure(error);
+        }
+
         let base_key = Key::Account(deploy_item.address);
 
         let account_public_key = match base_key.into_account() {
             Some(account_addr) => account_addr,
             None => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ));
+                return ExecutionResult::precondition_failure(error::Error::Authorization);
             }
         };
 
         let authorization_keys = deploy_item.authorization_keys;
 
-        let account = match self.get_authorized_account(
+        let mut account = match self.get_authorized_account(
             correlation_id,
             account_public_key,
             &authorization_keys,
             Rc::clone(&tracking_copy),
         ) {
             Ok(account) => account,
-            Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
+            Err(e) => return ExecutionResult::precondition_failure(e),
         };
 
+        if let Err(error) = self.check_nonce(&account, deploy_item.nonce) {
+            return ExecutionResult::precondition_failure(error);
+        }
+
         let input_runtime_args = match deploy_item.session.into_runtime_args() {
             Ok(runtime_args) => runtime_args,
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
         };
 
         let runtime_args_builder = TransferRuntimeArgsBuilder::new(input_runtime_args);
@@ -989,11 +1693,12 @@ where
             match runtime_args_builder.build(correlation_id, &account, Rc::clone(&tracking_copy)) {
                 Ok(runtime_args) => runtime_args,
                 Err(error) => {
-                    return Ok(ExecutionResult::Failure {
+                    return ExecutionResult::Failure {
                         error,
                         effect: Default::default(),
+                        events: Vec::new(),
                         cost: Gas::default(),
-                    });
+                    };
                 }
             };
 
@@ -1003,7 +1708,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return ExecutionResult::precondition_failure(error.into());
             }
         };
 
@@ -1019,11 +1724,13 @@ where
             ) {
                 Ok(module) => module,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
 
+        self.increment_nonce(&mut account, &tracking_copy);
+
         let mut named_keys = mint_contract.named_keys().to_owned();
         let base_key = Key::from(protocol_data.mint());
         let deploy_hash = deploy_item.deploy_hash;
@@ -1031,7 +1738,7 @@ where
         let phase = Phase::Session;
         let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-        Ok(executor.exec_system_contract(
+        executor.exec_system_contract(
             direct_system_contract_call,
             parity_module,
             runtime_args,
@@ -1048,7 +1755,7 @@ where
             phase,
             protocol_data,
             system_contract_cache,
-        ))
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1062,6 +1769,33 @@ where
         blocktime: BlockTime,
         deploy_item: DeployItem,
     ) -> Result<ExecutionResult, RootNotFound> {
+        let (execution_result, _return_value) = self.run_deploy(
+            correlation_id,
+            executor,
+            preprocessor,
+            protocol_version,
+            prestate_hash,
+            blocktime,
+            deploy_item,
+            false,
+        )?;
+        Ok(execution_result)
+    }
+
+    /// Executes a deploy like [`EngineState::deploy`], skipping its payment if `bypass_payment` is
+    /// set, as described in [`EngineState::deploy_using_tracking_copy`].
+    #[allow(clippy::too_many_arguments)]
+    fn run_deploy(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+        bypass_payment: bool,
+    ) -> Result<(ExecutionResult, Option<CLValue>), RootNotFound> {
         // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
 
         // Obtain current protocol data for given version
@@ -1070,12 +1804,11 @@ where
             Ok(Some(protocol_data)) => protocol_data,
             Ok(None) => {
                 let error = Error::InvalidProtocolVersion(protocol_version);
-                return Ok(ExecutionResult::precondition_failure(error));
+                return Ok((ExecutionResult::precondition_failure(error), None));
             }
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(Error::Exec(
-                    error.into(),
-                )));
+                let error = Error::Exec(error.into());
+                return Ok((ExecutionResult::precondition_failure(error), None));
             }
         };
 
@@ -1083,11 +1816,52 @@ where
         // validation_spec_2: prestate_hash check
         // do this second; as there is no reason to proceed if the prestate hash is invalid
         let tracking_copy = match self.tracking_copy(prestate_hash) {
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            Err(error) => return Ok((ExecutionResult::precondition_failure(error), None)),
             Ok(None) => return Err(RootNotFound::new(prestate_hash)),
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
+        Ok(self.deploy_using_tracking_copy(
+            correlation_id,
+            executor,
+            preprocessor,
+            protocol_version,
+            protocol_data,
+            blocktime,
+            deploy_item,
+            tracking_copy,
+            bypass_payment,
+        ))
+    }
+
+    /// Executes a deploy against `tracking_copy` rather than a fresh checkout of global state.
+    ///
+    /// If `bypass_payment` is set, the payment code, the check of the account's main purse balance
+    /// and the finalization of payment are skipped, and the session code is executed with an
+    /// effectively unlimited gas limit.  The value returned by the session code is only provided in
+    /// that case.
+    #[allow(clippy::too_many_arguments)]
+    fn deploy_using_tracking_copy<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        bypass_payment: bool,
+    ) -> (ExecutionResult, Option<CLValue>)
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        // Reject the deploy before running any code if it's outside its validity window
+        if let Err(error) = self.check_block_time(&deploy_item, blocktime) {
+            return (ExecutionResult::precondition_failure(error), None);
+        }
+
         let base_key = Key::Account(deploy_item.address);
 
         // Get addr bytes from `address` (which is actually a Key)
@@ -1095,9 +1869,10 @@ where
         let account_public_key = match base_key.into_account() {
             Some(account_addr) => account_addr,
             None => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ));
+                return (
+                    ExecutionResult::precondition_failure(error::Error::Authorization),
+                    None,
+                );
             }
         };
 
@@ -1105,16 +1880,21 @@ where
 
         // Get account from tracking copy
         // validation_spec_3: account validity
-        let account = match self.get_authorized_account(
+        let mut account = match self.get_authorized_account(
             correlation_id,
             account_public_key,
             &authorization_keys,
             Rc::clone(&tracking_copy),
         ) {
             Ok(account) => account,
-            Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
+            Err(e) => return (ExecutionResult::precondition_failure(e), None),
         };
 
+        // Check the deploy is the next one expected from the account
+        if let Err(error) = self.check_nonce(&account, deploy_item.nonce) {
+            return (ExecutionResult::precondition_failure(error), None);
+        }
+
         let session = deploy_item.session;
         let payment = deploy_item.payment;
         let deploy_hash = deploy_item.deploy_hash;
@@ -1132,7 +1912,7 @@ where
         ) {
             Ok(module) => module,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
+                return (ExecutionResult::precondition_failure(error), None);
             }
         };
 
@@ -1146,7 +1926,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return (ExecutionResult::precondition_failure(error.into()), None);
             }
         };
 
@@ -1160,7 +1940,7 @@ where
             ) {
                 Ok(contract) => contract,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return (ExecutionResult::precondition_failure(error.into()), None);
                 }
             };
 
@@ -1180,7 +1960,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return (ExecutionResult::precondition_failure(error.into()), None);
             }
         };
 
@@ -1192,7 +1972,7 @@ where
         ) {
             Ok(module) => module,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return (ExecutionResult::precondition_failure(error.into()), None);
             }
         };
 
@@ -1202,6 +1982,49 @@ where
                 .insert(proof_of_stake_hash, proof_of_stake_module.clone());
         }
 
+        if bypass_payment {
+            self.increment_nonce(&mut account, &tracking_copy);
+
+            let (
+                session_module,
+                session_base_key,
+                session_named_keys,
+                session_package,
+                session_entry_point,
+            ) = session_module.into_execution_context(&account);
+
+            let session_args = match session.into_runtime_args() {
+                Ok(args) => args,
+                Err(e) => {
+                    let exec_err: crate::execution::Error = e.into();
+                    warn!("Unable to deserialize session arguments: {:?}", exec_err);
+                    return (ExecutionResult::precondition_failure(exec_err.into()), None);
+                }
+            };
+            let gas_limit = Gas::new(U512::from(std::u64::MAX));
+            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+            return executor.exec_with_return_value(
+                session_module,
+                session_entry_point,
+                session_args,
+                session_base_key,
+                &account,
+                session_named_keys,
+                authorization_keys,
+                blocktime,
+                deploy_hash,
+                gas_limit,
+                protocol_version,
+                correlation_id,
+                tracking_copy,
+                Phase::Session,
+                protocol_data,
+                system_contract_cache,
+                &session_package,
+            );
+        }
+
         // Get account main purse balance key
         // validation_spec_5: account main purse minimum balance
         let account_main_purse_balance_key: Key = {
@@ -1212,7 +2035,7 @@ where
             {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return (ExecutionResult::precondition_failure(error.into()), None);
                 }
             }
         };
@@ -1224,19 +2047,23 @@ where
             .get_purse_balance(correlation_id, account_main_purse_balance_key)
         {
             Ok(balance) => balance,
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+            Err(error) => return (ExecutionResult::precondition_failure(error.into()), None),
         };
 
-        let max_payment_cost: Motes = Motes::new(U512::from(MAX_PAYMENT));
+        let payment_costs = *protocol_data.payment_costs();
+        let max_payment_cost: Motes = payment_costs.max_payment_cost_motes();
 
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
         if account_main_purse_balance < max_payment_cost {
-            return Ok(ExecutionResult::precondition_failure(
-                Error::InsufficientPayment,
-            ));
+            return (
+                ExecutionResult::precondition_failure(Error::InsufficientPayment),
+                None,
+            );
         }
 
+        let nonce_incremented = self.increment_nonce(&mut account, &tracking_copy);
+
         // Finalization is executed by system account (currently genesis account)
         // payment_code_spec_5: system executes finalization
         let system_account = Account::new(
@@ -1254,7 +2081,8 @@ where
         let payment_result = {
             // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
             // conv_rate)
-            let pay_gas_limit = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+            let pay_gas_limit =
+                Gas::from_motes(max_payment_cost, payment_costs.conv_rate).unwrap_or_default();
 
             let module_bytes_is_empty = match payment {
                 ExecutableDeployItem::ModuleBytes {
@@ -1266,16 +2094,21 @@ where
             // Create payment code module from bytes
             // validation_spec_1: valid wasm bytes
             let maybe_payment_module = if module_bytes_is_empty {
-                let standard_payment_hash: ContractHash =
-                    match self.state.get_protocol_data(protocol_version) {
-                        Ok(Some(protocol_data)) => protocol_data.standard_payment(),
-                        Ok(None) => {
-                            return Ok(ExecutionResult::precondition_failure(
-                                Error::InvalidProtocolVersion(protocol_version),
-                            ));
-                        }
-                        Err(_) => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
-                    };
+                let standard_payment_hash: ContractHash = match self
+                    .state
+                    .get_protocol_data(protocol_version)
+                {
+                    Ok(Some(protocol_data)) => protocol_data.standard_payment(),
+                    Ok(None) => {
+                        return (
+                            ExecutionResult::precondition_failure(Error::InvalidProtocolVersion(
+                                protocol_version,
+                            )),
+                            None,
+                        );
+                    }
+                    Err(_) => return (ExecutionResult::precondition_failure(Error::Deploy), None),
+                };
 
                 // if "use-system-contracts" is false, "do_nothing" wasm is returned
                 self.get_module_from_contract_hash(
@@ -1303,7 +2136,7 @@ where
             let payment_module = match maybe_payment_module {
                 Ok(module) => module,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error));
+                    return (ExecutionResult::precondition_failure(error), None);
                 }
             };
 
@@ -1315,39 +2148,14 @@ where
                 mut payment_named_keys,
                 payment_package,
                 payment_entry_point,
-            ) = match payment_module {
-                GetModuleResult::Session {
-                    module,
-                    contract_package,
-                    entry_point,
-                } => (
-                    module,
-                    base_key,
-                    account.named_keys().clone(),
-                    contract_package,
-                    entry_point,
-                ),
-                GetModuleResult::Contract {
-                    module,
-                    base_key,
-                    contract,
-                    contract_package,
-                    entry_point,
-                } => (
-                    module,
-                    base_key,
-                    contract.named_keys().clone(),
-                    contract_package,
-                    entry_point,
-                ),
-            };
+            ) = payment_module.into_execution_context(&account);
 
             let payment_args = match payment.into_runtime_args() {
                 Ok(args) => args,
                 Err(e) => {
                     let exec_err: crate::execution::Error = e.into();
                     warn!("Unable to deserialize arguments: {:?}", exec_err);
-                    return Ok(ExecutionResult::precondition_failure(exec_err.into()));
+                    return (ExecutionResult::precondition_failure(exec_err.into()), None);
                 }
             };
 
@@ -1383,6 +2191,11 @@ where
                     let generator = AddressGenerator::new(&deploy_hash, phase);
                     Rc::new(RefCell::new(generator))
                 };
+                let random_bytes_generator = {
+                    let generator =
+                        AddressGenerator::new_random_bytes_generator(&deploy_hash, phase);
+                    Rc::new(RefCell::new(generator))
+                };
 
                 let mut runtime = match executor.create_runtime(
                     payment_module,
@@ -1397,6 +2210,7 @@ where
                     pay_gas_limit,
                     hash_address_generator,
                     uref_address_generator,
+                    random_bytes_generator,
                     protocol_version,
                     correlation_id,
                     Rc::clone(&tracking_copy),
@@ -1406,7 +2220,10 @@ where
                 ) {
                     Ok((_instance, runtime)) => runtime,
                     Err(error) => {
-                        return Ok(ExecutionResult::precondition_failure(Error::Exec(error)));
+                        return (
+                            ExecutionResult::precondition_failure(Error::Exec(error)),
+                            None,
+                        );
                     }
                 };
 
@@ -1415,11 +2232,13 @@ where
                 match runtime.call_host_standard_payment() {
                     Ok(()) => ExecutionResult::Success {
                         effect: runtime.context().effect(),
+                        events: runtime.context().events().to_vec(),
                         cost: runtime.context().gas_counter(),
                     },
                     Err(error) => ExecutionResult::Failure {
                         error: error.into(),
                         effect: effects_snapshot,
+                        events: Vec::new(),
                         cost: runtime.context().gas_counter(),
                     },
                 }
@@ -1437,7 +2256,7 @@ where
             let payment_purse_key: Key =
                 match proof_of_stake_contract.named_keys().get(POS_PAYMENT_PURSE) {
                     Some(key) => *key,
-                    None => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
+                    None => return (ExecutionResult::precondition_failure(Error::Deploy), None),
                 };
 
             let purse_balance_key = match tracking_copy
@@ -1446,7 +2265,7 @@ where
             {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return (ExecutionResult::precondition_failure(error.into()), None);
                 }
             };
 
@@ -1456,12 +2275,14 @@ where
             {
                 Ok(balance) => balance,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return (ExecutionResult::precondition_failure(error.into()), None);
                 }
             }
         };
 
-        if let Some(forced_transfer) = payment_result.check_forced_transfer(payment_purse_balance) {
+        if let Some(forced_transfer) =
+            payment_result.check_forced_transfer(payment_purse_balance, payment_costs.conv_rate)
+        {
             // Get rewards purse balance key
             // payment_code_spec_6: system contract validity
             let rewards_purse_balance_key: Key = {
@@ -1471,7 +2292,7 @@ where
                     match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE) {
                         Some(key) => *key,
                         None => {
-                            return Ok(ExecutionResult::precondition_failure(Error::Deploy));
+                            return (ExecutionResult::precondition_failure(Error::Deploy), None);
                         }
                     };
 
@@ -1481,7 +2302,7 @@ where
                 {
                     Ok(key) => key,
                     Err(error) => {
-                        return Ok(ExecutionResult::precondition_failure(error.into()));
+                        return (ExecutionResult::precondition_failure(error.into()), None);
                     }
                 }
             };
@@ -1490,13 +2311,21 @@ where
                 ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
                 ForcedTransferResult::PaymentFailure => payment_result.take_error().unwrap(),
             };
-            return Ok(ExecutionResult::new_payment_code_error(
-                error,
-                max_payment_cost,
-                account_main_purse_balance,
-                account_main_purse_balance_key,
-                rewards_purse_balance_key,
-            ));
+            return (
+                ExecutionResult::new_payment_code_error(
+                    error,
+                    payment_costs,
+                    account_main_purse_balance,
+                    account_main_purse_balance_key,
+                    rewards_purse_balance_key,
+                    if nonce_incremented {
+                        Some(account)
+                    } else {
+                        None
+                    },
+                ),
+                None,
+            );
         }
 
         execution_result_builder.set_payment_execution_result(payment_result);
@@ -1511,39 +2340,14 @@ where
             session_named_keys,
             session_package,
             session_entry_point,
-        ) = match session_module {
-            GetModuleResult::Session {
-                module,
-                contract_package,
-                entry_point,
-            } => (
-                module,
-                base_key,
-                account.named_keys().clone(),
-                contract_package,
-                entry_point,
-            ),
-            GetModuleResult::Contract {
-                module,
-                base_key,
-                contract,
-                contract_package,
-                entry_point,
-            } => (
-                module,
-                base_key,
-                contract.named_keys().clone(),
-                contract_package,
-                entry_point,
-            ),
-        };
+        ) = session_module.into_execution_context(&account);
 
         let session_args = match session.into_runtime_args() {
             Ok(args) => args,
             Err(e) => {
                 let exec_err: crate::execution::Error = e.into();
                 warn!("Unable to deserialize session arguments: {:?}", exec_err);
-                return Ok(ExecutionResult::precondition_failure(exec_err.into()));
+                return (ExecutionResult::precondition_failure(exec_err.into()), None);
             }
         };
         let session_result = {
@@ -1551,9 +2355,9 @@ where
             // payment code execution) * conv_rate, yes session
             // session_code_spec_1: gas limit = ((balance of PoS payment purse) / conv_rate)
             // - (gas spent during payment execution)
-            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, CONV_RATE)
-                .unwrap_or_default()
-                - payment_result_cost;
+            let session_gas_limit: Gas =
+                Gas::from_motes(payment_purse_balance, payment_costs.conv_rate).unwrap_or_default()
+                    - payment_result_cost;
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
             executor.exec(
@@ -1597,7 +2401,7 @@ where
 
             let proof_of_stake_args = {
                 //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
-                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), CONV_RATE).expect("motes overflow");
+                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), payment_costs.conv_rate).expect("motes overflow");
                 const ARG_AMOUNT: &str = "amount";
                 const ARG_ACCOUNT_KEY: &str = "account";
                 runtime_args! {
@@ -1613,7 +2417,7 @@ where
                 .get_contract(correlation_id, proof_of_stake_hash)
             {
                 Ok(info) => info,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                Err(error) => return (ExecutionResult::precondition_failure(error.into()), None),
             };
 
             let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
@@ -1651,7 +2455,7 @@ where
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
-        Ok(ret)
+        (ret, None)
     }
 
     pub fn apply_effect(
@@ -1677,6 +2481,235 @@ where
         }
     }
 
+    pub fn get_bid_state(
+        &self,
+        correlation_id: CorrelationId,
+        bid_state_request: BidStateRequest,
+    ) -> Result<BidStateResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let protocol_version = bid_state_request.protocol_version();
+        let protocol_data = match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let reader = match self.state.checkout(bid_state_request.pre_state_hash())? {
+            Some(reader) => reader,
+            None => return Ok(BidStateResult::RootNotFound),
+        };
+
+        let bids = Self::read_bonded_validators(correlation_id, &protocol_data, &reader)?;
+
+        Ok(BidStateResult::Success { bids })
+    }
+
+    pub fn commit_slash(
+        &self,
+        correlation_id: CorrelationId,
+        slash_request: SlashRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let args = runtime_args! {
+            ARG_VALIDATORS => slash_request.slashes().to_owned(),
+        };
+
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            DirectSystemContractCall::Slash,
+            slash_request.pre_state_hash(),
+            slash_request.protocol_version(),
+            BlockTime::default(),
+            args,
+        )
+    }
+
+    pub fn commit_distribute_rewards(
+        &self,
+        correlation_id: CorrelationId,
+        distribute_rewards_request: DistributeRewardsRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let args = runtime_args! {
+            ARG_REWARDS => distribute_rewards_request.rewards().to_owned(),
+        };
+
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            DirectSystemContractCall::DistributeRewards,
+            distribute_rewards_request.pre_state_hash(),
+            distribute_rewards_request.protocol_version(),
+            BlockTime::default(),
+            args,
+        )
+    }
+
+    pub fn commit_unbond_payout(
+        &self,
+        correlation_id: CorrelationId,
+        unbond_payout_request: UnbondPayoutRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            DirectSystemContractCall::UnbondPayout,
+            unbond_payout_request.pre_state_hash(),
+            unbond_payout_request.protocol_version(),
+            unbond_payout_request.block_time(),
+            RuntimeArgs::new(),
+        )
+    }
+
+    pub fn commit_step(
+        &self,
+        correlation_id: CorrelationId,
+        step_request: StepRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            DirectSystemContractCall::Step,
+            step_request.pre_state_hash(),
+            step_request.protocol_version(),
+            step_request.block_time(),
+            RuntimeArgs::new(),
+        )
+    }
+
+    pub fn commit_distribute_seigniorage(
+        &self,
+        correlation_id: CorrelationId,
+        distribute_seigniorage_request: DistributeSeigniorageRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let protocol_version = distribute_seigniorage_request.protocol_version();
+        let protocol_data = match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let args = runtime_args! {
+            ARG_REWARD_RATE => protocol_data.reward_rate(),
+        };
+
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            DirectSystemContractCall::DistributeSeigniorage,
+            distribute_seigniorage_request.pre_state_hash(),
+            protocol_version,
+            BlockTime::default(),
+            args,
+        )
+    }
+
+    /// Runs one of the system-only proof-of-stake entry points as the system account on top of
+    /// `pre_state_hash`, and commits the resulting effects.
+    fn commit_proof_of_stake_call(
+        &self,
+        correlation_id: CorrelationId,
+        direct_system_contract_call: DirectSystemContractCall,
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        blocktime: BlockTime,
+        args: RuntimeArgs,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let protocol_data = match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(CommitResult::RootNotFound),
+        };
+
+        let proof_of_stake_hash = protocol_data.proof_of_stake();
+
+        let proof_of_stake_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, proof_of_stake_hash)?;
+
+        let proof_of_stake_module = {
+            let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+            tracking_copy.borrow_mut().get_system_module(
+                correlation_id,
+                proof_of_stake_contract.contract_wasm_hash(),
+                self.config.use_system_contracts(),
+                &preprocessor,
+            )?
+        };
+
+        let mut named_keys = proof_of_stake_contract.named_keys().to_owned();
+
+        let system_account = Account::new(
+            SYSTEM_ACCOUNT_ADDR,
+            Default::default(),
+            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
+            Default::default(),
+            Default::default(),
+        );
+
+        let authorization_keys = {
+            let mut ret = BTreeSet::new();
+            ret.insert(SYSTEM_ACCOUNT_ADDR);
+            ret
+        };
+
+        let deploy_hash = {
+            // seeds address generator w/ pre-state hash and the called entry point
+            let mut bytes = pre_state_hash.to_vec();
+            bytes.extend_from_slice(direct_system_contract_call.entry_point_name().as_bytes());
+            Blake2bHash::new(&bytes).into()
+        };
+
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+        let executor = Executor::new(self.config);
+
+        let execution_result = executor.exec_system_contract(
+            direct_system_contract_call,
+            proof_of_stake_module,
+            args,
+            &mut named_keys,
+            Key::from(proof_of_stake_hash),
+            &system_account,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::System,
+            protocol_data,
+            system_contract_cache,
+        );
+
+        match execution_result {
+            ExecutionResult::Failure { error, .. } => Err(error),
+            ExecutionResult::Success { effect, .. } => self.apply_effect(
+                correlation_id,
+                protocol_version,
+                pre_state_hash,
+                effect.transforms,
+            ),
+        }
+    }
+
     /// Calculates bonded validators at `root_hash` state.
     ///
     /// Should only be called with a valid root hash after a successful call to
@@ -1695,13 +2728,25 @@ where
             None => return Err(Error::InvalidProtocolVersion(protocol_version)),
         };
 
-        let proof_of_stake_key = protocol_data.proof_of_stake().into();
-
         let reader = match self.state.checkout(root_hash)? {
             Some(reader) => reader,
             None => panic!("get_bonded_validators called with an invalid root hash"),
         };
 
+        Self::read_bonded_validators(correlation_id, &protocol_data, &reader)
+    }
+
+    /// Reads the bonded validators from the named keys of the PoS contract visible to `reader`.
+    fn read_bonded_validators(
+        correlation_id: CorrelationId,
+        protocol_data: &ProtocolData,
+        reader: &S::Reader,
+    ) -> Result<HashMap<PublicKey, U512>, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let proof_of_stake_key = protocol_data.proof_of_stake().into();
+
         let contract = match reader.read(correlation_id, &proof_of_stake_key)? {
             Some(StoredValue::Contract(contract)) => contract,
             _ => return Err(MissingSystemContract(PROOF_OF_STAKE.to_string())),
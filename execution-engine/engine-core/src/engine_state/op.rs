@@ -12,6 +12,17 @@ pub enum Op {
     NoOp,
 }
 
+impl Op {
+    /// Returns `true` if applying `self` and `other` to the same key gives the same outcome
+    /// regardless of the order in which they are applied.
+    pub fn commutes_with(self, other: Op) -> bool {
+        matches!(
+            (self, other),
+            (_, Op::NoOp) | (Op::NoOp, _) | (Op::Read, Op::Read) | (Op::Add, Op::Add)
+        )
+    }
+}
+
 impl Add for Op {
     type Output = Op;
 
@@ -43,3 +54,28 @@ impl Default for Op {
         Op::NoOp
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Op;
+
+    #[test]
+    fn should_commute() {
+        assert!(Op::Read.commutes_with(Op::Read));
+        assert!(Op::Add.commutes_with(Op::Add));
+        for op in &[Op::Read, Op::Write, Op::Add, Op::NoOp] {
+            assert!(op.commutes_with(Op::NoOp));
+            assert!(Op::NoOp.commutes_with(*op));
+        }
+    }
+
+    #[test]
+    fn should_not_commute() {
+        assert!(!Op::Read.commutes_with(Op::Add));
+        assert!(!Op::Add.commutes_with(Op::Read));
+        for op in &[Op::Read, Op::Write, Op::Add] {
+            assert!(!op.commutes_with(Op::Write));
+            assert!(!Op::Write.commutes_with(*op));
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use engine_shared::newtypes::Blake2bHash;
+
+pub use engine_storage::global_state::PruneResult;
+
+/// The number of trie elements deleted per write transaction if a request doesn't specify it.
+pub const DEFAULT_PRUNE_BATCH_SIZE: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneRequest {
+    roots_to_keep: Vec<Blake2bHash>,
+    batch_size: usize,
+}
+
+impl PruneRequest {
+    pub fn new(roots_to_keep: Vec<Blake2bHash>, batch_size: usize) -> Self {
+        PruneRequest {
+            roots_to_keep,
+            batch_size,
+        }
+    }
+
+    pub fn roots_to_keep(&self) -> &[Blake2bHash] {
+        &self.roots_to_keep
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+}
@@ -1,8 +1,15 @@
 use engine_shared::{newtypes::Blake2bHash, stored_value::StoredValue};
-use types::Key;
+use engine_storage::trie::merkle_proof::TrieMerkleProof;
+use types::{Key, KeyTag};
 
 use crate::tracking_copy::TrackingCopyQueryResult;
 
+/// The number of keys listed per page if a request doesn't specify it.
+pub const DEFAULT_LIST_KEYS_LIMIT: usize = 100;
+
+/// The maximum number of keys listed per page.
+pub const MAX_LIST_KEYS_LIMIT: usize = 10_000;
+
 pub enum QueryResult {
     RootNotFound,
     ValueNotFound(String),
@@ -10,6 +17,23 @@ pub enum QueryResult {
     Success(StoredValue),
 }
 
+pub enum QueryWithProofResult {
+    RootNotFound,
+    ValueNotFound(String),
+    Success(TrieMerkleProof<Key, StoredValue>),
+}
+
+pub enum ListKeysResult {
+    RootNotFound,
+    Success {
+        /// The listed keys in order of their serialized form, along with their values if they
+        /// were requested.
+        entries: Vec<(Key, Option<StoredValue>)>,
+        /// The cursor from which to continue listing, if there are more keys to list.
+        next_cursor: Option<Vec<u8>>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryRequest {
     state_hash: Blake2bHash,
@@ -39,6 +63,86 @@ impl QueryRequest {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryWithProofRequest {
+    state_hash: Blake2bHash,
+    key: Key,
+}
+
+impl QueryWithProofRequest {
+    pub fn new(state_hash: Blake2bHash, key: Key) -> Self {
+        QueryWithProofRequest { state_hash, key }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn key(&self) -> Key {
+        self.key
+    }
+}
+
+/// A request to list the keys of a given variant stored under a state hash, a page at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListKeysRequest {
+    state_hash: Blake2bHash,
+    key_tag: KeyTag,
+    prefix: Vec<u8>,
+    cursor: Option<Vec<u8>>,
+    limit: usize,
+    include_values: bool,
+}
+
+impl ListKeysRequest {
+    /// Creates a request for up to `limit` keys whose serialized form starts with `key_tag`
+    /// followed by `prefix`.
+    ///
+    /// The first page is listed if `cursor` is `None`, otherwise the page following the one which
+    /// returned `cursor`.
+    pub fn new(
+        state_hash: Blake2bHash,
+        key_tag: KeyTag,
+        prefix: Vec<u8>,
+        cursor: Option<Vec<u8>>,
+        limit: usize,
+        include_values: bool,
+    ) -> Self {
+        ListKeysRequest {
+            state_hash,
+            key_tag,
+            prefix,
+            cursor,
+            limit,
+            include_values,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn key_tag(&self) -> KeyTag {
+        self.key_tag
+    }
+
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    pub fn cursor(&self) -> Option<&[u8]> {
+        self.cursor.as_deref()
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn include_values(&self) -> bool {
+        self.include_values
+    }
+}
+
 impl From<TrackingCopyQueryResult> for QueryResult {
     fn from(tracking_copy_query_result: TrackingCopyQueryResult) -> Self {
         match tracking_copy_query_result {
@@ -0,0 +1,26 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::ProtocolVersion;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributeSeigniorageRequest {
+    pre_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+}
+
+impl DistributeSeigniorageRequest {
+    pub fn new(pre_state_hash: Blake2bHash, protocol_version: ProtocolVersion) -> Self {
+        DistributeSeigniorageRequest {
+            pre_state_hash,
+            protocol_version,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    /// The reward rate is taken from the protocol data of this version.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashRequest {
+    pre_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    slashes: BTreeMap<PublicKey, U512>,
+}
+
+impl SlashRequest {
+    pub fn new(
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        slashes: BTreeMap<PublicKey, U512>,
+    ) -> Self {
+        SlashRequest {
+            pre_state_hash,
+            protocol_version,
+            slashes,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn slashes(&self) -> &BTreeMap<PublicKey, U512> {
+        &self.slashes
+    }
+}
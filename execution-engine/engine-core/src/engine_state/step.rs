@@ -0,0 +1,36 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{BlockTime, ProtocolVersion};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepRequest {
+    pre_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    block_time: BlockTime,
+}
+
+impl StepRequest {
+    pub fn new(
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        block_time: BlockTime,
+    ) -> Self {
+        StepRequest {
+            pre_state_hash,
+            protocol_version,
+            block_time,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Bonding and unbonding requests which have matured by this block time are applied.
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+}
@@ -110,18 +110,15 @@ impl TransferRuntimeArgsBuilder {
 
                 Ok(uref)
             }
-            Some(cl_value)
-                if *cl_value.cl_type()
-                    == types::CLType::FixedList(Box::new(types::CLType::U8), 32) =>
-            {
+            Some(cl_value) if *cl_value.cl_type() == types::CLType::PublicKey => {
                 let account_key: Key = {
-                    let hash = match cl_value.clone().into_t() {
-                        Ok(hash) => hash,
+                    let public_key = match cl_value.clone().into_t() {
+                        Ok(public_key) => public_key,
                         Err(error) => {
                             return Err(Error::Exec(ExecError::Revert(error.into())));
                         }
                     };
-                    Key::Account(hash)
+                    Key::Account(public_key)
                 };
                 match account_key.into_account() {
                     Some(public_key) => {
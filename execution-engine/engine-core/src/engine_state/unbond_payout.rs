@@ -0,0 +1,43 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{BlockTime, ProtocolVersion};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnbondPayoutRequest {
+    pre_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    era_height: u64,
+    block_time: BlockTime,
+}
+
+impl UnbondPayoutRequest {
+    pub fn new(
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        era_height: u64,
+        block_time: BlockTime,
+    ) -> Self {
+        UnbondPayoutRequest {
+            pre_state_hash,
+            protocol_version,
+            era_height,
+            block_time,
+        }
+    }
+
+    pub fn pre_state_hash(&self) -> Blake2bHash {
+        self.pre_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn era_height(&self) -> u64 {
+        self.era_height
+    }
+
+    /// Unbonding requests made at or before this block time are paid out.
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+}
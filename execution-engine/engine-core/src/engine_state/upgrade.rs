@@ -1,11 +1,14 @@
 use std::fmt;
 
-use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{newtypes::Blake2bHash, payment_costs::PaymentCosts, TypeMismatch};
 use engine_storage::global_state::CommitResult;
-use engine_wasm_prep::wasm_costs::WasmCosts;
-use types::{bytesrepr, Key, ProtocolVersion};
+use engine_wasm_prep::{host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts};
+use types::{bytesrepr, Key, ProtocolVersion, U512};
 
-use crate::engine_state::execution_effect::ExecutionEffect;
+use crate::engine_state::{
+    execution_effect::ExecutionEffect,
+    genesis::{QueueDelays, StakeLimits},
+};
 
 pub type ActivationPoint = u64;
 
@@ -60,10 +63,17 @@ pub struct UpgradeConfig {
     upgrade_installer_args: Option<Vec<u8>>,
     upgrade_installer_bytes: Option<Vec<u8>>,
     wasm_costs: Option<WasmCosts>,
+    host_function_costs: Option<HostFunctionCosts>,
+    payment_costs: Option<PaymentCosts>,
+    queue_delays: Option<QueueDelays>,
+    stake_limits: Option<StakeLimits>,
+    reward_rate: Option<u64>,
+    initial_total_supply: Option<U512>,
     activation_point: Option<ActivationPoint>,
 }
 
 impl UpgradeConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pre_state_hash: Blake2bHash,
         current_protocol_version: ProtocolVersion,
@@ -71,6 +81,12 @@ impl UpgradeConfig {
         upgrade_installer_args: Option<Vec<u8>>,
         upgrade_installer_bytes: Option<Vec<u8>>,
         wasm_costs: Option<WasmCosts>,
+        host_function_costs: Option<HostFunctionCosts>,
+        payment_costs: Option<PaymentCosts>,
+        queue_delays: Option<QueueDelays>,
+        stake_limits: Option<StakeLimits>,
+        reward_rate: Option<u64>,
+        initial_total_supply: Option<U512>,
         activation_point: Option<ActivationPoint>,
     ) -> Self {
         UpgradeConfig {
@@ -80,6 +96,12 @@ impl UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            queue_delays,
+            stake_limits,
+            reward_rate,
+            initial_total_supply,
             activation_point,
         }
     }
@@ -110,6 +132,32 @@ impl UpgradeConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> Option<HostFunctionCosts> {
+        self.host_function_costs
+    }
+
+    pub fn payment_costs(&self) -> Option<PaymentCosts> {
+        self.payment_costs
+    }
+
+    pub fn queue_delays(&self) -> Option<QueueDelays> {
+        self.queue_delays
+    }
+
+    pub fn stake_limits(&self) -> Option<StakeLimits> {
+        self.stake_limits
+    }
+
+    pub fn reward_rate(&self) -> Option<u64> {
+        self.reward_rate
+    }
+
+    /// The total supply of motes recorded by the mint if it doesn't record one yet, as is the
+    /// case for a mint installed before the total supply was tracked.
+    pub fn initial_total_supply(&self) -> Option<U512> {
+        self.initial_total_supply
+    }
+
     pub fn activation_point(&self) -> Option<u64> {
         self.activation_point
     }
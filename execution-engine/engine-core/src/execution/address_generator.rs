@@ -11,6 +11,9 @@ use crate::{Address, ADDRESS_LENGTH};
 
 const SEED_LENGTH: usize = 32;
 
+/// Domain separator mixed into the seed of generators created for the `random_bytes` host function.
+const RANDOM_BYTES_DOMAIN: &[u8] = b"random_bytes";
+
 /// An [`AddressGenerator`] generates [`URef`](types::URef) addresses.
 pub struct AddressGenerator(ChaChaRng);
 
@@ -23,11 +26,28 @@ impl AddressGenerator {
             .build()
     }
 
+    /// Creates an [`AddressGenerator`] for the `random_bytes` host function from a 32-byte hash
+    /// digest and [`Phase`].
+    ///
+    /// Its output is unrelated to that of a generator created via [`AddressGenerator::new`] from
+    /// the same inputs.
+    pub fn new_random_bytes_generator(hash: &[u8], phase: Phase) -> AddressGenerator {
+        AddressGeneratorBuilder::new()
+            .seed_with(hash)
+            .seed_with(&[phase as u8])
+            .seed_with(RANDOM_BYTES_DOMAIN)
+            .build()
+    }
+
     pub fn create_address(&mut self) -> Address {
         let mut buff = [0u8; ADDRESS_LENGTH];
         self.0.fill_bytes(&mut buff);
         buff
     }
+
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
 }
 
 /// A builder for [`AddressGenerator`].
@@ -103,4 +123,15 @@ mod tests {
             "different phase should have different output"
         );
     }
+
+    #[test]
+    fn should_not_generate_same_numbers_for_random_bytes() {
+        let mut ag_a = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut ag_b = AddressGenerator::new_random_bytes_generator(&DEPLOY_HASH_1, Phase::Session);
+        let address = ag_a.create_address();
+        let mut random_bytes = [0u8; 32];
+        ag_b.fill_bytes(&mut random_bytes);
+
+        assert_ne!(address, random_bytes);
+    }
 }
@@ -44,6 +44,7 @@ macro_rules! on_fail_charge {
                 return ExecutionResult::Failure {
                     error: exec_err.into(),
                     effect: Default::default(),
+                    events: Vec::new(),
                     cost: $cost,
                 };
             }
@@ -58,6 +59,7 @@ macro_rules! on_fail_charge {
                 return ExecutionResult::Failure {
                     error: exec_err.into(),
                     effect: $effect,
+                    events: Vec::new(),
                     cost: $cost,
                 };
             }
@@ -86,7 +88,7 @@ impl Executor {
         args: RuntimeArgs,
         base_key: Key,
         account: &Account,
-        mut named_keys: NamedKeys,
+        named_keys: NamedKeys,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
         deploy_hash: [u8; 32],
@@ -99,6 +101,59 @@ impl Executor {
         system_contract_cache: SystemContractCache,
         contract_package: &ContractPackage,
     ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        let (execution_result, _return_value) = self.exec_with_return_value(
+            module,
+            entry_point,
+            args,
+            base_key,
+            account,
+            named_keys,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            phase,
+            protocol_data,
+            system_contract_cache,
+            contract_package,
+        );
+        execution_result
+    }
+
+    /// Executes `module` like [`Executor::exec`], and also returns the value produced by the entry
+    /// point, if any.
+    ///
+    /// That is the unit type `()` if the entry point returns normally, the value passed to
+    /// `runtime::ret` if it ended by calling it, or the value returned by a host-side system
+    /// contract.  As with [`Executor::exec`], calling `runtime::ret` from a session entry point
+    /// still results in an [`Error::Ret`] failure.
+    pub fn exec_with_return_value<R>(
+        &self,
+        module: Module,
+        entry_point: EntryPoint,
+        args: RuntimeArgs,
+        base_key: Key,
+        account: &Account,
+        mut named_keys: NamedKeys,
+        authorization_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        phase: Phase,
+        protocol_data: ProtocolData,
+        system_contract_cache: SystemContractCache,
+        contract_package: &ContractPackage,
+    ) -> (ExecutionResult, Option<CLValue>)
     where
         R: StateReader<Key, StoredValue>,
         R::Error: Into<Error>,
@@ -107,8 +162,13 @@ impl Executor {
         let entry_point_type = entry_point.entry_point_type();
         let entry_point_access = entry_point.access();
 
-        let (instance, memory) =
-            on_fail_charge!(instance_and_memory(module.clone(), protocol_version));
+        let (instance, memory) = match instance_and_memory(module.clone(), protocol_version) {
+            Ok(instance_and_memory) => instance_and_memory,
+            Err(error) => {
+                warn!("Execution failed: {:?}", error);
+                return (ExecutionResult::precondition_failure(error.into()), None);
+            }
+        };
 
         let access_rights = {
             let keys: Vec<Key> = named_keys.values().cloned().collect();
@@ -123,6 +183,10 @@ impl Executor {
             let generator = AddressGenerator::new(&deploy_hash, phase);
             Rc::new(RefCell::new(generator))
         };
+        let random_bytes_generator = {
+            let generator = AddressGenerator::new_random_bytes_generator(&deploy_hash, phase);
+            Rc::new(RefCell::new(generator))
+        };
         let gas_counter: Gas = Gas::default();
 
         // Snapshot of effects before execution, so in case of error
@@ -144,6 +208,7 @@ impl Executor {
             gas_counter,
             hash_address_generator,
             uref_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -157,11 +222,14 @@ impl Executor {
             extract_access_rights_from_keys(keys)
         };
 
-        on_fail_charge!(runtime_context::validate_entry_point_access_with(
+        if let Err(error) = runtime_context::validate_entry_point_access_with(
             &contract_package,
             entry_point_access,
-            |uref| runtime_context::uref_has_access_rights(uref, &accounts_access_rights)
-        ));
+            |uref| runtime_context::uref_has_access_rights(uref, &accounts_access_rights),
+        ) {
+            warn!("Execution failed: {:?}", error);
+            return (ExecutionResult::precondition_failure(error.into()), None);
+        }
 
         if !self.config.use_system_contracts() {
             if runtime.is_mint(base_key) {
@@ -172,18 +240,22 @@ impl Executor {
                     &args,
                     Default::default(),
                 ) {
-                    Ok(_value) => {
-                        return ExecutionResult::Success {
+                    Ok(value) => {
+                        let result = ExecutionResult::Success {
                             effect: runtime.context().effect(),
+                            events: runtime.context().events().to_vec(),
                             cost: runtime.context().gas_counter(),
                         };
+                        return (result, Some(value));
                     }
                     Err(error) => {
-                        return ExecutionResult::Failure {
+                        let result = ExecutionResult::Failure {
                             error: error.into(),
                             effect: effects_snapshot,
+                            events: Vec::new(),
                             cost: runtime.context().gas_counter(),
                         };
+                        return (result, None);
                     }
                 }
             } else if runtime.is_proof_of_stake(base_key) {
@@ -194,32 +266,52 @@ impl Executor {
                     &args,
                     Default::default(),
                 ) {
-                    Ok(_value) => {
-                        return ExecutionResult::Success {
+                    Ok(value) => {
+                        let result = ExecutionResult::Success {
                             effect: runtime.context().effect(),
+                            events: runtime.context().events().to_vec(),
                             cost: runtime.context().gas_counter(),
                         };
+                        return (result, Some(value));
                     }
                     Err(error) => {
-                        return ExecutionResult::Failure {
+                        let result = ExecutionResult::Failure {
                             error: error.into(),
                             effect: effects_snapshot,
+                            events: Vec::new(),
                             cost: runtime.context().gas_counter(),
                         };
+                        return (result, None);
                     }
                 }
             }
         }
 
-        on_fail_charge!(
-            instance.invoke_export(entry_point_name, &[], &mut runtime),
-            runtime.context().gas_counter(),
-            effects_snapshot
-        );
-
-        ExecutionResult::Success {
-            effect: runtime.context().effect(),
-            cost: runtime.context().gas_counter(),
+        match instance.invoke_export(entry_point_name, &[], &mut runtime) {
+            Ok(_) => {
+                let result = ExecutionResult::Success {
+                    effect: runtime.context().effect(),
+                    events: runtime.context().events().to_vec(),
+                    cost: runtime.context().gas_counter(),
+                };
+                (result, CLValue::from_t(()).ok())
+            }
+            Err(error) => {
+                let exec_err: Error = error.into();
+                warn!("Execution failed: {:?}", exec_err);
+                // `runtime::ret` leaves the value it was given in the host buffer.
+                let return_value = match exec_err {
+                    Error::Ret(_) => runtime.take_host_buffer(),
+                    _ => None,
+                };
+                let result = ExecutionResult::Failure {
+                    error: exec_err.into(),
+                    effect: effects_snapshot,
+                    events: Vec::new(),
+                    cost: runtime.context().gas_counter(),
+                };
+                (result, return_value)
+            }
         }
     }
 
@@ -247,7 +339,12 @@ impl Executor {
         R::Error: Into<Error>,
     {
         match direct_system_contract_call {
-            DirectSystemContractCall::FinalizePayment => {
+            DirectSystemContractCall::FinalizePayment
+            | DirectSystemContractCall::Slash
+            | DirectSystemContractCall::DistributeRewards
+            | DirectSystemContractCall::UnbondPayout
+            | DirectSystemContractCall::Step
+            | DirectSystemContractCall::DistributeSeigniorage => {
                 if protocol_data.proof_of_stake() != base_key.into_seed() {
                     panic!("exec_finalize should only be called with the proof of stake contract");
                 }
@@ -273,6 +370,10 @@ impl Executor {
             let generator = AddressGenerator::new(&deploy_hash, phase);
             Rc::new(RefCell::new(generator))
         };
+        let random_bytes_generator = {
+            let generator = AddressGenerator::new_random_bytes_generator(&deploy_hash, phase);
+            Rc::new(RefCell::new(generator))
+        };
         let gas_counter = Gas::default(); // maybe const?
 
         // Snapshot of effects before execution, so in case of error only nonce update
@@ -294,6 +395,7 @@ impl Executor {
             gas_counter,
             hash_address_generator,
             uref_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -333,6 +435,7 @@ impl Executor {
             Ok(_) => {
                 return ExecutionResult::Success {
                     effect: runtime.context().effect(),
+                    events: runtime.context().events().to_vec(),
                     cost: runtime.context().gas_counter(),
                 };
             }
@@ -344,6 +447,7 @@ impl Executor {
                 Error::Ret(ref _ret_urefs) => {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
+                        events: runtime.context().events().to_vec(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -351,6 +455,7 @@ impl Executor {
                     return ExecutionResult::Failure {
                         error: Error::Revert(*status).into(),
                         effect: effects_snapshot,
+                        events: Vec::new(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -358,6 +463,7 @@ impl Executor {
                     return ExecutionResult::Failure {
                         error: error.clone().into(),
                         effect: effects_snapshot,
+                        events: Vec::new(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -367,6 +473,7 @@ impl Executor {
         ExecutionResult::Failure {
             error: Error::Interpreter(error.into()).into(),
             effect: effects_snapshot,
+            events: Vec::new(),
             cost: runtime.context().gas_counter(),
         }
     }
@@ -385,6 +492,7 @@ impl Executor {
         gas_limit: Gas,
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_bytes_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
@@ -418,6 +526,7 @@ impl Executor {
             gas_counter,
             hash_address_generator,
             uref_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -451,6 +560,7 @@ impl Executor {
         gas_limit: Gas,
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_bytes_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
@@ -479,6 +589,7 @@ impl Executor {
             gas_limit,
             hash_address_generator,
             uref_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             tracking_copy,
@@ -526,13 +637,23 @@ impl Executor {
 pub enum DirectSystemContractCall {
     FinalizePayment,
     Transfer,
+    Slash,
+    DistributeRewards,
+    UnbondPayout,
+    Step,
+    DistributeSeigniorage,
 }
 
 impl DirectSystemContractCall {
-    fn entry_point_name(&self) -> &str {
+    pub fn entry_point_name(&self) -> &str {
         match self {
             DirectSystemContractCall::FinalizePayment => "finalize_payment",
             DirectSystemContractCall::Transfer => "transfer",
+            DirectSystemContractCall::Slash => "slash",
+            DirectSystemContractCall::DistributeRewards => "distribute_rewards",
+            DirectSystemContractCall::UnbondPayout => "unbond_payout",
+            DirectSystemContractCall::Step => "step",
+            DirectSystemContractCall::DistributeSeigniorage => "distribute_seigniorage",
         }
     }
 
@@ -551,7 +672,12 @@ impl DirectSystemContractCall {
     {
         let entry_point_name = self.entry_point_name();
         let result = match self {
-            DirectSystemContractCall::FinalizePayment => runtime.call_host_proof_of_stake(
+            DirectSystemContractCall::FinalizePayment
+            | DirectSystemContractCall::Slash
+            | DirectSystemContractCall::DistributeRewards
+            | DirectSystemContractCall::UnbondPayout
+            | DirectSystemContractCall::Step
+            | DirectSystemContractCall::DistributeSeigniorage => runtime.call_host_proof_of_stake(
                 protocol_version,
                 entry_point_name,
                 named_keys,
@@ -570,11 +696,13 @@ impl DirectSystemContractCall {
         match result {
             Ok(_value) => ExecutionResult::Success {
                 effect: runtime.context().effect(),
+                events: runtime.context().events().to_vec(),
                 cost: runtime.context().gas_counter(),
             },
             Err(error) => ExecutionResult::Failure {
                 error: error.into(),
                 effect: execution_effect,
+                events: Vec::new(),
                 cost: runtime.context().gas_counter(),
             },
         }
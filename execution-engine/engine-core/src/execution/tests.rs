@@ -15,6 +15,7 @@ fn on_fail_charge_test_helper<T>(
     let _result = on_fail_charge!(f(), error_cost);
     ExecutionResult::Success {
         effect: Default::default(),
+        events: Vec::new(),
         cost: success_cost,
     }
 }
@@ -57,6 +58,7 @@ fn on_fail_charge_with_action() {
         });
         ExecutionResult::Success {
             effect: Default::default(),
+            events: Vec::new(),
             cost: Gas::default(),
         }
     };
@@ -49,6 +49,17 @@ pub enum FunctionIndex {
     RemoveContractUserGroupIndex,
     ExtendContractUserGroupURefsIndex,
     RemoveContractUserGroupURefsIndex,
+    EmitEventIndex,
+    ListNamedKeysPageIndex,
+    ListLocalKeysIndex,
+    Blake2b256Index,
+    Sha256Index,
+    Keccak256Index,
+    VerifyEd25519Index,
+    RecoverSecp256k1Index,
+    RandomBytesIndex,
+    ReadManyIndex,
+    WriteManyIndex,
 }
 
 impl Into<usize> for FunctionIndex {
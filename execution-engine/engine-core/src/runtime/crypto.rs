@@ -0,0 +1,160 @@
+//! Cryptographic primitives exposed to contracts via host functions.
+
+use std::convert::TryFrom;
+
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+use ed25519_dalek::Verifier;
+use sha2::{Digest, Sha256};
+use tiny_keccak::{Hasher, Keccak};
+
+use types::account::{
+    Ed25519Bytes, Secp256k1Bytes, ED25519_SIGNATURE_LENGTH, SECP256K1_RECOVERABLE_SIGNATURE_LENGTH,
+};
+
+/// The length in bytes of the digests returned by the hash functions.
+pub(crate) const DIGEST_LENGTH: usize = 32;
+
+/// The offset added to Secp256k1 recovery IDs by Ethereum-style signatures.
+const SECP256K1_RPC_RECOVERY_ID_OFFSET: u8 = 27;
+
+pub(crate) fn blake2b256(data: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut hasher = VarBlake2b::new(DIGEST_LENGTH).unwrap();
+    hasher.input(data);
+    let mut digest = [0; DIGEST_LENGTH];
+    hasher.variable_result(|hash| digest.clone_from_slice(hash));
+    digest
+}
+
+pub(crate) fn sha256(data: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut digest = [0; DIGEST_LENGTH];
+    digest.copy_from_slice(&Sha256::digest(data));
+    digest
+}
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut digest = [0; DIGEST_LENGTH];
+    hasher.finalize(&mut digest);
+    digest
+}
+
+/// Returns `true` if `signature` is a valid Ed25519 signature of `message` by `public_key`.
+///
+/// Malformed signatures and public keys are reported as invalid rather than as errors.
+pub(crate) fn verify_ed25519(
+    message: &[u8],
+    signature: &[u8; ED25519_SIGNATURE_LENGTH],
+    public_key: &Ed25519Bytes,
+) -> bool {
+    let public_key = match ed25519_dalek::PublicKey::from_bytes(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match ed25519_dalek::Signature::try_from(&signature[..]) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    public_key.verify(message, &signature).is_ok()
+}
+
+/// Recovers the compressed Secp256k1 public key which signed `message_hash`.
+///
+/// `signature` is the 64-byte compact signature followed by the recovery ID, which may be given
+/// either as 0 to 3, or offset by 27 as in Ethereum signatures.  Returns `None` if no public key
+/// can be recovered.
+pub(crate) fn recover_secp256k1(
+    message_hash: &[u8; DIGEST_LENGTH],
+    signature: &[u8; SECP256K1_RECOVERABLE_SIGNATURE_LENGTH],
+) -> Option<Secp256k1Bytes> {
+    let (compact_signature, recovery_id) = signature.split_at(signature.len() - 1);
+    let recovery_id = recovery_id[0];
+    let recovery_id = if recovery_id >= SECP256K1_RPC_RECOVERY_ID_OFFSET {
+        secp256k1::RecoveryId::parse_rpc(recovery_id)
+    } else {
+        secp256k1::RecoveryId::parse(recovery_id)
+    }
+    .ok()?;
+    let signature = secp256k1::Signature::parse_slice(compact_signature).ok()?;
+    let message = secp256k1::Message::parse(message_hash);
+    secp256k1::recover(&message, &signature, &recovery_id)
+        .ok()
+        .map(|public_key| public_key.serialize_compressed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"CasperLabs";
+
+    #[test]
+    fn should_hash_empty_input() {
+        assert_eq!(
+            base16::encode_lower(&blake2b256(&[])),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_eq!(
+            base16::encode_lower(&sha256(&[])),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            base16::encode_lower(&keccak256(&[])),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[test]
+    fn should_verify_ed25519_signature() {
+        let secret_key = ed25519_dalek::SecretKey::from_bytes(&[1; 32]).unwrap();
+        let public_key = ed25519_dalek::PublicKey::from(&secret_key);
+        let signature = ed25519_dalek::ExpandedSecretKey::from(&secret_key)
+            .sign(MESSAGE, &public_key)
+            .to_bytes();
+        let public_key = public_key.to_bytes();
+
+        assert!(verify_ed25519(MESSAGE, &signature, &public_key));
+        assert!(!verify_ed25519(b"Casper", &signature, &public_key));
+
+        let mut tampered_signature = signature;
+        tampered_signature[0] ^= 1;
+        assert!(!verify_ed25519(MESSAGE, &tampered_signature, &public_key));
+    }
+
+    #[test]
+    fn should_recover_secp256k1_public_key() {
+        let secret_key = secp256k1::SecretKey::parse(&[1; 32]).unwrap();
+        let public_key = secp256k1::PublicKey::from_secret_key(&secret_key)
+            .serialize_compressed()
+            .to_vec();
+        let message_hash = keccak256(MESSAGE);
+        let (signature, recovery_id) =
+            secp256k1::sign(&secp256k1::Message::parse(&message_hash), &secret_key);
+
+        let mut recoverable_signature = [0; SECP256K1_RECOVERABLE_SIGNATURE_LENGTH];
+        recoverable_signature[..64].copy_from_slice(&signature.serialize());
+        recoverable_signature[64] = recovery_id.serialize();
+        assert_eq!(
+            recover_secp256k1(&message_hash, &recoverable_signature).map(|key| key.to_vec()),
+            Some(public_key.clone())
+        );
+
+        recoverable_signature[64] += SECP256K1_RPC_RECOVERY_ID_OFFSET;
+        assert_eq!(
+            recover_secp256k1(&message_hash, &recoverable_signature).map(|key| key.to_vec()),
+            Some(public_key.clone())
+        );
+
+        let other_hash = keccak256(b"Casper");
+        assert_ne!(
+            recover_secp256k1(&other_hash, &recoverable_signature).map(|key| key.to_vec()),
+            Some(public_key)
+        );
+
+        recoverable_signature[64] = 4;
+        assert!(recover_secp256k1(&message_hash, &recoverable_signature).is_none());
+    }
+}
@@ -3,7 +3,7 @@ use std::{collections::BTreeSet, convert::TryFrom};
 use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 
 use types::{
-    account::PublicKey,
+    account::{PublicKey, SECP256K1_RECOVERABLE_SIGNATURE_LENGTH},
     api_error,
     bytesrepr::{self, ToBytes},
     contracts::{EntryPoints, NamedKeys},
@@ -13,7 +13,7 @@ use types::{
 use engine_shared::{gas::Gas, stored_value::StoredValue};
 use engine_storage::global_state::StateReader;
 
-use super::{args::Args, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
+use super::{args::Args, crypto, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
 use crate::resolvers::v1_function_index::FunctionIndex;
 
 impl<'a, R> Externals for Runtime<'a, R>
@@ -28,12 +28,14 @@ where
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
+        let host_function_costs = *self.protocol_data().host_function_costs();
         match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
                 // args(1) = size of key in Wasm memory
                 // args(2) = pointer to output size (output param)
                 let (key_ptr, key_size, output_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.read_value, &[key_size])?;
                 let ret = self.read(key_ptr, key_size, output_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -43,6 +45,7 @@ where
                 // args(1) = size of key in Wasm memory
                 // args(2) = pointer to output size (output param)
                 let (key_ptr, key_size, output_size_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.read_value_local, &[key_size])?;
                 scoped_instrumenter.add_property("key_size", key_size);
                 let ret = self.read_local(key_ptr, key_size, output_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
@@ -52,6 +55,7 @@ where
                 // args(0) = pointer to amount of keys (output)
                 // args(1) = pointer to amount of serialized bytes (output)
                 let (total_keys_ptr, result_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.load_named_keys, &[])?;
                 let ret = self.load_named_keys(
                     total_keys_ptr,
                     result_size_ptr,
@@ -66,6 +70,10 @@ where
                 // args(2) = pointer to value
                 // args(3) = size of value
                 let (key_ptr, key_size, value_ptr, value_size): (_, _, _, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.write,
+                    &[key_size, value_size],
+                )?;
                 scoped_instrumenter.add_property("value_size", value_size);
                 self.write(key_ptr, key_size, value_ptr, value_size)?;
                 Ok(None)
@@ -78,6 +86,10 @@ where
                 // args(3) = size of value
                 let (key_bytes_ptr, key_bytes_size, value_ptr, value_size): (_, u32, _, u32) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.write_local,
+                    &[key_bytes_size, value_size],
+                )?;
                 scoped_instrumenter.add_property("key_bytes_size", key_bytes_size);
                 scoped_instrumenter.add_property("value_size", value_size);
                 self.write_local(key_bytes_ptr, key_bytes_size, value_ptr, value_size)?;
@@ -90,6 +102,7 @@ where
                 // args(2) = pointer to value
                 // args(3) = size of value
                 let (key_ptr, key_size, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.add, &[key_size, value_size])?;
                 self.add(key_ptr, key_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -99,6 +112,7 @@ where
                 // args(1) = pointer to initial value
                 // args(2) = size of initial value
                 let (uref_ptr, value_ptr, value_size): (_, _, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.new_uref, &[value_size])?;
                 scoped_instrumenter.add_property("value_size", value_size);
                 self.new_uref(uref_ptr, value_ptr, value_size)?;
                 Ok(None)
@@ -108,6 +122,7 @@ where
                 // args(0) = pointer to value
                 // args(1) = size of value
                 let (value_ptr, value_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.ret, &[value_size])?;
                 scoped_instrumenter.add_property("value_size", value_size);
                 Err(self.ret(value_ptr, value_size as usize, &mut scoped_instrumenter))
             }
@@ -125,6 +140,7 @@ where
                     u32,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_key, &[name_size])?;
                 scoped_instrumenter.add_property("name_size", name_size);
                 let ret = self.load_key(
                     name_ptr,
@@ -140,6 +156,7 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 let (name_ptr, name_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.has_key, &[name_size])?;
                 scoped_instrumenter.add_property("name_size", name_size);
                 let result = self.has_key(name_ptr, name_size)?;
                 Ok(Some(RuntimeValue::I32(result)))
@@ -151,6 +168,10 @@ where
                 // args(2) = pointer to key in Wasm memory
                 // args(3) = size of key
                 let (name_ptr, name_size, key_ptr, key_size): (_, u32, _, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.put_key,
+                    &[name_size, key_size],
+                )?;
                 scoped_instrumenter.add_property("name_size", name_size);
                 self.put_key(name_ptr, name_size, key_ptr, key_size)?;
                 Ok(None)
@@ -160,6 +181,7 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 let (name_ptr, name_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.remove_key, &[name_size])?;
                 scoped_instrumenter.add_property("name_size", name_size);
                 self.remove_key(name_ptr, name_size)?;
                 Ok(None)
@@ -168,6 +190,7 @@ where
             FunctionIndex::GetCallerIndex => {
                 // args(0) = pointer where a size of serialized bytes will be stored
                 let output_size = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_caller, &[])?;
                 let ret = self.get_caller(output_size)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -175,6 +198,7 @@ where
             FunctionIndex::GetBlocktimeIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_blocktime, &[])?;
                 self.get_blocktime(dest_ptr)?;
                 Ok(None)
             }
@@ -189,6 +213,7 @@ where
                 // args(0) = pointer to value to validate
                 // args(1) = size of value
                 let (uref_ptr, uref_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.is_valid_uref, &[uref_size])?;
 
                 Ok(Some(RuntimeValue::I32(i32::from(
                     self.is_valid_uref(uref_ptr, uref_size)?,
@@ -198,6 +223,7 @@ where
             FunctionIndex::RevertFuncIndex => {
                 // args(0) = status u32
                 let status = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.revert, &[])?;
 
                 Err(self.revert(status))
             }
@@ -208,6 +234,10 @@ where
                 // args(2) = weight of the key
                 let (public_key_ptr, public_key_size, weight_value): (u32, u32, u8) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.add_associated_key,
+                    &[public_key_size],
+                )?;
                 let value = self.add_associated_key(
                     public_key_ptr,
                     public_key_size as usize,
@@ -220,6 +250,10 @@ where
                 // args(0) = pointer to array of bytes of a public key
                 // args(1) = size of a public key
                 let (public_key_ptr, public_key_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.remove_associated_key,
+                    &[public_key_size],
+                )?;
                 let value = self.remove_associated_key(public_key_ptr, public_key_size as usize)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -230,6 +264,10 @@ where
                 // args(2) = weight of the key
                 let (public_key_ptr, public_key_size, weight_value): (u32, u32, u8) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.update_associated_key,
+                    &[public_key_size],
+                )?;
                 let value = self.update_associated_key(
                     public_key_ptr,
                     public_key_size as usize,
@@ -242,6 +280,7 @@ where
                 // args(0) = action type
                 // args(1) = new threshold
                 let (action_type_value, threshold_value): (u32, u8) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.set_action_threshold, &[])?;
                 let value = self.set_action_threshold(action_type_value, threshold_value)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -250,6 +289,7 @@ where
                 // args(0) = pointer to array for return value
                 // args(1) = length of array for return value
                 let (dest_ptr, dest_size): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.create_purse, &[])?;
                 let purse = self.create_purse()?;
                 let purse_bytes = purse.into_bytes().map_err(Error::BytesRepr)?;
                 assert_eq!(dest_size, purse_bytes.len() as u32);
@@ -266,6 +306,10 @@ where
                 // args(3) = length of array of bytes of an amount
                 let (key_ptr, key_size, amount_ptr, amount_size): (u32, u32, u32, u32) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_to_account,
+                    &[key_size, amount_size],
+                )?;
                 let public_key: PublicKey = {
                     let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
                     bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
@@ -293,6 +337,10 @@ where
                     u32,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_from_purse_to_account,
+                    &[source_size, key_size, amount_size],
+                )?;
 
                 let source_purse = {
                     let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
@@ -319,6 +367,10 @@ where
                 // args(5) = length of array of bytes in Wasm memory of an amount
                 let (source_ptr, source_size, target_ptr, target_size, amount_ptr, amount_size) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_from_purse_to_purse,
+                    &[source_size, target_size, amount_size],
+                )?;
                 let ret = self.transfer_from_purse_to_purse(
                     source_ptr,
                     source_size,
@@ -335,6 +387,7 @@ where
                 // args(1) = length of purse
                 // args(2) = pointer to output size (output)
                 let (ptr, ptr_size, output_size_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_balance, &[ptr_size])?;
                 let ret = self.get_balance_host_buffer(ptr, ptr_size as usize, output_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -342,6 +395,7 @@ where
             FunctionIndex::GetPhaseIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_phase, &[])?;
                 self.get_phase(dest_ptr)?;
                 Ok(None)
             }
@@ -351,6 +405,7 @@ where
                 // args(1) = dest pointer for storing serialized result
                 // args(2) = dest pointer size
                 let (system_contract_index, dest_ptr, dest_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_system_contract, &[])?;
                 let ret = self.get_system_contract(system_contract_index, dest_ptr, dest_size)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -358,6 +413,7 @@ where
             FunctionIndex::GetMainPurseIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_main_purse, &[])?;
                 self.get_main_purse(dest_ptr)?;
                 Ok(None)
             }
@@ -365,6 +421,10 @@ where
             FunctionIndex::ReadHostBufferIndex => {
                 // args(0) = pointer to Wasm memory where to write size.
                 let (dest_ptr, dest_size, bytes_written_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.read_host_buffer,
+                    &[dest_size],
+                )?;
                 scoped_instrumenter.add_property("dest_size", dest_size);
                 let ret = self.read_host_buffer(dest_ptr, dest_size as usize, bytes_written_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
@@ -374,6 +434,10 @@ where
                 // args(0) = pointer to wasm memory where to write 32-byte Hash address
                 // args(1) = pointer to wasm memory where to write 32-byte access key address
                 let (hash_dest_ptr, access_dest_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.create_contract_package_at_hash,
+                    &[],
+                )?;
                 let (hash_addr, access_addr) = self.create_contract_package_at_hash()?;
                 self.function_address(hash_addr, hash_dest_ptr)?;
                 self.function_address(access_addr, access_dest_ptr)?;
@@ -399,6 +463,10 @@ where
                     existing_urefs_size,
                     output_size_ptr,
                 ): (_, _, _, u32, _, _, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.create_contract_user_group,
+                    &[package_key_size, label_size, existing_urefs_size],
+                )?;
                 scoped_instrumenter
                     .add_property("existing_urefs_size", existing_urefs_size.to_string());
                 scoped_instrumenter.add_property("label_size", label_size.to_string());
@@ -441,6 +509,14 @@ where
                     output_size,
                     bytes_written_ptr,
                 ): (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.add_contract_version,
+                    &[
+                        contract_package_hash_size,
+                        entry_points_size,
+                        named_keys_size,
+                    ],
+                )?;
 
                 scoped_instrumenter
                     .add_property("entry_points_size", entry_points_size.to_string());
@@ -470,6 +546,10 @@ where
                 // args(3) = size of contract hash in wasm memory
                 let (package_key_ptr, package_key_size, contract_hash_ptr, contract_hash_size) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.disable_contract_version,
+                    &[package_key_size, contract_hash_size],
+                )?;
 
                 let contract_package_hash = self.t_from_mem(package_key_ptr, package_key_size)?;
                 let contract_hash = self.t_from_mem(contract_hash_ptr, contract_hash_size)?;
@@ -496,6 +576,10 @@ where
                     args_size,
                     result_size_ptr,
                 ): (_, _, _, u32, _, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_contract,
+                    &[contract_hash_size, entry_point_name_size, args_size],
+                )?;
                 scoped_instrumenter
                     .add_property("entry_point_name_size", entry_point_name_size.to_string());
                 scoped_instrumenter.add_property("args_size", args_size.to_string());
@@ -540,6 +624,15 @@ where
                     args_size,
                     result_size_ptr,
                 ): (_, _, _, _, _, u32, _, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_versioned_contract,
+                    &[
+                        contract_package_hash_size,
+                        contract_package_size,
+                        entry_point_name_size,
+                        args_size,
+                    ],
+                )?;
 
                 scoped_instrumenter
                     .add_property("entry_point_name_size", entry_point_name_size.to_string());
@@ -580,6 +673,10 @@ where
                 // args(1) = size of name of the host runtime arg
                 // args(2) = pointer to a argument size (output)
                 let (name_ptr, name_size, size_ptr): (u32, u32, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.get_named_arg_size,
+                    &[name_size],
+                )?;
                 scoped_instrumenter.add_property("name_size", name_size.to_string());
                 let ret = self.get_named_arg_size(name_ptr, name_size as usize, size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
@@ -592,6 +689,7 @@ where
                 // args(3) = size of available data under output pointer
                 let (name_ptr, name_size, dest_ptr, dest_size): (u32, u32, u32, u32) =
                     Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_named_arg, &[name_size])?;
                 scoped_instrumenter.add_property("name_size", name_size.to_string());
                 scoped_instrumenter.add_property("dest_size", dest_size.to_string());
                 let ret =
@@ -606,6 +704,10 @@ where
                 // args(3) = size of serialized group label
                 let (package_key_ptr, package_key_size, label_ptr, label_size): (_, _, _, u32) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.remove_contract_user_group,
+                    &[package_key_size, label_size],
+                )?;
                 scoped_instrumenter.add_property("label_size", label_size.to_string());
                 let package_key = self.t_from_mem(package_key_ptr, package_key_size)?;
                 let label: Group = self.t_from_mem(label_ptr, label_size)?;
@@ -627,6 +729,10 @@ where
                     u32,
                     _,
                 ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.provision_contract_user_group_uref,
+                    &[package_size, label_size],
+                )?;
                 scoped_instrumenter.add_property("label_size", label_size.to_string());
                 let ret = self.provision_contract_user_group_uref(
                     package_ptr,
@@ -653,6 +759,10 @@ where
                     _,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.remove_contract_user_group_urefs,
+                    &[package_size, label_size, urefs_size],
+                )?;
                 scoped_instrumenter.add_property("label_size", label_size.to_string());
                 scoped_instrumenter.add_property("urefs_size", urefs_size.to_string());
                 let ret = self.remove_contract_user_group_urefs(
@@ -665,6 +775,163 @@ where
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
+
+            FunctionIndex::EmitEventIndex => {
+                // args(0) = pointer to serialized event name in Wasm memory
+                // args(1) = size of serialized event name
+                // args(2) = pointer to serialized event value in Wasm memory
+                // args(3) = size of serialized event value
+                let (name_ptr, name_size, value_ptr, value_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_event,
+                    &[name_size, value_size],
+                )?;
+                scoped_instrumenter.add_property("name_size", name_size);
+                scoped_instrumenter.add_property("value_size", value_size);
+                self.emit_event(name_ptr, name_size, value_ptr, value_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::ListNamedKeysPageIndex => {
+                // args(0) = pointer to serialized cursor (an `Option<String>`) in Wasm memory
+                // args(1) = size of serialized cursor
+                // args(2) = maximum number of named keys to list
+                // args(3) = pointer to output size (output param)
+                let (cursor_ptr, cursor_size, limit, output_size_ptr): (_, u32, u32, _) =
+                    Args::parse(args)?;
+                scoped_instrumenter.add_property("cursor_size", cursor_size);
+                scoped_instrumenter.add_property("limit", limit);
+                // The call is charged in `list_named_keys_page` once the size of the page is known.
+                let ret =
+                    self.list_named_keys_page(cursor_ptr, cursor_size, limit, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::ListLocalKeysIndex => {
+                // args(0) = pointer to key prefix in Wasm memory
+                // args(1) = size of key prefix
+                // args(2) = pointer to serialized cursor (an `Option<Vec<u8>>`) in Wasm memory
+                // args(3) = size of serialized cursor
+                // args(4) = maximum number of local keys to list
+                // args(5) = pointer to output size (output param)
+                let (prefix_ptr, prefix_size, cursor_ptr, cursor_size, limit, output_size_ptr): (
+                    _,
+                    u32,
+                    _,
+                    u32,
+                    u32,
+                    _,
+                ) = Args::parse(args)?;
+                scoped_instrumenter.add_property("prefix_size", prefix_size);
+                scoped_instrumenter.add_property("cursor_size", cursor_size);
+                scoped_instrumenter.add_property("limit", limit);
+                // The call is charged in `list_local_keys` as the local keys are visited.
+                let ret = self.list_local_keys(
+                    prefix_ptr,
+                    prefix_size,
+                    cursor_ptr,
+                    cursor_size,
+                    limit,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Blake2b256Index => {
+                // args(0) = pointer to data in Wasm memory
+                // args(1) = size of data
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (data_ptr, data_size, dest_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.blake2b256, &[data_size])?;
+                scoped_instrumenter.add_property("data_size", data_size);
+                self.hash(data_ptr, data_size, dest_ptr, crypto::blake2b256)?;
+                Ok(None)
+            }
+
+            FunctionIndex::Sha256Index => {
+                // args(0) = pointer to data in Wasm memory
+                // args(1) = size of data
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (data_ptr, data_size, dest_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.sha256, &[data_size])?;
+                scoped_instrumenter.add_property("data_size", data_size);
+                self.hash(data_ptr, data_size, dest_ptr, crypto::sha256)?;
+                Ok(None)
+            }
+
+            FunctionIndex::Keccak256Index => {
+                // args(0) = pointer to data in Wasm memory
+                // args(1) = size of data
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (data_ptr, data_size, dest_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.keccak256, &[data_size])?;
+                scoped_instrumenter.add_property("data_size", data_size);
+                self.hash(data_ptr, data_size, dest_ptr, crypto::keccak256)?;
+                Ok(None)
+            }
+
+            FunctionIndex::VerifyEd25519Index => {
+                // args(0) = pointer to message in Wasm memory
+                // args(1) = size of message
+                // args(2) = pointer to 64-byte signature in Wasm memory
+                // args(3) = pointer to 32-byte public key in Wasm memory
+                let (message_ptr, message_size, signature_ptr, public_key_ptr): (_, u32, _, _) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.verify_ed25519,
+                    &[message_size],
+                )?;
+                scoped_instrumenter.add_property("message_size", message_size);
+                let is_valid =
+                    self.verify_ed25519(message_ptr, message_size, signature_ptr, public_key_ptr)?;
+                Ok(Some(RuntimeValue::I32(i32::from(is_valid))))
+            }
+
+            FunctionIndex::RecoverSecp256k1Index => {
+                // args(0) = pointer to 32-byte message hash in Wasm memory
+                // args(1) = pointer to 65-byte recoverable signature in Wasm memory
+                // args(2) = pointer to Wasm memory where to write the 33-byte public key
+                let (message_hash_ptr, signature_ptr, dest_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.recover_secp256k1,
+                    &[(crypto::DIGEST_LENGTH + SECP256K1_RECOVERABLE_SIGNATURE_LENGTH) as u32],
+                )?;
+                let is_recovered =
+                    self.recover_secp256k1(message_hash_ptr, signature_ptr, dest_ptr)?;
+                Ok(Some(RuntimeValue::I32(i32::from(is_recovered))))
+            }
+
+            FunctionIndex::ReadManyIndex => {
+                // args(0) = pointer to serialized list of keys in Wasm memory
+                // args(1) = size of serialized list of keys
+                // args(2) = pointer to output size (output param)
+                let (keys_ptr, keys_size, output_size_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.read_many, &[keys_size])?;
+                scoped_instrumenter.add_property("keys_size", keys_size);
+                let ret = self.read_many(keys_ptr, keys_size, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::WriteManyIndex => {
+                // args(0) = pointer to serialized list of (key, value) entries in Wasm memory
+                // args(1) = size of serialized list of entries
+                let (entries_ptr, entries_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.write_many, &[entries_size])?;
+                scoped_instrumenter.add_property("entries_size", entries_size);
+                self.write_many(entries_ptr, entries_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::RandomBytesIndex => {
+                // args(0) = pointer to Wasm memory where to write the random bytes
+                // args(1) = number of random bytes to write
+                let (dest_ptr, dest_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.random_bytes, &[dest_size])?;
+                scoped_instrumenter.add_property("dest_size", dest_size);
+                self.random_bytes(dest_ptr, dest_size)?;
+                Ok(None)
+            }
         }
     }
 }
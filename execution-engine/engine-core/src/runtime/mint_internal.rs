@@ -19,6 +19,10 @@ where
         self.get_caller()
     }
 
+    fn get_key(&self, name: &str) -> Option<Key> {
+        self.named_keys_get(name).cloned()
+    }
+
     fn put_key(&mut self, name: &str, key: Key) {
         // TODO: update RuntimeProvider to better handle errors
         self.put_key(name.to_string(), key).expect("should put key")
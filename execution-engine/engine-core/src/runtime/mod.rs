@@ -1,4 +1,5 @@
 mod args;
+mod crypto;
 mod externals;
 mod mint_internal;
 mod proof_of_stake_internal;
@@ -14,15 +15,21 @@ use std::{
 
 use itertools::Itertools;
 use parity_wasm::elements::Module;
-use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
+use wasmi::{
+    memory_units::Bytes, ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind,
+};
 
 use ::mint::Mint;
 use engine_shared::{account::Account, gas::Gas, stored_value::StoredValue};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
+use engine_wasm_prep::host_function_costs::HostFunctionCost;
 use proof_of_stake::ProofOfStake;
 use standard_payment::StandardPayment;
 use types::{
-    account::{ActionType, PublicKey, Weight},
+    account::{
+        ActionType, PublicKey, Weight, ED25519_LENGTH, ED25519_SIGNATURE_LENGTH,
+        SECP256K1_RECOVERABLE_SIGNATURE_LENGTH,
+    },
     bytesrepr::{self, FromBytes, ToBytes},
     contracts::{
         self, Contract, ContractPackage, EntryPoint, EntryPointAccess, EntryPoints, Group,
@@ -31,7 +38,7 @@ use types::{
     system_contract_errors::mint,
     AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
     ContractVersionKey, ContractWasm, EntryPointType, Key, ProtocolVersion, RuntimeArgs,
-    SystemContractType, TransferResult, TransferredTo, URef, U128, U256, U512,
+    SystemContractType, TransferResult, TransferredTo, URef, KEY_HASH_LENGTH, U128, U256, U512,
 };
 
 use crate::{
@@ -44,6 +51,9 @@ use crate::{
 use contracts::{ContractVersion, ContractVersions, DisabledVersions, Groups, NamedKeys};
 use scoped_instrumenter::ScopedInstrumenter;
 
+/// The number of pseudorandom bytes generated at a time by `random_bytes`.
+const RANDOM_BYTES_CHUNK_SIZE: usize = 4096;
+
 pub struct Runtime<'a, R> {
     system_contract_cache: SystemContractCache,
     config: EngineConfig,
@@ -151,6 +161,7 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
         | CLType::U512
         | CLType::Unit
         | CLType::String
+        | CLType::PublicKey
         | CLType::Any => Ok(vec![]),
         CLType::Option(ty) => match **ty {
             CLType::URef => {
@@ -1408,6 +1419,19 @@ where
         }
     }
 
+    /// Charges the cost of a host function call, given the sizes of its variable-length arguments
+    /// in Wasm memory.
+    fn charge_host_function_call(
+        &mut self,
+        cost: &HostFunctionCost,
+        sizes: &[u32],
+    ) -> Result<(), Trap> {
+        let bytes = sizes
+            .iter()
+            .fold(0u32, |total, size| total.saturating_add(*size));
+        self.gas(Gas::new(cost.calculate_cost(bytes).into()))
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
         self.memory.get(ptr, size).map_err(Into::into)
     }
@@ -1544,6 +1568,198 @@ where
         Ok(())
     }
 
+    fn emit_event(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<(), Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        let value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context.emit_event(name, value);
+        Ok(())
+    }
+
+    /// Writes up to `limit` of the current context's named keys, ordered by name and following the
+    /// name given by the serialized cursor, to the host buffer.
+    ///
+    /// The call is charged per byte of the cursor and of the named keys written.
+    fn list_named_keys_page(
+        &mut self,
+        cursor_ptr: u32,
+        cursor_size: u32,
+        limit: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let start_after: Option<String> = self.t_from_mem(cursor_ptr, cursor_size)?;
+        let named_keys = self
+            .context
+            .named_keys_page(start_after.as_deref(), limit as usize);
+        let value = CLValue::from_t(named_keys).map_err(Error::CLValue)?;
+
+        let cost = self
+            .protocol_data()
+            .host_function_costs()
+            .list_named_keys_page;
+        self.charge_host_function_call(&cost, &[cursor_size, value.inner_bytes().len() as u32])?;
+
+        self.write_host_buffer_with_size(value, output_size_ptr)
+    }
+
+    /// Writes up to `limit` local keys which start with the given prefix, ordered by their bytes
+    /// and following the key given by the serialized cursor, to the host buffer.
+    ///
+    /// The call is charged per byte of the prefix, of the cursor and of every local key visited,
+    /// including those skipped because they were written by other contexts.
+    fn list_local_keys(
+        &mut self,
+        prefix_ptr: u32,
+        prefix_size: u32,
+        cursor_ptr: u32,
+        cursor_size: u32,
+        limit: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let cost = self.protocol_data().host_function_costs().list_local_keys;
+        self.charge_host_function_call(&cost, &[prefix_size, cursor_size])?;
+
+        let prefix = self.bytes_from_mem(prefix_ptr, prefix_size as usize)?;
+        let start_after: Option<Vec<u8>> = self.t_from_mem(cursor_ptr, cursor_size)?;
+        let cost_per_key = Gas::new(U512::from(
+            u64::from(cost.per_byte) * KEY_HASH_LENGTH as u64,
+        ));
+        let local_keys = self.context.list_ls(
+            &prefix,
+            start_after.as_deref(),
+            limit as usize,
+            cost_per_key,
+        )?;
+        let value = CLValue::from_t(local_keys).map_err(Error::CLValue)?;
+
+        self.write_host_buffer_with_size(value, output_size_ptr)
+    }
+
+    fn write_host_buffer_with_size(
+        &mut self,
+        value: CLValue,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let value_size = value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the digest produced by `hash` of the data in Wasm memory to `dest_ptr`.
+    fn hash(
+        &mut self,
+        data_ptr: u32,
+        data_size: u32,
+        dest_ptr: u32,
+        hash: fn(&[u8]) -> [u8; crypto::DIGEST_LENGTH],
+    ) -> Result<(), Trap> {
+        let data = self.bytes_from_mem(data_ptr, data_size as usize)?;
+        self.memory
+            .set(dest_ptr, &hash(&data))
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
+    /// Returns `true` if the signature in Wasm memory is a valid Ed25519 signature of the message
+    /// by the public key.
+    fn verify_ed25519(
+        &mut self,
+        message_ptr: u32,
+        message_size: u32,
+        signature_ptr: u32,
+        public_key_ptr: u32,
+    ) -> Result<bool, Trap> {
+        let message = self.bytes_from_mem(message_ptr, message_size as usize)?;
+        let mut signature = [0; ED25519_SIGNATURE_LENGTH];
+        self.memory
+            .get_into(signature_ptr, &mut signature)
+            .map_err(|e| Error::Interpreter(e.into()))?;
+        let mut public_key = [0; ED25519_LENGTH];
+        self.memory
+            .get_into(public_key_ptr, &mut public_key)
+            .map_err(|e| Error::Interpreter(e.into()))?;
+        Ok(crypto::verify_ed25519(&message, &signature, &public_key))
+    }
+
+    /// Recovers the Secp256k1 public key which signed the message hash in Wasm memory, writing it
+    /// to `dest_ptr` in compressed form.  Returns `false` if no public key can be recovered.
+    fn recover_secp256k1(
+        &mut self,
+        message_hash_ptr: u32,
+        signature_ptr: u32,
+        dest_ptr: u32,
+    ) -> Result<bool, Trap> {
+        let mut message_hash = [0; crypto::DIGEST_LENGTH];
+        self.memory
+            .get_into(message_hash_ptr, &mut message_hash)
+            .map_err(|e| Error::Interpreter(e.into()))?;
+        let mut signature = [0; SECP256K1_RECOVERABLE_SIGNATURE_LENGTH];
+        self.memory
+            .get_into(signature_ptr, &mut signature)
+            .map_err(|e| Error::Interpreter(e.into()))?;
+        match crypto::recover_secp256k1(&message_hash, &signature) {
+            Some(public_key) => {
+                self.memory
+                    .set(dest_ptr, &public_key)
+                    .map_err(|e| Error::Interpreter(e.into()))?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Writes `dest_size` deterministic pseudorandom bytes to [dest_ptr] in the Wasm memory.
+    ///
+    /// The destination is checked against the current memory size before anything is generated,
+    /// and the bytes are then written in chunks of at most `RANDOM_BYTES_CHUNK_SIZE`.
+    fn random_bytes(&mut self, dest_ptr: u32, dest_size: u32) -> Result<(), Trap> {
+        let memory_size = Bytes::from(self.memory.current_size()).0;
+        let dest_start = dest_ptr as usize;
+        let dest_end = dest_start + dest_size as usize;
+        if dest_end > memory_size {
+            return Err(Error::Interpreter(format!(
+                "trying to access region [{}..{}] in memory [0..{}]",
+                dest_start, dest_end, memory_size
+            ))
+            .into());
+        }
+
+        let mut chunk = [0; RANDOM_BYTES_CHUNK_SIZE];
+        let mut offset = dest_start;
+        while offset < dest_end {
+            let chunk_size = cmp::min(dest_end - offset, RANDOM_BYTES_CHUNK_SIZE);
+            let random_bytes = &mut chunk[..chunk_size];
+            self.context.random_bytes(random_bytes);
+            self.memory
+                .set(offset as u32, random_bytes)
+                .map_err(|e| Error::Interpreter(e.into()))?;
+            offset += chunk_size;
+        }
+        Ok(())
+    }
+
     /// Writes runtime context's account main purse to [dest_ptr] in the Wasm memory.
     fn get_main_purse(&mut self, dest_ptr: u32) -> Result<(), Trap> {
         let purse = self.context.get_main_purse()?;
@@ -1676,6 +1892,9 @@ where
         const METHOD_CREATE: &str = "create";
         const METHOD_BALANCE: &str = "balance";
         const METHOD_TRANSFER: &str = "transfer";
+        const METHOD_MINT_REWARD: &str = "mint_reward";
+        const METHOD_BURN: &str = "burn";
+        const METHOD_TOTAL_SUPPLY: &str = "total_supply";
 
         let state = self.context.state();
         let access_rights = {
@@ -1694,6 +1913,7 @@ where
         let gas_counter = self.context.gas_counter();
         let fn_store_id = self.context.fn_store_id();
         let address_generator = self.context.address_generator();
+        let random_bytes_generator = self.context.random_bytes_generator();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1713,6 +1933,7 @@ where
             gas_counter,
             fn_store_id,
             address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -1746,6 +1967,25 @@ where
                 let result: Result<(), mint::Error> = mint_context.transfer(source, target, amount);
                 CLValue::from_t(result).map_err(Self::reverter)?
             }
+            // Type: `fn mint_reward(purse: URef, amount: U512) -> Result<(), Error>`
+            METHOD_MINT_REWARD => {
+                let purse: URef = Self::get_named_argument(&args, "purse")?;
+                let amount: U512 = Self::get_named_argument(&args, "amount")?;
+                let result: Result<(), mint::Error> = mint_context.mint_reward(purse, amount);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn burn(purse: URef, amount: U512) -> Result<(), Error>`
+            METHOD_BURN => {
+                let purse: URef = Self::get_named_argument(&args, "purse")?;
+                let amount: U512 = Self::get_named_argument(&args, "amount")?;
+                let result: Result<(), mint::Error> = mint_context.burn(purse, amount);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn total_supply() -> U512`
+            METHOD_TOTAL_SUPPLY => {
+                let total_supply: U512 = mint_context.total_supply().map_err(Self::reverter)?;
+                CLValue::from_t(total_supply).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1764,12 +2004,23 @@ where
     ) -> Result<CLValue, Error> {
         const METHOD_BOND: &str = "bond";
         const METHOD_UNBOND: &str = "unbond";
+        const METHOD_DELEGATE: &str = "delegate";
+        const METHOD_UNDELEGATE: &str = "undelegate";
         const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
         const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
         const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
         const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+        const METHOD_SLASH: &str = "slash";
+        const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+        const METHOD_UNBOND_PAYOUT: &str = "unbond_payout";
+        const METHOD_STEP: &str = "step";
+        const METHOD_DISTRIBUTE_SEIGNIORAGE: &str = "distribute_seigniorage";
         const ARG_AMOUNT: &str = "amount";
         const ARG_PURSE: &str = "purse";
+        const ARG_VALIDATOR: &str = "validator";
+        const ARG_VALIDATORS: &str = "validators";
+        const ARG_REWARDS: &str = "rewards";
+        const ARG_REWARD_RATE: &str = "reward_rate";
 
         let state = self.context.state();
         let access_rights = {
@@ -1788,6 +2039,7 @@ where
         let gas_counter = self.context.gas_counter();
         let fn_store_id = self.context.fn_store_id();
         let address_generator = self.context.address_generator();
+        let random_bytes_generator = self.context.random_bytes_generator();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1807,6 +2059,7 @@ where
             gas_counter,
             fn_store_id,
             address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -1849,6 +2102,35 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_DELEGATE => {
+                if !self.config.enable_bonding() {
+                    let err = Error::Revert(ApiError::Unhandled);
+                    return Err(err);
+                }
+
+                let delegator: PublicKey = runtime.context.get_caller();
+                let validator: PublicKey = Self::get_named_argument(&args, ARG_VALIDATOR)?;
+                let amount: U512 = Self::get_named_argument(&args, ARG_AMOUNT)?;
+                let source_uref: URef = Self::get_named_argument(&args, ARG_PURSE)?;
+                runtime
+                    .delegate(delegator, validator, amount, source_uref)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_UNDELEGATE => {
+                if !self.config.enable_bonding() {
+                    let err = Error::Revert(ApiError::Unhandled);
+                    return Err(err);
+                }
+
+                let delegator: PublicKey = runtime.context.get_caller();
+                let validator: PublicKey = Self::get_named_argument(&args, ARG_VALIDATOR)?;
+                let maybe_amount: Option<U512> = Self::get_named_argument(&args, ARG_AMOUNT)?;
+                runtime
+                    .undelegate(delegator, validator, maybe_amount)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             METHOD_GET_PAYMENT_PURSE => {
                 let rights_controlled_purse =
                     runtime.get_payment_purse().map_err(Self::reverter)?;
@@ -1871,6 +2153,35 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_SLASH => {
+                let validators: BTreeMap<PublicKey, U512> =
+                    Self::get_named_argument(&args, ARG_VALIDATORS)?;
+                runtime.slash(validators).map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_DISTRIBUTE_REWARDS => {
+                let rewards: BTreeMap<PublicKey, U512> =
+                    Self::get_named_argument(&args, ARG_REWARDS)?;
+                runtime
+                    .distribute_rewards(rewards)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_UNBOND_PAYOUT => {
+                runtime.unbond_payout().map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_STEP => {
+                runtime.step().map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_DISTRIBUTE_SEIGNIORAGE => {
+                let reward_rate: u64 = Self::get_named_argument(&args, ARG_REWARD_RATE)?;
+                runtime
+                    .distribute_seigniorage(reward_rate)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1881,6 +2192,11 @@ where
 
     pub fn call_host_standard_payment(&mut self) -> Result<(), Error> {
         let amount: U512 = Self::get_named_argument(&self.context.args(), "amount")?;
+        let amount = self
+            .context
+            .protocol_data()
+            .payment_costs()
+            .cap_standard_payment(amount);
         self.pay(amount).map_err(Self::reverter)
     }
 
@@ -2135,6 +2451,7 @@ where
             self.context.gas_counter(),
             self.context.fn_store_id(),
             self.context.address_generator(),
+            self.context.random_bytes_generator(),
             protocol_version,
             self.context.correlation_id(),
             self.context.phase(),
@@ -2157,6 +2474,10 @@ where
         // counter from there to our counter
         self.context.set_gas_counter(runtime.context.gas_counter());
 
+        // Likewise, the events emitted by the sub-call follow ours.  If the sub-call failed, the
+        // whole execution fails and these are discarded along with its effects.
+        self.context.extend_events(runtime.context.take_events());
+
         let error = match result {
             Err(error) => error,
             // If `Ok` and the `host_buffer` is `None`, the contract's execution succeeded but did
@@ -2643,6 +2964,46 @@ where
         Ok(Ok(()))
     }
 
+    /// Reads the values under each of the keys in Wasm memory, writing them to the host buffer as
+    /// a serialized `Vec<Option<CLValue>>` in the same order as the keys.
+    fn read_many(
+        &mut self,
+        keys_ptr: u32,
+        keys_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let keys: Vec<Key> = self.t_from_mem(keys_ptr, keys_size)?;
+        let mut cl_values = Vec::with_capacity(keys.len());
+        for key in &keys {
+            let cl_value = match self.context.read_gs(key)? {
+                Some(stored_value) => {
+                    Some(CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?)
+                }
+                None => None,
+            };
+            cl_values.push(cl_value);
+        }
+
+        let cl_values_bytes = cl_values.into_bytes().map_err(Error::BytesRepr)?;
+        let value = CLValue::from_components(CLType::Any, cl_values_bytes);
+        self.write_host_buffer_with_size(value, output_size_ptr)
+    }
+
+    /// Writes each of the serialized `(Key, CLValue)` entries in Wasm memory to global state.
+    fn write_many(&mut self, entries_ptr: u32, entries_size: u32) -> Result<(), Trap> {
+        let entries: Vec<(Key, CLValue)> = self.t_from_mem(entries_ptr, entries_size)?;
+        let entries = entries
+            .into_iter()
+            .map(|(key, cl_value)| (key, StoredValue::CLValue(cl_value)))
+            .collect();
+        self.context.write_gs_many(entries).map_err(Into::into)
+    }
+
     /// Similar to `read`, this function is for reading from the "local cluster"
     /// of global state
     fn read_local(
@@ -3383,6 +3744,7 @@ mod tests {
                 | CLType::String
                 | CLType::Key
                 | CLType::URef
+                | CLType::PublicKey
                 | CLType::Option(_)
                 | CLType::List(_)
                 | CLType::FixedList(..)
@@ -3412,6 +3774,8 @@ mod tests {
                 (CLValue::from_t(x).expect("should create CLValue"), urefs)
             }),
             uref_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![x])),
+            public_key_arb()
+                .prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             ".*".prop_map(|x: String| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             option::of(any::<u64>())
                 .prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
@@ -1,19 +1,28 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
     fmt::Write,
 };
 
 use engine_shared::stored_value::StoredValue;
 use engine_storage::global_state::StateReader;
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    Delegations, DelegationsProvider, MintProvider, ProofOfStake, Queue, QueueDelays,
+    QueueProvider, RuntimeProvider, StakeLimits, Stakes, StakesProvider,
 };
 use types::{
-    account::PublicKey, bytesrepr::ToBytes, system_contract_errors::pos::Error, ApiError,
-    BlockTime, CLValue, Key, Phase, TransferredTo, URef, U512,
+    account::{PublicKey, ED25519_LENGTH, SECP256K1_LENGTH},
+    bytesrepr::ToBytes,
+    runtime_args,
+    system_contract_errors::{mint, pos::Error},
+    ApiError, BlockTime, CLValue, Key, Phase, RuntimeArgs, TransferredTo, URef, U512,
 };
 
-use crate::{execution, runtime::Runtime};
+use crate::{
+    engine_state::genesis::{POS_DELEGATIONS, POS_QUEUE_DELAYS, POS_STAKE_LIMITS},
+    execution,
+    runtime::Runtime,
+};
 
 const BONDING_KEY: [u8; 32] = {
     let mut result = [0; 32];
@@ -27,6 +36,39 @@ const UNBONDING_KEY: [u8; 32] = {
     result
 };
 
+impl<'a, R> DelegationsProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_delegations(&mut self) -> Result<Delegations, Error> {
+        let uref = self
+            .context
+            .named_keys_get(POS_DELEGATIONS)
+            .and_then(|key| key.into_uref())
+            .ok_or(Error::DelegationsNotFound)?;
+        match self.context.read_gs(&Key::URef(uref)) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                .into_t()
+                .map_err(|_| Error::DelegationsDeserializationFailed),
+            Ok(Some(_)) => Err(Error::DelegationsDeserializationFailed),
+            Ok(None) | Err(_) => Err(Error::DelegationsNotFound),
+        }
+    }
+
+    fn write_delegations(&mut self, delegations: Delegations) {
+        let uref = self
+            .context
+            .named_keys_get(POS_DELEGATIONS)
+            .and_then(|key| key.into_uref())
+            .expect("should have delegations uref");
+        let value = CLValue::from_t(delegations).expect("should convert");
+        self.context
+            .write_gs(Key::URef(uref), StoredValue::CLValue(value))
+            .expect("should write delegations")
+    }
+}
+
 // TODO: Update MintProvider to better handle errors
 impl<'a, R> MintProvider for Runtime<'a, R>
 where
@@ -63,6 +105,21 @@ where
     fn balance(&mut self, purse: URef) -> Option<U512> {
         self.get_balance(purse).expect("should get balance")
     }
+
+    fn mint_reward(&mut self, purse: URef, amount: U512) -> Result<(), ()> {
+        let mint_contract_key = self.get_mint_contract();
+        let args = runtime_args! {
+            "purse" => purse,
+            "amount" => amount,
+        };
+        match self.call_contract(mint_contract_key, "mint_reward", args) {
+            Ok(cl_value) => match cl_value.into_t::<Result<(), mint::Error>>() {
+                Ok(Ok(())) => Ok(()),
+                _ => Err(()),
+            },
+            Err(_) => Err(()),
+        }
+    }
 }
 
 // TODO: Update QueueProvider to better handle errors
@@ -102,6 +159,21 @@ where
             .write_ls(&key, value)
             .expect("should write local state")
     }
+
+    fn read_delays(&mut self) -> Result<QueueDelays, Error> {
+        let uref = self
+            .context
+            .named_keys_get(POS_QUEUE_DELAYS)
+            .and_then(|key| key.into_uref())
+            .ok_or(Error::QueueDelaysNotFound)?;
+        match self.context.read_gs(&Key::URef(uref)) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                .into_t()
+                .map_err(|_| Error::QueueDelaysDeserializationFailed),
+            Ok(Some(_)) => Err(Error::QueueDelaysDeserializationFailed),
+            Ok(None) | Err(_) => Err(Error::QueueDelaysNotFound),
+        }
+    }
 }
 
 // TODO: Update RuntimeProvider to better handle errors
@@ -152,14 +224,18 @@ where
             let hex_key = split_name
                 .next()
                 .ok_or(Error::StakesKeyDeserializationFailed)?;
-            if hex_key.len() != 64 {
+            let key_length = hex_key.len() / 2;
+            if hex_key.len() % 2 != 0
+                || (key_length != ED25519_LENGTH && key_length != SECP256K1_LENGTH)
+            {
                 return Err(Error::StakesKeyDeserializationFailed);
             }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+            let mut key_bytes = [0u8; SECP256K1_LENGTH];
+            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes[..key_length])
+                .map_err(|_| Error::StakesKeyDeserializationFailed)?;
+            debug_assert!(_bytes_written == key_length);
+            let pub_key = PublicKey::try_from(&key_bytes[..key_length])
                 .map_err(|_| Error::StakesKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            let pub_key = PublicKey::ed25519_from(key_bytes);
             let balance = split_name
                 .next()
                 .and_then(|b| U512::from_dec_str(b).ok())
@@ -178,9 +254,9 @@ where
             .0
             .iter()
             .map(|(pub_key, balance)| {
-                let key_bytes = pub_key.value();
-                let mut hex_key = String::with_capacity(64);
-                for byte in &key_bytes[..32] {
+                let key_bytes = pub_key.as_bytes();
+                let mut hex_key = String::with_capacity(2 * key_bytes.len());
+                for byte in key_bytes {
                     write!(hex_key, "{:02x}", byte).expect("Writing to a string cannot fail");
                 }
                 let mut uref = String::new();
@@ -205,6 +281,21 @@ where
                 .expect("should put key")
         }
     }
+
+    fn read_limits(&mut self) -> Result<StakeLimits, Error> {
+        let uref = self
+            .context
+            .named_keys_get(POS_STAKE_LIMITS)
+            .and_then(|key| key.into_uref())
+            .ok_or(Error::StakeLimitsNotFound)?;
+        match self.context.read_gs(&Key::URef(uref)) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                .into_t()
+                .map_err(|_| Error::StakeLimitsDeserializationFailed),
+            Ok(Some(_)) => Err(Error::StakeLimitsDeserializationFailed),
+            Ok(None) | Err(_) => Err(Error::StakeLimitsNotFound),
+        }
+    }
 }
 
 impl<'a, R> ProofOfStake for Runtime<'a, R>
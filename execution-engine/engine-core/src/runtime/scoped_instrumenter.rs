@@ -139,6 +139,17 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::RemoveContractUserGroupURefsIndex => {
                 "host_remove_contract_user_group_urefs"
             }
+            FunctionIndex::EmitEventIndex => "host_function_emit_event",
+            FunctionIndex::ListNamedKeysPageIndex => "host_function_list_named_keys_page",
+            FunctionIndex::ListLocalKeysIndex => "host_function_list_local_keys",
+            FunctionIndex::Blake2b256Index => "host_function_blake2b256",
+            FunctionIndex::Sha256Index => "host_function_sha256",
+            FunctionIndex::Keccak256Index => "host_function_keccak256",
+            FunctionIndex::VerifyEd25519Index => "host_function_verify_ed25519",
+            FunctionIndex::RecoverSecp256k1Index => "host_function_recover_secp256k1",
+            FunctionIndex::RandomBytesIndex => "host_function_random_bytes",
+            FunctionIndex::ReadManyIndex => "host_function_read_many",
+            FunctionIndex::WriteManyIndex => "host_function_write_many",
         };
 
         let mut properties = mem::take(&mut self.properties);
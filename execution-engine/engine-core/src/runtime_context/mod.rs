@@ -3,6 +3,8 @@ use std::{
     collections::{BTreeSet, HashMap, HashSet},
     convert::{TryFrom, TryInto},
     fmt::Debug,
+    mem,
+    ops::Bound,
     rc::Rc,
 };
 
@@ -20,15 +22,15 @@ use types::{
         ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure,
         UpdateKeyFailure, Weight,
     },
-    bytesrepr,
+    bytesrepr::{self, ToBytes},
     contracts::NamedKeys,
     AccessRights, BlockTime, CLType, CLValue, Contract, ContractPackage, ContractPackageHash,
-    EntryPointAccess, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
+    EntryPointAccess, EntryPointType, Key, KeyTag, Phase, ProtocolVersion, RuntimeArgs, URef,
     KEY_HASH_LENGTH,
 };
 
 use crate::{
-    engine_state::execution_effect::ExecutionEffect,
+    engine_state::{execution_effect::ExecutionEffect, execution_event::ExecutionEvent},
     execution::{AddressGenerator, Error},
     tracking_copy::{AddResult, TrackingCopy},
     Address,
@@ -37,6 +39,10 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
+/// The maximum number of keys fetched at a time while listing local keys, so that at most this
+/// many keys are visited before their gas is charged.
+const LIST_LS_BATCH_SIZE: usize = 64;
+
 /// Checks whether given uref has enough access rights.
 pub(crate) fn uref_has_access_rights(
     uref: &URef,
@@ -101,11 +107,14 @@ pub struct RuntimeContext<'a, R> {
     gas_counter: Gas,
     hash_address_generator: Rc<RefCell<AddressGenerator>>,
     uref_address_generator: Rc<RefCell<AddressGenerator>>,
+    random_bytes_generator: Rc<RefCell<AddressGenerator>>,
     protocol_version: ProtocolVersion,
     correlation_id: CorrelationId,
     phase: Phase,
     protocol_data: ProtocolData,
     entry_point_type: EntryPointType,
+    // Events emitted so far, including those emitted by successfully completed sub-calls
+    events: Vec<ExecutionEvent>,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -129,6 +138,7 @@ where
         gas_counter: Gas,
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_bytes_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         phase: Phase,
@@ -149,10 +159,12 @@ where
             gas_counter,
             hash_address_generator,
             uref_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
+            events: Vec::new(),
         }
     }
 
@@ -168,6 +180,19 @@ where
         &self.named_keys
     }
 
+    /// Returns up to `limit` of the named keys whose names follow `start_after`, ordered by name.
+    pub fn named_keys_page(&self, start_after: Option<&str>, limit: usize) -> NamedKeys {
+        let lower_bound = match start_after {
+            Some(name) => Bound::Excluded(name),
+            None => Bound::Unbounded,
+        };
+        self.named_keys
+            .range::<str, _>((lower_bound, Bound::Unbounded))
+            .take(limit)
+            .map(|(name, key)| (name.clone(), *key))
+            .collect()
+    }
+
     pub fn named_keys_mut(&mut self) -> &mut NamedKeys {
         &mut self.named_keys
     }
@@ -265,6 +290,10 @@ where
         Rc::clone(&self.hash_address_generator)
     }
 
+    pub fn random_bytes_generator(&self) -> Rc<RefCell<AddressGenerator>> {
+        Rc::clone(&self.random_bytes_generator)
+    }
+
     pub fn state(&self) -> Rc<RefCell<TrackingCopy<R>>> {
         Rc::clone(&self.tracking_copy)
     }
@@ -281,6 +310,17 @@ where
         self.gas_counter = new_gas_counter;
     }
 
+    /// Adds `amount` to the gas counter, failing if that would exceed the gas limit.
+    pub fn charge_gas(&mut self, amount: Gas) -> Result<(), Error> {
+        match self.gas_counter.checked_add(amount) {
+            Some(gas_counter) if gas_counter <= self.gas_limit => {
+                self.gas_counter = gas_counter;
+                Ok(())
+            }
+            _ => Err(Error::GasLimit),
+        }
+    }
+
     pub fn base_key(&self) -> Key {
         self.base_key
     }
@@ -308,6 +348,14 @@ where
         Ok(hash_bytes)
     }
 
+    /// Fills `dest` with deterministic pseudorandom bytes.
+    ///
+    /// These are drawn from a generator which is separate from those used to create URef and hash
+    /// addresses, so calling this never changes the addresses allocated later in the deploy.
+    pub fn random_bytes(&mut self, dest: &mut [u8]) {
+        self.random_bytes_generator.borrow_mut().fill_bytes(dest)
+    }
+
     pub fn new_uref(&mut self, value: StoredValue) -> Result<URef, Error> {
         let uref = {
             let addr = self.uref_address_generator.borrow_mut().create_address();
@@ -369,12 +417,106 @@ where
             });
         }
         let hash: [u8; KEY_HASH_LENGTH] = key_bytes.try_into().unwrap();
-        self.tracking_copy
-            .borrow_mut()
-            .write(hash.into(), StoredValue::CLValue(cl_value));
+        let marker = self.local_key_marker(&hash);
+        let cl_unit = CLValue::from_components(CLType::Unit, Vec::new());
+        let mut tracking_copy = self.tracking_copy.borrow_mut();
+        tracking_copy.write(hash.into(), StoredValue::CLValue(cl_value));
+        tracking_copy.write(marker, StoredValue::CLValue(cl_unit));
         Ok(())
     }
 
+    /// Returns the key marking `local_key` as written by the current context.
+    ///
+    /// Markers live under URefs derived from the base key and the local key, which no contract can
+    /// forge, so they can't be written other than via `write_ls` in the context they belong to.
+    fn local_key_marker(&self, local_key: &[u8; KEY_HASH_LENGTH]) -> Key {
+        let mut hasher = VarBlake2b::new(KEY_HASH_LENGTH).unwrap();
+        hasher.input(self.base_key.to_bytes().expect("should serialize key"));
+        hasher.input(local_key);
+        let mut address = [0; KEY_HASH_LENGTH];
+        hasher.variable_result(|hash| address.clone_from_slice(hash));
+        Key::URef(URef::new(address, AccessRights::NONE))
+    }
+
+    /// Returns up to `limit` local keys which start with `prefix` and, if given, follow
+    /// `start_after`, ordered by their bytes.
+    ///
+    /// Only keys written via `write_ls` in the current context are returned, including those
+    /// written earlier in this deploy.  Local keys written before markers were recorded are not
+    /// listed.  `cost_per_key` is charged for every key visited, whether or not it is returned, and
+    /// a read is recorded for each key visited and for its marker.
+    pub fn list_ls(
+        &mut self,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+        cost_per_key: Gas,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        if prefix.len() > KEY_HASH_LENGTH {
+            return Err(Error::InvalidKeyLength {
+                actual: prefix.len(),
+                expected: KEY_HASH_LENGTH,
+            });
+        }
+        if let Some(start_after) = start_after {
+            if start_after.len() != KEY_HASH_LENGTH {
+                return Err(Error::InvalidKeyLength {
+                    actual: start_after.len(),
+                    expected: KEY_HASH_LENGTH,
+                });
+            }
+        }
+
+        let to_key_bytes = |local_key: &[u8]| {
+            let mut key_bytes = vec![KeyTag::Hash as u8];
+            key_bytes.extend_from_slice(local_key);
+            key_bytes
+        };
+        let key_prefix = to_key_bytes(prefix);
+        let mut cursor = start_after.map(to_key_bytes);
+
+        // Local values of all contexts share the `Key::Hash` space with stored contracts, so keys
+        // without a marker for the current context are skipped, and further keys fetched until the
+        // page is full.
+        let mut local_keys = Vec::new();
+        while local_keys.len() < limit {
+            let requested = (limit - local_keys.len()).min(LIST_LS_BATCH_SIZE);
+            let keys = self
+                .tracking_copy
+                .borrow()
+                .keys_with_prefix(
+                    self.correlation_id,
+                    &key_prefix,
+                    cursor.as_deref(),
+                    requested,
+                )
+                .map_err(Into::into)?;
+            let exhausted = keys.len() < requested;
+            for key in keys {
+                self.charge_gas(cost_per_key)?;
+                let hash = key.into_hash().expect("should be a hash key");
+                let marker = self.local_key_marker(&hash);
+                let maybe_marker = {
+                    let mut tracking_copy = self.tracking_copy.borrow_mut();
+                    tracking_copy
+                        .read(self.correlation_id, &key)
+                        .map_err(Into::into)?;
+                    tracking_copy
+                        .read(self.correlation_id, &marker)
+                        .map_err(Into::into)?
+                };
+                if maybe_marker.is_some() {
+                    local_keys.push(hash.to_vec());
+                }
+                cursor = Some(to_key_bytes(&hash));
+            }
+            if exhausted {
+                break;
+            }
+        }
+        Ok(local_keys)
+    }
+
     pub fn read_gs(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         self.validate_readable(key)?;
         self.validate_key(key)?;
@@ -423,6 +565,21 @@ where
         Ok(())
     }
 
+    /// Writes each of `entries` to global state, applying the checks of `write_gs` to all of them
+    /// before any is written.
+    pub fn write_gs_many(&mut self, entries: Vec<(Key, StoredValue)>) -> Result<(), Error> {
+        for (key, value) in &entries {
+            self.validate_writeable(key)?;
+            self.validate_key(key)?;
+            self.validate_value(value)?;
+        }
+        let mut tracking_copy = self.tracking_copy.borrow_mut();
+        for (key, value) in entries {
+            tracking_copy.write(key, value);
+        }
+        Ok(())
+    }
+
     pub fn read_account(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         if let Key::Account(_) = key {
             self.validate_key(key)?;
@@ -485,6 +642,24 @@ where
         self.tracking_copy.borrow_mut().effect()
     }
 
+    /// Records an event emitted by the code currently executing.
+    pub fn emit_event(&mut self, name: String, value: CLValue) {
+        let event = ExecutionEvent::new(self.base_key.into_hash(), self.phase, name, value);
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[ExecutionEvent] {
+        &self.events
+    }
+
+    pub fn take_events(&mut self) -> Vec<ExecutionEvent> {
+        mem::take(&mut self.events)
+    }
+
+    pub fn extend_events(&mut self, events: Vec<ExecutionEvent>) {
+        self.events.extend(events);
+    }
+
     /// Validates whether keys used in the `value` are not forged.
     fn validate_value(&self, value: &StoredValue) -> Result<(), Error> {
         match value {
@@ -500,6 +675,7 @@ where
                 | CLType::U512
                 | CLType::Unit
                 | CLType::String
+                | CLType::PublicKey
                 | CLType::Option(_)
                 | CLType::List(_)
                 | CLType::FixedList(..)
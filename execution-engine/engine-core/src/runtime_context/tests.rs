@@ -5,6 +5,7 @@ use std::{
     rc::Rc,
 };
 
+use assert_matches::assert_matches;
 use rand::RngCore;
 
 use engine_shared::{
@@ -30,7 +31,7 @@ use types::{
 
 use super::{Address, Error, RuntimeContext};
 use crate::{
-    execution::AddressGenerator, runtime::extract_access_rights_from_keys,
+    engine_state::op::Op, execution::AddressGenerator, runtime::extract_access_rights_from_keys,
     tracking_copy::TrackingCopy,
 };
 
@@ -40,16 +41,25 @@ const PHASE: Phase = Phase::Session;
 fn mock_tracking_copy(
     init_key: Key,
     init_account: Account,
+) -> TrackingCopy<InMemoryGlobalStateView> {
+    mock_tracking_copy_with(init_key, init_account, AdditiveMap::new())
+}
+
+// Creates a tracking copy of a global state holding `init_account` and the values written by
+// `transforms`.
+fn mock_tracking_copy_with(
+    init_key: Key,
+    init_account: Account,
+    mut transforms: AdditiveMap<Key, Transform>,
 ) -> TrackingCopy<InMemoryGlobalStateView> {
     let correlation_id = CorrelationId::new();
     let hist = InMemoryGlobalState::empty().unwrap();
     let root_hash = hist.empty_root_hash;
     let transform = Transform::Write(StoredValue::Account(init_account));
 
-    let mut m = AdditiveMap::new();
-    m.insert(init_key, transform);
+    transforms.insert(init_key, transform);
     let commit_result = hist
-        .commit(correlation_id, root_hash, m)
+        .commit(correlation_id, root_hash, transforms)
         .expect("Creation of mocked account should be a success.");
 
     let new_hash = match commit_result {
@@ -133,6 +143,10 @@ fn mock_runtime_context<'a>(
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
+        Rc::new(RefCell::new(AddressGenerator::new_random_bytes_generator(
+            &[1u8; 32],
+            Phase::Session,
+        ))),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         Phase::Session,
@@ -205,6 +219,29 @@ fn use_uref_forged() {
     assert_forged_reference(query_result);
 }
 
+#[test]
+fn write_gs_many_should_check_every_entry_before_writing() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let valid_uref = create_uref(&mut rng, AccessRights::READ_WRITE);
+    let forged_uref = create_uref(&mut rng, AccessRights::READ_WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![valid_uref]);
+    let value = StoredValue::CLValue(CLValue::from_t(43_i32).unwrap());
+
+    let query_result = test(access_rights, |mut rc| {
+        let result = rc.write_gs_many(vec![
+            (valid_uref, value.clone()),
+            (forged_uref, value.clone()),
+        ]);
+        assert_eq!(rc.read_gs(&valid_uref)?, None);
+
+        rc.write_gs_many(vec![(valid_uref, value.clone())])?;
+        assert_eq!(rc.read_gs(&valid_uref)?, Some(value));
+        result
+    });
+
+    assert_forged_reference(query_result);
+}
+
 #[test]
 fn account_key_not_writeable() {
     let mut rng = rand::thread_rng();
@@ -361,6 +398,10 @@ fn contract_key_addable_valid() {
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
+        Rc::new(RefCell::new(AddressGenerator::new_random_bytes_generator(
+            &DEPLOY_HASH,
+            PHASE,
+        ))),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -431,6 +472,10 @@ fn contract_key_addable_invalid() {
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
+        Rc::new(RefCell::new(AddressGenerator::new_random_bytes_generator(
+            &DEPLOY_HASH,
+            PHASE,
+        ))),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -832,3 +877,165 @@ fn validate_valid_purse_of_an_account() {
     let purse = URef::new([53; 32], AccessRights::READ_ADD_WRITE);
     assert!(runtime_context.validate_uref(&purse).is_err());
 }
+
+#[test]
+fn should_list_named_keys_in_pages() {
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        for index in 0..5u8 {
+            runtime_context
+                .named_keys_mut()
+                .insert(format!("key_{}", index), Key::Hash([index; 32]));
+        }
+
+        let first_page = runtime_context.named_keys_page(None, 2);
+        let names: Vec<&String> = first_page.keys().collect();
+        assert_eq!(names, vec!["key_0", "key_1"]);
+
+        let last_page = runtime_context.named_keys_page(Some("key_3"), 2);
+        let names: Vec<&String> = last_page.keys().collect();
+        assert_eq!(names, vec!["key_4"]);
+        Ok(())
+    };
+    test(HashMap::new(), query).expect("should list named keys");
+}
+
+#[test]
+fn should_list_local_keys_with_prefix_in_pages() {
+    let local_key = |index: u8| {
+        let mut local_key = [7u8; KEY_HASH_LENGTH];
+        local_key[KEY_HASH_LENGTH - 1] = index;
+        local_key
+    };
+
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        runtime_context.write_ls(&local_key(0), CLValue::from_t(0u8).unwrap())?;
+        runtime_context.write_ls(&local_key(2), CLValue::from_t(2u8).unwrap())?;
+        // Stored contracts under the prefix aren't listed as local keys.
+        let contract = StoredValue::Contract(Contract::default());
+        runtime_context
+            .state()
+            .borrow_mut()
+            .write(Key::Hash(local_key(1)), contract);
+        // Nor are local values written by other contexts.
+        let foreign_value = StoredValue::CLValue(CLValue::from_t(3u8).unwrap());
+        runtime_context
+            .state()
+            .borrow_mut()
+            .write(Key::Hash(local_key(3)), foreign_value);
+        // Nor are local keys outside of the prefix.
+        runtime_context.write_ls(&[8u8; KEY_HASH_LENGTH], CLValue::from_t(8u8).unwrap())?;
+
+        let first_page = runtime_context.list_ls(&[7, 7], None, 1, Gas::default())?;
+        assert_eq!(first_page, vec![local_key(0).to_vec()]);
+
+        let last_page =
+            runtime_context.list_ls(&[7, 7], Some(&first_page[0]), 2, Gas::default())?;
+        assert_eq!(last_page, vec![local_key(2).to_vec()]);
+
+        let result = runtime_context.list_ls(&[7; KEY_HASH_LENGTH + 1], None, 1, Gas::default());
+        assert!(result.is_err());
+        Ok(())
+    };
+    test(HashMap::new(), query).expect("should list local keys");
+}
+
+#[test]
+fn should_record_reads_of_local_keys_visited() {
+    let (base_key, account) = mock_account(PublicKey::ed25519_from([0u8; 32]));
+    let foreign_key = Key::Hash([7; KEY_HASH_LENGTH]);
+    let foreign_value = StoredValue::CLValue(CLValue::from_t(7u8).unwrap());
+    let mut transforms = AdditiveMap::new();
+    transforms.insert(foreign_key, Transform::Write(foreign_value));
+    let tracking_copy = Rc::new(RefCell::new(mock_tracking_copy_with(
+        base_key,
+        account.clone(),
+        transforms,
+    )));
+
+    let mut named_keys = NamedKeys::new();
+    let mut runtime_context = RuntimeContext::new(
+        Rc::clone(&tracking_copy),
+        EntryPointType::Session,
+        &mut named_keys,
+        HashMap::new(),
+        RuntimeArgs::new(),
+        BTreeSet::from_iter(vec![PublicKey::ed25519_from([0; 32])]),
+        &account,
+        base_key,
+        BlockTime::new(0),
+        DEPLOY_HASH,
+        Gas::default(),
+        Gas::default(),
+        Rc::new(RefCell::new(AddressGenerator::new(&DEPLOY_HASH, PHASE))),
+        Rc::new(RefCell::new(AddressGenerator::new(&DEPLOY_HASH, PHASE))),
+        Rc::new(RefCell::new(AddressGenerator::new_random_bytes_generator(
+            &DEPLOY_HASH,
+            PHASE,
+        ))),
+        ProtocolVersion::V1_0_0,
+        CorrelationId::new(),
+        PHASE,
+        Default::default(),
+    );
+
+    // The value written by another context is skipped, but its having been visited is recorded so
+    // that listing conflicts with deploys writing it.
+    let local_keys = runtime_context
+        .list_ls(&[7], None, 1, Gas::default())
+        .expect("should list local keys");
+    assert!(local_keys.is_empty());
+    assert_eq!(
+        tracking_copy.borrow().effect().ops.get(&foreign_key),
+        Some(&Op::Read)
+    );
+}
+
+#[test]
+fn should_charge_for_every_local_key_visited() {
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        // A stored contract is visited, though not listed, so listing still exceeds the gas limit.
+        let contract = StoredValue::Contract(Contract::default());
+        runtime_context
+            .state()
+            .borrow_mut()
+            .write(Key::Hash([7; KEY_HASH_LENGTH]), contract);
+        runtime_context.list_ls(&[7], None, 1, Gas::new(1.into()))
+    };
+    assert_matches!(test(HashMap::new(), query), Err(Error::GasLimit));
+}
+
+#[test]
+fn random_bytes_should_not_change_new_uref_addresses() {
+    let new_uref_address = |draw_random_bytes: bool| {
+        let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+            if draw_random_bytes {
+                let mut random_bytes = [0u8; 64];
+                runtime_context.random_bytes(&mut random_bytes);
+            }
+            let uref =
+                runtime_context.new_uref(StoredValue::CLValue(CLValue::from_t(()).unwrap()))?;
+            Ok(uref.addr())
+        };
+        test(HashMap::new(), query).expect("should create uref")
+    };
+
+    assert_eq!(new_uref_address(false), new_uref_address(true));
+}
+
+#[test]
+fn random_bytes_should_be_deterministic() {
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        let mut first = [0u8; 16];
+        let mut second = [0u8; 16];
+        runtime_context.random_bytes(&mut first);
+        runtime_context.random_bytes(&mut second);
+        Ok((first, second))
+    };
+    let (first, second) = test(HashMap::new(), query).expect("should draw random bytes");
+
+    assert_ne!(first, second);
+    assert_eq!(
+        test(HashMap::new(), query).expect("should draw random bytes"),
+        (first, second)
+    );
+}
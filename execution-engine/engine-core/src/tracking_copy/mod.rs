@@ -5,7 +5,7 @@ pub(self) mod meter;
 mod tests;
 
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     convert::From,
     iter,
 };
@@ -19,8 +19,14 @@ use engine_shared::{
     transform::{self, Transform},
     TypeMismatch,
 };
-use engine_storage::global_state::StateReader;
-use types::{bytesrepr, CLType, CLValueError, Key};
+use engine_storage::{
+    global_state::{KeysPage, StateReader},
+    trie::merkle_proof::TrieMerkleProof,
+};
+use types::{
+    bytesrepr::{self, ToBytes},
+    CLType, CLValueError, Key,
+};
 
 use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
 
@@ -226,6 +232,37 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         }
     }
 
+    /// Returns up to `limit` keys whose serialized forms start with `prefix` and, if given, follow
+    /// `start_after`, ordered by their serialized forms.
+    ///
+    /// Keys written to this tracking copy are included alongside those held by the underlying
+    /// reader.  Listing keys doesn't record reads of them.
+    pub fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Key>, R::Error> {
+        let follows_start_after =
+            |key_bytes: &[u8]| start_after.map_or(true, |start_after| key_bytes > start_after);
+
+        let mut keys: BTreeMap<Vec<u8>, Key> = self
+            .reader
+            .keys_with_prefix(correlation_id, prefix, start_after, limit)?
+            .into_iter()
+            .map(|key| (key.to_bytes().expect("should serialize"), key))
+            .collect();
+        for key in self.cache.muts_cached.keys() {
+            let key_bytes = key.to_bytes().expect("should serialize");
+            if key_bytes.starts_with(prefix) && follows_start_after(&key_bytes) {
+                keys.insert(key_bytes, *key);
+            }
+        }
+
+        Ok(keys.values().take(limit).copied().collect())
+    }
+
     pub fn read(
         &mut self,
         correlation_id: CorrelationId,
@@ -329,6 +366,52 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         ExecutionEffect::new(self.ops.clone(), self.fns.clone())
     }
 
+    /// Applies an `ExecutionEffect` produced against this `TrackingCopy` (e.g. by a fork of it),
+    /// so that subsequent reads observe its transforms and they are included in `self.effect()`.
+    ///
+    /// The effect is applied atomically: if any of its transforms can't be applied, this
+    /// `TrackingCopy` is left unchanged.
+    pub fn apply_effect(
+        &mut self,
+        correlation_id: CorrelationId,
+        effect: ExecutionEffect,
+    ) -> Result<AddResult, R::Error> {
+        let mut new_values = Vec::with_capacity(effect.transforms.len());
+        for (key, transform) in effect.transforms.iter() {
+            let new_value = match transform {
+                Transform::Identity => continue,
+                Transform::Write(value) => value.to_owned(),
+                _ => {
+                    let current_value = match self.get(correlation_id, key)? {
+                        None => return Ok(AddResult::KeyNotFound(*key)),
+                        Some(current_value) => current_value,
+                    };
+                    match transform.clone().apply(current_value) {
+                        Ok(new_value) => new_value,
+                        Err(transform::Error::TypeMismatch(type_mismatch)) => {
+                            return Ok(AddResult::TypeMismatch(type_mismatch))
+                        }
+                        Err(transform::Error::Serialization(error)) => {
+                            return Ok(AddResult::Serialization(error))
+                        }
+                    }
+                }
+            };
+            new_values.push((*key, new_value));
+        }
+
+        for (key, new_value) in new_values {
+            self.cache.insert_write(key, new_value);
+        }
+        for (key, op) in effect.ops {
+            self.ops.insert_add(key, op);
+        }
+        for (key, transform) in effect.transforms {
+            self.fns.insert_add(key, transform);
+        }
+        Ok(AddResult::Success)
+    }
+
     /// Calling `query()` avoids calling into `self.cache`, so this will not return any values
     /// written or mutated in this `TrackingCopy` via previous calls to `write()` or `add()`, since
     /// these updates are only held in `self.cache`.
@@ -426,4 +509,40 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
             Ok(None)
         }
     }
+
+    /// Proofs are only available for values committed under the underlying reader's root, so no
+    /// proof is returned for a key written in this tracking copy.
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        if self.cache.muts_cached.contains_key(key) {
+            return Ok(None);
+        }
+        self.reader.read_with_proof(correlation_id, key)
+    }
+
+    /// Pages are read from the underlying reader's root, so cached writes are not taken into
+    /// account.
+    fn read_page(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<KeysPage<Key, StoredValue>, Self::Error> {
+        self.reader
+            .read_page(correlation_id, prefix, start_after, limit)
+    }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        TrackingCopy::keys_with_prefix(self, correlation_id, prefix, start_after, limit)
+    }
 }
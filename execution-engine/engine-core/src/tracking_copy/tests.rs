@@ -5,22 +5,27 @@ use proptest::prelude::*;
 
 use engine_shared::{
     account::{Account, AssociatedKeys},
+    additive_map::AdditiveMap,
     newtypes::CorrelationId,
     stored_value::{gens::stored_value_arb, StoredValue},
     transform::Transform,
 };
-use engine_storage::global_state::{in_memory::InMemoryGlobalState, StateProvider, StateReader};
+use engine_storage::{
+    global_state::{in_memory::InMemoryGlobalState, KeysPage, StateProvider, StateReader},
+    trie::merkle_proof::TrieMerkleProof,
+};
 use types::{
     account::{PublicKey, Weight, ED25519_LENGTH},
+    bytesrepr::ToBytes,
     contracts::NamedKeys,
     gens::*,
-    AccessRights, CLValue, Contract, EntryPoints, Key, ProtocolVersion, URef,
+    AccessRights, CLValue, Contract, EntryPoints, Key, KeyTag, ProtocolVersion, URef,
 };
 
 use super::{
     meter::count_meter::Count, AddResult, TrackingCopy, TrackingCopyCache, TrackingCopyQueryResult,
 };
-use crate::engine_state::op::Op;
+use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
 
 struct CountingDb {
     count: Rc<Cell<i32>>,
@@ -58,6 +63,37 @@ impl StateReader<Key, StoredValue> for CountingDb {
         self.count.set(count + 1);
         Ok(Some(value))
     }
+
+    fn read_with_proof(
+        &self,
+        _correlation_id: CorrelationId,
+        _key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        Ok(None)
+    }
+
+    fn read_page(
+        &self,
+        _correlation_id: CorrelationId,
+        _prefix: &[u8],
+        _start_after: Option<&[u8]>,
+        _limit: usize,
+    ) -> Result<KeysPage<Key, StoredValue>, Self::Error> {
+        Ok(KeysPage {
+            entries: Vec::new(),
+            has_more: false,
+        })
+    }
+
+    fn keys_with_prefix(
+        &self,
+        _correlation_id: CorrelationId,
+        _prefix: &[u8],
+        _start_after: Option<&[u8]>,
+        _limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        Ok(Vec::new())
+    }
 }
 
 #[test]
@@ -229,6 +265,74 @@ fn tracking_copy_add_named_key() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Add));
 }
 
+#[test]
+fn tracking_copy_apply_effect() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([0u8; 32]);
+    let k2 = Key::Hash([1u8; 32]);
+
+    let effect = {
+        let mut fork = tc.fork();
+        let add = fork.add(
+            correlation_id,
+            k1,
+            StoredValue::CLValue(CLValue::from_t(2_i32).unwrap()),
+        );
+        assert_matches!(add, Ok(AddResult::Success));
+        fork.write(k2, StoredValue::CLValue(CLValue::from_t(5_i32).unwrap()));
+        fork.effect()
+    };
+
+    let result = tc.apply_effect(correlation_id, effect.clone());
+    assert_matches!(result, Ok(AddResult::Success));
+
+    // applied transforms are included in the accumulated effect
+    assert_eq!(tc.fns.get(&k1), Some(&Transform::AddInt32(2)));
+    assert_eq!(
+        tc.fns.get(&k2),
+        Some(&Transform::Write(StoredValue::CLValue(
+            CLValue::from_t(5_i32).unwrap()
+        )))
+    );
+    assert_eq!(tc.ops, effect.ops);
+
+    // and are visible to subsequent reads
+    let value = tc.read(correlation_id, &k1).unwrap().unwrap();
+    assert_eq!(value, StoredValue::CLValue(CLValue::from_t(3_i32).unwrap()));
+    let value = tc.read(correlation_id, &k2).unwrap().unwrap();
+    assert_eq!(value, StoredValue::CLValue(CLValue::from_t(5_i32).unwrap()));
+}
+
+#[test]
+fn tracking_copy_apply_effect_is_atomic() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([0u8; 32]);
+    let k2 = Key::Hash([1u8; 32]);
+
+    let effect = {
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(
+            k1,
+            Transform::Write(StoredValue::CLValue(CLValue::from_t(5_i32).unwrap())),
+        );
+        // can't add named keys to the stored `i32`
+        transforms.insert(k2, Transform::AddKeys(Default::default()));
+        ExecutionEffect::new(AdditiveMap::new(), transforms)
+    };
+
+    let result = tc.apply_effect(correlation_id, effect);
+    assert_matches!(result, Ok(AddResult::TypeMismatch(_)));
+
+    assert!(tc.fns.is_empty());
+    assert!(tc.ops.is_empty());
+    let value = tc.read(correlation_id, &k1).unwrap().unwrap();
+    assert_eq!(value, StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+}
+
 #[test]
 fn tracking_copy_rw() {
     let correlation_id = CorrelationId::new();
@@ -490,6 +594,76 @@ fn cache_writes_not_invalidated() {
     assert_eq!(tc_cache.get(&k3), Some(&v3));
 }
 
+#[test]
+fn tracking_copy_keys_with_prefix_include_cached_writes() {
+    let correlation_id = CorrelationId::new();
+    let value = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+    let (gs, root_hash) = InMemoryGlobalState::from_pairs(
+        correlation_id,
+        &[
+            (Key::Hash([1; 32]), value.clone()),
+            (Key::Hash([3; 32]), value.clone()),
+            (
+                Key::URef(URef::new([2; 32], AccessRights::READ)),
+                value.clone(),
+            ),
+        ],
+    )
+    .unwrap();
+    let view = gs.checkout(root_hash).unwrap().unwrap();
+    let mut tc = TrackingCopy::new(view);
+    tc.write(Key::Hash([2; 32]), value.clone());
+    tc.write(Key::Hash([4; 32]), value);
+
+    let hash_prefix = [KeyTag::Hash as u8];
+    let keys = tc
+        .keys_with_prefix(correlation_id, &hash_prefix, None, 3)
+        .unwrap();
+    assert_eq!(
+        keys,
+        vec![Key::Hash([1; 32]), Key::Hash([2; 32]), Key::Hash([3; 32])]
+    );
+
+    let start_after = Key::Hash([2; 32]).to_bytes().unwrap();
+    let keys = tc
+        .keys_with_prefix(correlation_id, &hash_prefix, Some(&start_after), 10)
+        .unwrap();
+    assert_eq!(keys, vec![Key::Hash([3; 32]), Key::Hash([4; 32])]);
+
+    // Listing keys records no reads.
+    assert!(tc.ops.values().all(|op| *op == Op::Write));
+}
+
+#[test]
+fn tracking_copy_read_with_proof_excludes_cached_writes() {
+    let correlation_id = CorrelationId::new();
+    let value = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+    let (gs, root_hash) = InMemoryGlobalState::from_pairs(
+        correlation_id,
+        &[
+            (Key::Hash([1; 32]), value.clone()),
+            (Key::Hash([2; 32]), value.clone()),
+        ],
+    )
+    .unwrap();
+    let view = gs.checkout(root_hash).unwrap().unwrap();
+    let mut tc = TrackingCopy::new(view);
+    let new_value = StoredValue::CLValue(CLValue::from_t(2_i32).unwrap());
+    tc.write(Key::Hash([2; 32]), new_value);
+
+    let proof = (&tc)
+        .read_with_proof(correlation_id, &Key::Hash([1; 32]))
+        .unwrap()
+        .expect("should have proof of committed value");
+    assert_eq!(proof.value(), &value);
+
+    // The committed value of a written key is stale, so no proof is returned for it.
+    assert!((&tc)
+        .read_with_proof(correlation_id, &Key::Hash([2; 32]))
+        .unwrap()
+        .is_none());
+}
+
 #[test]
 fn query_for_circular_references_should_fail() {
     // create self-referential key
@@ -0,0 +1,43 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::bid_state::BidStateRequest;
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{
+    ipc::{self, BidState_Bid},
+    mappings::MappingError,
+};
+
+impl TryFrom<ipc::BidStateRequest> for BidStateRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_bid_state_request: ipc::BidStateRequest) -> Result<Self, Self::Error> {
+        let pre_state_hash = pb_bid_state_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("pre_state_hash".to_string()))?;
+
+        let protocol_version = pb_bid_state_request.take_protocol_version().into();
+
+        Ok(BidStateRequest::new(pre_state_hash, protocol_version))
+    }
+}
+
+protobuf_conversions_for_validator_amount!(BidState_Bid, set_id, get_id);
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            test_utils::protobuf_round_trip::<(PublicKey, U512), BidState_Bid>((public_key, u512));
+        }
+    }
+}
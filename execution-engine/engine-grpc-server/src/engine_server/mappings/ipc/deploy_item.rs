@@ -12,7 +12,7 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
     type Error = MappingError;
 
     fn try_from(mut pb_deploy_item: ipc::DeployItem) -> Result<Self, Self::Error> {
-        let address = PublicKey::ed25519_try_from(pb_deploy_item.get_address())
+        let address = PublicKey::try_from(pb_deploy_item.get_address())
             .map_err(|_| MappingError::invalid_public_key_length(pb_deploy_item.address.len()))?;
 
         let session = pb_deploy_item
@@ -33,7 +33,7 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
             .get_authorization_keys()
             .iter()
             .map(|raw: &Vec<u8>| {
-                PublicKey::ed25519_try_from(raw.as_slice())
+                PublicKey::try_from(raw.as_slice())
                     .map_err(|_| MappingError::invalid_public_key_length(raw.len()))
             })
             .collect::<Result<BTreeSet<PublicKey>, Self::Error>>()?;
@@ -42,6 +42,15 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
             MappingError::invalid_deploy_hash_length(pb_deploy_item.deploy_hash.len())
         })?;
 
+        let nonce = pb_deploy_item.get_nonce();
+
+        // Zero means the field is unset
+        let valid_from = Some(pb_deploy_item.get_valid_from()).filter(|value| *value != 0);
+        let ttl = Some(pb_deploy_item.get_ttl()).filter(|value| *value != 0);
+        if let (None, Some(ttl)) = (valid_from, ttl) {
+            return Err(MappingError::TtlWithoutValidFrom { ttl });
+        }
+
         Ok(DeployItem::new(
             address,
             session,
@@ -49,6 +58,9 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            nonce,
+            valid_from,
+            ttl,
         ))
     }
 }
@@ -68,6 +80,85 @@ impl From<DeployItem> for ipc::DeployItem {
                 .collect(),
         );
         result.set_deploy_hash(deploy_item.deploy_hash.to_vec());
+        result.set_nonce(deploy_item.nonce);
+        result.set_valid_from(deploy_item.valid_from.unwrap_or_default());
+        result.set_ttl(deploy_item.ttl.unwrap_or_default());
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use engine_core::engine_state::executable_deploy_item::ExecutableDeployItem;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    fn module_bytes() -> ExecutableDeployItem {
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![1, 2, 3],
+            args: vec![],
+        }
+    }
+
+    #[test]
+    fn round_trip_with_mixed_key_types() {
+        let address = PublicKey::secp256k1_from([1; 33]);
+        let authorization_keys = vec![address, PublicKey::ed25519_from([2; 32])]
+            .into_iter()
+            .collect();
+        let deploy_item = DeployItem::new(
+            address,
+            module_bytes(),
+            module_bytes(),
+            1,
+            authorization_keys,
+            [3; 32],
+            4,
+            Some(5),
+            Some(6),
+        );
+        test_utils::protobuf_round_trip::<DeployItem, ipc::DeployItem>(deploy_item);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_authorization_key() {
+        let deploy_item = DeployItem::new(
+            PublicKey::ed25519_from([1; 32]),
+            module_bytes(),
+            module_bytes(),
+            1,
+            BTreeSet::new(),
+            [3; 32],
+            4,
+            None,
+            None,
+        );
+        let mut pb_deploy_item = ipc::DeployItem::from(deploy_item);
+        pb_deploy_item.set_authorization_keys(vec![vec![2; 31]].into());
+        match DeployItem::try_from(pb_deploy_item) {
+            Err(MappingError::InvalidPublicKeyLength { actual: 31 }) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_ttl_without_valid_from() {
+        let deploy_item = DeployItem::new(
+            PublicKey::ed25519_from([1; 32]),
+            module_bytes(),
+            module_bytes(),
+            1,
+            BTreeSet::new(),
+            [3; 32],
+            4,
+            None,
+            Some(6),
+        );
+        let pb_deploy_item = ipc::DeployItem::from(deploy_item);
+        match DeployItem::try_from(pb_deploy_item) {
+            Err(MappingError::TtlWithoutValidFrom { ttl: 6 }) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -7,18 +7,30 @@ use engine_core::{
 };
 use engine_shared::gas::Gas;
 
-use crate::engine_server::ipc::{DeployError_OutOfGasError, DeployResult};
+use crate::engine_server::ipc::{self, DeployError_OutOfGasError, DeployResult};
 
 impl From<ExecutionResult> for DeployResult {
     fn from(execution_result: ExecutionResult) -> DeployResult {
-        match execution_result {
-            ExecutionResult::Success { effect, cost } => detail::execution_success(effect, cost),
+        let (mut pb_deploy_result, events) = match execution_result {
+            ExecutionResult::Success {
+                effect,
+                events,
+                cost,
+            } => (detail::execution_success(effect, cost), events),
             ExecutionResult::Failure {
                 error,
                 effect,
+                events,
                 cost,
-            } => (error, effect, cost).into(),
+            } => ((error, effect, cost).into(), events),
+        };
+        if pb_deploy_result.has_execution_result() {
+            let pb_events: Vec<ipc::ExecutionEvent> = events.into_iter().map(Into::into).collect();
+            pb_deploy_result
+                .mut_execution_result()
+                .set_events(pb_events.into());
         }
+        pb_deploy_result
     }
 }
 
@@ -32,11 +44,15 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             | error @ EngineStateError::InvalidPublicKeyLength { .. }
             | error @ EngineStateError::InvalidProtocolVersion { .. }
             | error @ EngineStateError::InvalidUpgradeConfig
+            | error @ EngineStateError::ZeroConvRate
             | error @ EngineStateError::WasmPreprocessing(_)
             | error @ EngineStateError::WasmSerialization(_)
             | error @ EngineStateError::Exec(ExecutionError::DeploymentAuthorizationFailure)
             | error @ EngineStateError::InvalidKeyVariant(_)
             | error @ EngineStateError::Authorization
+            | error @ EngineStateError::InvalidNonce { .. }
+            | error @ EngineStateError::InvalidBlockTime { .. }
+            | error @ EngineStateError::TtlWithoutValidFrom { .. }
             | error @ EngineStateError::InvalidDeployItemVariant(_)
             | error @ EngineStateError::InvalidUpgradeResult => {
                 detail::precondition_error(error.to_string())
@@ -145,8 +161,11 @@ mod detail {
 mod tests {
     use std::convert::TryInto;
 
+    use engine_core::engine_state::execution_event::ExecutionEvent;
     use engine_shared::{additive_map::AdditiveMap, transform::Transform};
-    use types::{bytesrepr::Error as BytesReprError, AccessRights, ApiError, Key, URef, U512};
+    use types::{
+        bytesrepr::Error as BytesReprError, AccessRights, ApiError, CLValue, Key, Phase, URef, U512,
+    };
 
     use super::*;
 
@@ -164,6 +183,7 @@ mod tests {
         let cost = Gas::new(U512::from(123));
         let execution_result = ExecutionResult::Success {
             effect: execution_effect,
+            events: Vec::new(),
             cost,
         };
         let mut ipc_deploy_result: DeployResult = execution_result.into();
@@ -185,10 +205,40 @@ mod tests {
         assert_eq!(input_transforms, ipc_transforms);
     }
 
+    #[test]
+    fn deploy_result_to_ipc_with_events() {
+        let events = vec![
+            ExecutionEvent::new(
+                None,
+                Phase::Session,
+                "first".to_string(),
+                CLValue::from_t(1u64).unwrap(),
+            ),
+            ExecutionEvent::new(
+                Some([1; 32]),
+                Phase::Session,
+                "second".to_string(),
+                CLValue::from_t(2u64).unwrap(),
+            ),
+        ];
+        let execution_result = ExecutionResult::Success {
+            effect: Default::default(),
+            events,
+            cost: Gas::new(U512::from(1)),
+        };
+        let ipc_deploy_result: DeployResult = execution_result.into();
+        let pb_events = ipc_deploy_result.get_execution_result().get_events();
+        let names: Vec<&str> = pb_events.iter().map(|event| event.get_name()).collect();
+        assert_eq!(names, vec!["first", "second"]);
+        assert!(pb_events[0].get_contract_hash().is_empty());
+        assert_eq!(pb_events[1].get_contract_hash(), &[1; 32]);
+    }
+
     fn test_cost<E: Into<EngineStateError>>(expected_cost: Gas, error: E) -> Gas {
         let execution_failure = ExecutionResult::Failure {
             error: error.into(),
             effect: Default::default(),
+            events: Vec::new(),
             cost: expected_cost,
         };
         let mut ipc_deploy_result: DeployResult = execution_failure.into();
@@ -232,6 +282,7 @@ mod tests {
         let exec_result = ExecutionResult::Failure {
             error: EngineStateError::Exec(revert_error),
             effect: Default::default(),
+            events: Vec::new(),
             cost: Gas::new(amount),
         };
         let mut ipc_result: DeployResult = exec_result.into();
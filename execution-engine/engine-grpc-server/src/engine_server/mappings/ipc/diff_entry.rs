@@ -0,0 +1,91 @@
+use engine_core::engine_state::diff::DiffEntry;
+
+use crate::engine_server::ipc::{self, DiffEntry_Changed};
+
+impl From<DiffEntry> for ipc::DiffEntry {
+    fn from(diff_entry: DiffEntry) -> Self {
+        let mut pb_diff_entry = ipc::DiffEntry::new();
+        match diff_entry {
+            DiffEntry::Added { key, value } => {
+                pb_diff_entry.set_key(key.into());
+                pb_diff_entry.set_added(value.into());
+            }
+            DiffEntry::Removed { key, value } => {
+                pb_diff_entry.set_key(key.into());
+                pb_diff_entry.set_removed(value.into());
+            }
+            DiffEntry::Changed {
+                key,
+                old_value,
+                new_value,
+            } => {
+                let mut pb_changed = DiffEntry_Changed::new();
+                pb_changed.set_old_value(old_value.into());
+                pb_changed.set_new_value(new_value.into());
+                pb_diff_entry.set_key(key.into());
+                pb_diff_entry.set_changed(pb_changed);
+            }
+        }
+        pb_diff_entry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use engine_shared::stored_value::StoredValue;
+    use types::{CLValue, Key};
+
+    use super::*;
+
+    fn stored_value(value: i32) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    #[test]
+    fn should_map_changed_entry() {
+        let key = Key::Hash([1u8; 32]);
+        let diff_entry = DiffEntry::Changed {
+            key,
+            old_value: stored_value(1),
+            new_value: stored_value(2),
+        };
+
+        let mut pb_diff_entry = ipc::DiffEntry::from(diff_entry);
+        assert_eq!(Key::try_from(pb_diff_entry.take_key()).unwrap(), key);
+        assert!(pb_diff_entry.has_changed());
+        let mut pb_changed = pb_diff_entry.take_changed();
+        assert_eq!(
+            StoredValue::try_from(pb_changed.take_old_value()).unwrap(),
+            stored_value(1)
+        );
+        assert_eq!(
+            StoredValue::try_from(pb_changed.take_new_value()).unwrap(),
+            stored_value(2)
+        );
+    }
+
+    #[test]
+    fn should_map_added_and_removed_entries() {
+        let key = Key::Hash([2u8; 32]);
+
+        let mut pb_added = ipc::DiffEntry::from(DiffEntry::Added {
+            key,
+            value: stored_value(3),
+        });
+        assert_eq!(
+            StoredValue::try_from(pb_added.take_added()).unwrap(),
+            stored_value(3)
+        );
+
+        let mut pb_removed = ipc::DiffEntry::from(DiffEntry::Removed {
+            key,
+            value: stored_value(4),
+        });
+        assert_eq!(
+            StoredValue::try_from(pb_removed.take_removed()).unwrap(),
+            stored_value(4)
+        );
+    }
+}
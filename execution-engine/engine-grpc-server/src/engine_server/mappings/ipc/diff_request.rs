@@ -0,0 +1,51 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::diff::DiffRequest;
+use engine_shared::newtypes::Blake2bHash;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::DiffRequest> for DiffRequest {
+    type Error = MappingError;
+
+    fn try_from(pb_diff_request: ipc::DiffRequest) -> Result<Self, Self::Error> {
+        let old_state_hash: Blake2bHash = pb_diff_request
+            .get_old_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("old_state_hash".to_string()))?;
+
+        let new_state_hash: Blake2bHash = pb_diff_request
+            .get_new_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("new_state_hash".to_string()))?;
+
+        Ok(DiffRequest::new(old_state_hash, new_state_hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_diff_request() {
+        let old_state_hash = Blake2bHash::new(b"old");
+        let new_state_hash = Blake2bHash::new(b"new");
+
+        let mut pb_diff_request = ipc::DiffRequest::new();
+        pb_diff_request.set_old_state_hash(old_state_hash.to_vec());
+        pb_diff_request.set_new_state_hash(new_state_hash.to_vec());
+
+        let diff_request = DiffRequest::try_from(pb_diff_request).unwrap();
+        assert_eq!(diff_request.old_state_hash(), old_state_hash);
+        assert_eq!(diff_request.new_state_hash(), new_state_hash);
+    }
+
+    #[test]
+    fn should_fail_to_map_invalid_state_hash() {
+        let mut pb_diff_request = ipc::DiffRequest::new();
+        pb_diff_request.set_old_state_hash(Blake2bHash::new(b"old").to_vec());
+        pb_diff_request.set_new_state_hash(vec![1u8; 3]);
+        assert!(DiffRequest::try_from(pb_diff_request).is_err());
+    }
+}
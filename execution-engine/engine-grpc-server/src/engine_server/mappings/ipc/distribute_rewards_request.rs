@@ -0,0 +1,62 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::distribute_rewards::DistributeRewardsRequest;
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{
+    ipc::{self, DistributeRewardsRequest_ValidatorReward},
+    mappings::MappingError,
+};
+
+impl TryFrom<ipc::DistributeRewardsRequest> for DistributeRewardsRequest {
+    type Error = MappingError;
+
+    fn try_from(
+        mut pb_distribute_rewards_request: ipc::DistributeRewardsRequest,
+    ) -> Result<Self, Self::Error> {
+        let pre_state_hash = pb_distribute_rewards_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("pre_state_hash".to_string()))?;
+
+        let protocol_version = pb_distribute_rewards_request.take_protocol_version().into();
+
+        let rewards = pb_distribute_rewards_request
+            .take_rewards()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(DistributeRewardsRequest::new(
+            pre_state_hash,
+            protocol_version,
+            rewards,
+        ))
+    }
+}
+
+protobuf_conversions_for_validator_amount!(
+    DistributeRewardsRequest_ValidatorReward,
+    set_validator_id,
+    get_validator_id
+);
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            test_utils::protobuf_round_trip::<
+                (PublicKey, U512),
+                DistributeRewardsRequest_ValidatorReward,
+            >((public_key, u512));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::{
+    engine_state::{
+        estimate::{EstimateRequest, EstimateResult},
+        Error as EngineStateError,
+    },
+    execution::Error as ExecutionError,
+};
+use engine_shared::newtypes::Blake2bHash;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::EstimateRequest> for EstimateRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_estimate_request: ipc::EstimateRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash: Blake2bHash = pb_estimate_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("parent_state_hash".to_string()))?;
+
+        let block_time = pb_estimate_request.get_block_time();
+
+        let deploy_item = pb_estimate_request.take_deploy().try_into()?;
+
+        let protocol_version = pb_estimate_request.take_protocol_version().into();
+
+        Ok(EstimateRequest::new(
+            parent_state_hash,
+            block_time,
+            deploy_item,
+            protocol_version,
+        ))
+    }
+}
+
+impl From<EstimateResult> for ipc::EstimateResult {
+    fn from(estimate_result: EstimateResult) -> Self {
+        let mut pb_estimate_result = ipc::EstimateResult::new();
+        if let Some(EngineStateError::Exec(ExecutionError::Revert(api_error))) =
+            estimate_result.execution_result.as_error()
+        {
+            pb_estimate_result
+                .mut_api_error()
+                .set_code(u32::from(*api_error));
+        }
+        if let Some(return_value) = estimate_result.return_value {
+            pb_estimate_result.set_return_value(return_value.into());
+        }
+        pb_estimate_result.set_deploy_result(estimate_result.execution_result.into());
+        pb_estimate_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use engine_core::engine_state::execution_result::ExecutionResult;
+    use engine_shared::gas::Gas;
+    use types::{ApiError, CLValue, U512};
+
+    use super::*;
+
+    #[test]
+    fn should_map_successful_estimate_result() {
+        let cost = Gas::new(U512::from(123));
+        let return_value = CLValue::from_t(42u64).unwrap();
+        let estimate_result = EstimateResult {
+            execution_result: ExecutionResult::Success {
+                effect: Default::default(),
+                events: Vec::new(),
+                cost,
+            },
+            return_value: Some(return_value.clone()),
+        };
+
+        let mut pb_estimate_result = ipc::EstimateResult::from(estimate_result);
+        assert!(!pb_estimate_result.has_api_error());
+        assert_eq!(
+            CLValue::try_from(pb_estimate_result.take_return_value()).unwrap(),
+            return_value
+        );
+        let mut pb_execution_result = pb_estimate_result
+            .take_deploy_result()
+            .take_execution_result();
+        assert!(!pb_execution_result.has_error());
+        let pb_cost: U512 = pb_execution_result.take_cost().try_into().unwrap();
+        assert_eq!(pb_cost, cost.value());
+    }
+
+    #[test]
+    fn should_map_reverted_estimate_result() {
+        let api_error = ApiError::User(7);
+        let estimate_result = EstimateResult {
+            execution_result: ExecutionResult::Failure {
+                error: EngineStateError::Exec(ExecutionError::Revert(api_error)),
+                effect: Default::default(),
+                events: Vec::new(),
+                cost: Gas::new(U512::from(5)),
+            },
+            return_value: None,
+        };
+
+        let mut pb_estimate_result = ipc::EstimateResult::from(estimate_result);
+        assert_eq!(
+            pb_estimate_result.get_api_error().get_code(),
+            u32::from(api_error)
+        );
+        assert!(!pb_estimate_result.has_return_value());
+        assert!(pb_estimate_result
+            .take_deploy_result()
+            .get_execution_result()
+            .has_error());
+    }
+
+    #[test]
+    fn should_fail_to_map_invalid_parent_state_hash() {
+        let mut pb_estimate_request = ipc::EstimateRequest::new();
+        pb_estimate_request.set_parent_state_hash(vec![1u8; 3]);
+        assert!(EstimateRequest::try_from(pb_estimate_request).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use std::convert::{TryFrom, TryInto};
 
-use engine_core::engine_state::genesis::{ExecConfig, GenesisAccount};
+use engine_core::engine_state::genesis::{ExecConfig, GenesisAccount, QueueDelays, StakeLimits};
+use engine_shared::payment_costs::PaymentCosts;
+use engine_wasm_prep::host_function_costs::HostFunctionCosts;
 
 use crate::engine_server::{ipc, mappings::MappingError};
 
@@ -15,7 +17,35 @@ impl TryFrom<ipc::ChainSpec_GenesisConfig_ExecConfig> for ExecConfig {
             .into_iter()
             .map(TryInto::try_into)
             .collect::<Result<Vec<GenesisAccount>, Self::Error>>()?;
-        let wasm_costs = pb_exec_config.take_costs().take_wasm().into();
+        let (wasm_costs, host_function_costs, payment_costs) = {
+            let mut costs = pb_exec_config.take_costs();
+            let host_function_costs = if !costs.has_host_functions() {
+                HostFunctionCosts::default()
+            } else {
+                costs.take_host_functions().into()
+            };
+            let payment_costs = if !costs.has_payment() {
+                PaymentCosts::default()
+            } else {
+                costs.take_payment().try_into()?
+            };
+            (
+                costs.take_wasm().into(),
+                host_function_costs,
+                payment_costs,
+            )
+        };
+        let queue_delays = if !pb_exec_config.has_queue_delays() {
+            QueueDelays::default()
+        } else {
+            pb_exec_config.take_queue_delays().into()
+        };
+        let stake_limits = if !pb_exec_config.has_stake_limits() {
+            StakeLimits::default()
+        } else {
+            pb_exec_config.take_stake_limits().try_into()?
+        };
+        let reward_rate = pb_exec_config.get_reward_rate();
         let mint_initializer_bytes = pb_exec_config.take_mint_installer();
         let proof_of_stake_initializer_bytes = pb_exec_config.take_pos_installer();
         let standard_payment_installer_bytes = pb_exec_config.take_standard_payment_installer();
@@ -25,6 +55,11 @@ impl TryFrom<ipc::ChainSpec_GenesisConfig_ExecConfig> for ExecConfig {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            queue_delays,
+            stake_limits,
+            reward_rate,
         ))
     }
 }
@@ -50,6 +85,15 @@ impl From<ExecConfig> for ipc::ChainSpec_GenesisConfig_ExecConfig {
             .mut_costs()
             .set_wasm(exec_config.wasm_costs().into());
         pb_exec_config
+            .mut_costs()
+            .set_host_functions(exec_config.host_function_costs().into());
+        pb_exec_config
+            .mut_costs()
+            .set_payment(exec_config.payment_costs().into());
+        pb_exec_config.set_queue_delays(exec_config.queue_delays().into());
+        pb_exec_config.set_stake_limits(exec_config.stake_limits().into());
+        pb_exec_config.set_reward_rate(exec_config.reward_rate());
+        pb_exec_config
     }
 }
 
@@ -0,0 +1,66 @@
+use engine_core::engine_state::execution_event::ExecutionEvent;
+use types::Phase;
+
+use crate::engine_server::ipc::{self, ExecutionEvent_Phase};
+
+impl From<Phase> for ExecutionEvent_Phase {
+    fn from(phase: Phase) -> Self {
+        match phase {
+            Phase::System => ExecutionEvent_Phase::SYSTEM,
+            Phase::Payment => ExecutionEvent_Phase::PAYMENT,
+            Phase::Session => ExecutionEvent_Phase::SESSION,
+            Phase::FinalizePayment => ExecutionEvent_Phase::FINALIZE_PAYMENT,
+        }
+    }
+}
+
+impl From<ExecutionEvent> for ipc::ExecutionEvent {
+    fn from(event: ExecutionEvent) -> Self {
+        let mut pb_event = ipc::ExecutionEvent::new();
+        if let Some(contract_hash) = event.contract_hash() {
+            pb_event.set_contract_hash(contract_hash.to_vec());
+        }
+        pb_event.set_phase(event.phase().into());
+        pb_event.set_name(event.name().to_string());
+        pb_event.set_value(event.value().clone().into());
+        pb_event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use types::CLValue;
+
+    use super::*;
+
+    #[test]
+    fn should_map_execution_event() {
+        let value = CLValue::from_t(String::from("transferred")).unwrap();
+        let event = ExecutionEvent::new(
+            Some([7; 32]),
+            Phase::Session,
+            "transfer".to_string(),
+            value.clone(),
+        );
+        let mut pb_event = ipc::ExecutionEvent::from(event);
+        assert_eq!(pb_event.get_contract_hash(), &[7; 32]);
+        assert_eq!(pb_event.get_phase(), ExecutionEvent_Phase::SESSION);
+        assert_eq!(pb_event.get_name(), "transfer");
+        assert_eq!(CLValue::try_from(pb_event.take_value()).unwrap(), value);
+    }
+
+    #[test]
+    fn should_leave_contract_hash_empty_for_session_code() {
+        let event = ExecutionEvent::new(
+            None,
+            Phase::Payment,
+            "paid".to_string(),
+            CLValue::from_t(()).unwrap(),
+        );
+        let pb_event = ipc::ExecutionEvent::from(event);
+        assert!(pb_event.get_contract_hash().is_empty());
+        assert_eq!(pb_event.get_phase(), ExecutionEvent_Phase::PAYMENT);
+    }
+}
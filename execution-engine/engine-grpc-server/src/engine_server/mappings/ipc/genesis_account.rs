@@ -28,7 +28,7 @@ impl TryFrom<ChainSpec_GenesisConfig_ExecConfig_GenesisAccount> for GenesisAccou
     ) -> Result<Self, Self::Error> {
         // TODO: our TryFromSliceForPublicKeyError should convey length info
         let public_key =
-            PublicKey::ed25519_try_from(pb_genesis_account.get_public_key()).map_err(|_| {
+            PublicKey::try_from(pb_genesis_account.get_public_key()).map_err(|_| {
                 MappingError::invalid_public_key_length(pb_genesis_account.public_key.len())
             })?;
         let balance = pb_genesis_account
@@ -0,0 +1,167 @@
+use engine_wasm_prep::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
+use crate::engine_server::ipc::{
+    ChainSpec_CostTable_HostFunctionCost, ChainSpec_CostTable_HostFunctionCosts,
+};
+
+impl From<HostFunctionCost> for ChainSpec_CostTable_HostFunctionCost {
+    fn from(cost: HostFunctionCost) -> Self {
+        ChainSpec_CostTable_HostFunctionCost {
+            base: cost.base,
+            per_byte: cost.per_byte,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ChainSpec_CostTable_HostFunctionCost> for HostFunctionCost {
+    fn from(pb_cost: ChainSpec_CostTable_HostFunctionCost) -> Self {
+        HostFunctionCost {
+            base: pb_cost.base,
+            per_byte: pb_cost.per_byte,
+        }
+    }
+}
+
+impl From<HostFunctionCosts> for ChainSpec_CostTable_HostFunctionCosts {
+    fn from(costs: HostFunctionCosts) -> Self {
+        let mut pb_costs = ChainSpec_CostTable_HostFunctionCosts::new();
+        pb_costs.set_read_value(costs.read_value.into());
+        pb_costs.set_read_value_local(costs.read_value_local.into());
+        pb_costs.set_write(costs.write.into());
+        pb_costs.set_write_local(costs.write_local.into());
+        pb_costs.set_add(costs.add.into());
+        pb_costs.set_new_uref(costs.new_uref.into());
+        pb_costs.set_load_named_keys(costs.load_named_keys.into());
+        pb_costs.set_ret(costs.ret.into());
+        pb_costs.set_get_key(costs.get_key.into());
+        pb_costs.set_has_key(costs.has_key.into());
+        pb_costs.set_put_key(costs.put_key.into());
+        pb_costs.set_remove_key(costs.remove_key.into());
+        pb_costs.set_revert(costs.revert.into());
+        pb_costs.set_is_valid_uref(costs.is_valid_uref.into());
+        pb_costs.set_add_associated_key(costs.add_associated_key.into());
+        pb_costs.set_remove_associated_key(costs.remove_associated_key.into());
+        pb_costs.set_update_associated_key(costs.update_associated_key.into());
+        pb_costs.set_set_action_threshold(costs.set_action_threshold.into());
+        pb_costs.set_get_caller(costs.get_caller.into());
+        pb_costs.set_get_blocktime(costs.get_blocktime.into());
+        pb_costs.set_create_purse(costs.create_purse.into());
+        pb_costs.set_transfer_to_account(costs.transfer_to_account.into());
+        pb_costs.set_transfer_from_purse_to_account(costs.transfer_from_purse_to_account.into());
+        pb_costs.set_transfer_from_purse_to_purse(costs.transfer_from_purse_to_purse.into());
+        pb_costs.set_get_balance(costs.get_balance.into());
+        pb_costs.set_get_phase(costs.get_phase.into());
+        pb_costs.set_get_system_contract(costs.get_system_contract.into());
+        pb_costs.set_get_main_purse(costs.get_main_purse.into());
+        pb_costs.set_read_host_buffer(costs.read_host_buffer.into());
+        pb_costs.set_create_contract_package_at_hash(costs.create_contract_package_at_hash.into());
+        pb_costs.set_create_contract_user_group(costs.create_contract_user_group.into());
+        pb_costs.set_add_contract_version(costs.add_contract_version.into());
+        pb_costs.set_disable_contract_version(costs.disable_contract_version.into());
+        pb_costs.set_call_contract(costs.call_contract.into());
+        pb_costs.set_call_versioned_contract(costs.call_versioned_contract.into());
+        pb_costs.set_get_named_arg_size(costs.get_named_arg_size.into());
+        pb_costs.set_get_named_arg(costs.get_named_arg.into());
+        pb_costs.set_remove_contract_user_group(costs.remove_contract_user_group.into());
+        pb_costs.set_provision_contract_user_group_uref(
+            costs.provision_contract_user_group_uref.into(),
+        );
+        pb_costs
+            .set_remove_contract_user_group_urefs(costs.remove_contract_user_group_urefs.into());
+        pb_costs.set_emit_event(costs.emit_event.into());
+        pb_costs.set_list_named_keys_page(costs.list_named_keys_page.into());
+        pb_costs.set_list_local_keys(costs.list_local_keys.into());
+        pb_costs.set_blake2b256(costs.blake2b256.into());
+        pb_costs.set_sha256(costs.sha256.into());
+        pb_costs.set_keccak256(costs.keccak256.into());
+        pb_costs.set_verify_ed25519(costs.verify_ed25519.into());
+        pb_costs.set_recover_secp256k1(costs.recover_secp256k1.into());
+        pb_costs.set_random_bytes(costs.random_bytes.into());
+        pb_costs.set_read_many(costs.read_many.into());
+        pb_costs.set_write_many(costs.write_many.into());
+        pb_costs
+    }
+}
+
+impl From<ChainSpec_CostTable_HostFunctionCosts> for HostFunctionCosts {
+    fn from(mut pb_costs: ChainSpec_CostTable_HostFunctionCosts) -> Self {
+        HostFunctionCosts {
+            read_value: pb_costs.take_read_value().into(),
+            read_value_local: pb_costs.take_read_value_local().into(),
+            write: pb_costs.take_write().into(),
+            write_local: pb_costs.take_write_local().into(),
+            add: pb_costs.take_add().into(),
+            new_uref: pb_costs.take_new_uref().into(),
+            load_named_keys: pb_costs.take_load_named_keys().into(),
+            ret: pb_costs.take_ret().into(),
+            get_key: pb_costs.take_get_key().into(),
+            has_key: pb_costs.take_has_key().into(),
+            put_key: pb_costs.take_put_key().into(),
+            remove_key: pb_costs.take_remove_key().into(),
+            revert: pb_costs.take_revert().into(),
+            is_valid_uref: pb_costs.take_is_valid_uref().into(),
+            add_associated_key: pb_costs.take_add_associated_key().into(),
+            remove_associated_key: pb_costs.take_remove_associated_key().into(),
+            update_associated_key: pb_costs.take_update_associated_key().into(),
+            set_action_threshold: pb_costs.take_set_action_threshold().into(),
+            get_caller: pb_costs.take_get_caller().into(),
+            get_blocktime: pb_costs.take_get_blocktime().into(),
+            create_purse: pb_costs.take_create_purse().into(),
+            transfer_to_account: pb_costs.take_transfer_to_account().into(),
+            transfer_from_purse_to_account: pb_costs.take_transfer_from_purse_to_account().into(),
+            transfer_from_purse_to_purse: pb_costs.take_transfer_from_purse_to_purse().into(),
+            get_balance: pb_costs.take_get_balance().into(),
+            get_phase: pb_costs.take_get_phase().into(),
+            get_system_contract: pb_costs.take_get_system_contract().into(),
+            get_main_purse: pb_costs.take_get_main_purse().into(),
+            read_host_buffer: pb_costs.take_read_host_buffer().into(),
+            create_contract_package_at_hash: pb_costs.take_create_contract_package_at_hash().into(),
+            create_contract_user_group: pb_costs.take_create_contract_user_group().into(),
+            add_contract_version: pb_costs.take_add_contract_version().into(),
+            disable_contract_version: pb_costs.take_disable_contract_version().into(),
+            call_contract: pb_costs.take_call_contract().into(),
+            call_versioned_contract: pb_costs.take_call_versioned_contract().into(),
+            get_named_arg_size: pb_costs.take_get_named_arg_size().into(),
+            get_named_arg: pb_costs.take_get_named_arg().into(),
+            remove_contract_user_group: pb_costs.take_remove_contract_user_group().into(),
+            provision_contract_user_group_uref: pb_costs
+                .take_provision_contract_user_group_uref()
+                .into(),
+            remove_contract_user_group_urefs: pb_costs
+                .take_remove_contract_user_group_urefs()
+                .into(),
+            emit_event: pb_costs.take_emit_event().into(),
+            list_named_keys_page: pb_costs.take_list_named_keys_page().into(),
+            list_local_keys: pb_costs.take_list_local_keys().into(),
+            blake2b256: pb_costs.take_blake2b256().into(),
+            sha256: pb_costs.take_sha256().into(),
+            keccak256: pb_costs.take_keccak256().into(),
+            verify_ed25519: pb_costs.take_verify_ed25519().into(),
+            recover_secp256k1: pb_costs.take_recover_secp256k1().into(),
+            random_bytes: pb_costs.take_random_bytes().into(),
+            read_many: pb_costs.take_read_many().into(),
+            write_many: pb_costs.take_write_many().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use engine_wasm_prep::host_function_costs::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(host_function_costs in gens::host_function_costs_arb()) {
+            test_utils::protobuf_round_trip::<
+                HostFunctionCosts,
+                ChainSpec_CostTable_HostFunctionCosts,
+            >(host_function_costs);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::query::{ListKeysRequest, DEFAULT_LIST_KEYS_LIMIT};
+use engine_shared::newtypes::Blake2bHash;
+use types::KeyTag;
+
+use crate::engine_server::{
+    ipc::{self, ListKeysRequest_KeyTag},
+    mappings::MappingError,
+};
+
+impl From<ListKeysRequest_KeyTag> for KeyTag {
+    fn from(pb_key_tag: ListKeysRequest_KeyTag) -> Self {
+        match pb_key_tag {
+            ListKeysRequest_KeyTag::ACCOUNT => KeyTag::Account,
+            ListKeysRequest_KeyTag::HASH => KeyTag::Hash,
+            ListKeysRequest_KeyTag::UREF => KeyTag::URef,
+        }
+    }
+}
+
+impl TryFrom<ipc::ListKeysRequest> for ListKeysRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_list_keys_request: ipc::ListKeysRequest) -> Result<Self, Self::Error> {
+        let state_hash: Blake2bHash = pb_list_keys_request
+            .get_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("state_hash".to_string()))?;
+
+        let key_tag = pb_list_keys_request.get_key_tag().into();
+
+        let prefix = pb_list_keys_request.take_prefix();
+
+        let cursor = Some(pb_list_keys_request.take_cursor()).filter(|cursor| !cursor.is_empty());
+
+        let limit = match pb_list_keys_request.get_limit() {
+            0 => DEFAULT_LIST_KEYS_LIMIT,
+            limit => limit as usize,
+        };
+
+        let include_values = pb_list_keys_request.get_include_values();
+
+        Ok(ListKeysRequest::new(
+            state_hash,
+            key_tag,
+            prefix,
+            cursor,
+            limit,
+            include_values,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_list_keys_request() {
+        let state_hash = Blake2bHash::new(b"state");
+
+        let mut pb_list_keys_request = ipc::ListKeysRequest::new();
+        pb_list_keys_request.set_state_hash(state_hash.to_vec());
+        pb_list_keys_request.set_key_tag(ListKeysRequest_KeyTag::UREF);
+        pb_list_keys_request.set_prefix(vec![1, 2]);
+        pb_list_keys_request.set_cursor(vec![2, 1, 2, 3]);
+        pb_list_keys_request.set_limit(10);
+        pb_list_keys_request.set_include_values(true);
+
+        let list_keys_request = ListKeysRequest::try_from(pb_list_keys_request).unwrap();
+        assert_eq!(list_keys_request.state_hash(), state_hash);
+        assert_eq!(list_keys_request.key_tag(), KeyTag::URef);
+        assert_eq!(list_keys_request.prefix(), &[1, 2]);
+        assert_eq!(list_keys_request.cursor(), Some(&[2u8, 1, 2, 3][..]));
+        assert_eq!(list_keys_request.limit(), 10);
+        assert!(list_keys_request.include_values());
+    }
+
+    #[test]
+    fn should_map_defaults_of_list_keys_request() {
+        let mut pb_list_keys_request = ipc::ListKeysRequest::new();
+        pb_list_keys_request.set_state_hash(Blake2bHash::new(b"state").to_vec());
+
+        let list_keys_request = ListKeysRequest::try_from(pb_list_keys_request).unwrap();
+        assert_eq!(list_keys_request.key_tag(), KeyTag::Account);
+        assert!(list_keys_request.prefix().is_empty());
+        assert_eq!(list_keys_request.cursor(), None);
+        assert_eq!(list_keys_request.limit(), DEFAULT_LIST_KEYS_LIMIT);
+        assert!(!list_keys_request.include_values());
+    }
+
+    #[test]
+    fn should_fail_to_map_invalid_state_hash() {
+        let mut pb_list_keys_request = ipc::ListKeysRequest::new();
+        pb_list_keys_request.set_state_hash(vec![1u8; 3]);
+        assert!(ListKeysRequest::try_from(pb_list_keys_request).is_err());
+    }
+}
@@ -1,16 +1,63 @@
 //! Functions for converting between CasperLabs types and their Protobuf equivalents which are
 //! defined in protobuf/io/casperlabs/ipc/ipc.proto
 
+/// Implements the conversions between `(PublicKey, U512)` and a Protobuf message holding a
+/// validator's public key in the given bytes field and an amount in its `value` field.
+macro_rules! protobuf_conversions_for_validator_amount {
+    ($type:ty, $set_id:ident, $get_id:ident) => {
+        impl From<(PublicKey, U512)> for $type {
+            fn from((key, amount): (PublicKey, U512)) -> Self {
+                let mut pb_validator_amount = <$type>::new();
+                pb_validator_amount.$set_id(key.as_bytes().to_vec());
+                pb_validator_amount.set_value(amount.into());
+                pb_validator_amount
+            }
+        }
+
+        impl TryFrom<$type> for (PublicKey, U512) {
+            type Error = MappingError;
+
+            fn try_from(mut pb_validator_amount: $type) -> Result<Self, Self::Error> {
+                let public_key =
+                    PublicKey::try_from(pb_validator_amount.$get_id()).map_err(|_| {
+                        MappingError::invalid_public_key_length(pb_validator_amount.$get_id().len())
+                    })?;
+
+                let value = pb_validator_amount.take_value().try_into()?;
+
+                Ok((public_key, value))
+            }
+        }
+    };
+}
+
+mod bid_state;
 mod bond;
 mod deploy_item;
 mod deploy_result;
+mod diff_entry;
+mod diff_request;
+mod distribute_rewards_request;
+mod estimate;
 mod exec_config;
 mod executable_deploy_item;
 mod execute_request;
 mod execution_effect;
+mod execution_event;
 mod genesis_account;
 mod genesis_config;
+mod host_function_costs;
+mod list_keys_request;
+mod payment_costs;
+mod prune_request;
 mod query_request;
+mod query_with_proof_request;
+mod queue_delays;
 mod run_genesis_request;
+mod seigniorage_request;
+mod slash_request;
+mod stake_limits;
+mod step_request;
+mod unbond_payout_request;
 mod upgrade_request;
 mod wasm_costs;
@@ -0,0 +1,62 @@
+use std::convert::TryFrom;
+
+use engine_shared::payment_costs::PaymentCosts;
+
+use crate::engine_server::{ipc::ChainSpec_CostTable_PaymentCosts, mappings::MappingError};
+
+impl From<PaymentCosts> for ChainSpec_CostTable_PaymentCosts {
+    fn from(payment_costs: PaymentCosts) -> Self {
+        ChainSpec_CostTable_PaymentCosts {
+            conv_rate: payment_costs.conv_rate,
+            max_payment_cost: payment_costs.max_payment_cost,
+            standard_payment_cap: payment_costs.standard_payment_cap,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<ChainSpec_CostTable_PaymentCosts> for PaymentCosts {
+    type Error = MappingError;
+
+    fn try_from(pb_payment_costs: ChainSpec_CostTable_PaymentCosts) -> Result<Self, Self::Error> {
+        if pb_payment_costs.conv_rate == 0 {
+            return Err(MappingError::ZeroConvRate);
+        }
+        Ok(PaymentCosts {
+            conv_rate: pb_payment_costs.conv_rate,
+            max_payment_cost: pb_payment_costs.max_payment_cost,
+            standard_payment_cap: pb_payment_costs.standard_payment_cap,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use engine_shared::payment_costs::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(payment_costs in gens::payment_costs_arb()) {
+            test_utils::protobuf_round_trip::<PaymentCosts, ChainSpec_CostTable_PaymentCosts>(
+                payment_costs
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_zero_conv_rate() {
+        let pb_payment_costs = ChainSpec_CostTable_PaymentCosts::from(PaymentCosts {
+            conv_rate: 0,
+            ..Default::default()
+        });
+        match PaymentCosts::try_from(pb_payment_costs) {
+            Err(MappingError::ZeroConvRate) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::prune::{PruneRequest, DEFAULT_PRUNE_BATCH_SIZE};
+use engine_shared::newtypes::Blake2bHash;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::PruneRequest> for PruneRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_prune_request: ipc::PruneRequest) -> Result<Self, Self::Error> {
+        let roots_to_keep = pb_prune_request
+            .take_roots_to_keep()
+            .into_iter()
+            .map(|root| {
+                root.as_slice()
+                    .try_into()
+                    .map_err(|_| MappingError::InvalidStateHash("roots_to_keep".to_string()))
+            })
+            .collect::<Result<Vec<Blake2bHash>, Self::Error>>()?;
+
+        let batch_size = match pb_prune_request.get_batch_size() {
+            0 => DEFAULT_PRUNE_BATCH_SIZE,
+            batch_size => batch_size as usize,
+        };
+
+        Ok(PruneRequest::new(roots_to_keep, batch_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_prune_request() {
+        let root_1 = Blake2bHash::new(b"root_1");
+        let root_2 = Blake2bHash::new(b"root_2");
+
+        let mut pb_prune_request = ipc::PruneRequest::new();
+        pb_prune_request.set_roots_to_keep(vec![root_1.to_vec(), root_2.to_vec()].into());
+        pb_prune_request.set_batch_size(7);
+
+        let prune_request = PruneRequest::try_from(pb_prune_request).unwrap();
+        assert_eq!(prune_request.roots_to_keep(), &[root_1, root_2]);
+        assert_eq!(prune_request.batch_size(), 7);
+    }
+
+    #[test]
+    fn should_use_default_batch_size_if_unset() {
+        let prune_request = PruneRequest::try_from(ipc::PruneRequest::new()).unwrap();
+        assert!(prune_request.roots_to_keep().is_empty());
+        assert_eq!(prune_request.batch_size(), DEFAULT_PRUNE_BATCH_SIZE);
+    }
+
+    #[test]
+    fn should_fail_to_map_invalid_root() {
+        let mut pb_prune_request = ipc::PruneRequest::new();
+        pb_prune_request.set_roots_to_keep(vec![vec![1u8; 3]].into());
+        assert!(PruneRequest::try_from(pb_prune_request).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::query::QueryWithProofRequest;
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::QueryWithProofRequest> for QueryWithProofRequest {
+    type Error = MappingError;
+
+    fn try_from(mut query_request: ipc::QueryWithProofRequest) -> Result<Self, Self::Error> {
+        let state_hash = {
+            let state_hash = query_request.get_state_hash();
+            let length = state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                return Err(MappingError::InvalidStateHashLength {
+                    expected: BLAKE2B_DIGEST_LENGTH,
+                    actual: length,
+                });
+            }
+            state_hash
+                .try_into()
+                .map_err(|_| MappingError::TryFromSlice)?
+        };
+
+        let key = query_request
+            .take_base_key()
+            .try_into()
+            .map_err(MappingError::Parsing)?;
+
+        Ok(QueryWithProofRequest::new(state_hash, key))
+    }
+}
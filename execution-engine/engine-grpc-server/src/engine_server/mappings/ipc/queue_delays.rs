@@ -0,0 +1,38 @@
+use engine_core::engine_state::genesis::QueueDelays;
+
+use crate::engine_server::ipc::ChainSpec_QueueDelays;
+
+impl From<QueueDelays> for ChainSpec_QueueDelays {
+    fn from(queue_delays: QueueDelays) -> Self {
+        ChainSpec_QueueDelays {
+            bond_delay: queue_delays.bond_delay,
+            unbond_delay: queue_delays.unbond_delay,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ChainSpec_QueueDelays> for QueueDelays {
+    fn from(pb_queue_delays: ChainSpec_QueueDelays) -> Self {
+        QueueDelays {
+            bond_delay: pb_queue_delays.bond_delay,
+            unbond_delay: pb_queue_delays.unbond_delay,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(bond_delay: u64, unbond_delay: u64) {
+            let queue_delays = QueueDelays { bond_delay, unbond_delay };
+            test_utils::protobuf_round_trip::<QueueDelays, ChainSpec_QueueDelays>(queue_delays);
+        }
+    }
+}
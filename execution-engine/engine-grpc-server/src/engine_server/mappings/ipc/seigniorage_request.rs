@@ -0,0 +1,27 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::seigniorage::DistributeSeigniorageRequest;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::DistributeSeigniorageRequest> for DistributeSeigniorageRequest {
+    type Error = MappingError;
+
+    fn try_from(
+        mut pb_distribute_seigniorage_request: ipc::DistributeSeigniorageRequest,
+    ) -> Result<Self, Self::Error> {
+        let pre_state_hash = pb_distribute_seigniorage_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("pre_state_hash".to_string()))?;
+
+        let protocol_version = pb_distribute_seigniorage_request
+            .take_protocol_version()
+            .into();
+
+        Ok(DistributeSeigniorageRequest::new(
+            pre_state_hash,
+            protocol_version,
+        ))
+    }
+}
@@ -0,0 +1,56 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::slash::SlashRequest;
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{
+    ipc::{self, SlashRequest_ValidatorSlash},
+    mappings::MappingError,
+};
+
+impl TryFrom<ipc::SlashRequest> for SlashRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_slash_request: ipc::SlashRequest) -> Result<Self, Self::Error> {
+        let pre_state_hash = pb_slash_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("pre_state_hash".to_string()))?;
+
+        let protocol_version = pb_slash_request.take_protocol_version().into();
+
+        let slashes = pb_slash_request
+            .take_slashes()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(SlashRequest::new(pre_state_hash, protocol_version, slashes))
+    }
+}
+
+protobuf_conversions_for_validator_amount!(
+    SlashRequest_ValidatorSlash,
+    set_validator_id,
+    get_validator_id
+);
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            test_utils::protobuf_round_trip::<
+                (PublicKey, U512),
+                SlashRequest_ValidatorSlash,
+            >((public_key, u512));
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::genesis::StakeLimits;
+
+use crate::engine_server::{ipc::ChainSpec_StakeLimits, mappings::MappingError};
+
+impl From<StakeLimits> for ChainSpec_StakeLimits {
+    fn from(stake_limits: StakeLimits) -> Self {
+        let mut pb_stake_limits = ChainSpec_StakeLimits::new();
+        pb_stake_limits.set_min_bond(stake_limits.min_bond.into());
+        pb_stake_limits.set_max_validators(stake_limits.max_validators);
+        pb_stake_limits.set_max_spread(stake_limits.max_spread.into());
+        pb_stake_limits.set_max_increase(stake_limits.max_increase.into());
+        pb_stake_limits.set_max_decrease(stake_limits.max_decrease.into());
+        pb_stake_limits.set_max_rel_increase(stake_limits.max_rel_increase);
+        pb_stake_limits.set_max_rel_decrease(stake_limits.max_rel_decrease);
+        pb_stake_limits
+    }
+}
+
+impl TryFrom<ChainSpec_StakeLimits> for StakeLimits {
+    type Error = MappingError;
+
+    fn try_from(mut pb_stake_limits: ChainSpec_StakeLimits) -> Result<Self, Self::Error> {
+        Ok(StakeLimits {
+            min_bond: pb_stake_limits.take_min_bond().try_into()?,
+            max_validators: pb_stake_limits.get_max_validators(),
+            max_spread: pb_stake_limits.take_max_spread().try_into()?,
+            max_increase: pb_stake_limits.take_max_increase().try_into()?,
+            max_decrease: pb_stake_limits.take_max_decrease().try_into()?,
+            max_rel_increase: pb_stake_limits.get_max_rel_increase(),
+            max_rel_decrease: pb_stake_limits.get_max_rel_decrease(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            min_bond in gens::u512_arb(),
+            max_validators: u32,
+            max_spread in gens::u512_arb(),
+            max_increase in gens::u512_arb(),
+            max_decrease in gens::u512_arb(),
+            max_rel_increase: u64,
+            max_rel_decrease: u64,
+        ) {
+            let stake_limits = StakeLimits {
+                min_bond,
+                max_validators,
+                max_spread,
+                max_increase,
+                max_decrease,
+                max_rel_increase,
+                max_rel_decrease,
+            };
+            test_utils::protobuf_round_trip::<StakeLimits, ChainSpec_StakeLimits>(stake_limits);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::step::StepRequest;
+use types::BlockTime;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::StepRequest> for StepRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_step_request: ipc::StepRequest) -> Result<Self, Self::Error> {
+        let pre_state_hash = pb_step_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("pre_state_hash".to_string()))?;
+
+        let protocol_version = pb_step_request.take_protocol_version().into();
+
+        let block_time = BlockTime::new(pb_step_request.get_block_time());
+
+        Ok(StepRequest::new(
+            pre_state_hash,
+            protocol_version,
+            block_time,
+        ))
+    }
+}
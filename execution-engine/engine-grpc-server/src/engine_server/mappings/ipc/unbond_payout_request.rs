@@ -0,0 +1,32 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::unbond_payout::UnbondPayoutRequest;
+use types::BlockTime;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::UnbondPayoutRequest> for UnbondPayoutRequest {
+    type Error = MappingError;
+
+    fn try_from(
+        mut pb_unbond_payout_request: ipc::UnbondPayoutRequest,
+    ) -> Result<Self, Self::Error> {
+        let pre_state_hash = pb_unbond_payout_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("pre_state_hash".to_string()))?;
+
+        let protocol_version = pb_unbond_payout_request.take_protocol_version().into();
+
+        let era_height = pb_unbond_payout_request.get_era_height();
+
+        let block_time = BlockTime::new(pb_unbond_payout_request.get_block_time());
+
+        Ok(UnbondPayoutRequest::new(
+            pre_state_hash,
+            protocol_version,
+            era_height,
+            block_time,
+        ))
+    }
+}
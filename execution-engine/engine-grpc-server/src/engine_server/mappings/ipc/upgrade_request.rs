@@ -30,10 +30,46 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
                 (bytes, args)
             };
 
-        let wasm_costs = if !upgrade_point.has_new_costs() {
+        let (wasm_costs, host_function_costs, payment_costs) = if !upgrade_point.has_new_costs() {
+            (None, None, None)
+        } else {
+            let new_costs = upgrade_point.mut_new_costs();
+            let wasm_costs = if !new_costs.has_wasm() {
+                None
+            } else {
+                Some(new_costs.take_wasm().into())
+            };
+            let host_function_costs = if !new_costs.has_host_functions() {
+                None
+            } else {
+                Some(new_costs.take_host_functions().into())
+            };
+            let payment_costs = if !new_costs.has_payment() {
+                None
+            } else {
+                Some(new_costs.take_payment().try_into()?)
+            };
+            (wasm_costs, host_function_costs, payment_costs)
+        };
+        let queue_delays = if !upgrade_point.has_new_queue_delays() {
+            None
+        } else {
+            Some(upgrade_point.take_new_queue_delays().into())
+        };
+        let stake_limits = if !upgrade_point.has_new_stake_limits() {
+            None
+        } else {
+            Some(upgrade_point.take_new_stake_limits().try_into()?)
+        };
+        let reward_rate = if !upgrade_point.has_new_reward_rate() {
+            None
+        } else {
+            Some(upgrade_point.get_new_reward_rate().rate)
+        };
+        let initial_total_supply = if !upgrade_point.has_initial_total_supply() {
             None
         } else {
-            Some(upgrade_point.mut_new_costs().take_wasm().into())
+            Some(upgrade_point.take_initial_total_supply().try_into()?)
         };
         let activation_point = if !upgrade_point.has_activation_point() {
             None
@@ -48,6 +84,12 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            queue_delays,
+            stake_limits,
+            reward_rate,
+            initial_total_supply,
             activation_point,
         ))
     }
@@ -5,13 +5,16 @@ mod state;
 mod transforms;
 
 use std::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     fmt::{self, Display, Formatter},
     string::ToString,
 };
 
 use engine_core::{engine_state, DEPLOY_HASH_LENGTH};
-use types::{account::ED25519_LENGTH, KEY_HASH_LENGTH};
+use types::{
+    account::{PublicKey, ED25519_LENGTH, SECP256K1_LENGTH},
+    KEY_HASH_LENGTH,
+};
 
 pub use transforms::TransformMap;
 
@@ -23,22 +26,37 @@ pub(crate) fn vec_to_array(input: Vec<u8>, input_name: &str) -> Result<[u8; 32],
         .map_err(|_| format!("{} must be 32 bytes.", input_name).into())
 }
 
+/// Try to convert a byte slice to a [`PublicKey`], inferring the key type from its length.
+pub(crate) fn public_key_from_slice(
+    input: &[u8],
+    input_name: &str,
+) -> Result<PublicKey, ParsingError> {
+    PublicKey::try_from(input).map_err(|_| {
+        format!(
+            "{} must be {} or {} bytes.",
+            input_name, ED25519_LENGTH, SECP256K1_LENGTH
+        )
+        .into()
+    })
+}
+
 #[derive(Debug)]
 pub enum MappingError {
     InvalidStateHashLength { expected: usize, actual: usize },
-    InvalidPublicKeyLength { expected: usize, actual: usize },
+    InvalidPublicKeyLength { actual: usize },
     InvalidDeployHashLength { expected: usize, actual: usize },
     InvalidHashLength { expected: usize, actual: usize },
     Parsing(ParsingError),
     InvalidStateHash(String),
     MissingPayload,
     TryFromSlice,
+    TtlWithoutValidFrom { ttl: u64 },
+    ZeroConvRate,
 }
 
 impl MappingError {
     pub fn invalid_public_key_length(actual: usize) -> Self {
-        let expected = ED25519_LENGTH;
-        MappingError::InvalidPublicKeyLength { expected, actual }
+        MappingError::InvalidPublicKeyLength { actual }
     }
 
     pub fn invalid_deploy_hash_length(actual: usize) -> Self {
@@ -78,10 +96,10 @@ impl Display for MappingError {
                 "Invalid hash length: expected {}, actual {}",
                 expected, actual
             ),
-            MappingError::InvalidPublicKeyLength { expected, actual } => write!(
+            MappingError::InvalidPublicKeyLength { actual } => write!(
                 f,
-                "Invalid public key length: expected {}, actual {}",
-                expected, actual
+                "Invalid public key length: expected {} (Ed25519) or {} (Secp256k1), actual {}",
+                ED25519_LENGTH, SECP256K1_LENGTH, actual
             ),
             MappingError::InvalidDeployHashLength { expected, actual } => write!(
                 f,
@@ -92,6 +110,12 @@ impl Display for MappingError {
             MappingError::InvalidStateHash(message) => write!(f, "Invalid hash: {}", message),
             MappingError::MissingPayload => write!(f, "Missing payload"),
             MappingError::TryFromSlice => write!(f, "Unable to convert from slice"),
+            MappingError::TtlWithoutValidFrom { ttl } => {
+                write!(f, "Deploy has a ttl of {} but no valid_from", ttl)
+            }
+            MappingError::ZeroConvRate => {
+                write!(f, "Invalid payment costs: conv_rate must be non-zero")
+            }
             MappingError::InvalidHashLength { expected, actual } => write!(
                 f,
                 "Invalid hash length: expected {}, actual {}",
@@ -37,6 +37,8 @@ impl From<Account> for state::Account {
             pb_action_thresholds.set_key_management_threshold(key_management)
         }
 
+        pb_account.set_nonce(account.nonce());
+
         pb_account
     }
 }
@@ -46,7 +48,7 @@ impl TryFrom<state::Account> for Account {
 
     fn try_from(pb_account: state::Account) -> Result<Self, Self::Error> {
         let public_key =
-            mappings::vec_to_array(pb_account.public_key, "Protobuf Account::PublicKey")?;
+            mappings::public_key_from_slice(&pb_account.public_key, "Protobuf Account::PublicKey")?;
 
         let named_keys: NamedKeyMap = pb_account.named_keys.into_vec().try_into()?;
 
@@ -92,12 +94,13 @@ impl TryFrom<state::Account> for Account {
         };
 
         let account = Account::new(
-            PublicKey::ed25519_from(public_key),
+            public_key,
             named_keys.into_inner(),
             main_purse,
             associated_keys,
             action_thresholds,
-        );
+        )
+        .with_nonce(pb_account.nonce);
         Ok(account)
     }
 }
@@ -115,10 +118,10 @@ impl TryFrom<Account_AssociatedKey> for (PublicKey, Weight) {
     type Error = ParsingError;
 
     fn try_from(pb_associated_key: Account_AssociatedKey) -> Result<Self, Self::Error> {
-        let public_key = PublicKey::ed25519_from(mappings::vec_to_array(
-            pb_associated_key.public_key,
+        let public_key = mappings::public_key_from_slice(
+            &pb_associated_key.public_key,
             "Protobuf Account::AssociatedKey",
-        )?);
+        )?;
 
         let weight = weight_from(pb_associated_key.weight, "Protobuf AssociatedKey::Weight")?;
 
@@ -30,10 +30,22 @@ use grpc::{Error as GrpcError, RequestOptions, ServerBuilder, SingleResponse};
 use log::{info, warn, Level};
 
 use engine_core::engine_state::{
+    bid_state::{BidStateRequest, BidStateResult},
+    diff::{DiffRequest, DiffResult},
+    distribute_rewards::DistributeRewardsRequest,
+    estimate::EstimateRequest,
     execute_request::ExecuteRequest,
     genesis::GenesisResult,
-    query::{QueryRequest, QueryResult},
+    prune::{PruneRequest, PruneResult},
+    query::{
+        ListKeysRequest, ListKeysResult, QueryRequest, QueryResult, QueryWithProofRequest,
+        QueryWithProofResult,
+    },
     run_genesis_request::RunGenesisRequest,
+    seigniorage::DistributeSeigniorageRequest,
+    slash::SlashRequest,
+    step::StepRequest,
+    unbond_payout::UnbondPayoutRequest,
     upgrade::{UpgradeConfig, UpgradeResult},
     EngineState, Error as EngineError,
 };
@@ -46,9 +58,11 @@ use types::{bytesrepr::ToBytes, ProtocolVersion};
 
 use self::{
     ipc::{
-        BidStateRequest, BidStateResponse, CommitRequest, CommitResponse, DistributeRewardsRequest,
-        DistributeRewardsResponse, ExecuteResponse, GenesisResponse, QueryResponse, SlashRequest,
-        SlashResponse, UnbondPayoutRequest, UnbondPayoutResponse, UpgradeRequest, UpgradeResponse,
+        BidStateResponse, CommitRequest, CommitResponse, DeployConflict, DiffResponse,
+        DistributeRewardsResponse, DistributeSeigniorageResponse, EstimateResponse,
+        ExecuteResponse, GenesisResponse, ListKeysResponse, PruneResponse, QueryResponse,
+        QueryWithProofResponse, SlashResponse, StepResponse, UnbondPayoutResponse, UpgradeRequest,
+        UpgradeResponse,
     },
     ipc_grpc::{ExecutionEngineService, ExecutionEngineServiceServer},
     mappings::{ParsingError, TransformMap},
@@ -56,17 +70,37 @@ use self::{
 
 const METRIC_DURATION_COMMIT: &str = "commit_duration";
 const METRIC_DURATION_EXEC: &str = "exec_duration";
+const METRIC_DURATION_ESTIMATE: &str = "estimate_duration";
 const METRIC_DURATION_QUERY: &str = "query_duration";
+const METRIC_DURATION_QUERY_WITH_PROOF: &str = "query_with_proof_duration";
+const METRIC_DURATION_LIST_KEYS: &str = "list_keys_duration";
 const METRIC_DURATION_GENESIS: &str = "genesis_duration";
 const METRIC_DURATION_UPGRADE: &str = "upgrade_duration";
+const METRIC_DURATION_PRUNE: &str = "prune_duration";
+const METRIC_DURATION_DIFF: &str = "diff_duration";
+const METRIC_DURATION_BID_STATE: &str = "bid_state_duration";
+const METRIC_DURATION_DISTRIBUTE_REWARDS: &str = "distribute_rewards_duration";
+const METRIC_DURATION_SLASH: &str = "slash_duration";
+const METRIC_DURATION_UNBOND_PAYOUT: &str = "unbond_payout_duration";
+const METRIC_DURATION_STEP: &str = "step_duration";
+const METRIC_DURATION_DISTRIBUTE_SEIGNIORAGE: &str = "distribute_seigniorage_duration";
 
 const TAG_RESPONSE_COMMIT: &str = "commit_response";
 const TAG_RESPONSE_EXEC: &str = "exec_response";
+const TAG_RESPONSE_ESTIMATE: &str = "estimate_response";
 const TAG_RESPONSE_QUERY: &str = "query_response";
+const TAG_RESPONSE_QUERY_WITH_PROOF: &str = "query_with_proof_response";
+const TAG_RESPONSE_LIST_KEYS: &str = "list_keys_response";
 const TAG_RESPONSE_GENESIS: &str = "genesis_response";
 const TAG_RESPONSE_UPGRADE: &str = "upgrade_response";
-
-const UNIMPLEMENTED: &str = "unimplemented";
+const TAG_RESPONSE_PRUNE: &str = "prune_response";
+const TAG_RESPONSE_DIFF: &str = "diff_response";
+const TAG_RESPONSE_BID_STATE: &str = "bid_state_response";
+const TAG_RESPONSE_DISTRIBUTE_REWARDS: &str = "distribute_rewards_response";
+const TAG_RESPONSE_SLASH: &str = "slash_response";
+const TAG_RESPONSE_UNBOND_PAYOUT: &str = "unbond_payout_response";
+const TAG_RESPONSE_STEP: &str = "step_response";
+const TAG_RESPONSE_DISTRIBUTE_SEIGNIORAGE: &str = "distribute_seigniorage_response";
 
 const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 
@@ -77,7 +111,7 @@ const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 // (outer layer) leading to cleaner design.
 impl<S> ExecutionEngineService for EngineState<S>
 where
-    S: StateProvider,
+    S: StateProvider + Sync,
     EngineError: From<S::Error>,
     S::Error: Into<engine_core::execution::Error> + Debug,
 {
@@ -162,6 +196,154 @@ where
         SingleResponse::completed(response)
     }
 
+    fn query_with_proof(
+        &self,
+        _request_options: RequestOptions,
+        query_request: ipc::QueryWithProofRequest,
+    ) -> SingleResponse<QueryWithProofResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let request: QueryWithProofRequest = match query_request.try_into() {
+            Ok(ret) => ret,
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                warn!("{}", log_message);
+                let mut result = ipc::QueryWithProofResponse::new();
+                result.set_failure(log_message);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_QUERY_WITH_PROOF,
+                    TAG_RESPONSE_QUERY_WITH_PROOF,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(result);
+            }
+        };
+
+        let result = self.run_query_with_proof(correlation_id, request);
+
+        let response = match result {
+            Ok(QueryWithProofResult::Success(proof)) => {
+                let mut result = ipc::QueryWithProofResponse::new();
+                match proof.to_bytes() {
+                    Ok(serialized_proof) => {
+                        info!(
+                            "query with proof successful; correlation_id: {}",
+                            correlation_id
+                        );
+                        result.set_success(serialized_proof);
+                    }
+                    Err(error_msg) => {
+                        let log_message =
+                            format!("Failed to serialize TrieMerkleProof: {}", error_msg);
+                        warn!("{}", log_message);
+                        result.set_failure(log_message);
+                    }
+                }
+                result
+            }
+            Ok(QueryWithProofResult::ValueNotFound(msg)) => {
+                info!("{}", msg);
+                let mut result = ipc::QueryWithProofResponse::new();
+                result.set_failure(msg);
+                result
+            }
+            Ok(QueryWithProofResult::RootNotFound) => {
+                let log_message = "Root not found";
+                info!("{}", log_message);
+                let mut result = ipc::QueryWithProofResponse::new();
+                result.set_failure(log_message.to_string());
+                result
+            }
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                warn!("{}", log_message);
+                let mut result = ipc::QueryWithProofResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_QUERY_WITH_PROOF,
+            TAG_RESPONSE_QUERY_WITH_PROOF,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(response)
+    }
+
+    fn list_keys(
+        &self,
+        _request_options: RequestOptions,
+        list_keys_request: ipc::ListKeysRequest,
+    ) -> SingleResponse<ListKeysResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = ListKeysResponse::new();
+
+        let list_keys_request: ListKeysRequest = match list_keys_request.try_into() {
+            Ok(list_keys_request) => list_keys_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.set_failure(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_LIST_KEYS,
+                    TAG_RESPONSE_LIST_KEYS,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+        let state_hash = list_keys_request.state_hash();
+
+        match self.run_list_keys(correlation_id, list_keys_request) {
+            Ok(ListKeysResult::Success {
+                entries,
+                next_cursor,
+            }) => {
+                info!("list keys successful; entries: {}", entries.len());
+                let pb_entries: Vec<ipc::ListKeysResult_Entry> = entries
+                    .into_iter()
+                    .map(|(key, maybe_value)| {
+                        let mut pb_entry = ipc::ListKeysResult_Entry::new();
+                        pb_entry.set_key(key.into());
+                        if let Some(value) = maybe_value {
+                            pb_entry.set_value(value.into());
+                        }
+                        pb_entry
+                    })
+                    .collect();
+                let pb_success = ret.mut_success();
+                pb_success.set_entries(pb_entries.into());
+                pb_success.set_next_cursor(next_cursor.unwrap_or_default());
+            }
+            Ok(ListKeysResult::RootNotFound) => {
+                warn!("RootNotFound");
+                ret.mut_missing_root().set_hash(state_hash.to_vec());
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.set_failure(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_LIST_KEYS,
+            TAG_RESPONSE_LIST_KEYS,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
+    }
+
     fn execute(
         &self,
         _request_options: RequestOptions,
@@ -170,6 +352,9 @@ where
         let start = Instant::now();
         let correlation_id = CorrelationId::new();
 
+        let chain_deploys = exec_request.get_chain_deploys();
+        let parallel = exec_request.get_parallel();
+
         let exec_request: ExecuteRequest = match exec_request.try_into() {
             Ok(ret) => ret,
             Err(err) => {
@@ -179,7 +364,18 @@ where
 
         let mut exec_response = ExecuteResponse::new();
 
-        let results = match self.run_execute(correlation_id, exec_request) {
+        let run_result = if chain_deploys {
+            self.run_execute_chained(correlation_id, exec_request)
+                .map(|(results, effect)| (results, Some(effect), Vec::new()))
+        } else if parallel {
+            self.run_execute_parallel(correlation_id, exec_request)
+                .map(|(results, conflicts)| (results, None, conflicts))
+        } else {
+            self.run_execute(correlation_id, exec_request)
+                .map(|results| (results, None, Vec::new()))
+        };
+
+        let (results, chained_effect, conflicts) = match run_result {
             Ok(results) => results,
             Err(error) => {
                 info!("deploy results error: RootNotFound");
@@ -195,9 +391,18 @@ where
         };
 
         let protobuf_results_iter = results.into_iter().map(Into::into);
-        exec_response
-            .mut_success()
-            .set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
+        let exec_result = exec_response.mut_success();
+        exec_result.set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
+        if let Some(chained_effect) = chained_effect {
+            exec_result.set_chained_effect(chained_effect.into());
+        }
+        let protobuf_conflicts_iter = conflicts.into_iter().map(|(first, second)| {
+            let mut conflict = DeployConflict::new();
+            conflict.set_first(first as u32);
+            conflict.set_second(second as u32);
+            conflict
+        });
+        exec_result.set_conflicts(FromIterator::from_iter(protobuf_conflicts_iter));
         log_duration(
             correlation_id,
             METRIC_DURATION_EXEC,
@@ -207,6 +412,56 @@ where
         SingleResponse::completed(exec_response)
     }
 
+    fn estimate(
+        &self,
+        _request_options: RequestOptions,
+        estimate_request: ipc::EstimateRequest,
+    ) -> SingleResponse<EstimateResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = EstimateResponse::new();
+
+        let estimate_request: EstimateRequest = match estimate_request.try_into() {
+            Ok(estimate_request) => estimate_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.set_failure(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_ESTIMATE,
+                    TAG_RESPONSE_ESTIMATE,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+
+        match self.run_estimate(correlation_id, estimate_request) {
+            Ok(estimate_result) => {
+                info!(
+                    "estimate successful; cost: {}",
+                    estimate_result.execution_result.cost().value()
+                );
+                ret.set_success(estimate_result.into());
+            }
+            Err(error) => {
+                info!("estimate error: RootNotFound");
+                ret.mut_missing_parent().set_hash(error.to_vec());
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_ESTIMATE,
+            TAG_RESPONSE_ESTIMATE,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
+    }
+
     fn commit(
         &self,
         _request_options: RequestOptions,
@@ -453,36 +708,499 @@ where
         SingleResponse::completed(upgrade_response)
     }
 
+    fn prune(
+        &self,
+        _request_options: RequestOptions,
+        prune_request: ipc::PruneRequest,
+    ) -> SingleResponse<PruneResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = PruneResponse::new();
+
+        let prune_request: PruneRequest = match prune_request.try_into() {
+            Ok(prune_request) => prune_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_PRUNE,
+                    TAG_RESPONSE_PRUNE,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+
+        match self.run_prune(correlation_id, prune_request) {
+            Ok(PruneResult::Success { reachable, deleted }) => {
+                info!(
+                    "pruned global state; reachable: {}, deleted: {}",
+                    reachable, deleted
+                );
+                let prune_result = ret.mut_success();
+                prune_result.set_reachable(reachable as u64);
+                prune_result.set_deleted(deleted as u64);
+            }
+            Ok(PruneResult::RootNotFound(root)) => {
+                warn!("RootNotFound");
+                ret.mut_missing_root().set_hash(root.to_vec());
+            }
+            Ok(prune_result @ PruneResult::AlreadyInProgress) => {
+                let err_msg = prune_result.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_PRUNE,
+            TAG_RESPONSE_PRUNE,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
+    }
+
+    fn diff(
+        &self,
+        _request_options: RequestOptions,
+        diff_request: ipc::DiffRequest,
+    ) -> SingleResponse<DiffResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = DiffResponse::new();
+
+        let diff_request: DiffRequest = match diff_request.try_into() {
+            Ok(diff_request) => diff_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_DIFF,
+                    TAG_RESPONSE_DIFF,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+
+        match self.run_diff(correlation_id, diff_request) {
+            Ok(DiffResult::Success(entries)) => {
+                info!("diff of global state; entries: {}", entries.len());
+                let pb_entries: Vec<ipc::DiffEntry> = entries.into_iter().map(Into::into).collect();
+                ret.mut_success().set_entries(pb_entries.into());
+            }
+            Ok(DiffResult::RootNotFound(root)) => {
+                warn!("RootNotFound");
+                ret.mut_missing_root().set_hash(root.to_vec());
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_DIFF,
+            TAG_RESPONSE_DIFF,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
+    }
+
     fn bid_state(
         &self,
         _request_options: RequestOptions,
-        _bid_state_request: BidStateRequest,
+        bid_state_request: ipc::BidStateRequest,
     ) -> SingleResponse<BidStateResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let bid_state_request: BidStateRequest = match bid_state_request.try_into() {
+            Ok(bid_state_request) => bid_state_request,
+            Err(error) => {
+                // `BidStateResponse` has no error variant, so a malformed request is reported as
+                // a gRPC error
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_BID_STATE,
+                    TAG_RESPONSE_BID_STATE,
+                    start.elapsed(),
+                );
+                return SingleResponse::err(GrpcError::Panic(err_msg));
+            }
+        };
+        let pre_state_hash = bid_state_request.pre_state_hash();
+
+        let response = match self.get_bid_state(correlation_id, bid_state_request) {
+            Ok(BidStateResult::Success { bids }) => {
+                let mut ret = BidStateResponse::new();
+                let bids = bids.into_iter().map(Into::into).collect();
+                ret.mut_success().set_bids(bids);
+                SingleResponse::completed(ret)
+            }
+            Ok(BidStateResult::RootNotFound) => {
+                warn!("RootNotFound");
+                let mut ret = BidStateResponse::new();
+                ret.mut_missing_parent().set_hash(pre_state_hash.to_vec());
+                SingleResponse::completed(ret)
+            }
+            Err(error) => {
+                warn!("{:?}", error);
+                SingleResponse::err(GrpcError::Panic(error.to_string()))
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_BID_STATE,
+            TAG_RESPONSE_BID_STATE,
+            start.elapsed(),
+        );
+
+        response
     }
 
     fn distribute_rewards(
         &self,
         _request_options: RequestOptions,
-        _distribute_rewards_request: DistributeRewardsRequest,
+        distribute_rewards_request: ipc::DistributeRewardsRequest,
     ) -> SingleResponse<DistributeRewardsResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = DistributeRewardsResponse::new();
+
+        let distribute_rewards_request: DistributeRewardsRequest =
+            match distribute_rewards_request.try_into() {
+                Ok(distribute_rewards_request) => distribute_rewards_request,
+                Err(error) => {
+                    let err_msg = error.to_string();
+                    warn!("{}", err_msg);
+                    ret.mut_error().set_message(err_msg);
+                    log_duration(
+                        correlation_id,
+                        METRIC_DURATION_DISTRIBUTE_REWARDS,
+                        TAG_RESPONSE_DISTRIBUTE_REWARDS,
+                        start.elapsed(),
+                    );
+                    return SingleResponse::completed(ret);
+                }
+            };
+        let pre_state_hash = distribute_rewards_request.pre_state_hash();
+
+        match self.commit_distribute_rewards(correlation_id, distribute_rewards_request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("rewards distributed; new state hash is: {:?}", state_root);
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                ret.mut_missing_parent().set_hash(pre_state_hash.to_vec());
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_DISTRIBUTE_REWARDS,
+            TAG_RESPONSE_DISTRIBUTE_REWARDS,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
     }
 
     fn slash(
         &self,
         _request_options: RequestOptions,
-        _slash_request: SlashRequest,
+        slash_request: ipc::SlashRequest,
     ) -> SingleResponse<SlashResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = SlashResponse::new();
+
+        let slash_request: SlashRequest = match slash_request.try_into() {
+            Ok(slash_request) => slash_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_SLASH,
+                    TAG_RESPONSE_SLASH,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+        let pre_state_hash = slash_request.pre_state_hash();
+
+        match self.commit_slash(correlation_id, slash_request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("validators slashed; new state hash is: {:?}", state_root);
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                ret.mut_missing_parent().set_hash(pre_state_hash.to_vec());
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_SLASH,
+            TAG_RESPONSE_SLASH,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
     }
 
     fn unbond_payout(
         &self,
         _request_options: RequestOptions,
-        _unbond_payout_request: UnbondPayoutRequest,
+        unbond_payout_request: ipc::UnbondPayoutRequest,
     ) -> SingleResponse<UnbondPayoutResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = UnbondPayoutResponse::new();
+
+        let unbond_payout_request: UnbondPayoutRequest = match unbond_payout_request.try_into() {
+            Ok(unbond_payout_request) => unbond_payout_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_UNBOND_PAYOUT,
+                    TAG_RESPONSE_UNBOND_PAYOUT,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+        let pre_state_hash = unbond_payout_request.pre_state_hash();
+
+        match self.commit_unbond_payout(correlation_id, unbond_payout_request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("unbonds paid out; new state hash is: {:?}", state_root);
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                ret.mut_missing_parent().set_hash(pre_state_hash.to_vec());
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_UNBOND_PAYOUT,
+            TAG_RESPONSE_UNBOND_PAYOUT,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
+    }
+
+    fn step(
+        &self,
+        _request_options: RequestOptions,
+        step_request: ipc::StepRequest,
+    ) -> SingleResponse<StepResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = StepResponse::new();
+
+        let step_request: StepRequest = match step_request.try_into() {
+            Ok(step_request) => step_request,
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_STEP,
+                    TAG_RESPONSE_STEP,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(ret);
+            }
+        };
+        let pre_state_hash = step_request.pre_state_hash();
+
+        match self.commit_step(correlation_id, step_request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("step applied; new state hash is: {:?}", state_root);
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                ret.mut_missing_parent().set_hash(pre_state_hash.to_vec());
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_STEP,
+            TAG_RESPONSE_STEP,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
+    }
+
+    fn distribute_seigniorage(
+        &self,
+        _request_options: RequestOptions,
+        distribute_seigniorage_request: ipc::DistributeSeigniorageRequest,
+    ) -> SingleResponse<DistributeSeigniorageResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut ret = DistributeSeigniorageResponse::new();
+
+        let distribute_seigniorage_request: DistributeSeigniorageRequest =
+            match distribute_seigniorage_request.try_into() {
+                Ok(distribute_seigniorage_request) => distribute_seigniorage_request,
+                Err(error) => {
+                    let err_msg = error.to_string();
+                    warn!("{}", err_msg);
+                    ret.mut_error().set_message(err_msg);
+                    log_duration(
+                        correlation_id,
+                        METRIC_DURATION_DISTRIBUTE_SEIGNIORAGE,
+                        TAG_RESPONSE_DISTRIBUTE_SEIGNIORAGE,
+                        start.elapsed(),
+                    );
+                    return SingleResponse::completed(ret);
+                }
+            };
+        let pre_state_hash = distribute_seigniorage_request.pre_state_hash();
+
+        match self.commit_distribute_seigniorage(correlation_id, distribute_seigniorage_request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!(
+                    "seigniorage distributed; new state hash is: {:?}",
+                    state_root
+                );
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                ret.mut_missing_parent().set_hash(pre_state_hash.to_vec());
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                ret.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_DISTRIBUTE_SEIGNIORAGE,
+            TAG_RESPONSE_DISTRIBUTE_SEIGNIORAGE,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(ret)
     }
 }
 
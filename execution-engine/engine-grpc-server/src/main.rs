@@ -1,7 +1,9 @@
 use std::{
     collections::BTreeMap,
+    convert::TryFrom,
     fs,
     path::PathBuf,
+    process,
     str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -10,14 +12,18 @@ use std::{
     time::Duration,
 };
 
-use clap::{App, Arg, ArgMatches};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 use dirs::home_dir;
-use engine_core::engine_state::{EngineConfig, EngineState};
+use engine_core::engine_state::{
+    prune::{PruneRequest, PruneResult, DEFAULT_PRUNE_BATCH_SIZE},
+    EngineConfig, EngineState,
+};
 use lmdb::DatabaseFlags;
 use log::{error, info, Level, LevelFilter};
 
 use engine_shared::{
     logging::{self, Settings, Style},
+    newtypes::{Blake2bHash, CorrelationId},
     os::get_page_size,
     socket,
 };
@@ -107,6 +113,27 @@ const ARG_ENABLE_BONDING: &str = "enable-bonding";
 const ARG_ENABLE_BONDING_SHORT: &str = "b";
 const ARG_ENABLE_BONDING_HELP: &str = "Enable bonding";
 
+// account nonces
+const ARG_ENABLE_ACCOUNT_NONCES: &str = "enable-account-nonces";
+const ARG_ENABLE_ACCOUNT_NONCES_HELP: &str =
+    "Require deploys to carry the next nonce of their account, protecting against replays";
+
+// prune
+const SUBCOMMAND_PRUNE: &str = "prune";
+const SUBCOMMAND_PRUNE_ABOUT: &str =
+    "Deletes global state which is unreachable from the given state roots, then exits.  The \
+     server must not be running against the same data directory.  Freed pages are reused by lmdb \
+     rather than returned to the filesystem.";
+const ARG_KEEP_ROOT: &str = "keep-root";
+const ARG_KEEP_ROOT_SHORT: &str = "k";
+const ARG_KEEP_ROOT_VALUE: &str = "HASH";
+const ARG_KEEP_ROOT_HELP: &str = "Hex-encoded state root hash to keep; can be given multiple times";
+const ARG_KEEP_ROOT_EXPECT: &str = "Could not parse keep-root argument";
+const ARG_BATCH_SIZE: &str = "batch-size";
+const ARG_BATCH_SIZE_VALUE: &str = "NUM";
+const ARG_BATCH_SIZE_HELP: &str = "Maximum number of deletions per write transaction";
+const ARG_BATCH_SIZE_EXPECT: &str = "Could not parse batch-size argument";
+
 // runnable
 const SIGINT_HANDLE_EXPECT: &str = "Error setting Ctrl-C handler";
 const RUNNABLE_CHECK_INTERVAL_SECONDS: u64 = 3;
@@ -118,6 +145,11 @@ fn main() {
 
     let _ = logging::initialize(get_log_settings(&arg_matches));
 
+    if let Some(prune_matches) = arg_matches.subcommand_matches(SUBCOMMAND_PRUNE) {
+        run_prune(&arg_matches, prune_matches);
+        return;
+    }
+
     info!("starting Execution Engine Server");
 
     let socket = get_socket(&arg_matches);
@@ -169,6 +201,7 @@ fn set_panic_hook() {
 fn get_args() -> ArgMatches<'static> {
     App::new(APP_NAME)
         .version(env!("CARGO_PKG_VERSION"))
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(
             Arg::with_name(ARG_LOG_LEVEL)
                 .required(false)
@@ -239,12 +272,39 @@ fn get_args() -> ArgMatches<'static> {
                 .long(ARG_ENABLE_BONDING)
                 .help(ARG_ENABLE_BONDING_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_ENABLE_ACCOUNT_NONCES)
+                .long(ARG_ENABLE_ACCOUNT_NONCES)
+                .help(ARG_ENABLE_ACCOUNT_NONCES_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
                 .required(true)
                 .help(ARG_SOCKET_HELP)
                 .index(1),
         )
+        .subcommand(
+            SubCommand::with_name(SUBCOMMAND_PRUNE)
+                .about(SUBCOMMAND_PRUNE_ABOUT)
+                .arg(
+                    Arg::with_name(ARG_KEEP_ROOT)
+                        .required(true)
+                        .short(ARG_KEEP_ROOT_SHORT)
+                        .long(ARG_KEEP_ROOT)
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name(ARG_KEEP_ROOT_VALUE)
+                        .help(ARG_KEEP_ROOT_HELP),
+                )
+                .arg(
+                    Arg::with_name(ARG_BATCH_SIZE)
+                        .long(ARG_BATCH_SIZE)
+                        .takes_value(true)
+                        .value_name(ARG_BATCH_SIZE_VALUE)
+                        .help(ARG_BATCH_SIZE_HELP),
+                ),
+        )
         .get_matches()
 }
 
@@ -304,9 +364,11 @@ fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
     // feature flags go here
     let use_system_contracts = arg_matches.is_present(ARG_USE_SYSTEM_CONTRACTS);
     let enable_bonding = arg_matches.is_present(ARG_ENABLE_BONDING);
+    let enable_account_nonces = arg_matches.is_present(ARG_ENABLE_ACCOUNT_NONCES);
     EngineConfig::new()
         .with_use_system_contracts(use_system_contracts)
         .with_enable_bonding(enable_bonding)
+        .with_enable_account_nonces(enable_account_nonces)
 }
 
 /// Builds and returns a gRPC server.
@@ -353,6 +415,50 @@ fn get_engine_state(
     EngineState::new(global_state, engine_config)
 }
 
+/// Deletes global state which is unreachable from the roots given to the prune subcommand
+fn run_prune(arg_matches: &ArgMatches, prune_matches: &ArgMatches) {
+    let data_dir = get_data_dir(arg_matches);
+
+    let map_size = get_map_size(arg_matches);
+
+    let engine_config: EngineConfig = get_engine_config(arg_matches);
+
+    let roots_to_keep: Vec<Blake2bHash> = prune_matches
+        .values_of(ARG_KEEP_ROOT)
+        .expect(ARG_KEEP_ROOT_EXPECT)
+        .map(|hex| {
+            base16::decode(hex)
+                .ok()
+                .and_then(|bytes| Blake2bHash::try_from(bytes.as_slice()).ok())
+                .expect(ARG_KEEP_ROOT_EXPECT)
+        })
+        .collect();
+
+    let batch_size = prune_matches
+        .value_of(ARG_BATCH_SIZE)
+        .map_or(Ok(DEFAULT_PRUNE_BATCH_SIZE), usize::from_str)
+        .expect(ARG_BATCH_SIZE_EXPECT);
+
+    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+
+    let prune_request = PruneRequest::new(roots_to_keep, batch_size);
+
+    match engine_state.run_prune(CorrelationId::new(), prune_request) {
+        Ok(PruneResult::Success { reachable, deleted }) => info!(
+            "pruned global state; reachable: {}, deleted: {}",
+            reachable, deleted
+        ),
+        Ok(prune_result) => {
+            error!("failed to prune global state: {}", prune_result);
+            process::exit(1);
+        }
+        Err(error) => {
+            error!("failed to prune global state: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
 /// Builds and returns log settings
 fn get_log_settings(arg_matches: &ArgMatches) -> Settings {
     let max_level = match arg_matches
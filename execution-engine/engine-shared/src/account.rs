@@ -3,6 +3,8 @@ mod associated_keys;
 
 use std::collections::BTreeSet;
 
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
 use types::{
     account::{
         ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure,
@@ -17,12 +19,15 @@ pub use action_thresholds::ActionThresholds;
 pub use associated_keys::AssociatedKeys;
 
 #[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct Account {
     public_key: PublicKey,
     named_keys: NamedKeys,
     main_purse: URef,
     associated_keys: AssociatedKeys,
     action_thresholds: ActionThresholds,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    nonce: u64,
 }
 
 impl Account {
@@ -39,6 +44,7 @@ impl Account {
             main_purse,
             associated_keys,
             action_thresholds,
+            nonce: 0,
         }
     }
 
@@ -87,6 +93,21 @@ impl Account {
         &self.action_thresholds
     }
 
+    /// Returns the number of deploys executed by this account while account nonces are enabled.
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Returns this account with its nonce set to `nonce`.
+    pub fn with_nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    pub fn increment_nonce(&mut self) {
+        self.nonce += 1;
+    }
+
     pub fn add_associated_key(
         &mut self,
         public_key: PublicKey,
@@ -208,15 +229,26 @@ impl ToBytes for Account {
         result.append(&mut self.main_purse.to_bytes()?);
         result.append(&mut self.associated_keys.to_bytes()?);
         result.append(&mut self.action_thresholds.to_bytes()?);
+        // Accounts stored before nonces were introduced end after their action thresholds, so a
+        // zero nonce is left out to keep their encoding, and with it their trie hashes, unchanged.
+        if self.nonce != 0 {
+            result.append(&mut self.nonce.to_bytes()?);
+        }
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
+        let nonce_length = if self.nonce != 0 {
+            self.nonce.serialized_length()
+        } else {
+            0
+        };
         self.public_key.serialized_length()
             + self.named_keys.serialized_length()
             + self.main_purse.serialized_length()
             + self.associated_keys.serialized_length()
             + self.action_thresholds.serialized_length()
+            + nonce_length
     }
 }
 
@@ -227,6 +259,16 @@ impl FromBytes for Account {
         let (main_purse, rem) = URef::from_bytes(rem)?;
         let (associated_keys, rem) = AssociatedKeys::from_bytes(rem)?;
         let (action_thresholds, rem) = ActionThresholds::from_bytes(rem)?;
+        // An account is always the last field of the stored value holding it, so no remaining
+        // bytes means the nonce was left out.
+        let (nonce, rem) = if rem.is_empty() {
+            (0, rem)
+        } else {
+            match u64::from_bytes(rem)? {
+                (0, _) => return Err(Error::Formatting),
+                (nonce, rem) => (nonce, rem),
+            }
+        };
         Ok((
             Account {
                 public_key,
@@ -234,6 +276,7 @@ impl FromBytes for Account {
                 main_purse,
                 associated_keys,
                 action_thresholds,
+                nonce,
             },
             rem,
         ))
@@ -260,6 +303,7 @@ pub mod gens {
             purse in uref_arb(),
             thresholds in action_thresholds_arb(),
             mut associated_keys in associated_keys_arb(MAX_ASSOCIATED_KEYS - 1),
+            nonce in any::<u64>(),
         ) -> Account {
                 associated_keys.add_key(pub_key, Weight::new(1)).unwrap();
                 Account::new(
@@ -269,6 +313,7 @@ pub mod gens {
                     associated_keys,
                     thresholds,
                 )
+                .with_nonce(nonce)
         }
     }
 }
@@ -286,6 +331,14 @@ mod proptests {
         fn test_value_account(acct in gens::account_arb()) {
             bytesrepr::test_serialization_roundtrip(&acct);
         }
+
+        #[cfg(feature = "serde-support")]
+        #[test]
+        fn test_json_account(acct in gens::account_arb()) {
+            let json = serde_json::to_string(&acct).unwrap();
+            let parsed: Account = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, acct);
+        }
     }
 }
 
@@ -643,4 +696,29 @@ mod tests {
             .update_associated_key(key_1, Weight::new(1))
             .expect("should work");
     }
+
+    #[test]
+    fn should_deserialize_account_without_nonce() {
+        let account = Account::create(
+            PublicKey::ed25519_from([1; 32]),
+            NamedKeys::new(),
+            URef::new([2; 32], AccessRights::READ_ADD_WRITE),
+        );
+
+        // Accounts stored before nonces were introduced have no trailing nonce.
+        let mut legacy_bytes = account.public_key.to_bytes().unwrap();
+        legacy_bytes.append(&mut account.named_keys.to_bytes().unwrap());
+        legacy_bytes.append(&mut account.main_purse.to_bytes().unwrap());
+        legacy_bytes.append(&mut account.associated_keys.to_bytes().unwrap());
+        legacy_bytes.append(&mut account.action_thresholds.to_bytes().unwrap());
+
+        let parsed: Account = bytesrepr::deserialize(legacy_bytes.clone()).unwrap();
+        assert_eq!(parsed, account);
+        assert_eq!(parsed.nonce(), 0);
+        assert_eq!(parsed.to_bytes().unwrap(), legacy_bytes);
+
+        let mut explicit_zero_nonce = legacy_bytes;
+        explicit_zero_nonce.append(&mut 0u64.to_bytes().unwrap());
+        assert!(bytesrepr::deserialize::<Account>(explicit_zero_nonce).is_err());
+    }
 }
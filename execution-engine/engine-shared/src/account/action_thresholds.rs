@@ -1,3 +1,5 @@
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
 use types::{
     account::{ActionType, SetThresholdFailure, Weight, WEIGHT_SERIALIZED_LENGTH},
     bytesrepr::{self, Error, FromBytes, ToBytes},
@@ -5,6 +7,7 @@ use types::{
 
 /// Thresholds that have to be met when executing an action of a certain type.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct ActionThresholds {
     deployment: Weight,
     key_management: Weight,
@@ -1,5 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
 use types::{
     account::{
         AddKeyFailure, PublicKey, RemoveKeyFailure, UpdateKeyFailure, Weight, MAX_ASSOCIATED_KEYS,
@@ -8,6 +10,7 @@ use types::{
 };
 
 #[derive(Default, PartialOrd, Ord, PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct AssociatedKeys(BTreeMap<PublicKey, Weight>);
 
 impl AssociatedKeys {
@@ -151,7 +154,9 @@ mod tests {
     use std::{collections::BTreeSet, iter::FromIterator};
 
     use types::{
-        account::{AddKeyFailure, PublicKey, Weight, ED25519_LENGTH, MAX_ASSOCIATED_KEYS},
+        account::{
+            AddKeyFailure, PublicKey, Weight, ED25519_LENGTH, MAX_ASSOCIATED_KEYS, SECP256K1_LENGTH,
+        },
         bytesrepr,
     };
 
@@ -327,4 +332,16 @@ mod tests {
             .unwrap();
         bytesrepr::test_serialization_roundtrip(&keys);
     }
+    #[test]
+    fn associated_keys_with_mixed_key_types() {
+        let ed25519 = PublicKey::ed25519_from([1; ED25519_LENGTH]);
+        let secp256k1 = PublicKey::secp256k1_from([1; SECP256K1_LENGTH]);
+        let mut keys = AssociatedKeys::new(ed25519, Weight::new(1));
+        keys.add_key(secp256k1, Weight::new(2)).unwrap();
+
+        assert_eq!(keys.get(&ed25519), Some(&Weight::new(1)));
+        assert_eq!(keys.get(&secp256k1), Some(&Weight::new(2)));
+        assert_eq!(keys.total_keys_weight(), Weight::new(3));
+        bytesrepr::test_serialization_roundtrip(&keys);
+    }
 }
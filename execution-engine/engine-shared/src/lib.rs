@@ -6,6 +6,7 @@ pub mod logging;
 pub mod motes;
 pub mod newtypes;
 pub mod os;
+pub mod payment_costs;
 pub mod socket;
 pub mod stored_value;
 pub mod test_utils;
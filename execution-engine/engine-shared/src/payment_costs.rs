@@ -0,0 +1,146 @@
+use types::{
+    bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
+    U512,
+};
+
+use crate::motes::Motes;
+
+/// The number of motes paid per unit of gas, unless configured otherwise.
+pub const DEFAULT_CONV_RATE: u64 = 10;
+/// The cost in motes of running payment code, unless configured otherwise.
+pub const DEFAULT_MAX_PAYMENT_COST: u64 = 10_000_000;
+/// The most motes the standard payment will transfer, unless configured otherwise.  Zero leaves
+/// standard payments uncapped.
+pub const DEFAULT_STANDARD_PAYMENT_CAP: u64 = 0;
+
+const NUM_FIELDS: usize = 3;
+pub const PAYMENT_COSTS_SERIALIZED_LENGTH: usize = NUM_FIELDS * U64_SERIALIZED_LENGTH;
+
+/// The economic parameters governing how deploys pay for their execution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PaymentCosts {
+    /// The number of motes paid per unit of gas.  Must be non-zero.
+    pub conv_rate: u64,
+    /// The cost in motes of running payment code.  This is charged in full if payment fails, and
+    /// is the minimum balance an account's main purse must hold for its deploys to be executed.
+    pub max_payment_cost: u64,
+    /// The most motes the standard payment will transfer into the payment purse, whatever amount
+    /// is requested, or zero if the amount is not capped.
+    pub standard_payment_cap: u64,
+}
+
+impl PaymentCosts {
+    /// Returns `max_payment_cost` as [`Motes`].
+    pub fn max_payment_cost_motes(&self) -> Motes {
+        Motes::new(U512::from(self.max_payment_cost))
+    }
+
+    /// Returns `amount` limited to `standard_payment_cap`, unless that is zero.
+    pub fn cap_standard_payment(&self, amount: U512) -> U512 {
+        if self.standard_payment_cap == 0 {
+            return amount;
+        }
+        amount.min(U512::from(self.standard_payment_cap))
+    }
+}
+
+impl Default for PaymentCosts {
+    fn default() -> Self {
+        PaymentCosts {
+            conv_rate: DEFAULT_CONV_RATE,
+            max_payment_cost: DEFAULT_MAX_PAYMENT_COST,
+            standard_payment_cap: DEFAULT_STANDARD_PAYMENT_CAP,
+        }
+    }
+}
+
+impl ToBytes for PaymentCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.conv_rate.to_bytes()?);
+        ret.append(&mut self.max_payment_cost.to_bytes()?);
+        ret.append(&mut self.standard_payment_cap.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        PAYMENT_COSTS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for PaymentCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (conv_rate, rem) = FromBytes::from_bytes(bytes)?;
+        let (max_payment_cost, rem) = FromBytes::from_bytes(rem)?;
+        let (standard_payment_cap, rem) = FromBytes::from_bytes(rem)?;
+        let payment_costs = PaymentCosts {
+            conv_rate,
+            max_payment_cost,
+            standard_payment_cap,
+        };
+        Ok((payment_costs, rem))
+    }
+}
+
+pub mod gens {
+    use proptest::{num, prop_compose};
+
+    use crate::payment_costs::PaymentCosts;
+
+    prop_compose! {
+        pub fn payment_costs_arb()(
+            conv_rate in 1..=std::u64::MAX,
+            max_payment_cost in num::u64::ANY,
+            standard_payment_cap in num::u64::ANY,
+        ) -> PaymentCosts {
+            PaymentCosts {
+                conv_rate,
+                max_payment_cost,
+                standard_payment_cap,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::{bytesrepr, U512};
+
+    use super::{gens, PaymentCosts};
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        bytesrepr::test_serialization_roundtrip(&PaymentCosts::default());
+    }
+
+    #[test]
+    fn should_cap_standard_payment() {
+        let payment_costs = PaymentCosts {
+            standard_payment_cap: 100,
+            ..Default::default()
+        };
+        assert_eq!(
+            payment_costs.cap_standard_payment(U512::from(99)),
+            U512::from(99)
+        );
+        assert_eq!(
+            payment_costs.cap_standard_payment(U512::from(101)),
+            U512::from(100)
+        );
+        assert_eq!(
+            PaymentCosts::default().cap_standard_payment(U512::max_value()),
+            U512::max_value()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn should_serialize_and_deserialize_with_arbitrary_values(
+            payment_costs in gens::payment_costs_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&payment_costs);
+        }
+    }
+}
@@ -6,11 +6,14 @@ use engine_shared::{
     stored_value::StoredValue,
     transform::Transform,
 };
-use types::{Key, ProtocolVersion};
+use types::{bytesrepr::ToBytes, Key, ProtocolVersion};
 
 use crate::{
     error::{self, in_memory},
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{
+        commit, diff, prune, CommitResult, DiffResult, KeysPage, PruneResult, PruneTracker,
+        StateProvider, StateReader,
+    },
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
     store::Store,
@@ -18,10 +21,12 @@ use crate::{
         in_memory::{InMemoryEnvironment, InMemoryReadTransaction},
         Transaction, TransactionSource,
     },
-    trie::{operations::create_hashed_empty_trie, Trie},
+    trie::{merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie},
     trie_store::{
         in_memory::InMemoryTrieStore,
-        operations::{self, read, ReadResult, WriteResult},
+        operations::{
+            self, keys_with_prefix, read, read_page, read_with_proof, ReadResult, WriteResult,
+        },
     },
 };
 
@@ -30,6 +35,7 @@ pub struct InMemoryGlobalState {
     pub trie_store: Arc<InMemoryTrieStore>,
     pub protocol_data_store: Arc<InMemoryProtocolDataStore>,
     pub empty_root_hash: Blake2bHash,
+    prune_tracker: PruneTracker,
 }
 
 /// Represents a "view" of global state at a particular root hash.
@@ -73,6 +79,7 @@ impl InMemoryGlobalState {
             trie_store,
             protocol_data_store,
             empty_root_hash,
+            prune_tracker: PruneTracker::new(),
         }
     }
 
@@ -137,6 +144,98 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read_with_proof::<
+            Key,
+            StoredValue,
+            InMemoryReadTransaction,
+            InMemoryTrieStore,
+            Self::Error,
+        >(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            key,
+        )? {
+            ReadResult::Found(proof) => Some(proof),
+            ReadResult::NotFound => None,
+            ReadResult::RootNotFound => panic!("InMemoryGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn read_page(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<KeysPage<Key, StoredValue>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read_page::<
+            Key,
+            StoredValue,
+            InMemoryReadTransaction,
+            InMemoryTrieStore,
+            Self::Error,
+        >(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+            start_after,
+            limit,
+        )? {
+            ReadResult::Found(page) => page,
+            ReadResult::NotFound => unreachable!("a page is always found under a root"),
+            ReadResult::RootNotFound => panic!("InMemoryGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let mut keys = Vec::new();
+        for maybe_key in
+            keys_with_prefix::<Key, StoredValue, InMemoryReadTransaction, InMemoryTrieStore>(
+                correlation_id,
+                &txn,
+                self.store.deref(),
+                &self.root_hash,
+                prefix,
+            )
+        {
+            if keys.len() == limit {
+                break;
+            }
+            let key = maybe_key?;
+            // Keys are iterated in the order of their serialized forms.
+            if let Some(start_after) = start_after {
+                if key.to_bytes()?.as_slice() <= start_after {
+                    continue;
+                }
+            }
+            keys.push(key);
+        }
+        txn.commit()?;
+        Ok(keys)
+    }
 }
 
 impl StateProvider for InMemoryGlobalState {
@@ -163,14 +262,15 @@ impl StateProvider for InMemoryGlobalState {
         prestate_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, Self::Error> {
-        let commit_result = commit::<InMemoryEnvironment, InMemoryTrieStore, _, Self::Error>(
-            &self.environment,
-            &self.trie_store,
-            correlation_id,
-            prestate_hash,
-            effects,
-        )?;
-        Ok(commit_result)
+        self.prune_tracker.track_commit(|| {
+            commit::<InMemoryEnvironment, InMemoryTrieStore, _, Self::Error>(
+                &self.environment,
+                &self.trie_store,
+                correlation_id,
+                prestate_hash,
+                effects,
+            )
+        })
     }
 
     fn put_protocol_data(
@@ -197,13 +297,47 @@ impl StateProvider for InMemoryGlobalState {
     fn empty_root(&self) -> Blake2bHash {
         self.empty_root_hash
     }
+
+    fn prune(
+        &self,
+        correlation_id: CorrelationId,
+        roots_to_keep: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, Self::Error> {
+        let mut roots_to_keep = roots_to_keep.to_vec();
+        roots_to_keep.push(self.empty_root_hash);
+        prune::<InMemoryEnvironment, InMemoryTrieStore, Self::Error>(
+            &self.environment,
+            &self.trie_store,
+            &self.prune_tracker,
+            correlation_id,
+            &roots_to_keep,
+            batch_size,
+        )
+    }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        old_root: Blake2bHash,
+        new_root: Blake2bHash,
+    ) -> Result<DiffResult<Key, StoredValue>, Self::Error> {
+        diff::<InMemoryEnvironment, InMemoryTrieStore, Self::Error>(
+            &self.environment,
+            &self.trie_store,
+            correlation_id,
+            old_root,
+            new_root,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use types::{account::PublicKey, CLValue};
+    use types::{account::PublicKey, CLValue, KeyTag};
 
     use super::*;
+    use crate::global_state::diff_into_transforms;
 
     #[derive(Debug, Clone)]
     struct TestPair {
@@ -263,6 +397,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reads_with_proof_from_a_checkout_verify_against_root() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter() {
+            let proof = checkout
+                .read_with_proof(correlation_id, key)
+                .unwrap()
+                .unwrap();
+            assert_eq!(proof.value(), value);
+            assert!(proof.verify(&root_hash).unwrap());
+        }
+    }
+
+    #[test]
+    fn read_pages_from_a_checkout_return_entries_in_key_order() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        let test_pairs = create_test_pairs();
+        let account_prefix = [KeyTag::Account as u8];
+
+        let page = checkout
+            .read_page(correlation_id, &account_prefix, None, 1)
+            .unwrap();
+        assert_eq!(
+            page.entries,
+            vec![(test_pairs[0].key, test_pairs[0].value.clone())]
+        );
+        assert!(page.has_more);
+
+        let start_after = test_pairs[0].key.to_bytes().unwrap();
+        let page = checkout
+            .read_page(correlation_id, &account_prefix, Some(&start_after), 1)
+            .unwrap();
+        assert_eq!(
+            page.entries,
+            vec![(test_pairs[1].key, test_pairs[1].value.clone())]
+        );
+        assert!(!page.has_more);
+
+        let page = checkout
+            .read_page(correlation_id, &[KeyTag::Hash as u8], None, 10)
+            .unwrap();
+        assert!(page.entries.is_empty());
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn keys_with_prefix_from_a_checkout_are_returned_in_key_order() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        let test_pairs = create_test_pairs();
+        let account_prefix = [KeyTag::Account as u8];
+
+        let keys = checkout
+            .keys_with_prefix(correlation_id, &account_prefix, None, 1)
+            .unwrap();
+        assert_eq!(keys, vec![test_pairs[0].key]);
+
+        let start_after = test_pairs[0].key.to_bytes().unwrap();
+        let keys = checkout
+            .keys_with_prefix(correlation_id, &account_prefix, Some(&start_after), 10)
+            .unwrap();
+        assert_eq!(keys, vec![test_pairs[1].key]);
+
+        let keys = checkout
+            .keys_with_prefix(correlation_id, &[KeyTag::Hash as u8], None, 10)
+            .unwrap();
+        assert!(keys.is_empty());
+    }
+
     #[test]
     fn checkout_fails_if_unknown_hash_is_given() {
         let (state, _) = create_test_state();
@@ -300,6 +508,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn prune_deletes_tries_unreachable_from_kept_roots() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        match state
+            .prune(correlation_id, &[root_hash, updated_hash], 1)
+            .unwrap()
+        {
+            PruneResult::Success { .. } => (),
+            other => panic!("prune failed: {}", other),
+        }
+
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter() {
+            assert_eq!(
+                Some(value),
+                checkout.read(correlation_id, key).unwrap().as_ref()
+            );
+        }
+
+        match state.prune(correlation_id, &[updated_hash], 1).unwrap() {
+            PruneResult::Success { deleted, .. } => assert!(deleted > 0),
+            other => panic!("prune failed: {}", other),
+        }
+
+        assert!(state.checkout(root_hash).unwrap().is_none());
+        assert!(state.checkout(state.empty_root()).unwrap().is_some());
+
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        for TestPair { key, value } in test_pairs_updated.iter() {
+            assert_eq!(
+                Some(value),
+                updated_checkout.read(correlation_id, key).unwrap().as_ref()
+            );
+        }
+    }
+
+    #[test]
+    fn prune_fails_if_unknown_hash_is_given() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let fake_hash: Blake2bHash = [1u8; 32].into();
+        let result = state
+            .prune(correlation_id, &[root_hash, fake_hash], 1)
+            .unwrap();
+        assert_eq!(result, PruneResult::RootNotFound(fake_hash));
+        assert!(state.checkout(root_hash).unwrap().is_some());
+    }
+
+    #[test]
+    fn diff_matches_committed_effects() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+
+        let updated_hash = match state
+            .commit(correlation_id, root_hash, effects.clone())
+            .unwrap()
+        {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let entries = match state.diff(correlation_id, root_hash, updated_hash).unwrap() {
+            DiffResult::Success(entries) => entries,
+            other => panic!("diff failed: {:?}", other),
+        };
+        assert_eq!(entries.len(), test_pairs_updated.len());
+        assert_eq!(diff_into_transforms(entries).unwrap(), effects);
+
+        // The reverse diff removes the key which was added by the commit.
+        let entries = match state.diff(correlation_id, updated_hash, root_hash).unwrap() {
+            DiffResult::Success(entries) => entries,
+            other => panic!("diff failed: {:?}", other),
+        };
+        assert_eq!(
+            diff_into_transforms(entries),
+            Err(test_pairs_updated[2].key)
+        );
+
+        assert_eq!(
+            state.diff(correlation_id, root_hash, root_hash).unwrap(),
+            DiffResult::Success(Vec::new())
+        );
+    }
+
     #[test]
     fn commit_updates_state_and_original_state_stays_intact() {
         let correlation_id = CorrelationId::new();
@@ -1,7 +1,14 @@
 pub mod in_memory;
 pub mod lmdb;
 
-use std::{collections::HashMap, fmt, hash::BuildHasher, time::Instant};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fmt,
+    hash::BuildHasher,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Instant,
+};
 
 use engine_shared::{
     additive_map::AdditiveMap,
@@ -15,21 +22,27 @@ use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
 
 use crate::{
     protocol_data::ProtocolData,
-    transaction_source::{Transaction, TransactionSource},
-    trie::Trie,
+    transaction_source::{Enumerable, Transaction, TransactionSource},
+    trie::{merkle_proof::TrieMerkleProof, Trie},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
+        self,
+        operations::{mark_reachable, read, write, ReadResult, WriteResult},
         TrieStore,
     },
     GAUGE_METRIC_KEY,
 };
 
+pub use crate::trie_store::operations::{DiffEntry, DiffResult, KeysPage};
+
 const GLOBAL_STATE_COMMIT_READS: &str = "global_state_commit_reads";
 const GLOBAL_STATE_COMMIT_WRITES: &str = "global_state_commit_writes";
 const GLOBAL_STATE_COMMIT_DURATION: &str = "global_state_commit_duration";
 const GLOBAL_STATE_COMMIT_READ_DURATION: &str = "global_state_commit_read_duration";
 const GLOBAL_STATE_COMMIT_WRITE_DURATION: &str = "global_state_commit_write_duration";
 const COMMIT: &str = "commit";
+const GLOBAL_STATE_PRUNE_DELETES: &str = "global_state_prune_deletes";
+const GLOBAL_STATE_PRUNE_DURATION: &str = "global_state_prune_duration";
+const PRUNE: &str = "prune";
 
 /// A reader of state
 pub trait StateReader<K, V> {
@@ -38,6 +51,34 @@ pub trait StateReader<K, V> {
 
     /// Returns the state value from the corresponding key
     fn read(&self, correlation_id: CorrelationId, key: &K) -> Result<Option<V>, Self::Error>;
+
+    /// Returns the state value from the corresponding key along with a proof of its inclusion
+    /// under the state root
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &K,
+    ) -> Result<Option<TrieMerkleProof<K, V>>, Self::Error>;
+
+    /// Returns up to `limit` entries whose serialized keys start with `prefix` and, if given,
+    /// follow `start_after`, ordered by their serialized keys
+    fn read_page(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<KeysPage<K, V>, Self::Error>;
+
+    /// Returns up to `limit` keys whose serialized forms start with `prefix` and, if given, follow
+    /// `start_after`, ordered by their serialized forms
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<K>, Self::Error>;
 }
 
 #[derive(Debug)]
@@ -84,6 +125,82 @@ impl From<transform::Error> for CommitResult {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PruneResult {
+    RootNotFound(Blake2bHash),
+    AlreadyInProgress,
+    Success { reachable: usize, deleted: usize },
+}
+
+impl fmt::Display for PruneResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            PruneResult::RootNotFound(root) => write!(f, "Root not found: {}", root),
+            PruneResult::AlreadyInProgress => write!(f, "Pruning already in progress"),
+            PruneResult::Success { reachable, deleted } => {
+                write!(f, "Success: reachable: {}, deleted: {}", reachable, deleted)
+            }
+        }
+    }
+}
+
+/// Keeps track of the state roots committed while global state is being pruned.
+///
+/// Commits and pruning batches are serialized through the tracker, so that each batch can mark
+/// the tries committed since pruning started as reachable before it deletes anything.
+#[derive(Debug, Default)]
+pub struct PruneTracker {
+    committed_roots: Mutex<Option<Vec<Blake2bHash>>>,
+}
+
+impl PruneTracker {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Runs `commit`, recording the resulting state root if pruning is in progress.
+    pub fn track_commit<E, F>(&self, commit: F) -> Result<CommitResult, E>
+    where
+        F: FnOnce() -> Result<CommitResult, E>,
+    {
+        let mut committed_roots = self.lock();
+        let commit_result = commit()?;
+        if let (Some(roots), CommitResult::Success { state_root, .. }) =
+            (committed_roots.as_mut(), &commit_result)
+        {
+            roots.push(*state_root);
+        }
+        Ok(commit_result)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Vec<Blake2bHash>>> {
+        // The guarded data is a plain list of hashes, so it can't be left in an invalid state.
+        self.committed_roots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Stops tracking committed roots when pruning ends, however it ends.
+struct PruneInProgress<'a>(&'a PruneTracker);
+
+impl<'a> PruneInProgress<'a> {
+    fn start(tracker: &'a PruneTracker) -> Option<Self> {
+        let mut committed_roots = tracker.lock();
+        if committed_roots.is_some() {
+            return None;
+        }
+        *committed_roots = Some(Vec::new());
+        Some(PruneInProgress(tracker))
+    }
+}
+
+impl<'a> Drop for PruneInProgress<'a> {
+    fn drop(&mut self) {
+        *self.0.lock() = None;
+    }
+}
+
 pub trait StateProvider {
     type Error;
     type Reader: StateReader<Key, StoredValue, Error = Self::Error>;
@@ -112,6 +229,24 @@ pub trait StateProvider {
     ) -> Result<Option<ProtocolData>, Self::Error>;
 
     fn empty_root(&self) -> Blake2bHash;
+
+    /// Deletes every trie element which is not reachable from `roots_to_keep` or from the empty
+    /// root, committing the deletions in batches of `batch_size`.
+    fn prune(
+        &self,
+        correlation_id: CorrelationId,
+        roots_to_keep: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, Self::Error>;
+
+    /// Returns the differences between the states under `old_root` and `new_root`, ordered by
+    /// key.
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        old_root: Blake2bHash,
+        new_root: Blake2bHash,
+    ) -> Result<DiffResult<Key, StoredValue>, Self::Error>;
 }
 
 pub fn commit<'a, R, S, H, E>(
@@ -217,3 +352,153 @@ where
         bonded_validators,
     })
 }
+
+/// Deletes every trie element of `store` which is not reachable from `roots_to_keep`.
+///
+/// Reachable elements are marked using a read transaction, which doesn't block writers.
+/// Unreachable elements are then deleted in read-write transactions of at most `batch_size`
+/// deletions each, so commits can proceed between batches.  Any root committed in the meantime
+/// through `prune_tracker` is marked as reachable before the next batch is deleted.
+pub fn prune<'a, R, S, E>(
+    environment: &'a R,
+    store: &S,
+    prune_tracker: &PruneTracker,
+    correlation_id: CorrelationId,
+    roots_to_keep: &[Blake2bHash],
+    batch_size: usize,
+) -> Result<PruneResult, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    R::ReadTransaction: Enumerable,
+    S: TrieStore<Key, StoredValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let _prune_in_progress = match PruneInProgress::start(prune_tracker) {
+        Some(prune_in_progress) => prune_in_progress,
+        None => return Ok(PruneResult::AlreadyInProgress),
+    };
+
+    let start = Instant::now();
+    let mut reachable: HashSet<Blake2bHash> = HashSet::new();
+    let mut unreachable: Vec<Blake2bHash> = Vec::new();
+
+    {
+        let txn = environment.create_read_txn()?;
+        for root in roots_to_keep {
+            let maybe_root: Option<Trie<Key, StoredValue>> = store.get(&txn, root)?;
+            if maybe_root.is_none() {
+                return Ok(PruneResult::RootNotFound(*root));
+            }
+        }
+        mark_reachable::<Key, StoredValue, _, _, E>(
+            correlation_id,
+            &txn,
+            store,
+            roots_to_keep,
+            &mut reachable,
+        )?;
+        txn.for_each_key(store.handle(), |key_bytes| {
+            if let Ok(hash) = Blake2bHash::try_from(key_bytes) {
+                if !reachable.contains(&hash) {
+                    unreachable.push(hash);
+                }
+            }
+        })?;
+        txn.commit()?;
+    }
+
+    let mut deleted: usize = 0;
+
+    for batch in unreachable.chunks(batch_size.max(1)) {
+        let mut committed_roots = prune_tracker.lock();
+        let mut txn = environment.create_read_write_txn()?;
+        if let Some(roots) = committed_roots.as_mut() {
+            mark_reachable::<Key, StoredValue, _, _, E>(
+                correlation_id,
+                &txn,
+                store,
+                roots,
+                &mut reachable,
+            )?;
+            roots.clear();
+        }
+        for hash in batch {
+            if !reachable.contains(hash) {
+                store.delete(&mut txn, hash)?;
+                deleted += 1;
+            }
+        }
+        txn.commit()?;
+    }
+
+    log_duration(
+        correlation_id,
+        GLOBAL_STATE_PRUNE_DURATION,
+        PRUNE,
+        start.elapsed(),
+    );
+
+    log_metric(
+        correlation_id,
+        GLOBAL_STATE_PRUNE_DELETES,
+        PRUNE,
+        GAUGE_METRIC_KEY,
+        deleted as f64,
+    );
+
+    Ok(PruneResult::Success {
+        reachable: reachable.len(),
+        deleted,
+    })
+}
+
+pub fn diff<'a, R, S, E>(
+    environment: &'a R,
+    store: &S,
+    correlation_id: CorrelationId,
+    old_root: Blake2bHash,
+    new_root: Blake2bHash,
+) -> Result<DiffResult<Key, StoredValue>, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<Key, StoredValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let txn = environment.create_read_txn()?;
+    let diff_result = trie_store::operations::diff::<_, _, _, _, E>(
+        correlation_id,
+        &txn,
+        store,
+        &old_root,
+        &new_root,
+    )?;
+    txn.commit()?;
+    Ok(diff_result)
+}
+
+/// Converts the entries of a diff into the effects which, committed on top of its old root,
+/// produce its new root.
+///
+/// Global state can't delete a key, so this fails with the key of the first removed entry, if
+/// any.
+pub fn diff_into_transforms(
+    entries: Vec<DiffEntry<Key, StoredValue>>,
+) -> Result<AdditiveMap<Key, Transform>, Key> {
+    let mut transforms = AdditiveMap::new();
+    for entry in entries {
+        match entry {
+            DiffEntry::Added { key, value }
+            | DiffEntry::Changed {
+                key,
+                new_value: value,
+                ..
+            } => {
+                transforms.insert(key, Transform::Write(value));
+            }
+            DiffEntry::Removed { key, .. } => return Err(key),
+        }
+    }
+    Ok(transforms)
+}
@@ -1,17 +1,32 @@
-use engine_wasm_prep::wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH};
+use engine_shared::payment_costs::{PaymentCosts, PAYMENT_COSTS_SERIALIZED_LENGTH};
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH},
+    wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH},
+};
 use std::collections::BTreeMap;
 use types::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
     ContractHash, HashAddr, KEY_HASH_LENGTH,
 };
 
-const PROTOCOL_DATA_SERIALIZED_LENGTH: usize = WASM_COSTS_SERIALIZED_LENGTH + 3 * KEY_HASH_LENGTH;
+/// Serialized length of the fields present since the first encoding of [`ProtocolData`].
+const LEGACY_FIELDS_SERIALIZED_LENGTH: usize = WASM_COSTS_SERIALIZED_LENGTH + 3 * KEY_HASH_LENGTH;
+/// Serialized length of the fields appended by the current format version.
+const EXTENDED_FIELDS_SERIALIZED_LENGTH: usize =
+    HOST_FUNCTION_COSTS_SERIALIZED_LENGTH + PAYMENT_COSTS_SERIALIZED_LENGTH + U64_SERIALIZED_LENGTH;
+const PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
+    LEGACY_FIELDS_SERIALIZED_LENGTH + U8_SERIALIZED_LENGTH + EXTENDED_FIELDS_SERIALIZED_LENGTH;
+/// Version tag written between the legacy fields and the extended fields.
+const FORMAT_VERSION: u8 = 1;
 const DEFAULT_ADDRESS: [u8; 32] = [0; 32];
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ProtocolData {
     wasm_costs: WasmCosts,
+    host_function_costs: HostFunctionCosts,
+    payment_costs: PaymentCosts,
+    reward_rate: u64,
     mint: ContractHash,
     proof_of_stake: ContractHash,
     standard_payment: ContractHash,
@@ -25,6 +40,9 @@ impl Default for ProtocolData {
     fn default() -> ProtocolData {
         ProtocolData {
             wasm_costs: WasmCosts::default(),
+            host_function_costs: HostFunctionCosts::default(),
+            payment_costs: PaymentCosts::default(),
+            reward_rate: 0,
             mint: DEFAULT_ADDRESS,
             proof_of_stake: DEFAULT_ADDRESS,
             standard_payment: DEFAULT_ADDRESS,
@@ -33,15 +51,22 @@ impl Default for ProtocolData {
 }
 
 impl ProtocolData {
-    /// Creates a new [`ProtocolData`] value from a given [`WasmCosts`] value.
+    /// Creates a new [`ProtocolData`] value from given [`WasmCosts`], [`HostFunctionCosts`] and
+    /// [`PaymentCosts`] values, and the reward rate.
     pub fn new(
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        payment_costs: PaymentCosts,
+        reward_rate: u64,
         mint: ContractHash,
         proof_of_stake: ContractHash,
         standard_payment: ContractHash,
     ) -> Self {
         ProtocolData {
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            reward_rate,
             mint,
             proof_of_stake,
             standard_payment,
@@ -64,11 +89,17 @@ impl ProtocolData {
     /// Used during `commit_genesis` before all system contracts' URefs are known.
     pub fn partial_without_standard_payment(
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        payment_costs: PaymentCosts,
+        reward_rate: u64,
         mint: ContractHash,
         proof_of_stake: ContractHash,
     ) -> Self {
         ProtocolData {
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            reward_rate,
             mint,
             proof_of_stake,
             ..Default::default()
@@ -80,6 +111,21 @@ impl ProtocolData {
         &self.wasm_costs
     }
 
+    /// Gets the [`HostFunctionCosts`] value from a given [`ProtocolData`] value.
+    pub fn host_function_costs(&self) -> &HostFunctionCosts {
+        &self.host_function_costs
+    }
+
+    /// Gets the [`PaymentCosts`] value from a given [`ProtocolData`] value.
+    pub fn payment_costs(&self) -> &PaymentCosts {
+        &self.payment_costs
+    }
+
+    /// Gets the validators' rewards per era, in billionths of the total stakes.
+    pub fn reward_rate(&self) -> u64 {
+        self.reward_rate
+    }
+
     pub fn mint(&self) -> ContractHash {
         self.mint
     }
@@ -123,6 +169,10 @@ impl ProtocolData {
     }
 }
 
+/// The legacy fields are serialized first, in their original layout, followed by a format version
+/// tag and the fields added since. Protocol data stored before the tag was introduced ends after
+/// the legacy fields, and is read back with the default values of the newer fields, which match
+/// the behavior of the engine at the time it was stored.
 impl ToBytes for ProtocolData {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
@@ -130,6 +180,10 @@ impl ToBytes for ProtocolData {
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.proof_of_stake.to_bytes()?);
         ret.append(&mut self.standard_payment.to_bytes()?);
+        ret.append(&mut FORMAT_VERSION.to_bytes()?);
+        ret.append(&mut self.host_function_costs.to_bytes()?);
+        ret.append(&mut self.payment_costs.to_bytes()?);
+        ret.append(&mut self.reward_rate.to_bytes()?);
         Ok(ret)
     }
 
@@ -145,9 +199,31 @@ impl FromBytes for ProtocolData {
         let (proof_of_stake, rem) = HashAddr::from_bytes(rem)?;
         let (standard_payment, rem) = HashAddr::from_bytes(rem)?;
 
+        if rem.is_empty() {
+            let legacy = ProtocolData {
+                wasm_costs,
+                mint,
+                proof_of_stake,
+                standard_payment,
+                ..Default::default()
+            };
+            return Ok((legacy, rem));
+        }
+
+        let (format_version, rem) = u8::from_bytes(rem)?;
+        if format_version != FORMAT_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (host_function_costs, rem) = HostFunctionCosts::from_bytes(rem)?;
+        let (payment_costs, rem) = PaymentCosts::from_bytes(rem)?;
+        let (reward_rate, rem) = u64::from_bytes(rem)?;
+
         Ok((
             ProtocolData {
                 wasm_costs,
+                host_function_costs,
+                payment_costs,
+                reward_rate,
                 mint,
                 proof_of_stake,
                 standard_payment,
@@ -159,9 +235,12 @@ impl FromBytes for ProtocolData {
 
 #[cfg(test)]
 pub(crate) mod gens {
-    use proptest::prop_compose;
+    use proptest::{prelude::any, prop_compose};
 
-    use engine_wasm_prep::wasm_costs::gens as wasm_costs_gens;
+    use engine_shared::payment_costs::gens as payment_costs_gens;
+    use engine_wasm_prep::{
+        host_function_costs::gens as host_function_costs_gens, wasm_costs::gens as wasm_costs_gens,
+    };
     use types::gens;
 
     use super::ProtocolData;
@@ -169,12 +248,18 @@ pub(crate) mod gens {
     prop_compose! {
         pub fn protocol_data_arb()(
             wasm_costs in wasm_costs_gens::wasm_costs_arb(),
+            host_function_costs in host_function_costs_gens::host_function_costs_arb(),
+            payment_costs in payment_costs_gens::payment_costs_arb(),
+            reward_rate in any::<u64>(),
             mint in gens::u8_slice_32(),
             proof_of_stake in gens::u8_slice_32(),
             standard_payment in gens::u8_slice_32(),
         ) -> ProtocolData {
             ProtocolData {
                 wasm_costs,
+                host_function_costs,
+                payment_costs,
+                reward_rate,
                 mint,
                 proof_of_stake,
                 standard_payment,
@@ -187,10 +272,17 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
-    use engine_wasm_prep::wasm_costs::WasmCosts;
-    use types::{bytesrepr, ContractHash};
+    use engine_shared::payment_costs::PaymentCosts;
+    use engine_wasm_prep::{
+        host_function_costs::{HostFunctionCost, HostFunctionCosts},
+        wasm_costs::WasmCosts,
+    };
+    use types::{
+        bytesrepr::{self, ToBytes},
+        ContractHash,
+    };
 
-    use super::{gens, ProtocolData};
+    use super::{gens, ProtocolData, FORMAT_VERSION, LEGACY_FIELDS_SERIALIZED_LENGTH};
 
     fn wasm_costs_mock() -> WasmCosts {
         WasmCosts {
@@ -222,6 +314,14 @@ mod tests {
         }
     }
 
+    fn host_function_costs_mock() -> HostFunctionCosts {
+        HostFunctionCosts {
+            write: HostFunctionCost::new(100, 1),
+            call_contract: HostFunctionCost::new(1_000, 2),
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn should_serialize_and_deserialize() {
         let mock = {
@@ -231,6 +331,9 @@ mod tests {
             let standard_payment_reference = [3u8; 32];
             ProtocolData::new(
                 costs,
+                host_function_costs_mock(),
+                PaymentCosts::default(),
+                0,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -243,6 +346,9 @@ mod tests {
             let standard_payment_reference = [2u8; 32];
             ProtocolData::new(
                 costs,
+                host_function_costs_mock(),
+                PaymentCosts::default(),
+                0,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -261,6 +367,9 @@ mod tests {
             let costs = wasm_costs_mock();
             ProtocolData::new(
                 costs,
+                host_function_costs_mock(),
+                PaymentCosts::default(),
+                0,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -291,6 +400,9 @@ mod tests {
             let costs = wasm_costs_mock();
             ProtocolData::new(
                 costs,
+                host_function_costs_mock(),
+                PaymentCosts::default(),
+                0,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -308,6 +420,42 @@ mod tests {
         assert_eq!(actual[1], standard_payment_reference);
     }
 
+    #[test]
+    fn should_deserialize_legacy_format_with_default_new_fields() {
+        let costs = wasm_costs_mock();
+        let mint_reference = [1u8; 32];
+        let proof_of_stake_reference = [2u8; 32];
+        let standard_payment_reference = [3u8; 32];
+        let legacy_bytes = {
+            let mut bytes = costs.to_bytes().unwrap();
+            bytes.append(&mut mint_reference.to_bytes().unwrap());
+            bytes.append(&mut proof_of_stake_reference.to_bytes().unwrap());
+            bytes.append(&mut standard_payment_reference.to_bytes().unwrap());
+            bytes
+        };
+
+        let protocol_data: ProtocolData = bytesrepr::deserialize(legacy_bytes).unwrap();
+
+        let expected = ProtocolData::new(
+            costs,
+            HostFunctionCosts::default(),
+            PaymentCosts::default(),
+            0,
+            mint_reference,
+            proof_of_stake_reference,
+            standard_payment_reference,
+        );
+        assert_eq!(protocol_data, expected);
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_format_version() {
+        let mut bytes = ProtocolData::default().to_bytes().unwrap();
+        bytes[LEGACY_FIELDS_SERIALIZED_LENGTH] = FORMAT_VERSION + 1;
+        let result: Result<ProtocolData, _> = bytesrepr::deserialize(bytes);
+        assert_eq!(result, Err(bytesrepr::Error::Formatting));
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
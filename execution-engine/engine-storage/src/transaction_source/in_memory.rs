@@ -5,7 +5,7 @@ use std::{
 
 use crate::{
     error::in_memory::Error,
-    transaction_source::{Readable, Transaction, TransactionSource, Writable},
+    transaction_source::{Enumerable, Readable, Transaction, TransactionSource, Writable},
 };
 
 /// A marker for use in a mutex which represents the capability to perform a
@@ -54,6 +54,21 @@ impl Readable for InMemoryReadTransaction {
     }
 }
 
+impl Enumerable for InMemoryReadTransaction {
+    fn for_each_key<F: FnMut(&[u8])>(
+        &self,
+        handle: Self::Handle,
+        mut f: F,
+    ) -> Result<(), Self::Error> {
+        if let Some(sub_view) = self.view.get(&handle) {
+            for key in sub_view.keys() {
+                f(key);
+            }
+        }
+        Ok(())
+    }
+}
+
 /// A read-write transaction for the in-memory trie store.
 pub struct InMemoryReadWriteTransaction<'a> {
     view: HashMap<Option<String>, BytesMap>,
@@ -105,6 +120,13 @@ impl<'a> Writable for InMemoryReadWriteTransaction<'a> {
         sub_view.insert(key.to_vec(), value.to_vec());
         Ok(())
     }
+
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<(), Self::Error> {
+        if let Some(sub_view) = self.view.get_mut(&handle) {
+            sub_view.remove(key);
+        }
+        Ok(())
+    }
 }
 
 /// An environment for the in-memory trie store.
@@ -1,10 +1,10 @@
 use std::path::PathBuf;
 
-use lmdb::{self, Database, Environment, RoTransaction, RwTransaction, WriteFlags};
+use lmdb::{self, Cursor, Database, Environment, RoTransaction, RwTransaction, WriteFlags};
 
 use crate::{
     error,
-    transaction_source::{Readable, Transaction, TransactionSource, Writable},
+    transaction_source::{Enumerable, Readable, Transaction, TransactionSource, Writable},
     MAX_DBS,
 };
 
@@ -28,6 +28,22 @@ impl<'a> Readable for RoTransaction<'a> {
     }
 }
 
+impl<'a> Enumerable for RoTransaction<'a> {
+    fn for_each_key<F: FnMut(&[u8])>(
+        &self,
+        handle: Self::Handle,
+        mut f: F,
+    ) -> Result<(), Self::Error> {
+        let mut cursor = lmdb::Transaction::open_ro_cursor(self, handle)?;
+        // `iter` on an unpositioned cursor starts at the first item and, unlike `iter_start`,
+        // does not panic on an empty database.
+        for (key, _) in cursor.iter() {
+            f(key);
+        }
+        Ok(())
+    }
+}
+
 impl<'a> Transaction for RwTransaction<'a> {
     type Error = lmdb::Error;
 
@@ -53,6 +69,13 @@ impl<'a> Writable for RwTransaction<'a> {
         self.put(handle, &key, &value, WriteFlags::empty())
             .map_err(Into::into)
     }
+
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<(), Self::Error> {
+        match self.del(handle, &key, None) {
+            Ok(()) | Err(lmdb::Error::NotFound) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 /// The environment for an LMDB-backed trie store.
@@ -31,6 +31,18 @@ pub trait Readable: Transaction {
 pub trait Writable: Transaction {
     /// Inserts a key-value pair into a given [`Transaction::Handle`].
     fn write(&mut self, handle: Self::Handle, key: &[u8], value: &[u8]) -> Result<(), Self::Error>;
+
+    /// Removes a key-value pair from a given [`Transaction::Handle`].
+    ///
+    /// Removing a key which is not present is not an error.
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// A transaction with the capability to visit every key stored in a given
+/// [`Handle`](Transaction::Handle).
+pub trait Enumerable: Readable {
+    /// Calls `f` with each key stored in a given [`Transaction::Handle`].
+    fn for_each_key<F: FnMut(&[u8])>(&self, handle: Self::Handle, f: F) -> Result<(), Self::Error>;
 }
 
 /// A source of transactions e.g. values that implement [`Readable`]
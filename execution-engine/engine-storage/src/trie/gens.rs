@@ -6,7 +6,10 @@ use engine_shared::{
 };
 use types::{gens::key_arb, Key};
 
-use super::{Pointer, PointerBlock, Trie};
+use super::{
+    merkle_proof::{TrieMerkleProof, TrieMerkleProofStep},
+    Pointer, PointerBlock, Trie,
+};
 
 pub fn blake2b_hash_arb() -> impl Strategy<Value = Blake2bHash> {
     vec(any::<u8>(), 0..1000).prop_map(|b| Blake2bHash::new(&b))
@@ -37,3 +40,25 @@ pub fn trie_arb() -> impl Strategy<Value = Trie<Key, StoredValue>> {
             .prop_map(|(affix, pointer)| Trie::Extension { affix, pointer })
     ]
 }
+
+pub fn trie_merkle_proof_step_arb() -> impl Strategy<Value = TrieMerkleProofStep> {
+    prop_oneof![
+        (any::<u8>(), vec((any::<u8>(), trie_pointer_arb()), 0..8)).prop_map(
+            |(hole_index, indexed_pointers_with_hole)| {
+                TrieMerkleProofStep::node(hole_index, indexed_pointers_with_hole)
+            }
+        ),
+        vec(any::<u8>(), 0..32).prop_map(TrieMerkleProofStep::extension)
+    ]
+}
+
+pub fn trie_merkle_proof_arb() -> impl Strategy<Value = TrieMerkleProof<Key, StoredValue>> {
+    (
+        key_arb(),
+        stored_value_arb(),
+        vec(trie_merkle_proof_step_arb(), 0..16),
+    )
+        .prop_map(|(key, value, proof_steps)| {
+            TrieMerkleProof::new(key, value, proof_steps.into_iter().collect())
+        })
+}
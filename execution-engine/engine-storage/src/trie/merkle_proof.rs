@@ -0,0 +1,217 @@
+//! Merkle proofs of inclusion for values stored in a Merkle Trie
+
+use std::collections::VecDeque;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
+
+use crate::trie::{Pointer, Trie, RADIX};
+
+const TRIE_MERKLE_PROOF_STEP_NODE_ID: u8 = 0;
+const TRIE_MERKLE_PROOF_STEP_EXTENSION_ID: u8 = 1;
+
+/// A component of a proof that an entry exists in the Merkle trie.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrieMerkleProofStep {
+    /// Corresponds to [`Trie::Node`]
+    Node {
+        /// The index of the pointer which leads towards the proven entry
+        hole_index: u8,
+        /// All other pointers of the node, along with their indices
+        indexed_pointers_with_hole: Vec<(u8, Pointer)>,
+    },
+    /// Corresponds to [`Trie::Extension`]
+    Extension { affix: Vec<u8> },
+}
+
+impl TrieMerkleProofStep {
+    /// Constructs a [`TrieMerkleProofStep::Node`] from a given hole index and the remaining
+    /// indexed pointers of the node.
+    pub fn node(hole_index: u8, indexed_pointers_with_hole: Vec<(u8, Pointer)>) -> Self {
+        TrieMerkleProofStep::Node {
+            hole_index,
+            indexed_pointers_with_hole,
+        }
+    }
+
+    /// Constructs a [`TrieMerkleProofStep::Extension`] from a given affix.
+    pub fn extension(affix: Vec<u8>) -> Self {
+        TrieMerkleProofStep::Extension { affix }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            TrieMerkleProofStep::Node { .. } => TRIE_MERKLE_PROOF_STEP_NODE_ID,
+            TrieMerkleProofStep::Extension { .. } => TRIE_MERKLE_PROOF_STEP_EXTENSION_ID,
+        }
+    }
+}
+
+impl ToBytes for TrieMerkleProofStep {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::allocate_buffer(self)?;
+        ret.push(self.tag());
+        match self {
+            TrieMerkleProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                ret.push(*hole_index);
+                ret.append(&mut indexed_pointers_with_hole.to_bytes()?);
+            }
+            TrieMerkleProofStep::Extension { affix } => {
+                ret.append(&mut affix.to_bytes()?);
+            }
+        }
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                TrieMerkleProofStep::Node {
+                    indexed_pointers_with_hole,
+                    ..
+                } => U8_SERIALIZED_LENGTH + indexed_pointers_with_hole.serialized_length(),
+                TrieMerkleProofStep::Extension { affix } => affix.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for TrieMerkleProofStep {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            TRIE_MERKLE_PROOF_STEP_NODE_ID => {
+                let (hole_index, rem) = u8::from_bytes(rem)?;
+                let (indexed_pointers_with_hole, rem) = Vec::<(u8, Pointer)>::from_bytes(rem)?;
+                Ok((
+                    TrieMerkleProofStep::node(hole_index, indexed_pointers_with_hole),
+                    rem,
+                ))
+            }
+            TRIE_MERKLE_PROOF_STEP_EXTENSION_ID => {
+                let (affix, rem) = Vec::<u8>::from_bytes(rem)?;
+                Ok((TrieMerkleProofStep::extension(affix), rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// A proof that a key/value pair is stored in a Merkle trie with a given root hash.
+///
+/// The proof steps are ordered from the parent of the leaf up to the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMerkleProof<K, V> {
+    key: K,
+    value: V,
+    proof_steps: VecDeque<TrieMerkleProofStep>,
+}
+
+impl<K, V> TrieMerkleProof<K, V> {
+    pub fn new(key: K, value: V, proof_steps: VecDeque<TrieMerkleProofStep>) -> Self {
+        TrieMerkleProof {
+            key,
+            value,
+            proof_steps,
+        }
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    pub fn proof_steps(&self) -> &VecDeque<TrieMerkleProofStep> {
+        &self.proof_steps
+    }
+
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+impl<K, V> TrieMerkleProof<K, V>
+where
+    K: ToBytes + Clone,
+    V: ToBytes + Clone,
+{
+    /// Recomputes the root hash of the trie from the leaf and the proof steps.
+    ///
+    /// The proof is valid for a given state if the returned hash equals that state's root hash.
+    pub fn compute_state_hash(&self) -> Result<Blake2bHash, bytesrepr::Error> {
+        let leaf = Trie::<K, V>::leaf(self.key.clone(), self.value.clone());
+        let mut hash = Blake2bHash::new(&leaf.to_bytes()?);
+
+        for (step_index, proof_step) in self.proof_steps.iter().enumerate() {
+            let pointer = if step_index == 0 {
+                Pointer::LeafPointer(hash)
+            } else {
+                Pointer::NodePointer(hash)
+            };
+            let trie = match proof_step {
+                TrieMerkleProofStep::Node {
+                    hole_index,
+                    indexed_pointers_with_hole,
+                } => {
+                    let mut indexed_pointers: Vec<(usize, Pointer)> = indexed_pointers_with_hole
+                        .iter()
+                        .map(|(index, pointer)| (usize::from(*index), *pointer))
+                        .collect();
+                    indexed_pointers.push((usize::from(*hole_index), pointer));
+                    debug_assert!(indexed_pointers.iter().all(|(index, _)| *index < RADIX));
+                    Trie::<K, V>::node(&indexed_pointers)
+                }
+                TrieMerkleProofStep::Extension { affix } => {
+                    Trie::<K, V>::extension(affix.clone(), pointer)
+                }
+            };
+            hash = Blake2bHash::new(&trie.to_bytes()?);
+        }
+
+        Ok(hash)
+    }
+
+    /// Returns `true` if this proof shows that its key/value pair is stored under `state_hash`.
+    pub fn verify(&self, state_hash: &Blake2bHash) -> Result<bool, bytesrepr::Error> {
+        Ok(self.compute_state_hash()? == *state_hash)
+    }
+}
+
+impl<K: ToBytes, V: ToBytes> ToBytes for TrieMerkleProof<K, V> {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::allocate_buffer(self)?;
+        ret.append(&mut self.key.to_bytes()?);
+        ret.append(&mut self.value.to_bytes()?);
+        let proof_steps: Vec<TrieMerkleProofStep> = self.proof_steps.iter().cloned().collect();
+        ret.append(&mut proof_steps.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.key.serialized_length()
+            + self.value.serialized_length()
+            + bytesrepr::U32_SERIALIZED_LENGTH
+            + self
+                .proof_steps
+                .iter()
+                .map(ToBytes::serialized_length)
+                .sum::<usize>()
+    }
+}
+
+impl<K: FromBytes, V: FromBytes> FromBytes for TrieMerkleProof<K, V> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (key, rem) = K::from_bytes(bytes)?;
+        let (value, rem) = V::from_bytes(rem)?;
+        let (proof_steps, rem) = Vec::<TrieMerkleProofStep>::from_bytes(rem)?;
+        Ok((
+            TrieMerkleProof::new(key, value, proof_steps.into_iter().collect()),
+            rem,
+        ))
+    }
+}
@@ -75,5 +75,15 @@ mod proptests {
         fn roundtrip_trie(trie in trie_arb()) {
             bytesrepr::test_serialization_roundtrip(&trie);
         }
+
+        #[test]
+        fn roundtrip_trie_merkle_proof_step(proof_step in trie_merkle_proof_step_arb()) {
+            bytesrepr::test_serialization_roundtrip(&proof_step);
+        }
+
+        #[test]
+        fn roundtrip_trie_merkle_proof(proof in trie_merkle_proof_arb()) {
+            bytesrepr::test_serialization_roundtrip(&proof);
+        }
     }
 }
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests;
 
-use std::{cmp, collections::VecDeque, mem, time::Instant};
+use std::{
+    cmp,
+    collections::{BTreeMap, HashSet, VecDeque},
+    mem,
+    time::Instant,
+};
 
 use engine_shared::{
     logging::{log_duration, log_metric},
@@ -11,22 +16,35 @@ use types::bytesrepr::{self, FromBytes, ToBytes};
 
 use crate::{
     transaction_source::{Readable, Writable},
-    trie::{self, Parents, Pointer, Trie, RADIX},
+    trie::{
+        self,
+        merkle_proof::{TrieMerkleProof, TrieMerkleProofStep},
+        Parents, Pointer, Trie, RADIX,
+    },
     trie_store::TrieStore,
     GAUGE_METRIC_KEY,
 };
 
 const TRIE_STORE_READ_DURATION: &str = "trie_store_read_duration";
 const TRIE_STORE_READ_GETS: &str = "trie_store_read_gets";
+const TRIE_STORE_READ_WITH_PROOF_DURATION: &str = "trie_store_read_with_proof_duration";
 const TRIE_STORE_SCAN_DURATION: &str = "trie_store_scan_duration";
 const TRIE_STORE_SCAN_GETS: &str = "trie_store_scan_gets";
 const TRIE_STORE_WRITE_DURATION: &str = "trie_store_write_duration";
+const TRIE_STORE_MARK_REACHABLE_DURATION: &str = "trie_store_mark_reachable_duration";
+const TRIE_STORE_DIFF_DURATION: &str = "trie_store_diff_duration";
+const TRIE_STORE_READ_PAGE_DURATION: &str = "trie_store_read_page_duration";
+const TRIE_STORE_READ_PAGE_GETS: &str = "trie_store_read_page_gets";
 const TRIE_STORE_WRITE_PUTS: &str = "trie_store_write_puts";
 const READ: &str = "read";
+const READ_WITH_PROOF: &str = "read_with_proof";
 const GET: &str = "get";
 const SCAN: &str = "scan";
 const WRITE: &str = "write";
 const PUT: &str = "put";
+const MARK_REACHABLE: &str = "mark_reachable";
+const DIFF: &str = "diff";
+const READ_PAGE: &str = "read_page";
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ReadResult<V> {
@@ -199,6 +217,548 @@ where
     }
 }
 
+/// Returns a value from the corresponding key at a given root in a given store, along with a
+/// [`TrieMerkleProof`] of its inclusion under that root.
+pub fn read_with_proof<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<ReadResult<TrieMerkleProof<K, V>>, E>
+where
+    K: ToBytes + FromBytes + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let path: Vec<u8> = key.to_bytes()?;
+
+    let mut depth: usize = 0;
+    let mut current: Trie<K, V> = match store.get(txn, root)? {
+        Some(root) => root,
+        None => return Ok(ReadResult::RootNotFound),
+    };
+
+    let start = Instant::now();
+    let mut proof_steps: VecDeque<TrieMerkleProofStep> = VecDeque::new();
+
+    let result = loop {
+        let pointer = match current {
+            Trie::Leaf {
+                key: leaf_key,
+                value: leaf_value,
+            } => {
+                if *key == leaf_key {
+                    let proof = TrieMerkleProof::new(leaf_key, leaf_value, proof_steps);
+                    break ReadResult::Found(proof);
+                } else {
+                    // Keys may not match in the case of a compressed path from
+                    // a Node directly to a Leaf
+                    break ReadResult::NotFound;
+                }
+            }
+            Trie::Node { pointer_block } => {
+                let hole_index: u8 = {
+                    assert!(depth < path.len(), "depth must be < {}", path.len());
+                    path[depth]
+                };
+                let pointer = match pointer_block[usize::from(hole_index)] {
+                    Some(pointer) => pointer,
+                    None => break ReadResult::NotFound,
+                };
+                let indexed_pointers_with_hole = pointer_block[..]
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != usize::from(hole_index))
+                    .filter_map(|(index, maybe_pointer)| {
+                        maybe_pointer.map(|pointer| (index as u8, pointer))
+                    })
+                    .collect();
+                proof_steps.push_front(TrieMerkleProofStep::node(
+                    hole_index,
+                    indexed_pointers_with_hole,
+                ));
+                depth += 1;
+                pointer
+            }
+            Trie::Extension { affix, pointer } => {
+                let sub_path = &path[depth..depth + affix.len()];
+                if sub_path != affix.as_slice() {
+                    break ReadResult::NotFound;
+                }
+                depth += affix.len();
+                proof_steps.push_front(TrieMerkleProofStep::extension(affix));
+                pointer
+            }
+        };
+        current = match store.get(txn, pointer.hash())? {
+            Some(next) => next,
+            None => panic!(
+                "No trie value at key: {:?} (reading from key: {:?})",
+                pointer.hash(),
+                key
+            ),
+        };
+    };
+
+    log_duration(
+        correlation_id,
+        TRIE_STORE_READ_WITH_PROOF_DURATION,
+        READ_WITH_PROOF,
+        start.elapsed(),
+    );
+    Ok(result)
+}
+
+/// Adds the hashes of all trie elements reachable from the given roots in a given store, including
+/// the roots themselves, to `reachable`.
+///
+/// Subtries whose hash is already in `reachable` are not visited again, and leaves are never read
+/// from the store.
+pub fn mark_reachable<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    roots: &[Blake2bHash],
+    reachable: &mut HashSet<Blake2bHash>,
+) -> Result<(), E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    let start = Instant::now();
+
+    let mut to_visit: Vec<Blake2bHash> = roots.to_vec();
+
+    while let Some(hash) = to_visit.pop() {
+        if !reachable.insert(hash) {
+            continue;
+        }
+        let pointers: Vec<Pointer> = match store.get(txn, &hash)? {
+            Some(Trie::Leaf { .. }) => continue,
+            Some(Trie::Node { pointer_block }) => {
+                pointer_block[..].iter().flatten().cloned().collect()
+            }
+            Some(Trie::Extension { pointer, .. }) => vec![pointer],
+            None => panic!("No trie value at key: {:?}", hash),
+        };
+        for pointer in pointers {
+            match pointer {
+                Pointer::LeafPointer(leaf_hash) => {
+                    reachable.insert(leaf_hash);
+                }
+                Pointer::NodePointer(node_hash) => to_visit.push(node_hash),
+            }
+        }
+    }
+
+    log_duration(
+        correlation_id,
+        TRIE_STORE_MARK_REACHABLE_DURATION,
+        MARK_REACHABLE,
+        start.elapsed(),
+    );
+    Ok(())
+}
+
+/// A difference between the entries stored under two roots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffEntry<K, V> {
+    /// An entry which is only stored under the second root.
+    Added { key: K, value: V },
+    /// An entry which is only stored under the first root.
+    Removed { key: K, value: V },
+    /// A key which is stored under both roots, with different values.
+    Changed { key: K, old_value: V, new_value: V },
+}
+
+impl<K, V> DiffEntry<K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            DiffEntry::Added { key, .. }
+            | DiffEntry::Removed { key, .. }
+            | DiffEntry::Changed { key, .. } => key,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DiffResult<K, V> {
+    Success(Vec<DiffEntry<K, V>>),
+    RootNotFound(Blake2bHash),
+}
+
+/// A subtrie compared by [`diff`]: either a stored trie, or the part of an extension which
+/// remains after some of its affix has been consumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiffBranch {
+    Stored(Blake2bHash),
+    Affix(Vec<u8>, Blake2bHash),
+}
+
+impl DiffBranch {
+    fn hash(&self) -> &Blake2bHash {
+        match self {
+            DiffBranch::Stored(hash) | DiffBranch::Affix(_, hash) => hash,
+        }
+    }
+}
+
+/// A [`DiffBranch`] expanded by one level, with extensions behaving as nodes with a single child.
+enum ExpandedBranch<K, V> {
+    Leaf(K, V),
+    Children(Vec<(u8, DiffBranch)>),
+}
+
+/// The leaves of two subtries, paired by the serialized form of their keys.
+type PairedLeaves<K, V> = BTreeMap<Vec<u8>, (Option<(K, V)>, Option<(K, V)>)>;
+
+enum DiffWork {
+    Compare(DiffBranch, DiffBranch),
+    Removed(DiffBranch),
+    Added(DiffBranch),
+}
+
+fn affix_children(affix: &[u8], hash: Blake2bHash) -> Vec<(u8, DiffBranch)> {
+    let (first, rest) = affix
+        .split_first()
+        .expect("extension affix should not be empty");
+    let branch = if rest.is_empty() {
+        DiffBranch::Stored(hash)
+    } else {
+        DiffBranch::Affix(rest.to_vec(), hash)
+    };
+    vec![(*first, branch)]
+}
+
+fn expand_branch<K, V, T, S, E>(
+    txn: &T,
+    store: &S,
+    branch: &DiffBranch,
+) -> Result<ExpandedBranch<K, V>, E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    let hash = match branch {
+        DiffBranch::Affix(affix, hash) => {
+            return Ok(ExpandedBranch::Children(affix_children(affix, *hash)))
+        }
+        DiffBranch::Stored(hash) => hash,
+    };
+    match store.get(txn, hash)? {
+        Some(Trie::Leaf { key, value }) => Ok(ExpandedBranch::Leaf(key, value)),
+        Some(Trie::Node { pointer_block }) => Ok(ExpandedBranch::Children(
+            pointer_block[..]
+                .iter()
+                .enumerate()
+                .filter_map(|(index, maybe_pointer)| {
+                    maybe_pointer.map(|pointer| (index as u8, DiffBranch::Stored(*pointer.hash())))
+                })
+                .collect(),
+        )),
+        Some(Trie::Extension { affix, pointer }) => Ok(ExpandedBranch::Children(affix_children(
+            &affix,
+            *pointer.hash(),
+        ))),
+        None => panic!("No trie value at key: {:?}", hash),
+    }
+}
+
+/// Returns the leaves under `branch`, ordered by key.
+fn collect_leaves<K, V, T, S, E>(txn: &T, store: &S, branch: DiffBranch) -> Result<Vec<(K, V)>, E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    let mut leaves = Vec::new();
+    let mut to_visit = vec![DiffBranch::Stored(*branch.hash())];
+    while let Some(branch) = to_visit.pop() {
+        match expand_branch::<K, V, T, S, E>(txn, store, &branch)? {
+            ExpandedBranch::Leaf(key, value) => leaves.push((key, value)),
+            ExpandedBranch::Children(children) => to_visit.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .map(|(_, child)| DiffBranch::Stored(*child.hash())),
+            ),
+        }
+    }
+    Ok(leaves)
+}
+
+/// Returns the differences between the entries stored under `old_root` and under `new_root`,
+/// ordered by key.
+///
+/// Both tries are walked together, skipping every pair of subtries with matching hashes.
+pub fn diff<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    old_root: &Blake2bHash,
+    new_root: &Blake2bHash,
+) -> Result<DiffResult<K, V>, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    for root in &[old_root, new_root] {
+        let maybe_root: Option<Trie<K, V>> = store.get(txn, root)?;
+        if maybe_root.is_none() {
+            return Ok(DiffResult::RootNotFound(**root));
+        }
+    }
+
+    let start = Instant::now();
+
+    let mut entries: Vec<DiffEntry<K, V>> = Vec::new();
+    let mut to_do = vec![DiffWork::Compare(
+        DiffBranch::Stored(*old_root),
+        DiffBranch::Stored(*new_root),
+    )];
+
+    while let Some(work) = to_do.pop() {
+        let (old_branch, new_branch) = match work {
+            DiffWork::Compare(old_branch, new_branch) => (old_branch, new_branch),
+            DiffWork::Removed(branch) => {
+                for (key, value) in collect_leaves::<K, V, T, S, E>(txn, store, branch)? {
+                    entries.push(DiffEntry::Removed { key, value });
+                }
+                continue;
+            }
+            DiffWork::Added(branch) => {
+                for (key, value) in collect_leaves::<K, V, T, S, E>(txn, store, branch)? {
+                    entries.push(DiffEntry::Added { key, value });
+                }
+                continue;
+            }
+        };
+
+        if old_branch == new_branch {
+            continue;
+        }
+
+        match (
+            expand_branch::<K, V, T, S, E>(txn, store, &old_branch)?,
+            expand_branch::<K, V, T, S, E>(txn, store, &new_branch)?,
+        ) {
+            (ExpandedBranch::Children(old_children), ExpandedBranch::Children(new_children)) => {
+                let mut paired: BTreeMap<u8, (Option<DiffBranch>, Option<DiffBranch>)> =
+                    BTreeMap::new();
+                for (index, child) in old_children {
+                    paired.entry(index).or_default().0 = Some(child);
+                }
+                for (index, child) in new_children {
+                    paired.entry(index).or_default().1 = Some(child);
+                }
+                // Pushed in reverse, so that entries are produced in key order.
+                for (_, pair) in paired.into_iter().rev() {
+                    match pair {
+                        (Some(old_child), Some(new_child)) => {
+                            to_do.push(DiffWork::Compare(old_child, new_child))
+                        }
+                        (Some(old_child), None) => to_do.push(DiffWork::Removed(old_child)),
+                        (None, Some(new_child)) => to_do.push(DiffWork::Added(new_child)),
+                        (None, None) => unreachable!("each index has at least one child"),
+                    }
+                }
+            }
+            (old_expanded, new_expanded) => {
+                // At least one side is a leaf, so the subtries are small enough to compare
+                // leaf by leaf.
+                let old_leaves = match old_expanded {
+                    ExpandedBranch::Leaf(key, value) => vec![(key, value)],
+                    ExpandedBranch::Children(_) => {
+                        collect_leaves::<K, V, T, S, E>(txn, store, old_branch)?
+                    }
+                };
+                let new_leaves = match new_expanded {
+                    ExpandedBranch::Leaf(key, value) => vec![(key, value)],
+                    ExpandedBranch::Children(_) => {
+                        collect_leaves::<K, V, T, S, E>(txn, store, new_branch)?
+                    }
+                };
+                let mut paired: PairedLeaves<K, V> = BTreeMap::new();
+                for leaf in old_leaves {
+                    let key_bytes = leaf.0.to_bytes()?;
+                    paired.entry(key_bytes).or_default().0 = Some(leaf);
+                }
+                for leaf in new_leaves {
+                    let key_bytes = leaf.0.to_bytes()?;
+                    paired.entry(key_bytes).or_default().1 = Some(leaf);
+                }
+                for (_, pair) in paired {
+                    match pair {
+                        (Some((key, old_value)), Some((_, new_value))) => {
+                            if old_value != new_value {
+                                entries.push(DiffEntry::Changed {
+                                    key,
+                                    old_value,
+                                    new_value,
+                                })
+                            }
+                        }
+                        (Some((key, value)), None) => {
+                            entries.push(DiffEntry::Removed { key, value })
+                        }
+                        (None, Some((key, value))) => entries.push(DiffEntry::Added { key, value }),
+                        (None, None) => unreachable!("each key has at least one leaf"),
+                    }
+                }
+            }
+        }
+    }
+
+    log_duration(
+        correlation_id,
+        TRIE_STORE_DIFF_DURATION,
+        DIFF,
+        start.elapsed(),
+    );
+
+    Ok(DiffResult::Success(entries))
+}
+
+/// A page of the entries stored under a root, ordered by their serialized keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysPage<K, V> {
+    pub entries: Vec<(K, V)>,
+    /// Whether further entries follow the last one of this page.
+    pub has_more: bool,
+}
+
+/// Returns `true` if the subtrie at `path` may hold keys which start with `prefix`.
+fn path_matches_prefix(path: &[u8], prefix: &[u8]) -> bool {
+    path.starts_with(prefix) || prefix.starts_with(path)
+}
+
+/// Returns `true` if the subtrie at `path` may hold keys which follow `start_after`.
+fn path_may_follow(path: &[u8], start_after: &[u8]) -> bool {
+    let length = cmp::min(path.len(), start_after.len());
+    path[..length] >= start_after[..length]
+}
+
+/// Returns up to `limit` entries stored under `root` whose serialized keys start with `prefix`
+/// and, if given, follow `start_after`.
+///
+/// Subtries which can't hold such keys are skipped, so that paging through a large trie doesn't
+/// require reading the subtries of previous pages again.
+pub fn read_page<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    prefix: &[u8],
+    start_after: Option<&[u8]>,
+    limit: usize,
+) -> Result<ReadResult<KeysPage<K, V>>, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let maybe_root: Option<Trie<K, V>> = store.get(txn, root)?;
+    if maybe_root.is_none() {
+        return Ok(ReadResult::RootNotFound);
+    }
+
+    let start = Instant::now();
+    let mut get_counter: i32 = 0;
+
+    let mut entries: Vec<(K, V)> = Vec::new();
+    let mut has_more = false;
+    let mut to_visit: Vec<(Blake2bHash, Vec<u8>)> = vec![(*root, Vec::new())];
+
+    while let Some((hash, path)) = to_visit.pop() {
+        let trie: Trie<K, V> = match store.get(txn, &hash)? {
+            Some(trie) => trie,
+            None => panic!("No trie value at key: {:?}", hash),
+        };
+        get_counter += 1;
+
+        let children: Vec<(Pointer, Vec<u8>)> = match trie {
+            Trie::Leaf { key, value } => {
+                let key_bytes = key.to_bytes()?;
+                let follows = start_after.map_or(true, |start_after| key_bytes[..] > *start_after);
+                if key_bytes.starts_with(prefix) && follows {
+                    if entries.len() == limit {
+                        has_more = true;
+                        break;
+                    }
+                    entries.push((key, value));
+                }
+                continue;
+            }
+            Trie::Node { pointer_block } => pointer_block[..]
+                .iter()
+                .enumerate()
+                .filter_map(|(index, maybe_pointer)| {
+                    maybe_pointer.map(|pointer| {
+                        let mut child_path = path.clone();
+                        child_path.push(index as u8);
+                        (pointer, child_path)
+                    })
+                })
+                .collect(),
+            Trie::Extension { affix, pointer } => {
+                let mut child_path = path;
+                child_path.extend(affix);
+                vec![(pointer, child_path)]
+            }
+        };
+        // Pushed in reverse, so that entries are visited in key order.
+        for (pointer, child_path) in children.into_iter().rev() {
+            if !path_matches_prefix(&child_path, prefix) {
+                continue;
+            }
+            if let Some(start_after) = start_after {
+                if !path_may_follow(&child_path, start_after) {
+                    continue;
+                }
+            }
+            to_visit.push((*pointer.hash(), child_path));
+        }
+    }
+
+    log_metric(
+        correlation_id,
+        TRIE_STORE_READ_PAGE_GETS,
+        READ_PAGE,
+        GAUGE_METRIC_KEY,
+        f64::from(get_counter),
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_READ_PAGE_DURATION,
+        READ_PAGE,
+        start.elapsed(),
+    );
+
+    Ok(ReadResult::Found(KeysPage { entries, has_more }))
+}
+
 struct TrieScan<K, V> {
     tip: Trie<K, V>,
     parents: Parents<K, V>,
@@ -902,7 +1462,6 @@ where
 /// Returns the iterator over the keys in the subtrie matching `prefix`.
 ///
 /// The root should be the apex of the trie.
-#[allow(dead_code)]
 pub fn keys_with_prefix<'a, 'b, K, V, T, S>(
     _correlation_id: CorrelationId,
     txn: &'b T,
@@ -0,0 +1,179 @@
+//! This module contains tests for [`diff`](operations::diff).
+//!
+//! A sequence of state roots is built by writing, updating and adding leaves one at a time, and
+//! the diff between every pair of those roots is checked against one computed from the full
+//! contents of both states.
+
+use std::collections::BTreeMap;
+
+use super::*;
+use crate::trie_store::operations::{DiffEntry, DiffResult};
+
+fn leaf_pairs(leaves: &[TestTrie]) -> Vec<(TestKey, TestValue)> {
+    leaves
+        .iter()
+        .map(|leaf| match leaf {
+            Trie::Leaf { key, value } => (*key, *value),
+            _ => panic!("leaves should contain only leaves"),
+        })
+        .collect()
+}
+
+fn test_pairs() -> Vec<(TestKey, TestValue)> {
+    let mut pairs = leaf_pairs(&TEST_LEAVES);
+    pairs.extend(leaf_pairs(&TEST_LEAVES_UPDATED));
+    pairs.extend(leaf_pairs(&TEST_LEAVES_ADJACENTS));
+    pairs
+}
+
+fn contents<T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+) -> Result<BTreeMap<TestKey, TestValue>, E>
+where
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let keys = operations::keys::<TestKey, TestValue, _, _>(correlation_id, txn, store, root)
+        .collect::<Result<Vec<TestKey>, S::Error>>()?;
+    let mut ret = BTreeMap::new();
+    for key in keys {
+        match read::<_, _, _, _, E>(correlation_id, txn, store, root, &key)? {
+            ReadResult::Found(value) => {
+                ret.insert(key, value);
+            }
+            _ => panic!("should find a value for {:?}", key),
+        }
+    }
+    Ok(ret)
+}
+
+fn expected_diff(
+    old_contents: &BTreeMap<TestKey, TestValue>,
+    new_contents: &BTreeMap<TestKey, TestValue>,
+) -> Vec<DiffEntry<TestKey, TestValue>> {
+    let mut keys: Vec<&TestKey> = old_contents.keys().chain(new_contents.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter_map(|key| match (old_contents.get(key), new_contents.get(key)) {
+            (Some(old_value), Some(new_value)) if old_value != new_value => {
+                Some(DiffEntry::Changed {
+                    key: *key,
+                    old_value: *old_value,
+                    new_value: *new_value,
+                })
+            }
+            (Some(value), None) => Some(DiffEntry::Removed {
+                key: *key,
+                value: *value,
+            }),
+            (None, Some(value)) => Some(DiffEntry::Added {
+                key: *key,
+                value: *value,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_diffs<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root: &Blake2bHash,
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut roots = vec![*empty_root];
+    roots.extend(write_pairs::<_, _, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        empty_root,
+        &test_pairs(),
+    )?);
+
+    let txn = environment.create_read_txn()?;
+    let all_contents = roots
+        .iter()
+        .map(|root| contents::<_, _, E>(correlation_id, &txn, store, root))
+        .collect::<Result<Vec<_>, E>>()?;
+
+    for (old_root, old_contents) in roots.iter().zip(&all_contents) {
+        for (new_root, new_contents) in roots.iter().zip(&all_contents) {
+            let actual = operations::diff::<TestKey, TestValue, _, _, E>(
+                correlation_id,
+                &txn,
+                store,
+                old_root,
+                new_root,
+            )?;
+            let expected = DiffResult::Success(expected_diff(old_contents, new_contents));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_diffs_between_roots_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_0_leaf_trie().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    check_diffs::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_diffs_between_roots_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_0_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    check_diffs::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_diff_with_unknown_root_returns_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let unknown_root = Blake2bHash::new(b"unknown");
+
+    let txn = context.environment.create_read_txn().unwrap();
+    for (old_root, new_root) in &[(root_hash, unknown_root), (unknown_root, root_hash)] {
+        let result = operations::diff::<TestKey, TestValue, _, _, in_memory::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            old_root,
+            new_root,
+        )
+        .unwrap();
+        assert_eq!(result, DiffResult::RootNotFound(unknown_root));
+    }
+    txn.commit().unwrap();
+}
@@ -0,0 +1,141 @@
+//! This module contains tests for [`read_page`](operations::read_page).
+//!
+//! A trie is paged through for several prefixes and page sizes, and the pages joined together
+//! must hold exactly the matching entries, in key order.
+
+use super::*;
+use crate::trie_store::operations::KeysPage;
+
+const TEST_PREFIXES: &[&[u8]] = &[&[], &[0], &[0, 0], &[0, 0, 0, 0, 0], &[0, 1], &[1]];
+
+fn leaf_pairs(leaves: &[TestTrie]) -> Vec<(TestKey, TestValue)> {
+    leaves
+        .iter()
+        .map(|leaf| match leaf {
+            Trie::Leaf { key, value } => (*key, *value),
+            _ => panic!("leaves should contain only leaves"),
+        })
+        .collect()
+}
+
+fn test_pairs() -> Vec<(TestKey, TestValue)> {
+    let mut pairs = leaf_pairs(&TEST_LEAVES);
+    pairs.extend(leaf_pairs(&TEST_LEAVES_ADJACENTS));
+    pairs
+}
+
+fn check_pages<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root: &Blake2bHash,
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let pairs = test_pairs();
+    let root =
+        *write_pairs::<_, _, _, _, E>(correlation_id, environment, store, empty_root, &pairs)?
+            .last()
+            .unwrap();
+
+    let txn = environment.create_read_txn()?;
+
+    for prefix in TEST_PREFIXES {
+        let expected = {
+            let mut tmp: Vec<(TestKey, TestValue)> = pairs
+                .iter()
+                .filter(|(key, _)| key.0.starts_with(prefix))
+                .cloned()
+                .collect();
+            tmp.sort_by_key(|(key, _)| *key);
+            tmp
+        };
+
+        for limit in 1..=pairs.len() + 1 {
+            let mut actual: Vec<(TestKey, TestValue)> = Vec::new();
+            let mut start_after: Option<Vec<u8>> = None;
+            loop {
+                let page = match operations::read_page::<_, _, _, _, E>(
+                    correlation_id,
+                    &txn,
+                    store,
+                    &root,
+                    prefix,
+                    start_after.as_deref(),
+                    limit,
+                )? {
+                    ReadResult::Found(page) => page,
+                    _ => panic!("should find a page"),
+                };
+                assert!(page.entries.len() <= limit);
+                let KeysPage { entries, has_more } = page;
+                if let Some((last_key, _)) = entries.last() {
+                    start_after = Some(last_key.to_bytes()?);
+                }
+                actual.extend(entries);
+                if !has_more {
+                    break;
+                }
+            }
+            assert_eq!(actual, expected, "prefix: {:?}, limit: {}", prefix, limit);
+        }
+    }
+
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_pages_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_0_leaf_trie().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    check_pages::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_pages_had_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_0_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    check_pages::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_page_of_unknown_root_is_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let (_, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let result = operations::read_page::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &txn,
+        &context.store,
+        &Blake2bHash::new(b"unknown"),
+        &[],
+        None,
+        1,
+    )
+    .unwrap();
+    assert_eq!(result, ReadResult::RootNotFound);
+    txn.commit().unwrap();
+}
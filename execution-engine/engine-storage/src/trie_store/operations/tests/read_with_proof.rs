@@ -0,0 +1,141 @@
+//! This module contains tests for [`read_with_proof`](operations::read_with_proof).
+//!
+//! Each of the partial test tries is checked for proofs of the leaves it contains, which must
+//! recompute to the trie's root hash, and for the absence of proofs of the leaves it does not.
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie::merkle_proof::TrieMerkleProof,
+};
+
+fn check_proofs<'a, K, V, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    present: &[Trie<K, V>],
+    absent: &[Trie<K, V>],
+) -> Result<(), E>
+where
+    K: ToBytes + FromBytes + Eq + std::fmt::Debug + Clone,
+    V: ToBytes + FromBytes + Eq + std::fmt::Debug + Clone,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let txn: R::ReadTransaction = environment.create_read_txn()?;
+
+    for leaf in present {
+        if let Trie::Leaf { key, value } = leaf {
+            let proof = match operations::read_with_proof::<K, V, _, _, E>(
+                correlation_id,
+                &txn,
+                store,
+                root,
+                key,
+            )? {
+                ReadResult::Found(proof) => proof,
+                _ => panic!("should find a proof for {:?}", key),
+            };
+            assert_eq!(proof.key(), key);
+            assert_eq!(proof.value(), value);
+            assert_eq!(proof.compute_state_hash()?, *root);
+            assert!(proof.verify(root)?);
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+
+    for leaf in absent {
+        if let Trie::Leaf { key, .. } = leaf {
+            let result = operations::read_with_proof::<K, V, _, _, E>(
+                correlation_id,
+                &txn,
+                store,
+                root,
+                key,
+            )?;
+            assert_eq!(result, ReadResult::NotFound);
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_proofs_from_n_leaf_partial_trie_had_expected_results() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        check_proofs::<_, _, _, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_proofs_from_n_leaf_partial_trie_had_expected_results() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        check_proofs::<_, _, _, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn tampered_proof_should_not_verify() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let txn = context.environment.create_read_txn().unwrap();
+
+    for leaf in TEST_LEAVES.iter() {
+        let key = leaf.key().unwrap();
+        let proof = match operations::read_with_proof::<TestKey, TestValue, _, _, in_memory::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            &root_hash,
+            key,
+        )
+        .unwrap()
+        {
+            ReadResult::Found(proof) => proof,
+            _ => panic!("should find a proof for {:?}", key),
+        };
+        assert!(proof.verify(&root_hash).unwrap());
+
+        let tampered =
+            TrieMerkleProof::new(*key, TestValue(*b"forged"), proof.proof_steps().clone());
+        assert!(!tampered.verify(&root_hash).unwrap());
+    }
+
+    txn.commit().unwrap();
+}
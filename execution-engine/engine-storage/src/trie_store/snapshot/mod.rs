@@ -0,0 +1,377 @@
+//! Export and import of every [`Trie`] reachable from a state root.
+//!
+//! A snapshot is a stream consisting of a header followed by chunks.
+//!
+//! The header is [`MAGIC`], followed by the format version as a little-endian `u32` and the
+//! 32-byte state root hash.
+//!
+//! Each chunk is the length of its payload as a little-endian `u32`, followed by the payload and
+//! the 32-byte BLAKE2b checksum of the payload.  A payload is a `bytesrepr`-serialized
+//! `Vec<Vec<u8>>` holding serialized tries, and is at most [`MAX_CHUNK_PAYLOAD_LENGTH`] bytes long.
+//! A chunk holding no tries ends the snapshot.
+//!
+//! Tries are written children first, so the state root is always the last trie of a snapshot, and
+//! every trie read during import refers only to tries which have already been imported.
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::HashSet,
+    fmt,
+    io::{self, Read, Write},
+    time::Instant,
+};
+
+use engine_shared::{
+    logging::log_duration,
+    newtypes::{Blake2bHash, CorrelationId, BLAKE2B_DIGEST_LENGTH},
+};
+use types::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
+
+use crate::{
+    transaction_source::{Readable, Transaction, TransactionSource},
+    trie::{Pointer, Trie},
+    trie_store::TrieStore,
+};
+
+/// The first bytes of every snapshot.
+pub const MAGIC: [u8; 8] = *b"CLTRIESS";
+
+/// The version of the snapshot format written by [`export`].
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// The number of tries per chunk used if no other value is given.
+pub const DEFAULT_CHUNK_SIZE: usize = 1024;
+
+/// The maximum length in bytes of a chunk's payload.  Larger chunks are split on export and
+/// rejected on import before their payload is allocated.
+pub const MAX_CHUNK_PAYLOAD_LENGTH: usize = 64 * 1024 * 1024;
+
+const SNAPSHOT_EXPORT_DURATION: &str = "snapshot_export_duration";
+const SNAPSHOT_IMPORT_DURATION: &str = "snapshot_import_duration";
+const EXPORT: &str = "export";
+const IMPORT: &str = "import";
+
+/// An error which can occur while exporting or importing a snapshot.
+#[derive(Debug)]
+pub enum Error<E> {
+    Io(io::Error),
+    BytesRepr(bytesrepr::Error),
+    Storage(E),
+    InvalidMagic,
+    UnsupportedVersion(u32),
+    ChecksumMismatch {
+        chunk: u64,
+    },
+    ChunkTooLarge {
+        chunk: u64,
+        length: usize,
+    },
+    RootNotFound(Blake2bHash),
+    MissingTrie(Blake2bHash),
+    RootMismatch {
+        expected: Blake2bHash,
+        actual: Option<Blake2bHash>,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::BytesRepr(error) => write!(f, "{}", error),
+            Error::Storage(error) => write!(f, "{}", error),
+            Error::InvalidMagic => write!(f, "Not a snapshot"),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "Unsupported snapshot format version: {}", version)
+            }
+            Error::ChecksumMismatch { chunk } => write!(f, "Checksum mismatch in chunk {}", chunk),
+            Error::ChunkTooLarge { chunk, length } => write!(
+                f,
+                "Chunk {} is too large: {} bytes, maximum: {} bytes",
+                chunk, length, MAX_CHUNK_PAYLOAD_LENGTH
+            ),
+            Error::RootNotFound(root) => write!(f, "Root not found: {}", root),
+            Error::MissingTrie(hash) => write!(f, "Missing trie: {}", hash),
+            Error::RootMismatch { expected, actual } => write!(
+                f,
+                "Root mismatch: expected: {}, actual: {:?}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for Error<E> {}
+
+impl<E> From<io::Error> for Error<E> {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl<E> From<bytesrepr::Error> for Error<E> {
+    fn from(error: bytesrepr::Error) -> Self {
+        Error::BytesRepr(error)
+    }
+}
+
+/// A summary of an exported or imported snapshot.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SnapshotInfo {
+    pub root: Blake2bHash,
+    pub tries: u64,
+    pub chunks: u64,
+}
+
+fn child_hashes<K, V>(trie: &Trie<K, V>) -> Vec<Blake2bHash> {
+    match trie {
+        Trie::Leaf { .. } => Vec::new(),
+        Trie::Node { pointer_block } => pointer_block[..]
+            .iter()
+            .flatten()
+            .map(Pointer::hash)
+            .cloned()
+            .collect(),
+        Trie::Extension { pointer, .. } => vec![*pointer.hash()],
+    }
+}
+
+fn write_chunk<W: Write, E>(writer: &mut W, tries: &[Vec<u8>]) -> Result<(), Error<E>> {
+    let payload = tries.to_vec().to_bytes()?;
+    let checksum = Blake2bHash::new(&payload);
+    let payload_length = payload.len() as u32;
+    writer.write_all(&payload_length.to_le_bytes())?;
+    writer.write_all(&payload)?;
+    writer.write_all(&checksum.value())?;
+    Ok(())
+}
+
+fn read_chunk<R: Read, E>(reader: &mut R, chunk: u64) -> Result<Vec<Vec<u8>>, Error<E>> {
+    let mut payload_length = [0u8; 4];
+    reader.read_exact(&mut payload_length)?;
+    let length = u32::from_le_bytes(payload_length) as usize;
+    if length > MAX_CHUNK_PAYLOAD_LENGTH {
+        return Err(Error::ChunkTooLarge { chunk, length });
+    }
+    let mut payload = vec![0u8; length];
+    reader.read_exact(&mut payload)?;
+    let mut checksum = [0u8; BLAKE2B_DIGEST_LENGTH];
+    reader.read_exact(&mut checksum)?;
+    if Blake2bHash::new(&payload) != Blake2bHash::from(checksum) {
+        return Err(Error::ChecksumMismatch { chunk });
+    }
+    Ok(bytesrepr::deserialize(payload)?)
+}
+
+/// Writes every trie reachable from `root` in `store` to `writer`, in chunks of at most
+/// `chunk_size` tries and [`MAX_CHUNK_PAYLOAD_LENGTH`] bytes.
+pub fn export<'a, K, V, R, S, W>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    chunk_size: usize,
+    mut writer: W,
+) -> Result<SnapshotInfo, Error<S::Error>>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    W: Write,
+{
+    enum Visit {
+        Enter(Blake2bHash),
+        Exit(Vec<u8>),
+    }
+
+    let start = Instant::now();
+    let chunk_size = chunk_size.max(1);
+
+    let txn = environment
+        .create_read_txn()
+        .map_err(|error| Error::Storage(error.into()))?;
+
+    let maybe_root: Option<Trie<K, V>> = store.get(&txn, root).map_err(Error::Storage)?;
+    if maybe_root.is_none() {
+        return Err(Error::RootNotFound(*root));
+    }
+
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&SNAPSHOT_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&root.value())?;
+
+    let mut info = SnapshotInfo {
+        root: *root,
+        tries: 0,
+        chunks: 0,
+    };
+    let mut visited: HashSet<Blake2bHash> = HashSet::new();
+    let mut to_visit: Vec<Visit> = vec![Visit::Enter(*root)];
+    let mut chunk: Vec<Vec<u8>> = Vec::with_capacity(chunk_size);
+    let mut chunk_length = U32_SERIALIZED_LENGTH;
+
+    while let Some(visit) = to_visit.pop() {
+        match visit {
+            Visit::Enter(hash) => {
+                if !visited.insert(hash) {
+                    continue;
+                }
+                let trie: Trie<K, V> = store
+                    .get(&txn, &hash)
+                    .map_err(Error::Storage)?
+                    .ok_or(Error::MissingTrie(hash))?;
+                let children = child_hashes(&trie);
+                to_visit.push(Visit::Exit(trie.to_bytes()?));
+                to_visit.extend(
+                    children
+                        .into_iter()
+                        .filter(|child| !visited.contains(child))
+                        .map(Visit::Enter),
+                );
+            }
+            Visit::Exit(trie_bytes) => {
+                let trie_length = U32_SERIALIZED_LENGTH + trie_bytes.len();
+                if U32_SERIALIZED_LENGTH + trie_length > MAX_CHUNK_PAYLOAD_LENGTH {
+                    return Err(Error::ChunkTooLarge {
+                        chunk: info.chunks,
+                        length: U32_SERIALIZED_LENGTH + trie_length,
+                    });
+                }
+                if chunk_length + trie_length > MAX_CHUNK_PAYLOAD_LENGTH {
+                    write_chunk(&mut writer, &chunk)?;
+                    info.chunks += 1;
+                    chunk.clear();
+                    chunk_length = U32_SERIALIZED_LENGTH;
+                }
+                chunk.push(trie_bytes);
+                chunk_length += trie_length;
+                info.tries += 1;
+                if chunk.len() == chunk_size {
+                    write_chunk(&mut writer, &chunk)?;
+                    info.chunks += 1;
+                    chunk.clear();
+                    chunk_length = U32_SERIALIZED_LENGTH;
+                }
+            }
+        }
+    }
+
+    if !chunk.is_empty() {
+        write_chunk(&mut writer, &chunk)?;
+        info.chunks += 1;
+    }
+    write_chunk(&mut writer, &[])?;
+    writer.flush()?;
+
+    txn.commit().map_err(|error| Error::Storage(error.into()))?;
+
+    log_duration(
+        correlation_id,
+        SNAPSHOT_EXPORT_DURATION,
+        EXPORT,
+        start.elapsed(),
+    );
+
+    Ok(info)
+}
+
+/// Reads a snapshot written by [`export`] from `reader` into `store`, committing one chunk per
+/// transaction.
+///
+/// Every trie is checked to refer only to tries which are already present in `store`, and the
+/// last trie of the snapshot must hash to the root given in its header.
+///
+/// If the import fails, the chunks committed before the failure are left in `store`.  Each of
+/// their tries is stored under its own hash and only after all of its children, so they never
+/// corrupt existing state; they are merely unreachable until a state root referring to them is
+/// imported, and importing the same snapshot again overwrites them with identical values.
+pub fn import<'a, K, V, R, S, Rd>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    mut reader: Rd,
+) -> Result<SnapshotInfo, Error<S::Error>>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    Rd: Read,
+{
+    let start = Instant::now();
+
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(Error::InvalidMagic);
+    }
+
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let version = u32::from_le_bytes(version);
+    if version != SNAPSHOT_FORMAT_VERSION {
+        return Err(Error::UnsupportedVersion(version));
+    }
+
+    let mut root = [0u8; BLAKE2B_DIGEST_LENGTH];
+    reader.read_exact(&mut root)?;
+    let root = Blake2bHash::from(root);
+
+    let mut info = SnapshotInfo {
+        root,
+        tries: 0,
+        chunks: 0,
+    };
+    let mut last_hash: Option<Blake2bHash> = None;
+
+    loop {
+        let chunk = read_chunk(&mut reader, info.chunks)?;
+        if chunk.is_empty() {
+            break;
+        }
+
+        let mut txn = environment
+            .create_read_write_txn()
+            .map_err(|error| Error::Storage(error.into()))?;
+
+        for trie_bytes in chunk {
+            let hash = Blake2bHash::new(&trie_bytes);
+            let trie: Trie<K, V> = bytesrepr::deserialize(trie_bytes)?;
+            for child in child_hashes(&trie) {
+                let child_bytes = child.to_bytes()?;
+                let maybe_child = txn
+                    .read(store.handle(), &child_bytes)
+                    .map_err(|error| Error::Storage(error.into()))?;
+                if maybe_child.is_none() {
+                    return Err(Error::MissingTrie(child));
+                }
+            }
+            store.put(&mut txn, &hash, &trie).map_err(Error::Storage)?;
+            last_hash = Some(hash);
+            info.tries += 1;
+        }
+
+        txn.commit().map_err(|error| Error::Storage(error.into()))?;
+        info.chunks += 1;
+    }
+
+    if last_hash != Some(root) {
+        return Err(Error::RootMismatch {
+            expected: root,
+            actual: last_hash,
+        });
+    }
+
+    log_duration(
+        correlation_id,
+        SNAPSHOT_IMPORT_DURATION,
+        IMPORT,
+        start.elapsed(),
+    );
+
+    Ok(info)
+}
@@ -0,0 +1,235 @@
+use lmdb::DatabaseFlags;
+use tempfile::tempdir;
+
+use engine_shared::{
+    newtypes::{Blake2bHash, CorrelationId},
+    stored_value::StoredValue,
+};
+use types::{account::PublicKey, CLValue, Key};
+
+use super::{Error, SnapshotInfo, DEFAULT_CHUNK_SIZE};
+use crate::{
+    error,
+    global_state::in_memory::InMemoryGlobalState,
+    transaction_source::{
+        in_memory::InMemoryEnvironment, lmdb::LmdbEnvironment, Transaction, TransactionSource,
+    },
+    trie_store::{
+        in_memory::InMemoryTrieStore,
+        lmdb::LmdbTrieStore,
+        operations::{self, ReadResult},
+    },
+    TEST_MAP_SIZE,
+};
+
+const TEST_PAIRS_LENGTH: u8 = 50;
+
+fn create_test_pairs() -> Vec<(Key, StoredValue)> {
+    (0..TEST_PAIRS_LENGTH)
+        .map(|i| {
+            (
+                Key::Account(PublicKey::ed25519_from([i; 32])),
+                StoredValue::CLValue(CLValue::from_t(i32::from(i)).unwrap()),
+            )
+        })
+        .collect()
+}
+
+fn create_test_state() -> (InMemoryGlobalState, Blake2bHash) {
+    InMemoryGlobalState::from_pairs(CorrelationId::new(), &create_test_pairs()).unwrap()
+}
+
+fn export_test_state(chunk_size: usize) -> (Blake2bHash, SnapshotInfo, Vec<u8>) {
+    let (state, root_hash) = create_test_state();
+    let mut snapshot = Vec::new();
+    let info = super::export::<Key, StoredValue, _, _, _>(
+        CorrelationId::new(),
+        &*state.environment,
+        &*state.trie_store,
+        &root_hash,
+        chunk_size,
+        &mut snapshot,
+    )
+    .unwrap();
+    (root_hash, info, snapshot)
+}
+
+#[test]
+fn lmdb_import_of_exported_snapshot_rebuilds_state() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, exported, snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    assert_eq!(exported.root, root_hash);
+    assert_eq!(exported.chunks, 1);
+
+    let tmp_dir = tempdir().unwrap();
+    let env = LmdbEnvironment::new(&tmp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+    let store = LmdbTrieStore::new(&env, None, DatabaseFlags::empty()).unwrap();
+
+    let imported = super::import::<Key, StoredValue, _, _, _>(
+        correlation_id,
+        &env,
+        &store,
+        snapshot.as_slice(),
+    )
+    .unwrap();
+    assert_eq!(imported, exported);
+
+    let txn = env.create_read_txn().unwrap();
+    for (key, value) in create_test_pairs() {
+        let result = operations::read::<_, _, _, _, error::Error>(
+            correlation_id,
+            &txn,
+            &store,
+            &root_hash,
+            &key,
+        )
+        .unwrap();
+        assert_eq!(result, ReadResult::Found(value));
+    }
+    txn.commit().unwrap();
+
+    tmp_dir.close().unwrap();
+}
+
+#[test]
+fn in_memory_import_of_exported_snapshot_rebuilds_state() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, exported, snapshot) = export_test_state(1);
+    assert_eq!(exported.chunks, exported.tries);
+
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+
+    let imported = super::import::<Key, StoredValue, _, _, _>(
+        correlation_id,
+        &env,
+        &store,
+        snapshot.as_slice(),
+    )
+    .unwrap();
+    assert_eq!(imported, exported);
+
+    // Exporting the imported state must produce an identical snapshot.
+    let mut reexported = Vec::new();
+    super::export::<Key, StoredValue, _, _, _>(
+        correlation_id,
+        &env,
+        &store,
+        &root_hash,
+        1,
+        &mut reexported,
+    )
+    .unwrap();
+    assert_eq!(reexported, snapshot);
+}
+
+#[test]
+fn export_fails_if_root_is_unknown() {
+    let (state, _) = create_test_state();
+    let unknown_root = Blake2bHash::new(&[42u8]);
+    let result = super::export::<Key, StoredValue, _, _, _>(
+        CorrelationId::new(),
+        &*state.environment,
+        &*state.trie_store,
+        &unknown_root,
+        DEFAULT_CHUNK_SIZE,
+        Vec::new(),
+    );
+    match result {
+        Err(Error::RootNotFound(root)) => assert_eq!(root, unknown_root),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+fn import_into_fresh_store(
+    snapshot: &[u8],
+) -> Result<SnapshotInfo, Error<error::in_memory::Error>> {
+    let env = InMemoryEnvironment::new();
+    let store = InMemoryTrieStore::new(&env, None);
+    super::import::<Key, StoredValue, _, _, _>(CorrelationId::new(), &env, &store, snapshot)
+}
+
+#[test]
+fn import_fails_on_invalid_magic() {
+    let (_, _, mut snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    snapshot[0] ^= 0xff;
+    match import_into_fresh_store(&snapshot) {
+        Err(Error::InvalidMagic) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn import_fails_on_unsupported_version() {
+    let (_, _, mut snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    snapshot[super::MAGIC.len()] = 2;
+    match import_into_fresh_store(&snapshot) {
+        Err(Error::UnsupportedVersion(2)) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn import_fails_on_corrupted_chunk() {
+    let (_, _, mut snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    // The first byte of the first chunk's payload.
+    let index = super::MAGIC.len() + 4 + 32 + 4;
+    snapshot[index] ^= 0xff;
+    match import_into_fresh_store(&snapshot) {
+        Err(Error::ChecksumMismatch { chunk: 0 }) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn import_fails_on_oversized_chunk() {
+    let (_, _, mut snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    let index = super::MAGIC.len() + 4 + 32;
+    let length = super::MAX_CHUNK_PAYLOAD_LENGTH as u32 + 1;
+    snapshot[index..index + 4].copy_from_slice(&length.to_le_bytes());
+    match import_into_fresh_store(&snapshot) {
+        Err(Error::ChunkTooLarge {
+            chunk: 0,
+            length: actual,
+        }) => assert_eq!(actual, length as usize),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn import_fails_on_truncated_snapshot() {
+    let (_, _, snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    match import_into_fresh_store(&snapshot[..snapshot.len() - 1]) {
+        Err(Error::Io(_)) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn import_fails_if_root_does_not_match_header() {
+    let (_, _, mut snapshot) = export_test_state(DEFAULT_CHUNK_SIZE);
+    let index = super::MAGIC.len() + 4;
+    snapshot[index] ^= 0xff;
+    match import_into_fresh_store(&snapshot) {
+        Err(Error::RootMismatch {
+            actual: Some(_), ..
+        }) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn import_fails_if_child_trie_is_missing() {
+    let (_, _, snapshot) = export_test_state(1);
+    // Drop the first chunk, which holds a single leaf referenced by a later trie.
+    let header_length = super::MAGIC.len() + 4 + 32;
+    let mut payload_length = [0u8; 4];
+    payload_length.copy_from_slice(&snapshot[header_length..header_length + 4]);
+    let chunk_length = 4 + u32::from_le_bytes(payload_length) as usize + 32;
+    let mut truncated = snapshot[..header_length].to_vec();
+    truncated.extend_from_slice(&snapshot[header_length + chunk_length..]);
+    match import_into_fresh_store(&truncated) {
+        Err(Error::MissingTrie(_)) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -19,6 +19,9 @@ struct DeployItemData {
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<PublicKey>,
     pub deploy_hash: DeployHash,
+    pub nonce: u64,
+    pub valid_from: Option<u64>,
+    pub ttl: Option<u64>,
 }
 
 pub struct DeployItemBuilder {
@@ -215,6 +218,21 @@ impl DeployItemBuilder {
         self
     }
 
+    pub fn with_nonce(mut self, nonce: u64) -> Self {
+        self.deploy_item.nonce = nonce;
+        self
+    }
+
+    pub fn with_valid_from(mut self, valid_from: u64) -> Self {
+        self.deploy_item.valid_from = Some(valid_from);
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: u64) -> Self {
+        self.deploy_item.ttl = Some(ttl);
+        self
+    }
+
     pub fn build(self) -> DeployItem {
         DeployItem {
             address: self
@@ -232,6 +250,9 @@ impl DeployItemBuilder {
             gas_price: self.deploy_item.gas_price,
             authorization_keys: self.deploy_item.authorization_keys,
             deploy_hash: self.deploy_item.deploy_hash,
+            nonce: self.deploy_item.nonce,
+            valid_from: self.deploy_item.valid_from,
+            ttl: self.deploy_item.ttl,
         }
     }
 
@@ -18,7 +18,10 @@ use types::{
     ProtocolVersion, RuntimeArgs, URef, U512,
 };
 
-use crate::internal::{utils, WasmTestBuilder, DEFAULT_WASM_COSTS};
+use crate::internal::{
+    utils, WasmTestBuilder, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_PAYMENT_COSTS,
+    DEFAULT_REWARD_RATE, DEFAULT_WASM_COSTS,
+};
 
 /// This function allows executing the contract stored in the given `wasm_file`, while capturing the
 /// output. It is essentially the same functionality as `Executor::exec`, but the return value of
@@ -65,6 +68,11 @@ where
         let fn_store_id = AddressGenerator::new(&deploy_hash, phase);
         Rc::new(RefCell::new(fn_store_id))
     };
+    let random_bytes_generator = {
+        let random_bytes_generator =
+            AddressGenerator::new_random_bytes_generator(&deploy_hash, phase);
+        Rc::new(RefCell::new(random_bytes_generator))
+    };
     let gas_limit = Gas::new(U512::from(std::u64::MAX));
     let protocol_version = ProtocolVersion::V1_0_0;
     let correlation_id = CorrelationId::new();
@@ -85,7 +93,15 @@ where
         let mint = builder.get_mint_contract_hash();
         let pos = builder.get_mint_contract_hash();
         let standard_payment = builder.get_standard_payment_contract_hash();
-        ProtocolData::new(*DEFAULT_WASM_COSTS, mint, pos, standard_payment)
+        ProtocolData::new(
+            *DEFAULT_WASM_COSTS,
+            *DEFAULT_HOST_FUNCTION_COSTS,
+            *DEFAULT_PAYMENT_COSTS,
+            DEFAULT_REWARD_RATE,
+            mint,
+            pos,
+            standard_payment,
+        )
     };
 
     let context = RuntimeContext::new(
@@ -103,6 +119,7 @@ where
         gas_counter,
         fn_store_id,
         address_generator,
+        random_bytes_generator,
         protocol_version,
         correlation_id,
         phase,
@@ -10,11 +10,11 @@ use lazy_static::lazy_static;
 use num_traits::identities::Zero;
 
 use engine_core::engine_state::{
-    genesis::{ExecConfig, GenesisAccount, GenesisConfig},
+    genesis::{ExecConfig, GenesisAccount, GenesisConfig, QueueDelays, StakeLimits},
     run_genesis_request::RunGenesisRequest,
 };
-use engine_shared::{motes::Motes, newtypes::Blake2bHash, test_utils};
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_shared::{motes::Motes, newtypes::Blake2bHash, payment_costs::PaymentCosts, test_utils};
+use engine_wasm_prep::{host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts};
 use types::{account::PublicKey, ProtocolVersion, U512};
 
 use super::{DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE};
@@ -33,6 +33,7 @@ pub const STANDARD_PAYMENT_INSTALL_CONTRACT: &str = "standard_payment_install.wa
 pub const DEFAULT_CHAIN_NAME: &str = "gerald";
 pub const DEFAULT_GENESIS_TIMESTAMP: u64 = 0;
 pub const DEFAULT_BLOCK_TIME: u64 = 0;
+pub const DEFAULT_REWARD_RATE: u64 = 0;
 pub const MOCKED_ACCOUNT_ADDRESS: PublicKey = PublicKey::ed25519_from([48u8; 32]);
 
 pub const DEFAULT_ACCOUNT_KEY: PublicKey = DEFAULT_ACCOUNT_ADDR;
@@ -54,6 +55,8 @@ lazy_static! {
     pub static ref DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
     pub static ref DEFAULT_PAYMENT: U512 = 100_000_000.into();
     pub static ref DEFAULT_WASM_COSTS: WasmCosts = test_utils::wasm_costs_mock();
+    pub static ref DEFAULT_HOST_FUNCTION_COSTS: HostFunctionCosts = HostFunctionCosts::default();
+    pub static ref DEFAULT_PAYMENT_COSTS: PaymentCosts = PaymentCosts::default();
     pub static ref DEFAULT_EXEC_CONFIG: ExecConfig = {
         let mint_installer_bytes;
         let pos_installer_bytes;
@@ -69,6 +72,11 @@ lazy_static! {
             standard_payment_installer_bytes,
             DEFAULT_ACCOUNTS.clone(),
             *DEFAULT_WASM_COSTS,
+            *DEFAULT_HOST_FUNCTION_COSTS,
+            *DEFAULT_PAYMENT_COSTS,
+            QueueDelays::default(),
+            StakeLimits::default(),
+            DEFAULT_REWARD_RATE,
         )
     };
     pub static ref DEFAULT_GENESIS_CONFIG: GenesisConfig = {
@@ -1,12 +1,16 @@
+use engine_core::engine_state::genesis::{QueueDelays, StakeLimits};
 use engine_grpc_server::engine_server::{
     ipc::{
-        ChainSpec_ActivationPoint, ChainSpec_CostTable_WasmCosts, ChainSpec_UpgradePoint,
-        DeployCode, UpgradeRequest,
+        ChainSpec_ActivationPoint, ChainSpec_CostTable, ChainSpec_CostTable_HostFunctionCosts,
+        ChainSpec_CostTable_PaymentCosts, ChainSpec_CostTable_WasmCosts, ChainSpec_QueueDelays,
+        ChainSpec_RewardRate, ChainSpec_StakeLimits, ChainSpec_UpgradePoint, DeployCode,
+        UpgradeRequest,
     },
     state,
 };
-use engine_wasm_prep::wasm_costs::WasmCosts;
-use types::ProtocolVersion;
+use engine_shared::payment_costs::PaymentCosts;
+use engine_wasm_prep::{host_function_costs::HostFunctionCosts, wasm_costs::WasmCosts};
+use types::{ProtocolVersion, U512};
 
 pub struct UpgradeRequestBuilder {
     pre_state_hash: Vec<u8>,
@@ -14,6 +18,12 @@ pub struct UpgradeRequestBuilder {
     new_protocol_version: state::ProtocolVersion,
     upgrade_installer: DeployCode,
     new_costs: Option<ChainSpec_CostTable_WasmCosts>,
+    new_host_function_costs: Option<ChainSpec_CostTable_HostFunctionCosts>,
+    new_payment_costs: Option<ChainSpec_CostTable_PaymentCosts>,
+    new_queue_delays: Option<ChainSpec_QueueDelays>,
+    new_stake_limits: Option<ChainSpec_StakeLimits>,
+    new_reward_rate: Option<ChainSpec_RewardRate>,
+    initial_total_supply: Option<state::BigInt>,
     activation_point: ChainSpec_ActivationPoint,
 }
 
@@ -58,6 +68,40 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_host_function_costs(mut self, host_function_costs: HostFunctionCosts) -> Self {
+        self.new_host_function_costs = Some(host_function_costs.into());
+        self
+    }
+
+    pub fn with_new_payment_costs(mut self, payment_costs: PaymentCosts) -> Self {
+        self.new_payment_costs = Some(payment_costs.into());
+        self
+    }
+
+    pub fn with_new_queue_delays(mut self, queue_delays: QueueDelays) -> Self {
+        self.new_queue_delays = Some(queue_delays.into());
+        self
+    }
+
+    pub fn with_new_stake_limits(mut self, stake_limits: StakeLimits) -> Self {
+        self.new_stake_limits = Some(stake_limits.into());
+        self
+    }
+
+    pub fn with_new_reward_rate(mut self, reward_rate: u64) -> Self {
+        self.new_reward_rate = {
+            let mut ret = ChainSpec_RewardRate::new();
+            ret.set_rate(reward_rate);
+            Some(ret)
+        };
+        self
+    }
+
+    pub fn with_initial_total_supply(mut self, initial_total_supply: U512) -> Self {
+        self.initial_total_supply = Some(initial_total_supply.into());
+        self
+    }
+
     pub fn with_activation_point(mut self, rank: u64) -> Self {
         self.activation_point = {
             let mut ret = ChainSpec_ActivationPoint::new();
@@ -70,14 +114,33 @@ impl UpgradeRequestBuilder {
     pub fn build(self) -> UpgradeRequest {
         let mut upgrade_point = ChainSpec_UpgradePoint::new();
         upgrade_point.set_activation_point(self.activation_point);
-        match self.new_costs {
-            None => {}
-            Some(new_costs) => {
-                let mut cost_table =
-                    engine_grpc_server::engine_server::ipc::ChainSpec_CostTable::new();
+        if self.new_costs.is_some()
+            || self.new_host_function_costs.is_some()
+            || self.new_payment_costs.is_some()
+        {
+            let mut cost_table = ChainSpec_CostTable::new();
+            if let Some(new_costs) = self.new_costs {
                 cost_table.set_wasm(new_costs);
-                upgrade_point.set_new_costs(cost_table);
             }
+            if let Some(new_host_function_costs) = self.new_host_function_costs {
+                cost_table.set_host_functions(new_host_function_costs);
+            }
+            if let Some(new_payment_costs) = self.new_payment_costs {
+                cost_table.set_payment(new_payment_costs);
+            }
+            upgrade_point.set_new_costs(cost_table);
+        }
+        if let Some(new_queue_delays) = self.new_queue_delays {
+            upgrade_point.set_new_queue_delays(new_queue_delays);
+        }
+        if let Some(new_stake_limits) = self.new_stake_limits {
+            upgrade_point.set_new_stake_limits(new_stake_limits);
+        }
+        if let Some(new_reward_rate) = self.new_reward_rate {
+            upgrade_point.set_new_reward_rate(new_reward_rate);
+        }
+        if let Some(initial_total_supply) = self.initial_total_supply {
+            upgrade_point.set_initial_total_supply(initial_total_supply);
         }
         upgrade_point.set_protocol_version(self.new_protocol_version);
         upgrade_point.set_upgrade_installer(self.upgrade_installer);
@@ -97,6 +160,12 @@ impl Default for UpgradeRequestBuilder {
             new_protocol_version: Default::default(),
             upgrade_installer: Default::default(),
             new_costs: None,
+            new_host_function_costs: None,
+            new_payment_costs: None,
+            new_queue_delays: None,
+            new_stake_limits: None,
+            new_reward_rate: None,
+            initial_total_supply: None,
             activation_point: Default::default(),
         }
     }
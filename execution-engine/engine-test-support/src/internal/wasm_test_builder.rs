@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     convert::{TryFrom, TryInto},
     ffi::OsStr,
     fs,
@@ -21,8 +21,9 @@ use engine_core::{
 };
 use engine_grpc_server::engine_server::{
     ipc::{
-        CommitRequest, CommitResponse, GenesisResponse, QueryRequest, UpgradeRequest,
-        UpgradeResponse,
+        self, BidStateRequest, CommitRequest, CommitResponse, DistributeRewardsRequest,
+        DistributeSeigniorageRequest, GenesisResponse, QueryRequest, SlashRequest, StepRequest,
+        UnbondPayoutRequest, UpgradeRequest, UpgradeResponse,
     },
     ipc_grpc::ExecutionEngineService,
     mappings::{MappingError, TransformMap},
@@ -50,7 +51,7 @@ use types::{
     CLValue, Contract, ContractHash, ContractWasm, Key, URef, U512,
 };
 
-use crate::internal::utils;
+use crate::internal::{utils, DEFAULT_PROTOCOL_VERSION};
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
 ///
@@ -70,6 +71,8 @@ pub struct WasmTestBuilder<S> {
     engine_state: Rc<EngineState<S>>,
     /// [`ExecutionResult`] is wrapped in [`Rc`] to work around a missing [`Clone`] implementation
     exec_responses: Vec<Vec<Rc<ExecutionResult>>>,
+    /// Pairs of conflicting deploys for each exec call, only non-empty for `exec_parallel()`
+    exec_conflicts: Vec<Vec<(usize, usize)>>,
     upgrade_responses: Vec<UpgradeResponse>,
     genesis_hash: Option<Vec<u8>>,
     post_state_hash: Option<Vec<u8>>,
@@ -98,17 +101,24 @@ impl<S> WasmTestBuilder<S> {
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
-        Self::initialize_logging();
         let engine_config = EngineConfig::new()
             .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
             .with_enable_bonding(cfg!(feature = "enable-bonding"));
+        Self::new_with_config(engine_config)
+    }
+}
 
+impl InMemoryWasmTestBuilder {
+    /// Returns a builder with empty global state, using the given [`EngineConfig`].
+    pub fn new_with_config(engine_config: EngineConfig) -> Self {
+        Self::initialize_logging();
         let global_state = InMemoryGlobalState::empty().expect("should create global state");
         let engine_state = EngineState::new(global_state, engine_config);
 
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            exec_conflicts: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
@@ -121,6 +131,21 @@ impl Default for InMemoryWasmTestBuilder {
             standard_payment_hash: None,
         }
     }
+
+    pub fn new(
+        global_state: InMemoryGlobalState,
+        engine_config: EngineConfig,
+        post_state_hash: Vec<u8>,
+    ) -> Self {
+        Self::initialize_logging();
+        let engine_state = EngineState::new(global_state, engine_config);
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            genesis_hash: Some(post_state_hash.clone()),
+            post_state_hash: Some(post_state_hash),
+            ..Default::default()
+        }
+    }
 }
 
 // TODO: Deriving `Clone` for `WasmTestBuilder<S>` doesn't work correctly (unsure why), so
@@ -130,6 +155,7 @@ impl<S> Clone for WasmTestBuilder<S> {
         WasmTestBuilder {
             engine_state: Rc::clone(&self.engine_state),
             exec_responses: self.exec_responses.clone(),
+            exec_conflicts: self.exec_conflicts.clone(),
             upgrade_responses: self.upgrade_responses.clone(),
             genesis_hash: self.genesis_hash.clone(),
             post_state_hash: self.post_state_hash.clone(),
@@ -155,23 +181,6 @@ impl<S> WasmTestResult<S> {
     }
 }
 
-impl InMemoryWasmTestBuilder {
-    pub fn new(
-        global_state: InMemoryGlobalState,
-        engine_config: EngineConfig,
-        post_state_hash: Vec<u8>,
-    ) -> Self {
-        Self::initialize_logging();
-        let engine_state = EngineState::new(global_state, engine_config);
-        WasmTestBuilder {
-            engine_state: Rc::new(engine_state),
-            genesis_hash: Some(post_state_hash.clone()),
-            post_state_hash: Some(post_state_hash),
-            ..Default::default()
-        }
-    }
-}
-
 impl LmdbWasmTestBuilder {
     pub fn new_with_config<T: AsRef<OsStr> + ?Sized>(
         data_dir: &T,
@@ -198,6 +207,7 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            exec_conflicts: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
@@ -259,6 +269,7 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            exec_conflicts: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: Some(post_state_hash),
@@ -295,6 +306,7 @@ where
         WasmTestBuilder {
             engine_state: result.0.engine_state,
             exec_responses: Vec::new(),
+            exec_conflicts: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: result.0.genesis_hash,
             post_state_hash: result.0.post_state_hash,
@@ -405,6 +417,60 @@ where
         );
         self.exec_responses
             .push(exec_response.unwrap().into_iter().map(Rc::new).collect());
+        self.exec_conflicts.push(Vec::new());
+        self
+    }
+
+    /// Runs the deploys of `exec_request` in order on the latest post-state hash, each one seeing
+    /// the effects of the ones before it, and caches their combined effects for `commit()`.
+    pub fn exec_chained(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
+        let exec_request = {
+            let hash = self
+                .post_state_hash
+                .clone()
+                .expect("expected post_state_hash");
+            exec_request.parent_state_hash =
+                hash.as_slice().try_into().expect("expected a valid hash");
+            exec_request
+        };
+        let (execution_results, effect) = self
+            .engine_state
+            .run_execute_chained(CorrelationId::new(), exec_request)
+            .expect("should run chained execution");
+        self.transforms.push(effect.transforms);
+        self.exec_responses
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self.exec_conflicts.push(Vec::new());
+        self
+    }
+
+    /// Runs the deploys of `exec_request` concurrently on the latest post-state hash and caches
+    /// their results along with the pairs of deploys whose effects conflict.
+    pub fn exec_parallel(&mut self, mut exec_request: ExecuteRequest) -> &mut Self
+    where
+        S: Sync,
+    {
+        let exec_request = {
+            let hash = self
+                .post_state_hash
+                .clone()
+                .expect("expected post_state_hash");
+            exec_request.parent_state_hash =
+                hash.as_slice().try_into().expect("expected a valid hash");
+            exec_request
+        };
+        let (execution_results, conflicts) = self
+            .engine_state
+            .run_execute_parallel(CorrelationId::new(), exec_request)
+            .expect("should run parallel execution");
+        self.transforms.extend(
+            execution_results
+                .iter()
+                .map(|res| res.effect().transforms.clone()),
+        );
+        self.exec_responses
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self.exec_conflicts.push(conflicts);
         self
     }
 
@@ -450,9 +516,13 @@ where
                 commit_response
             );
         }
-        let mut commit_success = commit_response.take_success();
-        self.post_state_hash = Some(commit_success.take_poststate_hash().to_vec());
-        let bonded_validators = commit_success
+        self.update_from_commit_result(commit_response.take_success())
+    }
+
+    /// Caches the post state hash and bonded validators of a successful commit.
+    fn update_from_commit_result(&mut self, mut commit_result: ipc::CommitResult) -> &mut Self {
+        self.post_state_hash = Some(commit_result.take_poststate_hash().to_vec());
+        let bonded_validators = commit_result
             .take_bonded_validators()
             .into_iter()
             .map(TryInto::try_into)
@@ -462,6 +532,146 @@ where
         self
     }
 
+    /// Queries the bids of the validators at the latest post-state hash.
+    pub fn get_bid_state(&self) -> HashMap<PublicKey, U512> {
+        let mut bid_state_request = BidStateRequest::new();
+        bid_state_request.set_parent_state_hash(self.get_post_state_hash());
+        bid_state_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        let mut bid_state_response = self
+            .engine_state
+            .bid_state(RequestOptions::new(), bid_state_request)
+            .wait_drop_metadata()
+            .expect("should get bid state");
+        if !bid_state_response.has_success() {
+            panic!(
+                "Expected bid state success but received a failure instead: {:?}",
+                bid_state_response
+            );
+        }
+
+        bid_state_response
+            .take_success()
+            .take_bids()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
+            .unwrap()
+    }
+
+    /// Slashes validators on the latest post-state hash, expecting success.
+    pub fn slash(&mut self, slashes: BTreeMap<PublicKey, U512>) -> &mut Self {
+        let mut slash_request = SlashRequest::new();
+        slash_request.set_parent_state_hash(self.get_post_state_hash());
+        slash_request.set_slashes(slashes.into_iter().map(Into::into).collect());
+        slash_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        let mut slash_response = self
+            .engine_state
+            .slash(RequestOptions::new(), slash_request)
+            .wait_drop_metadata()
+            .expect("should slash");
+        if !slash_response.has_success() {
+            panic!(
+                "Expected slash success but received a failure instead: {:?}",
+                slash_response
+            );
+        }
+
+        self.update_from_commit_result(slash_response.take_success())
+    }
+
+    /// Distributes rewards on the latest post-state hash, expecting success.
+    pub fn distribute_rewards(&mut self, rewards: BTreeMap<PublicKey, U512>) -> &mut Self {
+        let mut distribute_rewards_request = DistributeRewardsRequest::new();
+        distribute_rewards_request.set_parent_state_hash(self.get_post_state_hash());
+        distribute_rewards_request.set_rewards(rewards.into_iter().map(Into::into).collect());
+        distribute_rewards_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        let mut distribute_rewards_response = self
+            .engine_state
+            .distribute_rewards(RequestOptions::new(), distribute_rewards_request)
+            .wait_drop_metadata()
+            .expect("should distribute rewards");
+        if !distribute_rewards_response.has_success() {
+            panic!(
+                "Expected distribute rewards success but received a failure instead: {:?}",
+                distribute_rewards_response
+            );
+        }
+
+        self.update_from_commit_result(distribute_rewards_response.take_success())
+    }
+
+    /// Pays out unbonding requests made at or before `block_time` during era `era_height` on the
+    /// latest post-state hash, expecting success.
+    pub fn unbond_payout(&mut self, era_height: u64, block_time: u64) -> &mut Self {
+        let mut unbond_payout_request = UnbondPayoutRequest::new();
+        unbond_payout_request.set_parent_state_hash(self.get_post_state_hash());
+        unbond_payout_request.set_era_height(era_height);
+        unbond_payout_request.set_block_time(block_time);
+        unbond_payout_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        let mut unbond_payout_response = self
+            .engine_state
+            .unbond_payout(RequestOptions::new(), unbond_payout_request)
+            .wait_drop_metadata()
+            .expect("should pay out unbonds");
+        if !unbond_payout_response.has_success() {
+            panic!(
+                "Expected unbond payout success but received a failure instead: {:?}",
+                unbond_payout_response
+            );
+        }
+
+        self.update_from_commit_result(unbond_payout_response.take_success())
+    }
+
+    /// Applies the bonding and unbonding requests which have matured by `block_time` on the latest
+    /// post-state hash, expecting success.
+    pub fn step(&mut self, block_time: u64) -> &mut Self {
+        let mut step_request = StepRequest::new();
+        step_request.set_parent_state_hash(self.get_post_state_hash());
+        step_request.set_block_time(block_time);
+        step_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        let mut step_response = self
+            .engine_state
+            .step(RequestOptions::new(), step_request)
+            .wait_drop_metadata()
+            .expect("should step");
+        if !step_response.has_success() {
+            panic!(
+                "Expected step success but received a failure instead: {:?}",
+                step_response
+            );
+        }
+
+        self.update_from_commit_result(step_response.take_success())
+    }
+
+    /// Mints and distributes the validators' rewards for an era on the latest post-state hash,
+    /// expecting success.
+    pub fn distribute_seigniorage(&mut self) -> &mut Self {
+        let mut distribute_seigniorage_request = DistributeSeigniorageRequest::new();
+        distribute_seigniorage_request.set_parent_state_hash(self.get_post_state_hash());
+        distribute_seigniorage_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        let mut distribute_seigniorage_response = self
+            .engine_state
+            .distribute_seigniorage(RequestOptions::new(), distribute_seigniorage_request)
+            .wait_drop_metadata()
+            .expect("should distribute seigniorage");
+        if !distribute_seigniorage_response.has_success() {
+            panic!(
+                "Expected seigniorage distribution success but received a failure instead: {:?}",
+                distribute_seigniorage_response
+            );
+        }
+
+        self.update_from_commit_result(distribute_seigniorage_response.take_success())
+    }
+
     pub fn upgrade_with_upgrade_request(
         &mut self,
         upgrade_request: &mut UpgradeRequest,
@@ -580,6 +790,10 @@ where
         self.exec_responses.get(index)
     }
 
+    pub fn get_exec_conflicts(&self, index: usize) -> Option<&Vec<(usize, usize)>> {
+        self.exec_conflicts.get(index)
+    }
+
     pub fn get_exec_responses_count(&self) -> usize {
         self.exec_responses.len()
     }
@@ -618,6 +832,41 @@ where
             .expect("should parse balance into a U512")
     }
 
+    /// Returns the total supply of motes recorded by the mint contract.
+    pub fn get_total_supply(&self) -> U512 {
+        let mint_contract = self.get_mint_contract_hash().into();
+        self.query(None, mint_contract, &[mint::TOTAL_SUPPLY_KEY])
+            .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+            .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+            .expect("should find total supply")
+    }
+
+    /// Asserts that the balances of all purses add up to the total supply recorded by the mint
+    /// contract.
+    pub fn assert_total_supply_invariant(&self) -> &Self {
+        let mint_contract = self
+            .get_contract(self.get_mint_contract_hash())
+            .expect("should have mint contract");
+        // Apart from the total supply, the mint's named keys hold the balance URef of each purse.
+        let balances_total = mint_contract
+            .named_keys()
+            .iter()
+            .filter(|(name, _)| name.as_str() != mint::TOTAL_SUPPLY_KEY)
+            .map(|(_, balance_key)| {
+                self.query(None, *balance_key, &[])
+                    .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+                    .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+                    .expect("should parse balance into a U512")
+            })
+            .fold(U512::zero(), |sum, balance: U512| sum + balance);
+        assert_eq!(
+            balances_total,
+            self.get_total_supply(),
+            "the balances of all purses should add up to the total supply"
+        );
+        self
+    }
+
     pub fn get_account(&self, public_key: PublicKey) -> Option<Account> {
         let account_value = self
             .query(None, Key::Account(public_key), &[])
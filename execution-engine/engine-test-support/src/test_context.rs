@@ -3,10 +3,9 @@ use num_traits::identities::Zero;
 use engine_core::engine_state::{
     genesis::{GenesisAccount, GenesisConfig},
     run_genesis_request::RunGenesisRequest,
-    CONV_RATE,
 };
 
-use engine_shared::motes::Motes;
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use types::{AccessRights, Key, URef, U512};
 
 use crate::{
@@ -81,7 +80,7 @@ impl TestContext {
 
                 let expected_source_ending_balance = source_initial_balance
                     - Motes::new(session_transfer_info.transfer_amount)
-                    - Motes::from_gas(gas_cost, CONV_RATE).expect("motes from gas");
+                    - Motes::from_gas(gas_cost, DEFAULT_CONV_RATE).expect("motes from gas");
                 let actual_source_ending_balance = self
                     .maybe_purse_balance(Some(session_transfer_info.source_purse))
                     .expect("source ending balance");
@@ -7,14 +7,14 @@ use std::{env, path::PathBuf};
 use clap::{crate_version, App};
 
 use engine_core::engine_state::{
-    engine_config::EngineConfig, genesis::ExecConfig, run_genesis_request::RunGenesisRequest,
+    engine_config::EngineConfig, genesis::{ExecConfig, QueueDelays, StakeLimits}, run_genesis_request::RunGenesisRequest,
 };
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, LmdbWasmTestBuilder, ARG_AMOUNT,
-        DEFAULT_ACCOUNTS, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
-        DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT,
-        STANDARD_PAYMENT_INSTALL_CONTRACT,
+        DEFAULT_ACCOUNTS, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_PAYMENT_COSTS,
+        DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_REWARD_RATE, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT,
+        POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -80,6 +80,11 @@ fn main() {
         standard_payment_installer_bytes,
         DEFAULT_ACCOUNTS.clone(),
         *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        *DEFAULT_PAYMENT_COSTS,
+        QueueDelays::default(),
+        StakeLimits::default(),
+        DEFAULT_REWARD_RATE,
     );
     let run_genesis_request = RunGenesisRequest::new(
         *DEFAULT_GENESIS_CONFIG_HASH,
@@ -0,0 +1,58 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_HOST_CRYPTO: &str = "host_crypto.wasm";
+const ARG_DATA: &str = "data";
+const ARG_BLAKE2B256: &str = "blake2b256";
+const ARG_SHA256: &str = "sha256";
+const ARG_KECCAK256: &str = "keccak256";
+const ARG_ED25519_SIGNATURE: &str = "ed25519_signature";
+const ARG_ED25519_PUBLIC_KEY: &str = "ed25519_public_key";
+const ARG_SECP256K1_SIGNATURE: &str = "secp256k1_signature";
+const ARG_SECP256K1_PUBLIC_KEY: &str = "secp256k1_public_key";
+const DATA: &[u8] = b"CasperLabs";
+const BLAKE2B256: &str = "fcd489959b46cf59788da61993980fdf40433fcb0e8fb2b67e5a8dad43dde256";
+const SHA256: &str = "9690a3072d38deff804882920b82eeb090eeff2d1a9895fd6c6e14b3f013875c";
+const KECCAK256: &str = "a6f65e069600c5bfaf5dbd8424bdd62733c01e24a680c4b3917abc936a47d3f1";
+// Test vector 1 of RFC 8032, signing an empty message.
+const ED25519_SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555\
+                                 fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+const ED25519_PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+// Signature of the Keccak-256 digest of `DATA`, followed by the recovery ID.
+const SECP256K1_SIGNATURE: &str = "22530d5a21bf76ea1e9892121fe45eec1f7cea27b20ac9e46c92a3c5b687635\
+                                   e2fce400cf9b4a0238ce83d7a69616a0408cdbe7705afad1d9fca20f7c43dc9b800";
+const SECP256K1_PUBLIC_KEY: &str =
+    "02989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f";
+
+fn decode(hex: &str) -> Vec<u8> {
+    base16::decode(hex).expect("should decode")
+}
+
+#[ignore]
+#[test]
+fn should_hash_and_verify_signatures_on_the_host() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_HOST_CRYPTO,
+        runtime_args! {
+            ARG_DATA => DATA.to_vec(),
+            ARG_BLAKE2B256 => decode(BLAKE2B256),
+            ARG_SHA256 => decode(SHA256),
+            ARG_KECCAK256 => decode(KECCAK256),
+            ARG_ED25519_SIGNATURE => decode(ED25519_SIGNATURE),
+            ARG_ED25519_PUBLIC_KEY => decode(ED25519_PUBLIC_KEY),
+            ARG_SECP256K1_SIGNATURE => decode(SECP256K1_SIGNATURE),
+            ARG_SECP256K1_PUBLIC_KEY => decode(SECP256K1_PUBLIC_KEY),
+        },
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
@@ -0,0 +1,79 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, CLValue, Key, Phase, RuntimeArgs};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const HASH_KEY_NAME: &str = "emit_event";
+const ARG_REVERT: &str = "revert";
+
+#[ignore]
+#[test]
+fn should_collect_emitted_events_in_order() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(
+            ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_EMIT_EVENT,
+                runtime_args! { ARG_REVERT => false },
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    let contract_hash = match builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(HASH_KEY_NAME)
+    {
+        Some(Key::Hash(contract_hash)) => *contract_hash,
+        other => panic!("unexpected named key: {:?}", other),
+    };
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let events = response[0].events();
+
+    let names: Vec<&str> = events.iter().map(|event| event.name()).collect();
+    assert_eq!(names, vec!["before_call", "from_contract", "after_call"]);
+
+    let values: Vec<&CLValue> = events.iter().map(|event| event.value()).collect();
+    assert_eq!(
+        values,
+        vec![
+            &CLValue::from_t(1u64).unwrap(),
+            &CLValue::from_t(2u64).unwrap(),
+            &CLValue::from_t(3u64).unwrap(),
+        ]
+    );
+
+    let contract_hashes: Vec<_> = events.iter().map(|event| event.contract_hash()).collect();
+    assert_eq!(contract_hashes, vec![None, Some(contract_hash), None]);
+    assert!(events.iter().all(|event| event.phase() == Phase::Session));
+}
+
+#[ignore]
+#[test]
+fn should_discard_events_of_failed_session() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).exec(
+        ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_EMIT_EVENT,
+            runtime_args! { ARG_REVERT => true },
+        )
+        .build(),
+    );
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(response[0].is_failure());
+    assert!(response[0].events().is_empty());
+}
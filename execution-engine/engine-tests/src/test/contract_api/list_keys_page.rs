@@ -0,0 +1,41 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_LIST_KEYS_PAGE: &str = "list_keys_page.wasm";
+const ARG_COUNT: &str = "count";
+const ARG_LIMIT: &str = "limit";
+
+fn list_keys_in_pages(count: u8, limit: u32) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_KEYS_PAGE,
+        runtime_args! { ARG_COUNT => count, ARG_LIMIT => limit },
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_list_keys_in_pages() {
+    list_keys_in_pages(5, 2);
+}
+
+#[ignore]
+#[test]
+fn should_list_keys_in_a_single_page() {
+    list_keys_in_pages(5, 10);
+}
+
+#[ignore]
+#[test]
+fn should_list_no_keys() {
+    list_keys_in_pages(0, 2);
+}
@@ -1,12 +1,17 @@
 mod account;
 mod create_purse;
+mod crypto;
+mod emit_event;
 mod get_arg;
 mod get_blocktime;
 mod get_caller;
 mod get_phase;
+mod list_keys_page;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
+mod random_bytes;
+mod read_write_many;
 mod revert;
 mod subcall;
 mod transfer;
@@ -0,0 +1,83 @@
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, Key, RuntimeArgs};
+
+const CONTRACT_RANDOM_BYTES: &str = "random_bytes.wasm";
+const ARG_AMOUNT: &str = "amount";
+const ARG_DRAW_RANDOM_BYTES: &str = "draw_random_bytes";
+const RANDOM_BYTES_KEY_NAME: &str = "random_bytes";
+const UREF_KEY_NAME: &str = "uref";
+const RANDOM_BYTES_COUNT: usize = 32;
+
+fn exec_random_bytes(deploy_hash: [u8; 32], draw_random_bytes: bool) -> InMemoryWasmTestBuilder {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_RANDOM_BYTES,
+            runtime_args! { ARG_DRAW_RANDOM_BYTES => draw_random_bytes },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn named_key(builder: &InMemoryWasmTestBuilder, name: &str) -> Key {
+    *builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)
+        .expect("should have named key")
+}
+
+fn random_bytes(builder: &InMemoryWasmTestBuilder) -> Vec<u8> {
+    let key = named_key(builder, RANDOM_BYTES_KEY_NAME);
+    builder
+        .query(None, key, &[])
+        .expect("should have random bytes")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be Vec<u8>")
+}
+
+#[ignore]
+#[test]
+fn should_draw_same_random_bytes_for_same_deploy() {
+    let builder_1 = exec_random_bytes([1; 32], true);
+    let builder_2 = exec_random_bytes([1; 32], true);
+    let builder_3 = exec_random_bytes([2; 32], true);
+
+    let random_bytes_1 = random_bytes(&builder_1);
+    assert_eq!(random_bytes_1.len(), RANDOM_BYTES_COUNT);
+    assert_eq!(random_bytes_1, random_bytes(&builder_2));
+    assert_ne!(random_bytes_1, random_bytes(&builder_3));
+}
+
+#[ignore]
+#[test]
+fn should_not_change_uref_addresses_when_drawing_random_bytes() {
+    let builder_1 = exec_random_bytes([1; 32], true);
+    let builder_2 = exec_random_bytes([1; 32], false);
+
+    assert_eq!(
+        named_key(&builder_1, UREF_KEY_NAME),
+        named_key(&builder_2, UREF_KEY_NAME)
+    );
+}
@@ -0,0 +1,77 @@
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, CLValue, RuntimeArgs};
+
+const CONTRACT_READ_WRITE_MANY: &str = "read_write_many.wasm";
+const ARG_FORGE_UREF: &str = "forge_uref";
+const NUMBER_KEY_NAME: &str = "number";
+const TEXT_KEY_NAME: &str = "text";
+
+fn exec_read_write_many(forge_uref: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_WRITE_MANY,
+        runtime_args! { ARG_FORGE_UREF => forge_uref },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn query_named_value(builder: &InMemoryWasmTestBuilder, name: &str) -> CLValue {
+    let key = *builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)
+        .expect("should have named key");
+    builder
+        .query(None, key, &[])
+        .expect("should have value")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+}
+
+#[ignore]
+#[test]
+fn should_read_and_write_many_values_in_one_call() {
+    let mut builder = exec_read_write_many(false);
+    builder.expect_success();
+
+    let number: i32 = query_named_value(&builder, NUMBER_KEY_NAME)
+        .into_t()
+        .expect("should be i32");
+    assert_eq!(number, 2);
+    let text: String = query_named_value(&builder, TEXT_KEY_NAME)
+        .into_t()
+        .expect("should be String");
+    assert_eq!(text, "World");
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_write_many_values_with_forged_uref() {
+    let builder = exec_read_write_many(true);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+
+    assert!(
+        error_message.contains("ForgedReference"),
+        "{}",
+        error_message
+    );
+}
@@ -1,6 +1,6 @@
 use num_traits::cast::AsPrimitive;
 
-use engine_core::engine_state::CONV_RATE;
+use engine_shared::payment_costs::DEFAULT_CONV_RATE;
 use engine_test_support::{
     internal::{
         ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
@@ -88,7 +88,7 @@ fn should_add_all_gas_for_subcall() {
     const ADD_GAS_VIA_SUBCALL: &str = "add-gas-via-subcall";
 
     // Use 90% of the standard test contract's balance
-    let gas_to_add: U512 = *DEFAULT_PAYMENT / CONV_RATE * 9 / 10;
+    let gas_to_add: U512 = *DEFAULT_PAYMENT / DEFAULT_CONV_RATE * 9 / 10;
 
     assert!(gas_to_add <= U512::from(i32::max_value()));
     let gas_to_add_as_arg: i32 = gas_to_add.as_();
@@ -1,10 +1,7 @@
 use lazy_static::lazy_static;
 
-use engine_core::{
-    engine_state::{Error as CoreError, CONV_RATE},
-    execution::Error as ExecError,
-};
-use engine_shared::motes::Motes;
+use engine_core::{engine_state::Error as CoreError, execution::Error as ExecError};
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
@@ -75,8 +72,8 @@ fn should_transfer_to_account() {
 
     let genesis_balance = builder.get_purse_balance(default_account_purse);
 
-    let gas_cost =
-        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+    let gas_cost = Motes::from_gas(builder.exec_costs(0)[0], DEFAULT_CONV_RATE)
+        .expect("should convert gas to motes");
 
     assert_eq!(
         genesis_balance,
@@ -130,7 +127,7 @@ fn should_transfer_from_account_to_account() {
 
     let genesis_balance = builder.get_purse_balance(default_account_purse);
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_1_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_1_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     assert_eq!(
@@ -172,7 +169,7 @@ fn should_transfer_from_account_to_account() {
 
     let account_1_balance = builder.get_purse_balance(account_1_purse);
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_2_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_2_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     assert_eq!(
@@ -231,8 +228,8 @@ fn should_transfer_to_existing_account() {
 
     let genesis_balance = builder.get_purse_balance(default_account_purse);
 
-    let gas_cost =
-        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+    let gas_cost = Motes::from_gas(builder.exec_costs(0)[0], DEFAULT_CONV_RATE)
+        .expect("should convert gas to motes");
 
     assert_eq!(
         genesis_balance,
@@ -265,8 +262,8 @@ fn should_transfer_to_existing_account() {
 
     let account_1_balance = builder.get_purse_balance(account_1_purse);
 
-    let gas_cost =
-        Motes::from_gas(builder.exec_costs(1)[0], CONV_RATE).expect("should convert gas to motes");
+    let gas_cost = Motes::from_gas(builder.exec_costs(1)[0], DEFAULT_CONV_RATE)
+        .expect("should convert gas to motes");
 
     assert_eq!(
         account_1_balance,
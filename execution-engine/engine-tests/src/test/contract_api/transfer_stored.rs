@@ -1,5 +1,4 @@
-use engine_core::engine_state::CONV_RATE;
-use engine_shared::motes::Motes;
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
@@ -45,7 +44,7 @@ fn should_transfer_to_account_stored() {
         .clone();
     let mut result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_alpha = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
 
     let modified_balance_alpha: U512 = builder.get_purse_balance(default_account.main_purse());
 
@@ -84,7 +83,7 @@ fn should_transfer_to_account_stored() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_bravo = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
 
     let tally = motes_alpha.value()
         + motes_bravo.value()
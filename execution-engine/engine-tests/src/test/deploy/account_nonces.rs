@@ -0,0 +1,131 @@
+use assert_matches::assert_matches;
+
+use engine_core::engine_state::{
+    engine_config::EngineConfig, execute_request::ExecuteRequest, Error,
+};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const ARG_AMOUNT: &str = "amount";
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const REVERT_WASM: &str = "revert.wasm";
+
+fn builder_with_nonces() -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_enable_account_nonces(true);
+    InMemoryWasmTestBuilder::new_with_config(engine_config)
+}
+
+fn exec_request_with_nonce(
+    session_file: &str,
+    deploy_hash: [u8; 32],
+    nonce: u64,
+) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash(deploy_hash)
+        .with_session_code(session_file, RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_nonce(nonce)
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn account_nonce(builder: &InMemoryWasmTestBuilder) -> u64 {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .nonce()
+}
+
+#[ignore]
+#[test]
+fn should_increment_nonce_of_successful_deploy() {
+    let mut builder = builder_with_nonces();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    assert_eq!(account_nonce(&builder), 0);
+
+    builder
+        .exec(exec_request_with_nonce(DO_NOTHING_WASM, [1; 32], 1))
+        .expect_success()
+        .commit();
+    assert_eq!(account_nonce(&builder), 1);
+
+    builder
+        .exec(exec_request_with_nonce(DO_NOTHING_WASM, [2; 32], 2))
+        .expect_success()
+        .commit();
+    assert_eq!(account_nonce(&builder), 2);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_replayed_nonce() {
+    let mut builder = builder_with_nonces();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_with_nonce(DO_NOTHING_WASM, [1; 32], 1))
+        .expect_success()
+        .commit();
+
+    builder.exec(exec_request_with_nonce(DO_NOTHING_WASM, [2; 32], 1));
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("there should be a response");
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(
+        precondition_failure,
+        Error::InvalidNonce {
+            expected: 2,
+            actual: 1
+        }
+    );
+    assert_eq!(account_nonce(&builder), 1);
+}
+
+#[ignore]
+#[test]
+fn should_increment_nonce_of_failed_session() {
+    let mut builder = builder_with_nonces();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_with_nonce(REVERT_WASM, [1; 32], 1))
+        .commit();
+    assert!(builder.is_error());
+    assert_eq!(account_nonce(&builder), 1);
+
+    builder.exec(exec_request_with_nonce(DO_NOTHING_WASM, [2; 32], 1));
+    let response = builder
+        .get_exec_response(1)
+        .expect("there should be a response");
+    assert_matches!(
+        utils::get_precondition_failure(response),
+        Error::InvalidNonce { .. }
+    );
+}
+
+#[ignore]
+#[test]
+fn should_ignore_nonce_when_disabled() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_with_nonce(DO_NOTHING_WASM, [1; 32], 1))
+        .expect_success()
+        .commit()
+        .exec(exec_request_with_nonce(DO_NOTHING_WASM, [2; 32], 1))
+        .expect_success()
+        .commit();
+    assert_eq!(account_nonce(&builder), 0);
+}
@@ -0,0 +1,108 @@
+use assert_matches::assert_matches;
+
+use engine_core::engine_state::{execute_request::ExecuteRequest, Error};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const ARG_AMOUNT: &str = "amount";
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const VALID_FROM: u64 = 1_000;
+const TTL: u64 = 500;
+
+fn exec_request_at(block_time: u64) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash([1; 32])
+        .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_valid_from(VALID_FROM)
+        .with_ttl(TTL)
+        .build();
+
+    ExecuteRequestBuilder::new()
+        .push_deploy(deploy)
+        .with_block_time(block_time)
+        .build()
+}
+
+fn assert_invalid_block_time(block_time: u64) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_at(block_time));
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(
+        precondition_failure,
+        Error::InvalidBlockTime {
+            block_time: actual_block_time,
+            valid_from: Some(VALID_FROM),
+            valid_until: Some(_),
+        } if *actual_block_time == block_time
+    );
+}
+
+#[ignore]
+#[test]
+fn should_execute_deploy_within_validity_window() {
+    for block_time in &[VALID_FROM, VALID_FROM + TTL] {
+        InMemoryWasmTestBuilder::default()
+            .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+            .exec(exec_request_at(*block_time))
+            .expect_success()
+            .commit();
+    }
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_before_valid_from() {
+    assert_invalid_block_time(VALID_FROM - 1);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_after_ttl_expires() {
+    assert_invalid_block_time(VALID_FROM + TTL + 1);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_ttl_without_valid_from() {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash([1; 32])
+        .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_ttl(TTL)
+        .build();
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(deploy)
+        .with_block_time(TTL)
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(
+        precondition_failure,
+        Error::TtlWithoutValidFrom { ttl: TTL }
+    );
+}
@@ -0,0 +1,112 @@
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, runtime_args, RuntimeArgs, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([43u8; 32]);
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+fn create_account_1_then_transfer_to_account_2() -> ExecuteRequestBuilder {
+    let account_1_funding_amount = U512::from(1_000_000_000);
+    let account_2_funding_amount = U512::from(10_000_000);
+
+    let create_account_1 = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            runtime_args! {
+                ARG_TARGET => ACCOUNT_1_ADDR,
+                ARG_AMOUNT => account_1_funding_amount
+            },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([1; 32])
+        .build();
+
+    // only valid if account_1 already exists
+    let transfer_to_account_2 = DeployItemBuilder::new()
+        .with_address(ACCOUNT_1_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            runtime_args! {
+                ARG_TARGET => ACCOUNT_2_ADDR,
+                ARG_AMOUNT => account_2_funding_amount
+            },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[ACCOUNT_1_ADDR])
+        .with_deploy_hash([2; 32])
+        .build();
+
+    ExecuteRequestBuilder::new()
+        .push_deploy(create_account_1)
+        .push_deploy(transfer_to_account_2)
+}
+
+#[ignore]
+#[test]
+fn should_not_see_effects_of_previous_deploys_without_chaining() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = create_account_1_then_transfer_to_account_2().build();
+    builder.exec(exec_request);
+
+    let results = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(results[0].is_success());
+    assert!(results[1].is_failure());
+}
+
+#[ignore]
+#[test]
+fn should_see_effects_of_previous_deploys_when_chained() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = create_account_1_then_transfer_to_account_2().build();
+    builder.exec_chained(exec_request).commit();
+
+    let results = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(results[0].is_success());
+    assert!(results[1].is_success());
+
+    // the combined effects of both deploys were committed
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_some());
+    assert!(builder.get_account(ACCOUNT_2_ADDR).is_some());
+}
+
+#[ignore]
+#[test]
+fn should_match_sequential_commits_when_chained() {
+    let mut chained_builder = InMemoryWasmTestBuilder::default();
+    chained_builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    chained_builder
+        .exec_chained(create_account_1_then_transfer_to_account_2().build())
+        .commit();
+
+    let mut sequential_builder = InMemoryWasmTestBuilder::default();
+    sequential_builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let mut exec_request = create_account_1_then_transfer_to_account_2().build();
+    for deploy in exec_request.take_deploys() {
+        let deploy = deploy.expect("should be a valid deploy item");
+        let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+        sequential_builder.exec(exec_request).commit();
+    }
+
+    assert_eq!(
+        chained_builder.get_post_state_hash(),
+        sequential_builder.get_post_state_hash()
+    );
+}
@@ -0,0 +1,126 @@
+use std::convert::TryInto;
+
+use engine_core::{
+    engine_state::{estimate::EstimateRequest, execution_result::ExecutionResult, Error},
+    execution::Error as ExecError,
+};
+use engine_shared::newtypes::CorrelationId;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::PublicKey, runtime_args, ApiError, CLValue, ProtocolVersion, RuntimeArgs, U512,
+};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const REVERT_WASM: &str = "revert.wasm";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+fn estimate_request(
+    builder: &InMemoryWasmTestBuilder,
+    session_file: &str,
+    session_args: RuntimeArgs,
+) -> EstimateRequest {
+    let deploy_item = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(session_file, session_args)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([1; 32])
+        .build();
+    EstimateRequest::new(
+        builder.get_post_state_hash().as_slice().try_into().unwrap(),
+        0,
+        deploy_item,
+        ProtocolVersion::V1_0_0,
+    )
+}
+
+#[ignore]
+#[test]
+fn should_estimate_session_without_paying_or_committing() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let post_state_hash = builder.get_post_state_hash();
+    let balance_before = builder.get_purse_balance(
+        builder
+            .get_account(DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account")
+            .main_purse(),
+    );
+
+    let session_args = runtime_args! {
+        ARG_TARGET => ACCOUNT_1_ADDR,
+        ARG_AMOUNT => U512::from(1_000_000)
+    };
+    let request = estimate_request(
+        &builder,
+        TRANSFER_PURSE_TO_ACCOUNT_WASM,
+        session_args.clone(),
+    );
+    let estimate_result = builder
+        .get_engine_state()
+        .run_estimate(CorrelationId::new(), request)
+        .expect("should find parent state");
+
+    assert!(estimate_result.execution_result.is_success());
+    assert!(!estimate_result
+        .execution_result
+        .effect()
+        .transforms
+        .is_empty());
+    assert_eq!(
+        estimate_result.return_value,
+        Some(CLValue::from_t(()).unwrap())
+    );
+
+    // nothing was committed or charged
+    assert_eq!(builder.get_post_state_hash(), post_state_hash);
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+    let balance_after = builder.get_purse_balance(
+        builder
+            .get_account(DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account")
+            .main_purse(),
+    );
+    assert_eq!(balance_before, balance_after);
+
+    // executing the same deploy costs at least the gas used by its session code
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_PURSE_TO_ACCOUNT_WASM,
+        session_args,
+    )
+    .build();
+    builder.exec(exec_request).expect_success();
+    let session_cost = builder.exec_costs(0)[0];
+    assert!(estimate_result.execution_result.cost() <= session_cost);
+}
+
+#[ignore]
+#[test]
+fn should_estimate_reverting_session() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let request = estimate_request(&builder, REVERT_WASM, RuntimeArgs::default());
+    let estimate_result = builder
+        .get_engine_state()
+        .run_estimate(CorrelationId::new(), request)
+        .expect("should find parent state");
+
+    match estimate_result.execution_result {
+        ExecutionResult::Failure {
+            error: Error::Exec(ExecError::Revert(api_error)),
+            ..
+        } => assert_eq!(api_error, ApiError::User(1)),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(estimate_result.return_value, None);
+}
@@ -1,3 +1,8 @@
+mod account_nonces;
+mod block_time_validity;
+mod chained_execution;
+mod estimate;
 mod non_standard_payment;
+mod parallel_execution;
 mod preconditions;
 mod stored_contracts;
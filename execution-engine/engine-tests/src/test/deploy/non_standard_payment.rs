@@ -1,5 +1,4 @@
-use engine_core::engine_state::CONV_RATE;
-use engine_shared::motes::Motes;
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
@@ -125,7 +124,7 @@ fn should_charge_non_main_purse() {
 
     let result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
 
     let expected_resting_balance = account_1_purse_funding_amount - motes.value();
 
@@ -0,0 +1,83 @@
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, runtime_args, RuntimeArgs, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([43u8; 32]);
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+fn transfer_to_account_1_and_account_2() -> ExecuteRequestBuilder {
+    let amount = U512::from(1_000_000_000);
+
+    let transfer_to_account_1 = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => amount },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([1; 32])
+        .build();
+
+    let transfer_to_account_2 = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            runtime_args! { ARG_TARGET => ACCOUNT_2_ADDR, ARG_AMOUNT => amount },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([2; 32])
+        .build();
+
+    ExecuteRequestBuilder::new()
+        .push_deploy(transfer_to_account_1)
+        .push_deploy(transfer_to_account_2)
+}
+
+#[ignore]
+#[test]
+fn should_match_sequential_execution_when_parallel() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(transfer_to_account_1_and_account_2().build())
+        .exec_parallel(transfer_to_account_1_and_account_2().build());
+
+    let sequential_results = builder
+        .get_exec_response(0)
+        .expect("should have sequential exec response");
+    let parallel_results = builder
+        .get_exec_response(1)
+        .expect("should have parallel exec response");
+
+    assert_eq!(sequential_results.len(), parallel_results.len());
+    for (sequential, parallel) in sequential_results.iter().zip(parallel_results.iter()) {
+        assert!(parallel.is_success());
+        assert_eq!(sequential.effect(), parallel.effect());
+    }
+}
+
+#[ignore]
+#[test]
+fn should_report_conflicting_deploys_when_parallel() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder.exec_parallel(transfer_to_account_1_and_account_2().build());
+
+    // both deploys spend from the main purse of the default account
+    let conflicts = builder
+        .get_exec_conflicts(0)
+        .expect("should have exec conflicts");
+    assert_eq!(conflicts, &vec![(0, 1)]);
+}
@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 
-use engine_core::engine_state::{upgrade::ActivationPoint, CONV_RATE};
+use engine_core::engine_state::upgrade::ActivationPoint;
 use engine_grpc_server::engine_server::ipc::DeployCode;
 use engine_shared::{
-    account::Account, motes::Motes, stored_value::StoredValue, transform::Transform,
+    account::Account, motes::Motes, payment_costs::DEFAULT_CONV_RATE, stored_value::StoredValue,
+    transform::Transform,
 };
 use engine_storage::global_state::in_memory::InMemoryGlobalState;
 use engine_test_support::{
@@ -146,7 +147,7 @@ fn should_exec_non_stored_code() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
     let tally = motes.value() + U512::from(transferred_amount) + modified_balance;
 
     assert_eq!(
@@ -180,7 +181,7 @@ fn should_exec_stored_code_by_hash() {
             .clone();
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+        let motes_alpha = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
         (motes_alpha, modified_balance_alpha)
     };
 
@@ -224,7 +225,7 @@ fn should_exec_stored_code_by_hash() {
 
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+        let motes_bravo = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
 
         (motes_bravo, modified_balance_bravo)
     };
@@ -277,7 +278,7 @@ fn should_exec_stored_code_by_named_hash() {
             .clone();
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+        let motes_alpha = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
         (motes_alpha, modified_balance_alpha)
     };
 
@@ -321,7 +322,7 @@ fn should_exec_stored_code_by_named_hash() {
 
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+        let motes_bravo = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
 
         (motes_bravo, modified_balance_bravo)
     };
@@ -373,7 +374,7 @@ fn should_exec_payment_and_session_stored_code() {
             .clone();
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        Motes::from_gas(gas, CONV_RATE).expect("should have motes")
+        Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes")
     };
 
     // next store transfer contract
@@ -410,7 +411,7 @@ fn should_exec_payment_and_session_stored_code() {
 
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        Motes::from_gas(gas, CONV_RATE).expect("should have motes")
+        Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes")
     };
 
     let transferred_amount = 1;
@@ -455,7 +456,7 @@ fn should_exec_payment_and_session_stored_code() {
 
         let result = utils::get_success_result(&response);
         let gas = result.cost();
-        Motes::from_gas(gas, CONV_RATE).expect("should have motes")
+        Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes")
     };
 
     let modified_balance: U512 = {
@@ -0,0 +1,136 @@
+use engine_core::engine_state::{
+    genesis::{ExecConfig, QueueDelays, StakeLimits},
+    run_genesis_request::RunGenesisRequest,
+};
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_PAYMENT_COSTS, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_REWARD_RATE, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_WASM_COSTS,
+        MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use engine_wasm_prep::host_function_costs::{
+    HostFunctionCost, HostFunctionCosts, DEFAULT_BLAKE2B256_COST,
+};
+use types::{account::PublicKey, runtime_args, RuntimeArgs, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const HOST_CRYPTO_COST_WASM: &str = "host_crypto_cost.wasm";
+const ARG_DATA_SIZE: &str = "data_size";
+const SMALL_DATA_SIZE: u32 = 32;
+const LARGE_DATA_SIZE: u32 = 1024;
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const GET_MAIN_PURSE_COST: u32 = 10_000;
+const TRANSFER_FROM_PURSE_TO_ACCOUNT_COST: u32 = 1_000_000;
+
+fn run_genesis_request(host_function_costs: HostFunctionCosts) -> RunGenesisRequest {
+    let exec_config = ExecConfig::new(
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_COSTS,
+        host_function_costs,
+        *DEFAULT_PAYMENT_COSTS,
+        QueueDelays::default(),
+        StakeLimits::default(),
+        DEFAULT_REWARD_RATE,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+}
+
+fn transfer_purse_to_account_cost(host_function_costs: HostFunctionCosts) -> Gas {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(host_function_costs));
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_PURSE_TO_ACCOUNT_WASM,
+        runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => U512::from(1_000_000) },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+        .get_exec_response(0)
+        .expect("should have exec response")[0]
+        .cost()
+}
+
+fn blake2b256_cost(data_size: u32) -> Gas {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        HOST_CRYPTO_COST_WASM,
+        runtime_args! { ARG_DATA_SIZE => data_size },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+        .get_exec_response(0)
+        .expect("should have exec response")[0]
+        .cost()
+}
+
+#[ignore]
+#[test]
+fn should_store_host_function_costs_at_genesis() {
+    let host_function_costs = HostFunctionCosts {
+        write: HostFunctionCost::new(1, 2),
+        ..Default::default()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(host_function_costs));
+
+    let stored_host_function_costs = builder
+        .get_engine_state()
+        .host_function_costs(*DEFAULT_PROTOCOL_VERSION)
+        .expect("should have result")
+        .expect("should have costs");
+    assert_eq!(stored_host_function_costs, host_function_costs);
+}
+
+#[ignore]
+#[test]
+fn should_charge_for_host_function_calls() {
+    let free_cost = transfer_purse_to_account_cost(HostFunctionCosts::default());
+
+    // `transfer_purse_to_account.wasm` calls each of these host functions exactly once
+    let host_function_costs = HostFunctionCosts {
+        get_main_purse: HostFunctionCost::new(GET_MAIN_PURSE_COST, 0),
+        transfer_from_purse_to_account: HostFunctionCost::new(
+            TRANSFER_FROM_PURSE_TO_ACCOUNT_COST,
+            0,
+        ),
+        ..Default::default()
+    };
+    let charged_cost = transfer_purse_to_account_cost(host_function_costs);
+
+    let expected_difference =
+        Gas::new((GET_MAIN_PURSE_COST + TRANSFER_FROM_PURSE_TO_ACCOUNT_COST).into());
+    assert_eq!(charged_cost, free_cost + expected_difference);
+}
+
+#[ignore]
+#[test]
+fn should_charge_per_byte_of_input_by_default() {
+    let small_cost = blake2b256_cost(SMALL_DATA_SIZE);
+    let large_cost = blake2b256_cost(LARGE_DATA_SIZE);
+
+    let expected_difference =
+        Gas::new((DEFAULT_BLAKE2B256_COST.per_byte * (LARGE_DATA_SIZE - SMALL_DATA_SIZE)).into());
+    assert_eq!(large_cost, small_cost + expected_difference);
+}
@@ -5,7 +5,9 @@ mod counter;
 mod deploy;
 mod explorer;
 mod groups;
+mod host_function_costs;
 mod manage_groups;
+mod payment_costs;
 mod regression;
 mod system_contracts;
 mod upgrade;
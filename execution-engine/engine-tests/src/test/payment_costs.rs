@@ -0,0 +1,115 @@
+use engine_core::engine_state::{
+    genesis::{ExecConfig, QueueDelays, StakeLimits},
+    run_genesis_request::RunGenesisRequest,
+};
+use engine_shared::{motes::Motes, payment_costs::PaymentCosts};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_REWARD_RATE, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT,
+        STANDARD_PAYMENT_INSTALL_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{RuntimeArgs, U512};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const CONV_RATE: u64 = 3;
+
+fn run_genesis_request(payment_costs: PaymentCosts) -> RunGenesisRequest {
+    let exec_config = ExecConfig::new(
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        payment_costs,
+        QueueDelays::default(),
+        StakeLimits::default(),
+        DEFAULT_REWARD_RATE,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+}
+
+#[ignore]
+#[test]
+fn should_store_payment_costs_at_genesis() {
+    let payment_costs = PaymentCosts {
+        conv_rate: CONV_RATE,
+        max_payment_cost: 1_000,
+        standard_payment_cap: 1_000_000,
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(payment_costs));
+
+    let stored_payment_costs = builder
+        .get_engine_state()
+        .payment_costs(*DEFAULT_PROTOCOL_VERSION)
+        .expect("should have result")
+        .expect("should have costs");
+    assert_eq!(stored_payment_costs, payment_costs);
+}
+
+#[ignore]
+#[test]
+fn should_charge_using_conv_rate_from_genesis() {
+    let payment_costs = PaymentCosts {
+        conv_rate: CONV_RATE,
+        ..Default::default()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(payment_costs));
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, RuntimeArgs::new())
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let motes = Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should have motes");
+    let main_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .main_purse();
+    assert_eq!(
+        builder.get_purse_balance(main_purse),
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - motes.value()
+    );
+}
+
+#[cfg(not(feature = "use-system-contracts"))]
+#[ignore]
+#[test]
+fn should_cap_standard_payment() {
+    // Too little to pay for running the standard payment itself
+    let payment_costs = PaymentCosts {
+        standard_payment_cap: 1,
+        ..Default::default()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(payment_costs));
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, RuntimeArgs::new())
+            .build();
+    builder.exec(exec_request).commit();
+
+    let error_message = utils::get_error_message(
+        builder
+            .get_exec_response(0)
+            .expect("should have exec response"),
+    );
+    assert!(
+        error_message.contains("Insufficient payment"),
+        "{}",
+        error_message
+    );
+}
@@ -1,7 +1,6 @@
 use lazy_static::lazy_static;
 
-use engine_core::engine_state::CONV_RATE;
-use engine_shared::motes::Motes;
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
@@ -95,7 +94,7 @@ fn should_not_be_able_to_transfer_funds_with_transfer_purse_to_purse() {
         .builder()
         .get_exec_response(0)
         .expect("should have response");
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -167,7 +166,7 @@ fn should_not_be_able_to_transfer_funds_with_transfer_from_purse_to_account() {
         .get_exec_response(0)
         .expect("should have response");
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -249,7 +248,7 @@ fn should_not_be_able_to_transfer_funds_with_transfer_to_account() {
         .get_exec_response(0)
         .expect("should have response");
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -325,7 +324,7 @@ fn should_not_be_able_to_get_main_purse_in_invalid_context() {
         .get_exec_response(0)
         .expect("should have response");
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -3,9 +3,8 @@ use std::rc::Rc;
 use engine_core::engine_state::{
     execution_result::ExecutionResult,
     genesis::{GenesisAccount, POS_REWARDS_PURSE},
-    CONV_RATE,
 };
-use engine_shared::motes::Motes;
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
     DEFAULT_ACCOUNT_ADDR,
@@ -39,7 +38,7 @@ fn get_cost(response: &[Rc<ExecutionResult>]) -> U512 {
         utils::get_exec_costs(response)
             .into_iter()
             .fold(Default::default(), |i, acc| i + acc),
-        CONV_RATE,
+        DEFAULT_CONV_RATE,
     )
     .expect("should convert");
     motes.value()
@@ -1,12 +1,13 @@
 use engine_core::engine_state::{
-    genesis::{ExecConfig, GenesisAccount},
+    genesis::{ExecConfig, GenesisAccount, QueueDelays, StakeLimits},
     run_genesis_request::RunGenesisRequest,
     SYSTEM_ACCOUNT_ADDR,
 };
 use engine_shared::{motes::Motes, stored_value::StoredValue};
 use engine_test_support::internal::{
-    utils, InMemoryWasmTestBuilder, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT,
-    POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    utils, InMemoryWasmTestBuilder, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_PAYMENT_COSTS,
+    DEFAULT_REWARD_RATE, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT,
+    STANDARD_PAYMENT_INSTALL_CONTRACT,
 };
 use types::{account::PublicKey, ProtocolVersion, U512};
 
@@ -53,6 +54,8 @@ fn should_run_genesis() {
     let accounts = vec![account_1, account_2];
     let protocol_version = ProtocolVersion::V1_0_0;
     let wasm_costs = *DEFAULT_WASM_COSTS;
+    let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+    let payment_costs = *DEFAULT_PAYMENT_COSTS;
 
     let exec_config = ExecConfig::new(
         mint_installer_bytes,
@@ -60,6 +63,11 @@ fn should_run_genesis() {
         standard_payment_installer_bytes,
         accounts,
         wasm_costs,
+        host_function_costs,
+        payment_costs,
+        QueueDelays::default(),
+        StakeLimits::default(),
+        DEFAULT_REWARD_RATE,
     );
     let run_genesis_request =
         RunGenesisRequest::new(GENESIS_CONFIG_HASH.into(), protocol_version, exec_config);
@@ -138,6 +146,8 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+        let payment_costs = *DEFAULT_PAYMENT_COSTS;
 
         let exec_config = ExecConfig::new(
             mint_installer_bytes,
@@ -145,6 +155,11 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            QueueDelays::default(),
+            StakeLimits::default(),
+            DEFAULT_REWARD_RATE,
         );
         RunGenesisRequest::new(GENESIS_CONFIG_HASH.into(), protocol_version, exec_config)
     };
@@ -187,12 +202,19 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
+        let payment_costs = *DEFAULT_PAYMENT_COSTS;
         let exec_config = ExecConfig::new(
             mint_installer_bytes,
             pos_installer_bytes,
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            payment_costs,
+            QueueDelays::default(),
+            StakeLimits::default(),
+            DEFAULT_REWARD_RATE,
         );
         RunGenesisRequest::new(GENESIS_CONFIG_HASH.into(), protocol_version, exec_config)
     };
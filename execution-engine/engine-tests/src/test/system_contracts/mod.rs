@@ -4,4 +4,5 @@ mod pos_install;
 mod proof_of_stake;
 mod standard_payment;
 mod standard_payment_install;
+mod total_supply;
 mod upgrade;
@@ -3,7 +3,10 @@
 
 use std::collections::BTreeMap;
 
-use engine_core::engine_state::EngineConfig;
+use engine_core::engine_state::{
+    genesis::{QueueDelays, StakeLimits},
+    EngineConfig,
+};
 use engine_test_support::{
     internal::{
         exec_with_return, ExecuteRequestBuilder, WasmTestBuilder, DEFAULT_BLOCK_TIME,
@@ -22,8 +25,9 @@ const SYSTEM_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
 const N_VALIDATORS: u8 = 5;
 
-// one named_key for each validator and three for the purses
-const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 3;
+// one named_key for each validator, three for the purses, two for the queue delays and stake
+// limits and one for the delegations
+const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 6;
 
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
@@ -31,6 +35,8 @@ const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
 
 const ARG_MINT_PACKAGE_HASH: &str = "mint_contract_package_hash";
 const ARG_GENESIS_VALIDATORS: &str = "genesis_validators";
+const ARG_QUEUE_DELAYS: &str = "queue_delays";
+const ARG_STAKE_LIMITS: &str = "stake_limits";
 
 #[ignore]
 #[test]
@@ -78,6 +84,8 @@ fn should_run_pos_install_contract() {
         runtime_args! {
             ARG_MINT_PACKAGE_HASH => mint_package_hash,
             ARG_GENESIS_VALIDATORS => genesis_validators,
+            ARG_QUEUE_DELAYS => QueueDelays::default(),
+            ARG_STAKE_LIMITS => StakeLimits::default(),
         },
         vec![],
     );
@@ -1,8 +1,5 @@
-use engine_core::engine_state::{
-    genesis::{GenesisAccount, POS_BONDING_PURSE},
-    CONV_RATE,
-};
-use engine_shared::motes::Motes;
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE};
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_PAYMENT,
@@ -199,7 +196,7 @@ fn should_run_successful_bond_and_unbond() {
     let exec_response = builder
         .get_exec_response(0)
         .expect("should have exec response");
-    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], CONV_RATE)
+    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     assert_eq!(
@@ -261,7 +258,7 @@ fn should_run_successful_bond_and_unbond() {
         builder.get_purse_balance(default_account.main_purse()),
         U512::from(
             DEFAULT_ACCOUNT_INITIAL_BALANCE
-                - Motes::from_gas(genesis_gas_cost, CONV_RATE)
+                - Motes::from_gas(genesis_gas_cost, DEFAULT_CONV_RATE)
                     .expect("should convert")
                     .value()
                     .as_u64()
@@ -302,7 +299,7 @@ fn should_run_successful_bond_and_unbond() {
     let exec_response = builder
         .get_exec_response(0)
         .expect("should have exec response");
-    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], CONV_RATE)
+    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], DEFAULT_CONV_RATE)
         .expect("should convert");
 
     assert_eq!(
@@ -361,7 +358,7 @@ fn should_run_successful_bond_and_unbond() {
             .get_purse_balance(default_account.main_purse()),
         U512::from(
             DEFAULT_ACCOUNT_INITIAL_BALANCE
-                - Motes::from_gas(genesis_gas_cost, CONV_RATE)
+                - Motes::from_gas(genesis_gas_cost, DEFAULT_CONV_RATE)
                     .expect("should convert")
                     .value()
                     .as_u64()
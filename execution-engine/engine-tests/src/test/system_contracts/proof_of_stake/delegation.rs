@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 2_000;
+const ACCOUNT_1_BOND: u64 = 1_000;
+
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_2_BALANCE: u64 = 2_000;
+const ACCOUNT_2_BOND: u64 = 1_000;
+
+const DELEGATION: u64 = 1_000;
+const ACCOUNT_1_SLASH: u64 = 1_000;
+
+const TEST_DELEGATE: &str = "delegate";
+const TEST_UNDELEGATE: &str = "undelegate";
+
+const ARG_AMOUNT: &str = "amount";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_VALIDATOR: &str = "validator";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        let account_2 = GenesisAccount::new(
+            ACCOUNT_2_ADDR,
+            Motes::new(ACCOUNT_2_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_delegate_and_undelegate_slashed_stake() {
+    let mut builder = setup();
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        runtime_args! {
+            ARG_ENTRY_POINT => String::from(TEST_DELEGATE),
+            ARG_VALIDATOR => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(DELEGATION),
+        },
+    )
+    .build();
+
+    builder.exec(exec_request_1);
+    if !cfg!(feature = "enable-bonding") && builder.is_error() {
+        return;
+    }
+    builder.expect_success().commit();
+
+    // The delegation adds to the validator's stake.
+    let bids = builder.get_bid_state();
+    assert_eq!(
+        bids.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_BOND + DELEGATION))
+    );
+    assert_eq!(bids.get(&ACCOUNT_2_ADDR), Some(&U512::from(ACCOUNT_2_BOND)));
+
+    // Half of the validator's stake is slashed, so half of the delegation is lost.
+    let slashes = {
+        let mut tmp = BTreeMap::new();
+        tmp.insert(ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_SLASH));
+        tmp
+    };
+    builder.slash(slashes);
+
+    let expected_payout = DELEGATION / 2;
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        runtime_args! {
+            ARG_ENTRY_POINT => String::from(TEST_UNDELEGATE),
+            ARG_VALIDATOR => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => Option::<U512>::None,
+        },
+    )
+    .build();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get default account");
+    let balance_before = builder.get_purse_balance(default_account.main_purse());
+
+    builder.exec(exec_request_2).expect_success().commit();
+
+    let balance_after = builder.get_purse_balance(default_account.main_purse());
+    let exec_response = builder
+        .get_exec_response(1)
+        .expect("should have exec response");
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_response)[0], DEFAULT_CONV_RATE)
+        .expect("should convert");
+    assert_eq!(
+        balance_after,
+        balance_before - gas_cost.value() + expected_payout
+    );
+
+    // Only the validator's own, slashed stake remains.
+    let bids = builder.get_bid_state();
+    assert_eq!(
+        bids.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(
+            ACCOUNT_1_BOND + DELEGATION - ACCOUNT_1_SLASH - expected_payout
+        ))
+    );
+}
@@ -1,10 +1,7 @@
 use std::convert::TryInto;
 
-use engine_core::engine_state::{
-    genesis::{POS_PAYMENT_PURSE, POS_REWARDS_PURSE},
-    CONV_RATE,
-};
-use engine_shared::{account::Account, motes::Motes};
+use engine_core::engine_state::genesis::{POS_PAYMENT_PURSE, POS_REWARDS_PURSE};
+use engine_shared::{account::Account, motes::Motes, payment_costs::DEFAULT_CONV_RATE};
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
@@ -135,7 +132,7 @@ fn finalize_payment_should_refund_to_specified_purse() {
             .expect("there should be a response");
 
         let success_result = utils::get_success_result(response);
-        Motes::from_gas(success_result.cost(), CONV_RATE)
+        Motes::from_gas(success_result.cost(), DEFAULT_CONV_RATE)
             .expect("should have motes")
             .value()
     };
@@ -1,5 +1,10 @@
 mod bonding;
 mod commit_validators;
+mod delegation;
 mod finalize_payment;
 mod get_payment_purse;
 mod refund_purse;
+mod seigniorage;
+mod slash;
+mod stake_limits;
+mod step;
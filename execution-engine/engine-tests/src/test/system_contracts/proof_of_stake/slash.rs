@@ -0,0 +1,79 @@
+use std::collections::{BTreeMap, HashMap};
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::motes::Motes;
+use engine_test_support::internal::{utils, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS};
+use types::{account::PublicKey, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 2000;
+const ACCOUNT_1_BOND: u64 = 1000;
+const ACCOUNT_1_SLASH: u64 = 400;
+
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_2_BALANCE: u64 = 2000;
+const ACCOUNT_2_BOND: u64 = 200;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        let account_2 = GenesisAccount::new(
+            ACCOUNT_2_ADDR,
+            Motes::new(ACCOUNT_2_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_return_bid_state_after_genesis() {
+    let builder = setup();
+
+    let bids = builder.get_bid_state();
+
+    assert_eq!(bids.get(&ACCOUNT_1_ADDR), Some(&U512::from(ACCOUNT_1_BOND)));
+    assert_eq!(bids.get(&ACCOUNT_2_ADDR), Some(&U512::from(ACCOUNT_2_BOND)));
+}
+
+#[ignore]
+#[test]
+fn should_slash_validators() {
+    let mut builder = setup();
+
+    let slashes = {
+        let mut tmp = BTreeMap::new();
+        tmp.insert(ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_SLASH));
+        tmp.insert(ACCOUNT_2_ADDR, U512::from(ACCOUNT_2_BOND));
+        tmp
+    };
+
+    builder.slash(slashes);
+
+    let bonded_validators: HashMap<PublicKey, U512> = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators after slash");
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_BOND - ACCOUNT_1_SLASH))
+    );
+    assert!(!bonded_validators.contains_key(&ACCOUNT_2_ADDR));
+
+    assert_eq!(builder.get_bid_state(), bonded_validators);
+}
@@ -0,0 +1,189 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::{
+    genesis::{ExecConfig, GenesisAccount, QueueDelays, StakeLimits, POS_STAKE_LIMITS},
+    run_genesis_request::RunGenesisRequest,
+    upgrade::ActivationPoint,
+};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_ACCOUNTS, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_HOST_FUNCTION_COSTS,
+        DEFAULT_PAYMENT_COSTS, DEFAULT_PROTOCOL_VERSION, DEFAULT_REWARD_RATE, DEFAULT_WASM_COSTS,
+        MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::PublicKey, runtime_args, ApiError, CLValue, Key, ProtocolVersion, RuntimeArgs, U512,
+};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_BALANCE: u64 = 1_000_000_000;
+const ACCOUNT_BOND: u64 = 1_000;
+
+const MIN_BOND: u64 = 500;
+const MAX_VALIDATORS: u32 = 2;
+
+const TEST_BOND: &str = "bond";
+const TEST_UNBOND: &str = "unbond";
+
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+const ARG_AMOUNT: &str = "amount";
+const ARG_ENTRY_POINT: &str = "entry_point";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for public_key in &[ACCOUNT_1_ADDR, ACCOUNT_2_ADDR] {
+            tmp.push(GenesisAccount::new(
+                *public_key,
+                Motes::new(ACCOUNT_BALANCE.into()),
+                Motes::new(ACCOUNT_BOND.into()),
+            ));
+        }
+        tmp
+    };
+    let stake_limits = StakeLimits {
+        min_bond: U512::from(MIN_BOND),
+        max_validators: MAX_VALIDATORS,
+        ..Default::default()
+    };
+    let exec_config = ExecConfig::new(
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        accounts,
+        *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        *DEFAULT_PAYMENT_COSTS,
+        QueueDelays::default(),
+        stake_limits,
+        DEFAULT_REWARD_RATE,
+    );
+    let run_genesis_request = RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+    builder
+}
+
+fn get_stake_limits(builder: &InMemoryWasmTestBuilder) -> StakeLimits {
+    let pos_contract = Key::from(builder.get_pos_contract_hash());
+    builder
+        .query(None, pos_contract, &[POS_STAKE_LIMITS])
+        .and_then(|value| CLValue::try_from(value).map_err(|error| format!("{:?}", error)))
+        .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+        .expect("should find stake limits")
+}
+
+fn assert_pos_error(builder: &InMemoryWasmTestBuilder, index: usize, expected: u8) {
+    let response = builder
+        .get_exec_response(index)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+    if !cfg!(feature = "enable-bonding") {
+        assert!(error_message.contains(&format!("{:?}", ApiError::Unhandled)));
+    } else {
+        assert!(error_message.contains(&format!("{:?}", ApiError::ProofOfStake(expected))));
+    }
+}
+
+#[ignore]
+#[test]
+fn should_not_bond_more_than_max_validators() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        runtime_args! {
+            ARG_ENTRY_POINT => String::from(TEST_BOND),
+            ARG_AMOUNT => U512::from(ACCOUNT_BOND),
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    // pos::Error::TooManyValidators => 35
+    assert_pos_error(&builder, 0, 35);
+}
+
+#[ignore]
+#[test]
+fn should_not_unbond_below_min_bond() {
+    let mut builder = setup();
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        runtime_args! {
+            ARG_ENTRY_POINT => String::from(TEST_UNBOND),
+            ARG_AMOUNT => Some(U512::from(ACCOUNT_BOND - MIN_BOND + 1)),
+        },
+    )
+    .build();
+    builder.exec(exec_request_1).commit();
+
+    // pos::Error::BelowMinimumBond => 34
+    assert_pos_error(&builder, 0, 34);
+    if !cfg!(feature = "enable-bonding") {
+        return;
+    }
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        runtime_args! {
+            ARG_ENTRY_POINT => String::from(TEST_UNBOND),
+            ARG_AMOUNT => Some(U512::from(ACCOUNT_BOND - MIN_BOND)),
+        },
+    )
+    .build();
+    builder.exec(exec_request_2).expect_success().commit();
+
+    assert_eq!(
+        builder.get_bid_state().get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(MIN_BOND))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_store_stake_limits_in_pos_named_keys_and_update_them_on_upgrade() {
+    let mut builder = setup();
+    assert_eq!(get_stake_limits(&builder).min_bond, U512::from(MIN_BOND));
+    assert_eq!(get_stake_limits(&builder).max_validators, MAX_VALIDATORS);
+
+    let new_stake_limits = StakeLimits {
+        min_bond: U512::from(MIN_BOND + 1),
+        max_validators: MAX_VALIDATORS + 1,
+        ..Default::default()
+    };
+    let sem_ver = DEFAULT_PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_stake_limits(new_stake_limits)
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+    assert_eq!(get_stake_limits(&builder), new_stake_limits);
+}
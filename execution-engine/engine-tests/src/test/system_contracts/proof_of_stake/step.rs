@@ -0,0 +1,174 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::{
+    genesis::{ExecConfig, GenesisAccount, QueueDelays, StakeLimits, POS_QUEUE_DELAYS},
+    run_genesis_request::RunGenesisRequest,
+    upgrade::ActivationPoint,
+};
+use engine_shared::{motes::Motes, payment_costs::DEFAULT_CONV_RATE};
+use engine_test_support::internal::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNTS,
+    DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_PAYMENT_COSTS,
+    DEFAULT_PROTOCOL_VERSION, DEFAULT_REWARD_RATE, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT,
+    POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+};
+use types::{account::PublicKey, runtime_args, CLValue, Key, ProtocolVersion, RuntimeArgs, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 1_000_000_000;
+const ACCOUNT_1_BOND: u64 = 1_000;
+const ACCOUNT_1_UNBOND: u64 = 500;
+
+const UNBOND_DELAY: u64 = 20;
+const UNBOND_TIME: u64 = 100;
+
+const TEST_UNBOND: &str = "unbond";
+
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+const ARG_AMOUNT: &str = "amount";
+const ARG_ENTRY_POINT: &str = "entry_point";
+
+fn run_genesis_request(queue_delays: QueueDelays) -> RunGenesisRequest {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp
+    };
+    let exec_config = ExecConfig::new(
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        accounts,
+        *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        *DEFAULT_PAYMENT_COSTS,
+        queue_delays,
+        StakeLimits::default(),
+        DEFAULT_REWARD_RATE,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+}
+
+fn get_queue_delays(builder: &InMemoryWasmTestBuilder) -> QueueDelays {
+    let pos_contract = Key::from(builder.get_pos_contract_hash());
+    builder
+        .query(None, pos_contract, &[POS_QUEUE_DELAYS])
+        .and_then(|value| CLValue::try_from(value).map_err(|error| format!("{:?}", error)))
+        .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+        .expect("should find queue delays")
+}
+
+#[ignore]
+#[test]
+fn should_store_queue_delays_in_pos_named_keys_and_update_them_on_upgrade() {
+    let queue_delays = QueueDelays {
+        bond_delay: 0,
+        unbond_delay: UNBOND_DELAY,
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(queue_delays));
+    assert_eq!(get_queue_delays(&builder), queue_delays);
+
+    let new_queue_delays = QueueDelays {
+        bond_delay: 1,
+        unbond_delay: UNBOND_DELAY + 1,
+    };
+    let sem_ver = DEFAULT_PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_queue_delays(new_queue_delays)
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+    assert_eq!(get_queue_delays(&builder), new_queue_delays);
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_unbond_only_after_unbond_delay() {
+    let queue_delays = QueueDelays {
+        bond_delay: 0,
+        unbond_delay: UNBOND_DELAY,
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request(queue_delays));
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        runtime_args! {
+            ARG_ENTRY_POINT => String::from(TEST_UNBOND),
+            ARG_AMOUNT => Some(U512::from(ACCOUNT_1_UNBOND)),
+        },
+    )
+    .with_block_time(UNBOND_TIME)
+    .build();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1");
+    let balance_before = builder.get_purse_balance(account_1.main_purse());
+
+    builder.exec(exec_request);
+    if !cfg!(feature = "enable-bonding") && builder.is_error() {
+        return;
+    }
+    builder.expect_success().commit();
+
+    let exec_response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_response)[0], DEFAULT_CONV_RATE)
+        .expect("should convert");
+    let balance_after_unbond = balance_before - gas_cost.value();
+
+    // The request is queued, not paid out, while the delay has not passed yet.
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        balance_after_unbond
+    );
+    builder.step(UNBOND_TIME + UNBOND_DELAY - 1);
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        balance_after_unbond
+    );
+
+    builder.step(UNBOND_TIME + UNBOND_DELAY);
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        balance_after_unbond + ACCOUNT_1_UNBOND
+    );
+
+    // The request has been removed from the queue, so it is not paid out twice.
+    builder.step(UNBOND_TIME + UNBOND_DELAY + 1);
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        balance_after_unbond + ACCOUNT_1_UNBOND
+    );
+    assert_eq!(
+        builder.get_bid_state().get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_BOND - ACCOUNT_1_UNBOND))
+    );
+}
@@ -1,10 +1,14 @@
 use assert_matches::assert_matches;
 
 use engine_core::{
-    engine_state::{genesis::POS_REWARDS_PURSE, Error, CONV_RATE, MAX_PAYMENT},
+    engine_state::{genesis::POS_REWARDS_PURSE, Error},
     execution,
 };
-use engine_shared::{motes::Motes, transform::Transform};
+use engine_shared::{
+    motes::Motes,
+    payment_costs::{DEFAULT_CONV_RATE, DEFAULT_MAX_PAYMENT_COST},
+    transform::Transform,
+};
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
@@ -30,7 +34,7 @@ fn should_raise_insufficient_payment_when_caller_lacks_minimum_balance() {
     let exec_request = ExecuteRequestBuilder::standard(
         DEFAULT_ACCOUNT_ADDR,
         TRANSFER_PURSE_TO_ACCOUNT_WASM,
-        runtime_args! { "target" =>account_1_public_key, "amount" => U512::from(MAX_PAYMENT - 1) },
+        runtime_args! { "target" =>account_1_public_key, "amount" => U512::from(DEFAULT_MAX_PAYMENT_COST - 1) },
     )
     .build();
 
@@ -111,7 +115,7 @@ fn should_raise_insufficient_payment_when_payment_code_does_not_pay_enough() {
     let reward_balance = get_pos_rewards_purse_balance(&builder);
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT_COST);
 
     assert_eq!(
         modified_balance,
@@ -178,7 +182,7 @@ fn should_raise_insufficient_payment_error_when_out_of_gas() {
         .finish();
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT_COST);
 
     let modified_balance = builder.get_purse_balance(
         builder
@@ -251,7 +255,7 @@ fn should_forward_payment_execution_runtime_error() {
         .finish();
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT_COST);
 
     let modified_balance = builder.get_purse_balance(
         builder
@@ -320,7 +324,7 @@ fn should_forward_payment_execution_gas_limit_error() {
         .finish();
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT_COST);
 
     let modified_balance = builder.get_purse_balance(
         builder
@@ -440,7 +444,7 @@ fn should_correctly_charge_when_session_code_runs_out_of_gas() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
 
     let tally = motes.value() + modified_balance;
 
@@ -502,7 +506,7 @@ fn should_correctly_charge_when_session_code_fails() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
     let tally = motes.value() + modified_balance;
 
     assert_eq!(
@@ -560,7 +564,7 @@ fn should_correctly_charge_when_session_code_succeeds() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_CONV_RATE).expect("should have motes");
     let total = motes.value() + U512::from(transferred_amount);
     let tally = total + modified_balance;
 
@@ -0,0 +1,42 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{account::PublicKey, runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+
+#[ignore]
+#[test]
+fn should_record_total_supply_at_genesis() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    assert_eq!(
+        builder.get_total_supply(),
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE)
+    );
+    builder.assert_total_supply_invariant();
+}
+
+#[ignore]
+#[test]
+fn should_not_change_total_supply_on_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let total_supply_before = builder.get_total_supply();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! { "target" => ACCOUNT_1_ADDR, "amount" => U512::from(TRANSFER_AMOUNT) },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(builder.get_total_supply(), total_supply_before);
+    builder.assert_total_supply_invariant();
+}
@@ -1,5 +1,6 @@
 use engine_core::engine_state::{upgrade::ActivationPoint, Error};
 use engine_grpc_server::engine_server::ipc::DeployCode;
+use engine_shared::payment_costs::PaymentCosts;
 #[cfg(feature = "use-system-contracts")]
 use engine_shared::{stored_value::StoredValue, transform::Transform};
 use engine_test_support::internal::{
@@ -8,7 +9,10 @@ use engine_test_support::internal::{
 };
 #[cfg(feature = "use-system-contracts")]
 use engine_test_support::{internal::ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use engine_wasm_prep::wasm_costs::WasmCosts;
+use engine_wasm_prep::{
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    wasm_costs::WasmCosts,
+};
 use types::ProtocolVersion;
 #[cfg(feature = "use-system-contracts")]
 use types::{runtime_args, CLValue, Key, RuntimeArgs, U512};
@@ -353,6 +357,120 @@ fn should_allow_only_wasm_costs_patch_version() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_allow_only_host_function_costs_patch_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_host_function_costs = HostFunctionCosts {
+        write: HostFunctionCost::new(10_000, 100),
+        call_contract: HostFunctionCost::new(100_000, 1_000),
+        ..Default::default()
+    };
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_host_function_costs(new_host_function_costs)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_host_function_costs = builder
+        .get_engine_state()
+        .host_function_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded costs");
+
+    assert_eq!(
+        new_host_function_costs, upgraded_host_function_costs,
+        "upgraded host function costs should equal new costs"
+    );
+
+    let upgraded_wasm_costs = builder
+        .get_engine_state()
+        .wasm_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have costs");
+
+    assert_eq!(
+        *DEFAULT_WASM_COSTS, upgraded_wasm_costs,
+        "wasm costs should be unchanged"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_only_payment_costs_patch_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_payment_costs = PaymentCosts {
+        conv_rate: 2,
+        max_payment_cost: 20_000_000,
+        standard_payment_cap: 1_000_000_000,
+    };
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_payment_costs(new_payment_costs)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_payment_costs = builder
+        .get_engine_state()
+        .payment_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded costs");
+
+    assert_eq!(
+        new_payment_costs, upgraded_payment_costs,
+        "upgraded payment costs should equal new costs"
+    );
+
+    let upgraded_wasm_costs = builder
+        .get_engine_state()
+        .wasm_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have costs");
+
+    assert_eq!(
+        *DEFAULT_WASM_COSTS, upgraded_wasm_costs,
+        "wasm costs should be unchanged"
+    );
+}
+
 #[ignore]
 #[test]
 fn should_allow_only_wasm_costs_minor_version() {
@@ -0,0 +1,519 @@
+use types::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
+
+const NUM_FIELDS: usize = 51;
+pub const HOST_FUNCTION_COST_SERIALIZED_LENGTH: usize = 2 * U32_SERIALIZED_LENGTH;
+pub const HOST_FUNCTION_COSTS_SERIALIZED_LENGTH: usize =
+    NUM_FIELDS * HOST_FUNCTION_COST_SERIALIZED_LENGTH;
+
+/// The default cost of `emit_event`. Events are returned with the execution results, so each byte
+/// of the event name and value is charged.
+pub const DEFAULT_EMIT_EVENT_COST: HostFunctionCost = HostFunctionCost {
+    base: 10_000,
+    per_byte: 100,
+};
+
+/// The default cost of `list_named_keys_page`, charged per byte of the cursor and of the page.
+pub const DEFAULT_LIST_NAMED_KEYS_PAGE_COST: HostFunctionCost = HostFunctionCost {
+    base: 10_000,
+    per_byte: 100,
+};
+
+/// The default cost of `list_local_keys`, charged per byte of the prefix, of the cursor and of
+/// every local key visited.
+pub const DEFAULT_LIST_LOCAL_KEYS_COST: HostFunctionCost = HostFunctionCost {
+    base: 10_000,
+    per_byte: 100,
+};
+
+/// The default cost of `blake2b256`, charged per byte of the data hashed.
+pub const DEFAULT_BLAKE2B256_COST: HostFunctionCost = HostFunctionCost {
+    base: 1_000,
+    per_byte: 10,
+};
+
+/// The default cost of `sha256`, charged per byte of the data hashed.
+pub const DEFAULT_SHA256_COST: HostFunctionCost = HostFunctionCost {
+    base: 1_000,
+    per_byte: 10,
+};
+
+/// The default cost of `keccak256`, charged per byte of the data hashed.
+pub const DEFAULT_KECCAK256_COST: HostFunctionCost = HostFunctionCost {
+    base: 1_000,
+    per_byte: 10,
+};
+
+/// The default cost of `verify_ed25519`, charged per byte of the message verified.
+pub const DEFAULT_VERIFY_ED25519_COST: HostFunctionCost = HostFunctionCost {
+    base: 100_000,
+    per_byte: 10,
+};
+
+/// The default cost of `recover_secp256k1`, charged per byte of the message hash and signature.
+pub const DEFAULT_RECOVER_SECP256K1_COST: HostFunctionCost = HostFunctionCost {
+    base: 100_000,
+    per_byte: 10,
+};
+
+/// The default cost of `random_bytes`, charged per byte written to the Wasm memory.
+pub const DEFAULT_RANDOM_BYTES_COST: HostFunctionCost = HostFunctionCost {
+    base: 1_000,
+    per_byte: 10,
+};
+
+/// The default cost of `read_many`, charged per byte of the serialized keys.
+pub const DEFAULT_READ_MANY_COST: HostFunctionCost = HostFunctionCost {
+    base: 10_000,
+    per_byte: 100,
+};
+
+/// The default cost of `write_many`, charged per byte of the serialized entries.
+pub const DEFAULT_WRITE_MANY_COST: HostFunctionCost = HostFunctionCost {
+    base: 10_000,
+    per_byte: 100,
+};
+
+/// The cost of a single call to a host function.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCost {
+    /// Cost charged for every call
+    pub base: u32,
+    /// Cost charged per byte of the arguments passed in Wasm memory
+    pub per_byte: u32,
+}
+
+impl HostFunctionCost {
+    pub fn new(base: u32, per_byte: u32) -> Self {
+        HostFunctionCost { base, per_byte }
+    }
+
+    /// Returns the cost of a call passing `bytes` bytes of arguments.
+    pub fn calculate_cost(&self, bytes: u32) -> u64 {
+        u64::from(self.base) + u64::from(self.per_byte) * u64::from(bytes)
+    }
+}
+
+impl ToBytes for HostFunctionCost {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.base.to_bytes()?);
+        ret.append(&mut self.per_byte.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        HOST_FUNCTION_COST_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for HostFunctionCost {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (base, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((HostFunctionCost { base, per_byte }, rem))
+    }
+}
+
+/// The costs of calling each of the host functions available to contracts, charged on top of the
+/// cost of the Wasm opcodes given by [`WasmCosts`](crate::wasm_costs::WasmCosts).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCosts {
+    pub read_value: HostFunctionCost,
+    pub read_value_local: HostFunctionCost,
+    pub write: HostFunctionCost,
+    pub write_local: HostFunctionCost,
+    pub add: HostFunctionCost,
+    pub new_uref: HostFunctionCost,
+    pub load_named_keys: HostFunctionCost,
+    pub ret: HostFunctionCost,
+    pub get_key: HostFunctionCost,
+    pub has_key: HostFunctionCost,
+    pub put_key: HostFunctionCost,
+    pub remove_key: HostFunctionCost,
+    pub revert: HostFunctionCost,
+    pub is_valid_uref: HostFunctionCost,
+    pub add_associated_key: HostFunctionCost,
+    pub remove_associated_key: HostFunctionCost,
+    pub update_associated_key: HostFunctionCost,
+    pub set_action_threshold: HostFunctionCost,
+    pub get_caller: HostFunctionCost,
+    pub get_blocktime: HostFunctionCost,
+    pub create_purse: HostFunctionCost,
+    pub transfer_to_account: HostFunctionCost,
+    pub transfer_from_purse_to_account: HostFunctionCost,
+    pub transfer_from_purse_to_purse: HostFunctionCost,
+    pub get_balance: HostFunctionCost,
+    pub get_phase: HostFunctionCost,
+    pub get_system_contract: HostFunctionCost,
+    pub get_main_purse: HostFunctionCost,
+    pub read_host_buffer: HostFunctionCost,
+    pub create_contract_package_at_hash: HostFunctionCost,
+    pub create_contract_user_group: HostFunctionCost,
+    pub add_contract_version: HostFunctionCost,
+    pub disable_contract_version: HostFunctionCost,
+    pub call_contract: HostFunctionCost,
+    pub call_versioned_contract: HostFunctionCost,
+    pub get_named_arg_size: HostFunctionCost,
+    pub get_named_arg: HostFunctionCost,
+    pub remove_contract_user_group: HostFunctionCost,
+    pub provision_contract_user_group_uref: HostFunctionCost,
+    pub remove_contract_user_group_urefs: HostFunctionCost,
+    pub emit_event: HostFunctionCost,
+    pub list_named_keys_page: HostFunctionCost,
+    pub list_local_keys: HostFunctionCost,
+    pub blake2b256: HostFunctionCost,
+    pub sha256: HostFunctionCost,
+    pub keccak256: HostFunctionCost,
+    pub verify_ed25519: HostFunctionCost,
+    pub recover_secp256k1: HostFunctionCost,
+    pub random_bytes: HostFunctionCost,
+    pub read_many: HostFunctionCost,
+    pub write_many: HostFunctionCost,
+}
+
+impl Default for HostFunctionCosts {
+    fn default() -> Self {
+        HostFunctionCosts {
+            read_value: HostFunctionCost::default(),
+            read_value_local: HostFunctionCost::default(),
+            write: HostFunctionCost::default(),
+            write_local: HostFunctionCost::default(),
+            add: HostFunctionCost::default(),
+            new_uref: HostFunctionCost::default(),
+            load_named_keys: HostFunctionCost::default(),
+            ret: HostFunctionCost::default(),
+            get_key: HostFunctionCost::default(),
+            has_key: HostFunctionCost::default(),
+            put_key: HostFunctionCost::default(),
+            remove_key: HostFunctionCost::default(),
+            revert: HostFunctionCost::default(),
+            is_valid_uref: HostFunctionCost::default(),
+            add_associated_key: HostFunctionCost::default(),
+            remove_associated_key: HostFunctionCost::default(),
+            update_associated_key: HostFunctionCost::default(),
+            set_action_threshold: HostFunctionCost::default(),
+            get_caller: HostFunctionCost::default(),
+            get_blocktime: HostFunctionCost::default(),
+            create_purse: HostFunctionCost::default(),
+            transfer_to_account: HostFunctionCost::default(),
+            transfer_from_purse_to_account: HostFunctionCost::default(),
+            transfer_from_purse_to_purse: HostFunctionCost::default(),
+            get_balance: HostFunctionCost::default(),
+            get_phase: HostFunctionCost::default(),
+            get_system_contract: HostFunctionCost::default(),
+            get_main_purse: HostFunctionCost::default(),
+            read_host_buffer: HostFunctionCost::default(),
+            create_contract_package_at_hash: HostFunctionCost::default(),
+            create_contract_user_group: HostFunctionCost::default(),
+            add_contract_version: HostFunctionCost::default(),
+            disable_contract_version: HostFunctionCost::default(),
+            call_contract: HostFunctionCost::default(),
+            call_versioned_contract: HostFunctionCost::default(),
+            get_named_arg_size: HostFunctionCost::default(),
+            get_named_arg: HostFunctionCost::default(),
+            remove_contract_user_group: HostFunctionCost::default(),
+            provision_contract_user_group_uref: HostFunctionCost::default(),
+            remove_contract_user_group_urefs: HostFunctionCost::default(),
+            emit_event: DEFAULT_EMIT_EVENT_COST,
+            list_named_keys_page: DEFAULT_LIST_NAMED_KEYS_PAGE_COST,
+            list_local_keys: DEFAULT_LIST_LOCAL_KEYS_COST,
+            blake2b256: DEFAULT_BLAKE2B256_COST,
+            sha256: DEFAULT_SHA256_COST,
+            keccak256: DEFAULT_KECCAK256_COST,
+            verify_ed25519: DEFAULT_VERIFY_ED25519_COST,
+            recover_secp256k1: DEFAULT_RECOVER_SECP256K1_COST,
+            random_bytes: DEFAULT_RANDOM_BYTES_COST,
+            read_many: DEFAULT_READ_MANY_COST,
+            write_many: DEFAULT_WRITE_MANY_COST,
+        }
+    }
+}
+
+impl ToBytes for HostFunctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.read_value.to_bytes()?);
+        ret.append(&mut self.read_value_local.to_bytes()?);
+        ret.append(&mut self.write.to_bytes()?);
+        ret.append(&mut self.write_local.to_bytes()?);
+        ret.append(&mut self.add.to_bytes()?);
+        ret.append(&mut self.new_uref.to_bytes()?);
+        ret.append(&mut self.load_named_keys.to_bytes()?);
+        ret.append(&mut self.ret.to_bytes()?);
+        ret.append(&mut self.get_key.to_bytes()?);
+        ret.append(&mut self.has_key.to_bytes()?);
+        ret.append(&mut self.put_key.to_bytes()?);
+        ret.append(&mut self.remove_key.to_bytes()?);
+        ret.append(&mut self.revert.to_bytes()?);
+        ret.append(&mut self.is_valid_uref.to_bytes()?);
+        ret.append(&mut self.add_associated_key.to_bytes()?);
+        ret.append(&mut self.remove_associated_key.to_bytes()?);
+        ret.append(&mut self.update_associated_key.to_bytes()?);
+        ret.append(&mut self.set_action_threshold.to_bytes()?);
+        ret.append(&mut self.get_caller.to_bytes()?);
+        ret.append(&mut self.get_blocktime.to_bytes()?);
+        ret.append(&mut self.create_purse.to_bytes()?);
+        ret.append(&mut self.transfer_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
+        ret.append(&mut self.get_balance.to_bytes()?);
+        ret.append(&mut self.get_phase.to_bytes()?);
+        ret.append(&mut self.get_system_contract.to_bytes()?);
+        ret.append(&mut self.get_main_purse.to_bytes()?);
+        ret.append(&mut self.read_host_buffer.to_bytes()?);
+        ret.append(&mut self.create_contract_package_at_hash.to_bytes()?);
+        ret.append(&mut self.create_contract_user_group.to_bytes()?);
+        ret.append(&mut self.add_contract_version.to_bytes()?);
+        ret.append(&mut self.disable_contract_version.to_bytes()?);
+        ret.append(&mut self.call_contract.to_bytes()?);
+        ret.append(&mut self.call_versioned_contract.to_bytes()?);
+        ret.append(&mut self.get_named_arg_size.to_bytes()?);
+        ret.append(&mut self.get_named_arg.to_bytes()?);
+        ret.append(&mut self.remove_contract_user_group.to_bytes()?);
+        ret.append(&mut self.provision_contract_user_group_uref.to_bytes()?);
+        ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.list_named_keys_page.to_bytes()?);
+        ret.append(&mut self.list_local_keys.to_bytes()?);
+        ret.append(&mut self.blake2b256.to_bytes()?);
+        ret.append(&mut self.sha256.to_bytes()?);
+        ret.append(&mut self.keccak256.to_bytes()?);
+        ret.append(&mut self.verify_ed25519.to_bytes()?);
+        ret.append(&mut self.recover_secp256k1.to_bytes()?);
+        ret.append(&mut self.random_bytes.to_bytes()?);
+        ret.append(&mut self.read_many.to_bytes()?);
+        ret.append(&mut self.write_many.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        HOST_FUNCTION_COSTS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for HostFunctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (read_value, rem) = HostFunctionCost::from_bytes(bytes)?;
+        let (read_value_local, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (write, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (write_local, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (add, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (new_uref, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (load_named_keys, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (ret, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (has_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (put_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (remove_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (revert, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (is_valid_uref, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (add_associated_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (remove_associated_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (update_associated_key, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (set_action_threshold, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_caller, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_blocktime, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (create_purse, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (transfer_to_account, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (transfer_from_purse_to_account, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (transfer_from_purse_to_purse, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_balance, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_phase, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_system_contract, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_main_purse, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (read_host_buffer, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (create_contract_package_at_hash, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (create_contract_user_group, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (add_contract_version, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (disable_contract_version, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (call_contract, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (call_versioned_contract, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_named_arg_size, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (get_named_arg, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (remove_contract_user_group, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (provision_contract_user_group_uref, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (remove_contract_user_group_urefs, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (emit_event, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (list_named_keys_page, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (list_local_keys, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (blake2b256, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (sha256, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (keccak256, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (verify_ed25519, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (recover_secp256k1, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (random_bytes, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (read_many, rem) = HostFunctionCost::from_bytes(rem)?;
+        let (write_many, rem) = HostFunctionCost::from_bytes(rem)?;
+        let host_function_costs = HostFunctionCosts {
+            read_value,
+            read_value_local,
+            write,
+            write_local,
+            add,
+            new_uref,
+            load_named_keys,
+            ret,
+            get_key,
+            has_key,
+            put_key,
+            remove_key,
+            revert,
+            is_valid_uref,
+            add_associated_key,
+            remove_associated_key,
+            update_associated_key,
+            set_action_threshold,
+            get_caller,
+            get_blocktime,
+            create_purse,
+            transfer_to_account,
+            transfer_from_purse_to_account,
+            transfer_from_purse_to_purse,
+            get_balance,
+            get_phase,
+            get_system_contract,
+            get_main_purse,
+            read_host_buffer,
+            create_contract_package_at_hash,
+            create_contract_user_group,
+            add_contract_version,
+            disable_contract_version,
+            call_contract,
+            call_versioned_contract,
+            get_named_arg_size,
+            get_named_arg,
+            remove_contract_user_group,
+            provision_contract_user_group_uref,
+            remove_contract_user_group_urefs,
+            emit_event,
+            list_named_keys_page,
+            list_local_keys,
+            blake2b256,
+            sha256,
+            keccak256,
+            verify_ed25519,
+            recover_secp256k1,
+            random_bytes,
+            read_many,
+            write_many,
+        };
+        Ok((host_function_costs, rem))
+    }
+}
+
+pub mod gens {
+    use proptest::{num, prelude::*, prop_compose};
+
+    use crate::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
+    prop_compose! {
+        pub fn host_function_cost_arb()(
+            base in num::u32::ANY,
+            per_byte in num::u32::ANY,
+        ) -> HostFunctionCost {
+            HostFunctionCost { base, per_byte }
+        }
+    }
+
+    prop_compose! {
+        pub fn host_function_costs_arb()(
+            costs in prop::collection::vec(host_function_cost_arb(), super::NUM_FIELDS),
+        ) -> HostFunctionCosts {
+            let mut costs = costs.into_iter();
+            let mut next = || costs.next().expect("should have enough costs");
+            HostFunctionCosts {
+                read_value: next(),
+                read_value_local: next(),
+                write: next(),
+                write_local: next(),
+                add: next(),
+                new_uref: next(),
+                load_named_keys: next(),
+                ret: next(),
+                get_key: next(),
+                has_key: next(),
+                put_key: next(),
+                remove_key: next(),
+                revert: next(),
+                is_valid_uref: next(),
+                add_associated_key: next(),
+                remove_associated_key: next(),
+                update_associated_key: next(),
+                set_action_threshold: next(),
+                get_caller: next(),
+                get_blocktime: next(),
+                create_purse: next(),
+                transfer_to_account: next(),
+                transfer_from_purse_to_account: next(),
+                transfer_from_purse_to_purse: next(),
+                get_balance: next(),
+                get_phase: next(),
+                get_system_contract: next(),
+                get_main_purse: next(),
+                read_host_buffer: next(),
+                create_contract_package_at_hash: next(),
+                create_contract_user_group: next(),
+                add_contract_version: next(),
+                disable_contract_version: next(),
+                call_contract: next(),
+                call_versioned_contract: next(),
+                get_named_arg_size: next(),
+                get_named_arg: next(),
+                remove_contract_user_group: next(),
+                provision_contract_user_group_uref: next(),
+                remove_contract_user_group_urefs: next(),
+                emit_event: next(),
+                list_named_keys_page: next(),
+                list_local_keys: next(),
+                blake2b256: next(),
+                sha256: next(),
+                keccak256: next(),
+                verify_ed25519: next(),
+                recover_secp256k1: next(),
+                random_bytes: next(),
+                read_many: next(),
+                write_many: next(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::bytesrepr;
+
+    use super::{gens, HostFunctionCost, HostFunctionCosts};
+
+    #[test]
+    fn should_calculate_cost() {
+        let cost = HostFunctionCost::new(100, 3);
+        assert_eq!(cost.calculate_cost(0), 100);
+        assert_eq!(cost.calculate_cost(10), 130);
+
+        let max = HostFunctionCost::new(std::u32::MAX, std::u32::MAX);
+        let expected = u64::from(std::u32::MAX) * (u64::from(std::u32::MAX) + 1);
+        assert_eq!(max.calculate_cost(std::u32::MAX), expected);
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let mock = HostFunctionCosts {
+            write: HostFunctionCost::new(1, 2),
+            call_contract: HostFunctionCost::new(3, 4),
+            ..Default::default()
+        };
+        bytesrepr::test_serialization_roundtrip(&mock);
+        bytesrepr::test_serialization_roundtrip(&HostFunctionCosts::default());
+    }
+
+    proptest! {
+        #[test]
+        fn should_serialize_and_deserialize_with_arbitrary_values(
+            host_function_costs in gens::host_function_costs_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&host_function_costs);
+        }
+    }
+}
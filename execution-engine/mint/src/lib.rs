@@ -11,12 +11,18 @@ pub use crate::{runtime_provider::RuntimeProvider, storage_provider::StorageProv
 
 const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0; 32]);
 
+/// The name of the Mint contract's named key under which the total supply of motes is stored.
+pub const TOTAL_SUPPLY_KEY: &str = "total_supply";
+
 pub trait Mint: RuntimeProvider + StorageProvider {
     fn mint(&mut self, initial_balance: U512) -> Result<URef, Error> {
         let caller = self.get_caller();
         if !initial_balance.is_zero() && caller != SYSTEM_ACCOUNT {
             return Err(Error::InvalidNonEmptyPurseCreation);
         }
+        if !initial_balance.is_zero() {
+            increase_total_supply(self, initial_balance)?;
+        }
 
         let balance_key: Key = self.new_uref(initial_balance).into();
         let purse_uref: URef = self.new_uref(());
@@ -66,4 +72,71 @@ pub trait Mint: RuntimeProvider + StorageProvider {
         self.add(target_balance, amount)?;
         Ok(())
     }
+
+    /// Adds `amount` newly created motes to the balance of `purse`. Only the system account may
+    /// mint rewards.
+    fn mint_reward(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        if self.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::UnauthorizedRewardMinting);
+        }
+        if !purse.is_addable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::DestNotFound),
+        };
+        increase_total_supply(self, amount)?;
+        self.add(balance, amount)
+    }
+
+    /// Removes `amount` motes from the balance of `purse` and from the total supply. Only the
+    /// system account may burn motes.
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        if self.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::UnauthorizedBurning);
+        }
+        if !purse.is_writeable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::SourceNotFound),
+        };
+        let balance_value: U512 = match self.read(balance)? {
+            Some(balance_value) => balance_value,
+            None => return Err(Error::SourceNotFound),
+        };
+        if amount > balance_value {
+            return Err(Error::InsufficientFunds);
+        }
+        let total_supply = get_total_supply_uref(self)?;
+        let total_supply_value: U512 =
+            self.read(total_supply)?.ok_or(Error::TotalSupplyNotFound)?;
+        let new_total_supply = total_supply_value
+            .checked_sub(amount)
+            .ok_or(Error::TotalSupplyUnderflow)?;
+        self.write(balance, balance_value - amount)?;
+        self.write(total_supply, new_total_supply)
+    }
+
+    /// Returns the total supply of motes, i.e. the sum of the balances of all purses.
+    fn total_supply(&mut self) -> Result<U512, Error> {
+        let total_supply = get_total_supply_uref(self)?;
+        self.read(total_supply)?.ok_or(Error::TotalSupplyNotFound)
+    }
+}
+
+/// Returns the URef under which the total supply is stored. It is created by the Mint installer.
+fn get_total_supply_uref<R: RuntimeProvider + ?Sized>(runtime_provider: &R) -> Result<URef, Error> {
+    match runtime_provider.get_key(TOTAL_SUPPLY_KEY) {
+        Some(key) => URef::try_from(key).map_err(|_| Error::InvalidAccessRights),
+        None => Err(Error::TotalSupplyNotFound),
+    }
+}
+
+/// Adds `amount` to the total supply.
+fn increase_total_supply<M: Mint + ?Sized>(mint: &mut M, amount: U512) -> Result<(), Error> {
+    let total_supply = get_total_supply_uref(mint)?;
+    mint.add(total_supply, amount)
 }
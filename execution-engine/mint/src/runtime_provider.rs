@@ -3,5 +3,7 @@ use types::{account::PublicKey, Key};
 pub trait RuntimeProvider {
     fn get_caller(&self) -> PublicKey;
 
+    fn get_key(&self, name: &str) -> Option<Key>;
+
     fn put_key(&mut self, name: &str, key: Key);
 }
@@ -0,0 +1,299 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::result;
+
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    system_contract_errors::pos::{Error, Result},
+    CLType, CLTyped, U512,
+};
+
+/// The delegator book of a single validator, assigning the delegated amount of motes to each
+/// delegator.
+///
+/// The delegated motes are part of the validator's stakes; the rest of the stakes are the
+/// validator's own.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DelegatorBook(pub BTreeMap<PublicKey, U512>);
+
+impl DelegatorBook {
+    /// Returns the total amount delegated to the validator.
+    pub fn total(&self) -> U512 {
+        self.0
+            .values()
+            .fold(U512::zero(), |sum, amount| sum.saturating_add(*amount))
+    }
+
+    /// Adds `amount` to the delegator's delegation.
+    pub fn delegate(&mut self, delegator: &PublicKey, amount: U512) {
+        self.0
+            .entry(*delegator)
+            .and_modify(|x| *x += amount)
+            .or_insert(amount);
+    }
+
+    /// If `maybe_amount` is `None`, removes the delegator's whole delegation, otherwise subtracts
+    /// the given amount.
+    ///
+    /// Returns the amount that was actually subtracted, or an error if the delegator has no
+    /// delegation or the amount exceeds it.
+    pub fn undelegate(
+        &mut self,
+        delegator: &PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<U512> {
+        let delegation = self.0.get_mut(delegator).ok_or(Error::NotDelegated)?;
+        let amount = maybe_amount.unwrap_or(*delegation);
+        if amount > *delegation {
+            return Err(Error::UndelegateTooLarge);
+        }
+        *delegation -= amount;
+        if delegation.is_zero() {
+            self.0.remove(delegator);
+        }
+        Ok(amount)
+    }
+
+    /// Splits `amount` among the delegators in proportion to their part of the validator's total
+    /// `stake`, rounding down. Whatever is not assigned to a delegator is the validator's own part.
+    pub fn shares(&self, stake: U512, amount: U512) -> BTreeMap<PublicKey, U512> {
+        if stake.is_zero() {
+            return BTreeMap::new();
+        }
+        self.0
+            .iter()
+            .map(|(delegator, delegation)| (*delegator, amount * *delegation / stake))
+            .collect()
+    }
+
+    /// Decreases the delegations by their proportional part of `amount`, slashed from the
+    /// validator's total `stake`. Delegations which are slashed completely are removed.
+    ///
+    /// The validator's own stake absorbs the rounding, as far as it suffices; anything beyond that
+    /// is taken from the delegators in order.
+    pub fn slash(&mut self, stake: U512, amount: U512) {
+        if amount >= stake {
+            self.0.clear();
+            return;
+        }
+        let shares = self.shares(stake, amount);
+        let own_stake = stake.saturating_sub(self.total());
+        let assigned = shares
+            .values()
+            .fold(U512::zero(), |sum, share| sum.saturating_add(*share));
+        let mut leftover = amount.saturating_sub(assigned).saturating_sub(own_stake);
+
+        let mut removed = Vec::new();
+        for (delegator, delegation) in self.0.iter_mut() {
+            let share = shares.get(delegator).cloned().unwrap_or_default();
+            *delegation = delegation.saturating_sub(share);
+            let extra = leftover.min(*delegation);
+            *delegation -= extra;
+            leftover -= extra;
+            if delegation.is_zero() {
+                removed.push(*delegator);
+            }
+        }
+        for delegator in removed {
+            self.0.remove(&delegator);
+        }
+    }
+}
+
+impl ToBytes for DelegatorBook {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for DelegatorBook {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (map, bytes) = BTreeMap::from_bytes(bytes)?;
+        Ok((DelegatorBook(map), bytes))
+    }
+}
+
+impl CLTyped for DelegatorBook {
+    fn cl_type() -> CLType {
+        BTreeMap::<PublicKey, U512>::cl_type()
+    }
+}
+
+/// The delegator books of all validators which have been delegated to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Delegations(pub BTreeMap<PublicKey, DelegatorBook>);
+
+impl Delegations {
+    /// Returns the delegator book of the given validator, if anything is delegated to it.
+    pub fn book(&self, validator: &PublicKey) -> Option<&DelegatorBook> {
+        self.0.get(validator)
+    }
+
+    /// Returns the total amount delegated to the given validator.
+    pub fn total_delegated(&self, validator: &PublicKey) -> U512 {
+        self.book(validator)
+            .map(DelegatorBook::total)
+            .unwrap_or_default()
+    }
+
+    /// Adds `amount` to the delegator's delegation to the given validator.
+    pub fn delegate(&mut self, validator: &PublicKey, delegator: &PublicKey, amount: U512) {
+        self.0
+            .entry(*validator)
+            .or_default()
+            .delegate(delegator, amount);
+    }
+
+    /// Subtracts from the delegator's delegation to the given validator, as per
+    /// [`DelegatorBook::undelegate`].
+    pub fn undelegate(
+        &mut self,
+        validator: &PublicKey,
+        delegator: &PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<U512> {
+        let book = self.0.get_mut(validator).ok_or(Error::NotDelegated)?;
+        let amount = book.undelegate(delegator, maybe_amount)?;
+        if book.0.is_empty() {
+            self.0.remove(validator);
+        }
+        Ok(amount)
+    }
+
+    /// Removes and returns the delegator book of the given validator.
+    pub fn remove(&mut self, validator: &PublicKey) -> Option<DelegatorBook> {
+        self.0.remove(validator)
+    }
+
+    /// Slashes the delegations to the given validator, as per [`DelegatorBook::slash`].
+    pub fn slash(&mut self, validator: &PublicKey, stake: U512, amount: U512) {
+        if let Some(book) = self.0.get_mut(validator) {
+            book.slash(stake, amount);
+            if book.0.is_empty() {
+                self.0.remove(validator);
+            }
+        }
+    }
+}
+
+impl ToBytes for Delegations {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for Delegations {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (map, bytes) = BTreeMap::from_bytes(bytes)?;
+        Ok((Delegations(map), bytes))
+    }
+}
+
+impl CLTyped for Delegations {
+    fn cl_type() -> CLType {
+        BTreeMap::<PublicKey, DelegatorBook>::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{account::PublicKey, bytesrepr, system_contract_errors::pos::Error, U512};
+
+    use super::{Delegations, DelegatorBook};
+
+    const KEY1: [u8; 32] = [1; 32];
+    const KEY2: [u8; 32] = [2; 32];
+    const KEY3: [u8; 32] = [3; 32];
+
+    fn new_book(delegations: &[([u8; 32], u64)]) -> DelegatorBook {
+        DelegatorBook(
+            delegations
+                .iter()
+                .map(|&(key, amount)| (PublicKey::ed25519_from(key), U512::from(amount)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_delegate_and_undelegate() {
+        let validator = PublicKey::ed25519_from(KEY1);
+        let delegator = PublicKey::ed25519_from(KEY2);
+        let mut delegations = Delegations::default();
+        delegations.delegate(&validator, &delegator, U512::from(30));
+        delegations.delegate(&validator, &delegator, U512::from(20));
+        assert_eq!(U512::from(50), delegations.total_delegated(&validator));
+
+        assert_eq!(
+            Err(Error::UndelegateTooLarge),
+            delegations.undelegate(&validator, &delegator, Some(U512::from(51)))
+        );
+        assert_eq!(
+            Ok(U512::from(10)),
+            delegations.undelegate(&validator, &delegator, Some(U512::from(10)))
+        );
+        assert_eq!(
+            Ok(U512::from(40)),
+            delegations.undelegate(&validator, &delegator, None)
+        );
+        assert_eq!(Delegations::default(), delegations);
+        assert_eq!(
+            Err(Error::NotDelegated),
+            delegations.undelegate(&validator, &delegator, None)
+        );
+    }
+
+    #[test]
+    fn test_shares() {
+        let book = new_book(&[(KEY2, 300), (KEY3, 100)]);
+        let shares = book.shares(U512::from(1_000), U512::from(50));
+        assert_eq!(
+            Some(&U512::from(15)),
+            shares.get(&PublicKey::ed25519_from(KEY2))
+        );
+        assert_eq!(
+            Some(&U512::from(5)),
+            shares.get(&PublicKey::ed25519_from(KEY3))
+        );
+    }
+
+    #[test]
+    fn test_slash() {
+        let mut book = new_book(&[(KEY2, 300), (KEY3, 100)]);
+        book.slash(U512::from(1_000), U512::from(500));
+        assert_eq!(new_book(&[(KEY2, 150), (KEY3, 50)]), book);
+        book.slash(U512::from(500), U512::from(500));
+        assert_eq!(DelegatorBook::default(), book);
+    }
+
+    #[test]
+    fn test_slash_rounding_beyond_own_stake() {
+        // The validator's own stake is 1, so it can't absorb all of the rounding.
+        let mut book = new_book(&[(KEY1, 1), (KEY2, 1), (KEY3, 1)]);
+        book.slash(U512::from(4), U512::from(3));
+        assert_eq!(U512::from(1), book.total());
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let mut delegations = Delegations::default();
+        delegations.delegate(
+            &PublicKey::ed25519_from(KEY1),
+            &PublicKey::ed25519_from(KEY2),
+            U512::from(5),
+        );
+        delegations.delegate(
+            &PublicKey::ed25519_from(KEY1),
+            &PublicKey::secp256k1_from([3; 33]),
+            U512::from(6),
+        );
+        bytesrepr::test_serialization_roundtrip(&delegations);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::{delegations::Delegations, Result};
+
+pub trait DelegationsProvider {
+    /// Reads the delegator books.
+    fn read_delegations(&mut self) -> Result<Delegations>;
+
+    /// Writes the delegator books.
+    fn write_delegations(&mut self, delegations: Delegations);
+}
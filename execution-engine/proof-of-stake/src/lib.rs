@@ -2,6 +2,8 @@
 
 extern crate alloc;
 
+mod delegations;
+mod delegations_provider;
 mod mint_provider;
 mod queue;
 mod queue_provider;
@@ -9,21 +11,28 @@ mod runtime_provider;
 mod stakes;
 mod stakes_provider;
 
+use alloc::collections::BTreeMap;
 use core::marker::Sized;
 
 use types::{
     account::PublicKey,
     system_contract_errors::pos::{Error, Result},
-    AccessRights, TransferredTo, URef, U512,
+    AccessRights, URef, U512,
 };
 
 pub use crate::{
-    mint_provider::MintProvider, queue::Queue, queue_provider::QueueProvider,
-    runtime_provider::RuntimeProvider, stakes::Stakes, stakes_provider::StakesProvider,
+    delegations::{Delegations, DelegatorBook},
+    delegations_provider::DelegationsProvider,
+    mint_provider::MintProvider,
+    queue::{Queue, QueueDelays},
+    queue_provider::QueueProvider,
+    runtime_provider::RuntimeProvider,
+    stakes::{StakeLimits, Stakes},
+    stakes_provider::StakesProvider,
 };
 
 pub trait ProofOfStake:
-    MintProvider + QueueProvider + RuntimeProvider + StakesProvider + Sized
+    DelegationsProvider + MintProvider + QueueProvider + RuntimeProvider + StakesProvider + Sized
 {
     fn bond(&mut self, validator: PublicKey, amount: U512, source: URef) -> Result<()> {
         if amount.is_zero() {
@@ -38,28 +47,46 @@ pub trait ProofOfStake:
             .map_err(|_| Error::BondTransferFailed)?;
         internal::bond(self, amount, validator, timestamp)?;
 
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            let _: TransferredTo = self
-                .transfer_purse_to_account(source, entry.validator, entry.amount)
-                .map_err(|_| Error::BondTransferFailed)?;
-        }
-        Ok(())
+        // Also apply any due requests, including this one if there is no delay.
+        internal::step_and_pay_out(self, timestamp)
     }
 
     fn unbond(&mut self, validator: PublicKey, maybe_amount: Option<U512>) -> Result<()> {
-        let pos_purse = internal::get_bonding_purse(self)?;
         let timestamp = self.get_block_time();
         internal::unbond(self, maybe_amount, validator, timestamp)?;
 
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            self.transfer_purse_to_account(pos_purse, entry.validator, entry.amount)
-                .map_err(|_| Error::UnbondTransferFailed)?;
-        }
-        Ok(())
+        // Also apply any due requests, including this one if there is no delay.
+        internal::step_and_pay_out(self, timestamp)
+    }
+
+    fn delegate(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        source: URef,
+    ) -> Result<()> {
+        let pos_purse = internal::get_bonding_purse(self)?;
+        let timestamp = self.get_block_time();
+        internal::delegate(self, amount, delegator, validator, timestamp)?;
+        self.transfer_purse_to_purse(source, pos_purse, amount)
+            .map_err(|_| Error::BondTransferFailed)?;
+
+        // Also apply any due requests, including this one if there is no delay.
+        internal::step_and_pay_out(self, timestamp)
+    }
+
+    fn undelegate(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<()> {
+        let timestamp = self.get_block_time();
+        internal::undelegate(self, maybe_amount, delegator, validator, timestamp)?;
+
+        // Also apply any due requests, including this one if there is no delay.
+        internal::step_and_pay_out(self, timestamp)
     }
 
     fn get_payment_purse(&self) -> Result<URef> {
@@ -83,10 +110,36 @@ pub trait ProofOfStake:
     fn finalize_payment(&mut self, amount_spent: U512, account: PublicKey) -> Result<()> {
         internal::finalize_payment(self, amount_spent, account)
     }
+
+    fn slash(&mut self, validators: BTreeMap<PublicKey, U512>) -> Result<()> {
+        internal::slash(self, validators)
+    }
+
+    fn distribute_rewards(&mut self, rewards: BTreeMap<PublicKey, U512>) -> Result<()> {
+        internal::distribute_rewards(self, rewards)
+    }
+
+    /// Mints the validators' rewards for an era, `reward_rate` billionths of the total stakes, and
+    /// distributes them in proportion to the stakes. Called by the system once per era.
+    fn distribute_seigniorage(&mut self, reward_rate: u64) -> Result<()> {
+        internal::distribute_seigniorage(self, reward_rate)
+    }
+
+    /// Applies all queued requests which are due at the current block time, and pays out the due
+    /// unbonding requests. Called by the system once per block.
+    fn step(&mut self) -> Result<()> {
+        internal::check_system_caller(self)?;
+        let timestamp = self.get_block_time();
+        internal::step_and_pay_out(self, timestamp)
+    }
+
+    fn unbond_payout(&mut self) -> Result<()> {
+        self.step()
+    }
 }
 
 mod internal {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use types::{
         account::PublicKey,
@@ -95,8 +148,9 @@ mod internal {
     };
 
     use crate::{
-        mint_provider::MintProvider, queue::QueueEntry, queue_provider::QueueProvider,
-        runtime_provider::RuntimeProvider, stakes_provider::StakesProvider,
+        delegations_provider::DelegationsProvider, mint_provider::MintProvider, queue::QueueEntry,
+        queue_provider::QueueProvider, runtime_provider::RuntimeProvider,
+        stakes_provider::StakesProvider,
     };
 
     /// Account used to run system functions (in particular `finalize_payment`).
@@ -116,12 +170,6 @@ mod internal {
     /// corresponds to is set by the user.
     const REFUND_PURSE_KEY: &str = "pos_refund_purse";
 
-    /// The time from a bonding request until the bond becomes effective and part of the stake.
-    const BOND_DELAY: u64 = 0;
-
-    /// The time from an unbonding request until the stakes are paid out.
-    const UNBOND_DELAY: u64 = 0;
-
     /// The maximum number of pending bonding requests.
     const MAX_BOND_LEN: usize = 100;
 
@@ -146,7 +194,8 @@ mod internal {
         for entry in &queue.0 {
             stakes.bond(&entry.validator, entry.amount);
         }
-        stakes.validate_bonding(&validator, amount)?;
+        let limits = provider.read_limits()?;
+        stakes.validate_bonding(&validator, amount, &limits)?;
 
         queue.push(validator, amount, timestamp)?;
         provider.write_bonding(queue);
@@ -156,7 +205,10 @@ mod internal {
     /// Enqueues the deploy's creator for unbonding. Their vote weight as a validator is decreased
     /// immediately, but the funds will only be released after a delay. If `maybe_amount` is `None`,
     /// all funds are enqueued for withdrawal, terminating the validator status.
-    pub fn unbond<P: QueueProvider + StakesProvider>(
+    ///
+    /// Only the validator's own stake can be unbonded. When the validator status is terminated, all
+    /// delegations to the validator are enqueued for withdrawal as well.
+    pub fn unbond<P: DelegationsProvider + QueueProvider + StakesProvider>(
         provider: &mut P,
         maybe_amount: Option<U512>,
         validator: PublicKey,
@@ -168,27 +220,122 @@ mod internal {
         }
 
         let mut stakes = provider.read()?;
-        let payout = stakes.unbond(&validator, maybe_amount)?;
+        let mut delegations = provider.read_delegations()?;
+        let delegated = delegations.total_delegated(&validator);
+        let own_stake = stakes
+            .0
+            .get(&validator)
+            .ok_or(Error::NotBonded)?
+            .saturating_sub(delegated);
+        // Unbonding all of the validator's own stake terminates the validator status.
+        let maybe_amount = match maybe_amount {
+            Some(amount) if amount > own_stake => return Err(Error::UnbondTooLarge),
+            Some(amount) if amount == own_stake => None,
+            maybe_amount => maybe_amount,
+        };
+
+        let limits = provider.read_limits()?;
+        let mut payout = stakes.unbond(&validator, maybe_amount, &limits)?;
         provider.write(&stakes);
         // TODO: Make sure the destination is valid and the amount can be paid. The actual payment
         // will be made later, after the unbonding delay. contract_api::transfer_dry_run(POS_PURSE,
         // dest, amount)?;
+        if maybe_amount.is_none() {
+            if let Some(book) = delegations.remove(&validator) {
+                for (delegator, amount) in book.0 {
+                    payout -= amount;
+                    queue.push_delegation(delegator, validator, amount, timestamp)?;
+                }
+                provider.write_delegations(delegations);
+            }
+        }
         queue.push(validator, payout, timestamp)?;
         provider.write_unbonding(queue);
         Ok(())
     }
 
+    /// Enqueues the deploy's creator for delegating `amount` to `validator`. The validator must
+    /// already be bonded, or about to be.
+    pub fn delegate<P: QueueProvider + StakesProvider>(
+        provider: &mut P,
+        amount: U512,
+        delegator: PublicKey,
+        validator: PublicKey,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        if amount.is_zero() {
+            return Err(Error::DelegationTooSmall);
+        }
+        if delegator == validator {
+            return Err(Error::SelfDelegation);
+        }
+        let mut queue = provider.read_bonding();
+        if queue.0.len() >= MAX_BOND_LEN {
+            return Err(Error::TooManyEventsInQueue);
+        }
+
+        let mut stakes = provider.read()?;
+        // Simulate applying all earlier bonds. The modified stakes are not written.
+        for entry in &queue.0 {
+            stakes.bond(&entry.validator, entry.amount);
+        }
+        if !stakes.0.contains_key(&validator) {
+            return Err(Error::NotBonded);
+        }
+        let limits = provider.read_limits()?;
+        stakes.validate_bonding(&validator, amount, &limits)?;
+
+        queue.push_delegation(delegator, validator, amount, timestamp)?;
+        provider.write_bonding(queue);
+        Ok(())
+    }
+
+    /// Enqueues the deploy's creator for undelegating from `validator`. The validator's vote weight
+    /// is decreased immediately, but the funds will only be released after a delay. If
+    /// `maybe_amount` is `None`, the whole delegation is enqueued for withdrawal.
+    ///
+    /// The stake limits are not checked, so that undelegating is never blocked by the stakes of
+    /// other validators.
+    pub fn undelegate<P: DelegationsProvider + QueueProvider + StakesProvider>(
+        provider: &mut P,
+        maybe_amount: Option<U512>,
+        delegator: PublicKey,
+        validator: PublicKey,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        let mut queue = provider.read_unbonding();
+        if queue.0.len() >= MAX_UNBOND_LEN {
+            return Err(Error::TooManyEventsInQueue);
+        }
+
+        let mut delegations = provider.read_delegations()?;
+        let amount = delegations.undelegate(&validator, &delegator, maybe_amount)?;
+        let mut stakes = provider.read()?;
+        let payout = stakes.undelegate(&validator, amount)?;
+        provider.write(&stakes);
+        provider.write_delegations(delegations);
+
+        queue.push_delegation(delegator, validator, payout, timestamp)?;
+        provider.write_unbonding(queue);
+        Ok(())
+    }
+
     /// Removes all due requests from the queues and applies them.
-    pub fn step<P: QueueProvider + StakesProvider>(
+    ///
+    /// Returns the due unbonding requests, to be paid out. Due delegations to validators which are
+    /// no longer bonded are returned as well, to be refunded.
+    pub fn step<P: DelegationsProvider + QueueProvider + StakesProvider>(
         provider: &mut P,
         timestamp: BlockTime,
     ) -> Result<Vec<QueueEntry>> {
         let mut bonding_queue = provider.read_bonding();
         let mut unbonding_queue = provider.read_unbonding();
+        let delays = provider.read_delays()?;
 
-        let bonds = bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(BOND_DELAY)));
-        let unbonds =
-            unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(UNBOND_DELAY)));
+        let bonds =
+            bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(delays.bond_delay)));
+        let mut unbonds =
+            unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(delays.unbond_delay)));
 
         if !unbonds.is_empty() {
             provider.write_unbonding(unbonding_queue);
@@ -197,15 +344,151 @@ mod internal {
         if !bonds.is_empty() {
             provider.write_bonding(bonding_queue);
             let mut stakes = provider.read()?;
+            let mut delegations = provider.read_delegations()?;
             for entry in bonds {
-                stakes.bond(&entry.validator, entry.amount);
+                match entry.delegator {
+                    None => stakes.bond(&entry.validator, entry.amount),
+                    Some(delegator) if stakes.0.contains_key(&entry.validator) => {
+                        stakes.bond(&entry.validator, entry.amount);
+                        delegations.delegate(&entry.validator, &delegator, entry.amount);
+                    }
+                    Some(_) => unbonds.push(entry),
+                }
             }
             provider.write(&stakes);
+            provider.write_delegations(delegations);
         }
 
         Ok(unbonds)
     }
 
+    /// Applies all due requests and pays out the due unbonding requests from the bonding purse.
+    pub fn step_and_pay_out<
+        P: DelegationsProvider + MintProvider + QueueProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        let pos_purse = get_bonding_purse(provider)?;
+        let unbonds = step(provider, timestamp)?;
+        for entry in unbonds {
+            provider
+                .transfer_purse_to_account(pos_purse, entry.beneficiary(), entry.amount)
+                .map_err(|_| Error::UnbondTransferFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless the current caller is the system account.
+    pub fn check_system_caller<R: RuntimeProvider>(runtime_provider: &R) -> Result<()> {
+        if runtime_provider.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+        Ok(())
+    }
+
+    /// Decreases the stakes of the given validators by the given amounts. A validator whose stake
+    /// is slashed completely is removed from the stakes. The slashed motes stay locked in the
+    /// bonding purse.
+    ///
+    /// The delegations to each validator are slashed in proportion to their part of its stake.
+    pub fn slash<P: DelegationsProvider + RuntimeProvider + StakesProvider>(
+        provider: &mut P,
+        validators: BTreeMap<PublicKey, U512>,
+    ) -> Result<()> {
+        check_system_caller(provider)?;
+
+        let mut stakes = provider.read()?;
+        let mut delegations = provider.read_delegations()?;
+        for (validator, amount) in validators {
+            let stake = stakes.0.get(&validator).cloned().unwrap_or_default();
+            stakes.slash(&validator, amount)?;
+            delegations.slash(&validator, stake, amount);
+        }
+        provider.write(&stakes);
+        provider.write_delegations(delegations);
+        Ok(())
+    }
+
+    /// Pays the given amounts out of the rewards purse to the main purses of the given validators.
+    ///
+    /// Each validator's reward is shared with its delegators in proportion to their part of its
+    /// stake.
+    pub fn distribute_rewards<
+        P: DelegationsProvider + MintProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+        rewards: BTreeMap<PublicKey, U512>,
+    ) -> Result<()> {
+        check_system_caller(provider)?;
+
+        let rewards_purse = get_rewards_purse(provider)?;
+        let total = match provider.balance(rewards_purse) {
+            Some(balance) => balance,
+            None => return Err(Error::RewardsPurseBalanceNotFound),
+        };
+        let requested = rewards
+            .values()
+            .fold(U512::zero(), |sum, amount| sum.saturating_add(*amount));
+        if total < requested {
+            return Err(Error::InsufficientRewardsPurseBalance);
+        }
+
+        let stakes = provider.read()?;
+        let delegations = provider.read_delegations()?;
+        let mut payouts = BTreeMap::new();
+        for (validator, amount) in rewards {
+            let mut validator_reward = amount;
+            if let (Some(book), Some(stake)) =
+                (delegations.book(&validator), stakes.0.get(&validator))
+            {
+                for (delegator, share) in book.shares(*stake, amount) {
+                    validator_reward -= share;
+                    let payout = payouts.entry(delegator).or_insert_with(U512::zero);
+                    *payout += share;
+                }
+            }
+            let payout = payouts.entry(validator).or_insert_with(U512::zero);
+            *payout += validator_reward;
+        }
+
+        for (account, amount) in payouts {
+            if amount.is_zero() {
+                continue;
+            }
+            provider
+                .transfer_purse_to_account(rewards_purse, account, amount)
+                .map_err(|_| Error::RewardTransferFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Mints `reward_rate` billionths of the total stakes into the rewards purse, and pays them out
+    /// to the validators in proportion to their stakes.
+    pub fn distribute_seigniorage<
+        P: DelegationsProvider + MintProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+        reward_rate: u64,
+    ) -> Result<()> {
+        check_system_caller(provider)?;
+
+        let stakes = provider.read()?;
+        let amount = stakes
+            .total_bonds()
+            .checked_mul(U512::from(reward_rate))
+            .ok_or(Error::RewardOverflow)?
+            / 1_000_000_000;
+        if amount.is_zero() {
+            return Ok(());
+        }
+        let rewards_purse = get_rewards_purse(provider)?;
+        provider
+            .mint_reward(rewards_purse, amount)
+            .map_err(|_| Error::RewardMintingFailed)?;
+        distribute_rewards(provider, stakes.split_rewards(amount)?)
+    }
+
     /// Attempts to look up a purse from the named_keys
     fn get_purse<R: RuntimeProvider>(
         runtime_provider: &R,
@@ -264,10 +547,7 @@ mod internal {
         amount_spent: U512,
         account: PublicKey,
     ) -> Result<()> {
-        let caller = provider.get_caller();
-        if caller != SYSTEM_ACCOUNT {
-            return Err(Error::SystemFunctionCalledByUserAccount);
-        }
+        check_system_caller(provider)?;
 
         let payment_purse = get_payment_purse(provider)?;
         let total = match provider.balance(payment_purse) {
@@ -328,16 +608,28 @@ mod internal {
 
         use std::{cell::RefCell, iter, thread_local};
 
-        use types::{account::PublicKey, system_contract_errors::pos::Result, BlockTime, U512};
+        use types::{
+            account::PublicKey,
+            system_contract_errors::pos::{Error, Result},
+            BlockTime, U512,
+        };
 
-        use super::{bond, step, unbond, BOND_DELAY, UNBOND_DELAY};
+        use super::{bond, delegate, step, unbond, undelegate};
         use crate::{
-            queue::Queue, queue_provider::QueueProvider, stakes::Stakes,
+            delegations::Delegations,
+            delegations_provider::DelegationsProvider,
+            queue::{Queue, QueueDelays},
+            queue_provider::QueueProvider,
+            stakes::{StakeLimits, Stakes},
             stakes_provider::StakesProvider,
         };
 
+        const BOND_DELAY: u64 = 10;
+        const UNBOND_DELAY: u64 = 20;
+
         const KEY1: [u8; 32] = [1; 32];
         const KEY2: [u8; 32] = [2; 32];
+        const KEY3: [u8; 32] = [3; 32];
 
         thread_local! {
             static BONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
@@ -345,10 +637,22 @@ mod internal {
             static STAKES: RefCell<Stakes> = RefCell::new(
                 Stakes(iter::once((PublicKey::ed25519_from(KEY1), U512::from(1_000))).collect())
             );
+            static DELEGATIONS: RefCell<Delegations> = RefCell::new(Delegations::default());
+            static LIMITS: RefCell<StakeLimits> = RefCell::new(StakeLimits::default());
         }
 
         struct Provider;
 
+        impl DelegationsProvider for Provider {
+            fn read_delegations(&mut self) -> Result<Delegations> {
+                DELEGATIONS.with(|d| Ok(d.borrow().clone()))
+            }
+
+            fn write_delegations(&mut self, delegations: Delegations) {
+                DELEGATIONS.with(|d| d.replace(delegations));
+            }
+        }
+
         impl QueueProvider for Provider {
             fn read_bonding(&mut self) -> Queue {
                 BONDING.with(|b| b.borrow().clone())
@@ -365,6 +669,13 @@ mod internal {
             fn write_unbonding(&mut self, queue: Queue) {
                 UNBONDING.with(|ub| ub.replace(queue));
             }
+
+            fn read_delays(&mut self) -> Result<QueueDelays> {
+                Ok(QueueDelays {
+                    bond_delay: BOND_DELAY,
+                    unbond_delay: UNBOND_DELAY,
+                })
+            }
         }
 
         impl StakesProvider for Provider {
@@ -375,6 +686,10 @@ mod internal {
             fn write(&mut self, stakes: &Stakes) {
                 STAKES.with(|s| s.replace(stakes.clone()));
             }
+
+            fn read_limits(&mut self) -> Result<StakeLimits> {
+                LIMITS.with(|l| Ok(*l.borrow()))
+            }
         }
 
         fn assert_stakes(stakes: &[([u8; 32], usize)]) {
@@ -415,8 +730,199 @@ mod internal {
 
             // Unbonding becomes effective immediately.
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
-            step::<Provider>(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 3");
+
+            // The unbonded motes are only paid out after the delay.
+            let unbonds =
+                step::<Provider>(&mut provider, BlockTime::new(1 + UNBOND_DELAY)).expect("step 3");
+            assert!(unbonds.is_empty());
+            let unbonds =
+                step::<Provider>(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 4");
+            assert_eq!(1, unbonds.len());
+            assert_eq!(PublicKey::ed25519_from(KEY1), unbonds[0].beneficiary());
+            assert_eq!(U512::from(500), unbonds[0].amount);
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
         }
+
+        #[test]
+        fn test_delegate_step_undelegate() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY1);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            assert_eq!(
+                Err(Error::NotBonded),
+                delegate(
+                    &mut provider,
+                    U512::from(200),
+                    delegator,
+                    PublicKey::ed25519_from(KEY2),
+                    BlockTime::new(1),
+                )
+            );
+            assert_eq!(
+                Err(Error::SelfDelegation),
+                delegate(
+                    &mut provider,
+                    U512::from(200),
+                    validator,
+                    validator,
+                    BlockTime::new(1),
+                )
+            );
+            delegate(
+                &mut provider,
+                U512::from(200),
+                delegator,
+                validator,
+                BlockTime::new(1),
+            )
+            .expect("delegate to validator 1");
+
+            // Delegating becomes effective only after the delay.
+            assert_stakes(&[(KEY1, 1_000)]);
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            assert_stakes(&[(KEY1, 1_200)]);
+            assert_eq!(
+                Ok(U512::from(200)),
+                provider
+                    .read_delegations()
+                    .map(|delegations| delegations.total_delegated(&validator))
+            );
+
+            // The validator can't unbond the delegated motes.
+            assert_eq!(
+                Err(Error::UnbondTooLarge),
+                unbond(
+                    &mut provider,
+                    Some(U512::from(1_001)),
+                    validator,
+                    BlockTime::new(2),
+                )
+            );
+
+            undelegate(
+                &mut provider,
+                Some(U512::from(150)),
+                delegator,
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("partly undelegate from validator 1");
+
+            // Undelegating becomes effective immediately.
+            assert_stakes(&[(KEY1, 1_050)]);
+            let unbonds = step(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 2");
+            assert_eq!(1, unbonds.len());
+            assert_eq!(delegator, unbonds[0].beneficiary());
+            assert_eq!(U512::from(150), unbonds[0].amount);
+            assert_eq!(
+                Ok(U512::from(50)),
+                provider
+                    .read_delegations()
+                    .map(|delegations| delegations.total_delegated(&validator))
+            );
+        }
+
+        #[test]
+        fn test_undelegate_ignores_stake_limits() {
+            let mut provider = Provider;
+            let validator_1 = PublicKey::ed25519_from(KEY1);
+            let validator_2 = PublicKey::ed25519_from(KEY2);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            bond(
+                &mut provider,
+                U512::from(1_200),
+                validator_2,
+                BlockTime::new(1),
+            )
+            .expect("bond validator 2");
+            delegate(
+                &mut provider,
+                U512::from(200),
+                delegator,
+                validator_1,
+                BlockTime::new(1),
+            )
+            .expect("delegate to validator 1");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            assert_stakes(&[(KEY1, 1_200), (KEY2, 1_200)]);
+
+            // Unbonding the delegated amount would violate the maximum spread.
+            let limits = StakeLimits {
+                max_spread: U512::from(100),
+                ..StakeLimits::default()
+            };
+            LIMITS.with(|l| l.replace(limits));
+            let mut stakes = Provider.read().expect("should read stakes");
+            assert_eq!(
+                Err(Error::SpreadTooHigh),
+                stakes.unbond(&validator_1, Some(U512::from(200)), &limits)
+            );
+
+            undelegate(
+                &mut provider,
+                None,
+                delegator,
+                validator_1,
+                BlockTime::new(2),
+            )
+            .expect("undelegate from validator 1");
+            assert_stakes(&[(KEY1, 1_000), (KEY2, 1_200)]);
+            let unbonds = step(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 2");
+            assert_eq!(1, unbonds.len());
+            assert_eq!(delegator, unbonds[0].beneficiary());
+            assert_eq!(U512::from(200), unbonds[0].amount);
+        }
+
+        #[test]
+        fn test_unbond_validator_with_delegations() {
+            let mut provider = Provider;
+            let validator_1 = PublicKey::ed25519_from(KEY1);
+            let validator_2 = PublicKey::ed25519_from(KEY2);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            bond(
+                &mut provider,
+                U512::from(500),
+                validator_2,
+                BlockTime::new(1),
+            )
+            .expect("bond validator 2");
+            delegate(
+                &mut provider,
+                U512::from(100),
+                delegator,
+                validator_2,
+                BlockTime::new(1),
+            )
+            .expect("delegate to validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            assert_stakes(&[(KEY1, 1_000), (KEY2, 600)]);
+
+            // Unbonding all of the validator's own stake also returns the delegations.
+            unbond(
+                &mut provider,
+                Some(U512::from(500)),
+                validator_2,
+                BlockTime::new(2),
+            )
+            .expect("unbond validator 2");
+            assert_stakes(&[(KEY1, 1_000)]);
+            assert_eq!(Ok(Delegations::default()), provider.read_delegations());
+
+            let mut unbonds =
+                step(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 2");
+            unbonds.sort_by_key(|entry| entry.amount);
+            let payouts: std::vec::Vec<_> = unbonds
+                .iter()
+                .map(|entry| (entry.beneficiary(), entry.amount))
+                .collect();
+            assert_eq!(
+                std::vec![(delegator, U512::from(100)), (validator_2, U512::from(500)),],
+                payouts
+            );
+            assert!(!payouts.iter().any(|(account, _)| *account == validator_1));
+        }
     }
 }
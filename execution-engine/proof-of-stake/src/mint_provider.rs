@@ -16,4 +16,6 @@ pub trait MintProvider {
     ) -> Result<(), ()>;
 
     fn balance(&mut self, purse: URef) -> Option<U512>;
+
+    fn mint_reward(&mut self, purse: URef, amount: U512) -> Result<(), ()>;
 }
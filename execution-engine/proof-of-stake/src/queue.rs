@@ -8,15 +8,59 @@ use types::{
     BlockTime, CLType, CLTyped, U512,
 };
 
+/// The delays, in block time, from bonding and unbonding requests until they are applied.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueueDelays {
+    /// The time from a bonding request until the bond becomes effective and part of the stake.
+    pub bond_delay: u64,
+    /// The time from an unbonding request until the stakes are paid out.
+    pub unbond_delay: u64,
+}
+
+impl ToBytes for QueueDelays {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        let mut bytes = bytesrepr::allocate_buffer(self)?;
+        bytes.append(&mut self.bond_delay.to_bytes()?);
+        bytes.append(&mut self.unbond_delay.to_bytes()?);
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        2 * U64_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for QueueDelays {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (bond_delay, bytes) = u64::from_bytes(bytes)?;
+        let (unbond_delay, bytes) = u64::from_bytes(bytes)?;
+        let delays = QueueDelays {
+            bond_delay,
+            unbond_delay,
+        };
+        Ok((delays, bytes))
+    }
+}
+
+impl CLTyped for QueueDelays {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
 /// A pending entry in the bonding or unbonding queue.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct QueueEntry {
-    /// The validator who is bonding or unbonding.
+    /// The validator who is bonding or unbonding, or whose stakes are being delegated to or
+    /// undelegated from.
     pub validator: PublicKey,
     /// The amount by which to change the stakes.
     pub amount: U512,
     /// The timestamp when the request was made.
     pub timestamp: BlockTime,
+    /// The delegator who is delegating or undelegating, if this is not the validator's own
+    /// request.
+    pub delegator: Option<PublicKey>,
 }
 
 impl QueueEntry {
@@ -26,8 +70,30 @@ impl QueueEntry {
             validator,
             amount,
             timestamp,
+            delegator: None,
+        }
+    }
+
+    /// Creates a new `QueueEntry` for a delegator with the current block's timestamp.
+    fn new_delegation(
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> QueueEntry {
+        QueueEntry {
+            validator,
+            amount,
+            timestamp,
+            delegator: Some(delegator),
         }
     }
+
+    /// Returns the account which owns the motes of this entry: the delegator if there is one,
+    /// otherwise the validator.
+    pub fn beneficiary(&self) -> PublicKey {
+        self.delegator.unwrap_or(self.validator)
+    }
 }
 
 impl ToBytes for QueueEntry {
@@ -36,6 +102,7 @@ impl ToBytes for QueueEntry {
         bytes.append(&mut self.validator.to_bytes()?);
         bytes.append(&mut self.amount.to_bytes()?);
         bytes.append(&mut self.timestamp.to_bytes()?);
+        bytes.append(&mut self.delegator.to_bytes()?);
         Ok(bytes)
     }
 
@@ -43,6 +110,7 @@ impl ToBytes for QueueEntry {
         self.validator.serialized_length()
             + self.amount.serialized_length()
             + self.timestamp.serialized_length()
+            + self.delegator.serialized_length()
     }
 }
 
@@ -51,10 +119,12 @@ impl FromBytes for QueueEntry {
         let (validator, bytes) = PublicKey::from_bytes(bytes)?;
         let (amount, bytes) = U512::from_bytes(bytes)?;
         let (timestamp, bytes) = BlockTime::from_bytes(bytes)?;
+        let (delegator, bytes) = Option::<PublicKey>::from_bytes(bytes)?;
         let entry = QueueEntry {
             validator,
             amount,
             timestamp,
+            delegator,
         };
         Ok((entry, bytes))
     }
@@ -73,17 +143,39 @@ pub struct Queue(pub Vec<QueueEntry>);
 impl Queue {
     /// Pushes a new entry to the end of the queue.
     ///
-    /// Returns an error if the validator already has a request in the queue.
+    /// Returns an error if the validator already has a request of its own in the queue.
     pub fn push(&mut self, validator: PublicKey, amount: U512, timestamp: BlockTime) -> Result<()> {
-        if self.0.iter().any(|entry| entry.validator == validator) {
+        self.push_entry(QueueEntry::new(validator, amount, timestamp))
+    }
+
+    /// Pushes a new delegator's entry to the end of the queue.
+    ///
+    /// Returns an error if the delegator already has a request for the same validator in the
+    /// queue.
+    pub fn push_delegation(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        self.push_entry(QueueEntry::new_delegation(
+            delegator, validator, amount, timestamp,
+        ))
+    }
+
+    fn push_entry(&mut self, new_entry: QueueEntry) -> Result<()> {
+        if self.0.iter().any(|entry| {
+            entry.validator == new_entry.validator && entry.delegator == new_entry.delegator
+        }) {
             return Err(Error::MultipleRequests);
         }
         if let Some(entry) = self.0.last() {
-            if entry.timestamp > timestamp {
+            if entry.timestamp > new_entry.timestamp {
                 return Err(Error::TimeWentBackwards);
             }
         }
-        self.0.push(QueueEntry::new(validator, amount, timestamp));
+        self.0.push(new_entry);
         Ok(())
     }
 
@@ -140,7 +232,7 @@ mod tests {
         account::PublicKey, bytesrepr, system_contract_errors::pos::Error, BlockTime, U512,
     };
 
-    use super::{Queue, QueueEntry};
+    use super::{Queue, QueueDelays, QueueEntry};
 
     const KEY1: [u8; 32] = [1; 32];
     const KEY2: [u8; 32] = [2; 32];
@@ -186,6 +278,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_push_delegation() {
+        let val1 = PublicKey::ed25519_from(KEY1);
+        let del2 = PublicKey::ed25519_from(KEY2);
+        let del3 = PublicKey::ed25519_from(KEY3);
+        let mut queue: Queue = Default::default();
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), BlockTime::new(100)));
+        assert_eq!(
+            Ok(()),
+            queue.push_delegation(del2, val1, U512::from(5), BlockTime::new(100))
+        );
+        assert_eq!(
+            Ok(()),
+            queue.push_delegation(del3, val1, U512::from(5), BlockTime::new(101))
+        );
+        assert_eq!(
+            Err(Error::MultipleRequests),
+            queue.push_delegation(del2, val1, U512::from(5), BlockTime::new(102))
+        );
+        assert_eq!(
+            Err(Error::MultipleRequests),
+            queue.push(val1, U512::from(5), BlockTime::new(102))
+        );
+        assert_eq!(val1, queue.0[0].beneficiary());
+        assert_eq!(del2, queue.0[1].beneficiary());
+    }
+
     #[test]
     fn serialization_roundtrip() {
         let val1 = PublicKey::ed25519_from(KEY1);
@@ -195,6 +314,13 @@ mod tests {
         queue.push(val1, U512::from(5), BlockTime::new(0)).unwrap();
         queue.push(val2, U512::from(6), BlockTime::new(1)).unwrap();
         queue.push(val3, U512::from(7), BlockTime::new(2)).unwrap();
+        queue
+            .push_delegation(val1, val2, U512::from(8), BlockTime::new(3))
+            .unwrap();
         bytesrepr::test_serialization_roundtrip(&queue);
+        bytesrepr::test_serialization_roundtrip(&QueueDelays {
+            bond_delay: 1,
+            unbond_delay: 2,
+        });
     }
 }
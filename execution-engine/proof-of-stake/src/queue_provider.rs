@@ -1,4 +1,7 @@
-use crate::queue::Queue;
+use crate::{
+    queue::{Queue, QueueDelays},
+    Result,
+};
 
 pub trait QueueProvider {
     /// Reads bonding queue.
@@ -12,4 +15,7 @@ pub trait QueueProvider {
 
     /// Writes unbonding queue.
     fn write_unbonding(&mut self, queue: Queue);
+
+    /// Reads the delays after which queued requests are applied.
+    fn read_delays(&mut self) -> Result<QueueDelays>;
 }
@@ -5,30 +5,102 @@ use alloc::{
     },
     format,
     string::String,
+    vec::Vec,
 };
+use core::result;
 
 use types::{
     account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH},
     system_contract_errors::pos::{Error, Result},
-    U512,
+    CLType, CLTyped, U512,
 };
 
-/// The maximum difference between the largest and the smallest stakes.
-// TODO: Should this be a percentage instead?
-// TODO: Pick a reasonable value.
-const MAX_SPREAD: U512 = U512::MAX;
+/// Limits on the validators' stakes, and on how much they can change in a single request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakeLimits {
+    /// The minimum stake of a bonded validator.
+    pub min_bond: U512,
+    /// The maximum number of bonded validators.
+    pub max_validators: u32,
+    /// The maximum difference between the largest and the smallest stakes.
+    pub max_spread: U512,
+    /// The maximum increase of stakes in a single bonding request.
+    pub max_increase: U512,
+    /// The maximum decrease of stakes in a single unbonding request.
+    pub max_decrease: U512,
+    /// The maximum increase of stakes in millionths of the total stakes in a single bonding
+    /// request.
+    pub max_rel_increase: u64,
+    /// The maximum decrease of stakes in millionths of the total stakes in a single unbonding
+    /// request.
+    pub max_rel_decrease: u64,
+}
 
-/// The maximum increase of stakes in a single bonding request.
-const MAX_INCREASE: U512 = U512::MAX;
+impl Default for StakeLimits {
+    fn default() -> Self {
+        StakeLimits {
+            min_bond: U512::zero(),
+            max_validators: u32::max_value(),
+            max_spread: U512::max_value(),
+            max_increase: U512::max_value(),
+            max_decrease: U512::max_value(),
+            max_rel_increase: 1_000_000_000,
+            max_rel_decrease: 900_000,
+        }
+    }
+}
 
-/// The maximum decrease of stakes in a single unbonding request.
-const MAX_DECREASE: U512 = U512::MAX;
+impl ToBytes for StakeLimits {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        let mut bytes = bytesrepr::allocate_buffer(self)?;
+        bytes.append(&mut self.min_bond.to_bytes()?);
+        bytes.append(&mut self.max_validators.to_bytes()?);
+        bytes.append(&mut self.max_spread.to_bytes()?);
+        bytes.append(&mut self.max_increase.to_bytes()?);
+        bytes.append(&mut self.max_decrease.to_bytes()?);
+        bytes.append(&mut self.max_rel_increase.to_bytes()?);
+        bytes.append(&mut self.max_rel_decrease.to_bytes()?);
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.min_bond.serialized_length()
+            + U32_SERIALIZED_LENGTH
+            + self.max_spread.serialized_length()
+            + self.max_increase.serialized_length()
+            + self.max_decrease.serialized_length()
+            + 2 * U64_SERIALIZED_LENGTH
+    }
+}
 
-/// The maximum increase of stakes in millionths of the total stakes in a single bonding request.
-const MAX_REL_INCREASE: u64 = 1_000_000_000;
+impl FromBytes for StakeLimits {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (min_bond, bytes) = U512::from_bytes(bytes)?;
+        let (max_validators, bytes) = u32::from_bytes(bytes)?;
+        let (max_spread, bytes) = U512::from_bytes(bytes)?;
+        let (max_increase, bytes) = U512::from_bytes(bytes)?;
+        let (max_decrease, bytes) = U512::from_bytes(bytes)?;
+        let (max_rel_increase, bytes) = u64::from_bytes(bytes)?;
+        let (max_rel_decrease, bytes) = u64::from_bytes(bytes)?;
+        let limits = StakeLimits {
+            min_bond,
+            max_validators,
+            max_spread,
+            max_increase,
+            max_decrease,
+            max_rel_increase,
+            max_rel_decrease,
+        };
+        Ok((limits, bytes))
+    }
+}
 
-/// The maximum decrease of stakes in millionths of the total stakes in a single unbonding request.
-const MAX_REL_DECREASE: u64 = 900_000;
+impl CLTyped for StakeLimits {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
 
 /// The stakes map, assigning the staked amount of motes to each bonded
 /// validator.
@@ -66,20 +138,30 @@ impl Stakes {
     ///
     /// Returns the amount that was actually subtracted from the stakes, or an
     /// error if
-    /// * unbonding the specified amount is not allowed,
+    /// * unbonding the specified amount is not allowed by the `limits`,
     /// * tries to unbond last validator,
     /// * validator was not bonded.
-    pub fn unbond(&mut self, validator: &PublicKey, maybe_amount: Option<U512>) -> Result<U512> {
+    pub fn unbond(
+        &mut self,
+        validator: &PublicKey,
+        maybe_amount: Option<U512>,
+        limits: &StakeLimits,
+    ) -> Result<U512> {
+        // The minimum stake value to not violate the maximum spread.
         let min = self
             .max_without(validator)
             .unwrap_or_else(U512::zero)
-            .saturating_sub(MAX_SPREAD);
-        let max_decrease = MAX_DECREASE.min(self.sum() * MAX_REL_DECREASE / 1_000_000);
+            .saturating_sub(limits.max_spread);
+        let max_decrease = limits
+            .max_decrease
+            .min(self.sum() * limits.max_rel_decrease / 1_000_000);
 
         if let Some(amount) = maybe_amount {
-            // The minimum stake value to not violate the maximum spread.
             let stake = self.0.get_mut(validator).ok_or(Error::NotBonded)?;
             if *stake > amount {
+                if *stake - amount < limits.min_bond {
+                    return Err(Error::BelowMinimumBond);
+                }
                 if *stake - amount < min {
                     return Err(Error::SpreadTooHigh);
                 }
@@ -109,6 +191,34 @@ impl Stakes {
         Ok(stake)
     }
 
+    /// Subtracts `amount` from the validator's stakes, removing the validator if nothing is left.
+    ///
+    /// Returns the amount that was actually subtracted from the stakes, or an error if the
+    /// validator was not bonded.
+    pub fn slash(&mut self, validator: &PublicKey, amount: U512) -> Result<U512> {
+        let stake = self.0.get_mut(validator).ok_or(Error::NotBonded)?;
+        if *stake > amount {
+            *stake -= amount;
+            return Ok(amount);
+        }
+        let stake = self.0.remove(validator).ok_or(Error::NotBonded)?;
+        Ok(stake)
+    }
+
+    /// Decreases the validator's stake by the undelegated `amount`, and returns the amount removed.
+    ///
+    /// Unlike unbonding, this doesn't check the stake limits, so that delegators can always
+    /// withdraw their delegations.
+    pub fn undelegate(&mut self, validator: &PublicKey, amount: U512) -> Result<U512> {
+        let stake = self.0.get_mut(validator).ok_or(Error::NotBonded)?;
+        if *stake > amount {
+            *stake -= amount;
+            return Ok(amount);
+        }
+        let stake = self.0.remove(validator).ok_or(Error::NotBonded)?;
+        Ok(stake)
+    }
+
     /// Adds `amount` to the validator's stakes.
     pub fn bond(&mut self, validator: &PublicKey, amount: U512) {
         self.0
@@ -117,21 +227,73 @@ impl Stakes {
             .or_insert(amount);
     }
 
-    /// Returns an error if bonding the specified amount is not allowed.
-    pub fn validate_bonding(&self, validator: &PublicKey, amount: U512) -> Result<()> {
+    /// Splits `amount` among the validators in proportion to their stakes.
+    ///
+    /// Each validator's part is rounded down. The motes left over by rounding are handed out one
+    /// each, to the validators with the largest stakes first, and among equal stakes in the order
+    /// of their public keys.
+    ///
+    /// Returns an error if the product of `amount` and a validator's stake overflows.
+    pub fn split_rewards(&self, amount: U512) -> Result<BTreeMap<PublicKey, U512>> {
+        let total = self.sum();
+        if total.is_zero() {
+            return Ok(BTreeMap::new());
+        }
+        let mut rewards: BTreeMap<PublicKey, U512> = self
+            .0
+            .iter()
+            .map(|(validator, stake)| {
+                let product = amount.checked_mul(*stake).ok_or(Error::RewardOverflow)?;
+                Ok((*validator, product / total))
+            })
+            .collect::<Result<_>>()?;
+        let assigned = rewards
+            .values()
+            .fold(U512::zero(), |sum, reward| sum.saturating_add(*reward));
+        let mut leftover = amount.saturating_sub(assigned);
+
+        let mut by_stake: Vec<(&PublicKey, &U512)> = self.0.iter().collect();
+        by_stake.sort_by(|(v1, s1), (v2, s2)| s2.cmp(s1).then_with(|| v1.cmp(v2)));
+        for (validator, _) in by_stake {
+            if leftover.is_zero() {
+                break;
+            }
+            if let Some(reward) = rewards.get_mut(validator) {
+                *reward += U512::one();
+                leftover -= U512::one();
+            }
+        }
+        Ok(rewards)
+    }
+
+    /// Returns an error if bonding the specified amount is not allowed by the `limits`.
+    pub fn validate_bonding(
+        &self,
+        validator: &PublicKey,
+        amount: U512,
+        limits: &StakeLimits,
+    ) -> Result<()> {
+        if !self.0.contains_key(validator) && self.0.len() >= limits.max_validators as usize {
+            return Err(Error::TooManyValidators);
+        }
         let max = self
             .min_without(validator)
-            .unwrap_or(U512::MAX)
-            .saturating_add(MAX_SPREAD);
+            .unwrap_or_else(U512::max_value)
+            .saturating_add(limits.max_spread);
         let min = self
             .max_without(validator)
             .unwrap_or_else(U512::zero)
-            .saturating_sub(MAX_SPREAD);
+            .saturating_sub(limits.max_spread);
         let stake = self.0.get(validator).map(|s| *s + amount).unwrap_or(amount);
+        if stake < limits.min_bond {
+            return Err(Error::BelowMinimumBond);
+        }
         if stake > max || stake < min {
             return Err(Error::SpreadTooHigh);
         }
-        let max_increase = MAX_INCREASE.min(self.sum() * MAX_REL_INCREASE / 1_000_000);
+        let max_increase = limits
+            .max_increase
+            .min(self.sum() * limits.max_rel_increase / 1_000_000);
         if (stake.is_zero() && amount > min.saturating_add(max_increase))
             || (!stake.is_zero() && amount > max_increase)
         {
@@ -170,12 +332,15 @@ impl Stakes {
 
 #[cfg(test)]
 mod tests {
-    use types::{account::PublicKey, system_contract_errors::pos::Error, U512};
+    use alloc::collections::BTreeMap;
+
+    use types::{account::PublicKey, bytesrepr, system_contract_errors::pos::Error, U512};
 
-    use super::Stakes;
+    use super::{StakeLimits, Stakes};
 
     const KEY1: [u8; 32] = [1; 32];
     const KEY2: [u8; 32] = [2; 32];
+    const KEY3: [u8; 32] = [3; 32];
 
     fn new_stakes(stakes: &[([u8; 32], u64)]) -> Stakes {
         Stakes(
@@ -188,10 +353,11 @@ mod tests {
 
     #[test]
     fn test_bond() {
+        let limits = StakeLimits::default();
         let mut stakes = new_stakes(&[(KEY2, 100)]);
         assert_eq!(
             Ok(()),
-            stakes.validate_bonding(&PublicKey::ed25519_from(KEY1), U512::from(5))
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY1), U512::from(5), &limits)
         );
         stakes.bond(&PublicKey::ed25519_from(KEY1), U512::from(5));
         assert_eq!(new_stakes(&[(KEY1, 5), (KEY2, 100)]), stakes);
@@ -199,10 +365,11 @@ mod tests {
 
     #[test]
     fn test_bond_existing() {
+        let limits = StakeLimits::default();
         let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
         assert_eq!(
             Ok(()),
-            stakes.validate_bonding(&PublicKey::ed25519_from(KEY1), U512::from(4))
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY1), U512::from(4), &limits)
         );
         stakes.bond(&PublicKey::ed25519_from(KEY1), U512::from(4));
         assert_eq!(new_stakes(&[(KEY1, 54), (KEY2, 100)]), stakes);
@@ -210,13 +377,15 @@ mod tests {
 
     #[test]
     fn test_bond_too_much_rel() {
+        let limits = StakeLimits::default();
         let stakes = new_stakes(&[(KEY1, 1_000), (KEY2, 1_000)]);
         let total = 1_000 + 1_000;
         assert_eq!(
             Err(Error::BondTooLarge),
             stakes.validate_bonding(
                 &PublicKey::ed25519_from(KEY1),
-                U512::from(super::MAX_REL_INCREASE * total / 1_000_000 + 1),
+                U512::from(limits.max_rel_increase * total / 1_000_000 + 1),
+                &limits,
             ),
             "Successfully bonded more than the maximum amount."
         );
@@ -224,7 +393,8 @@ mod tests {
             Ok(()),
             stakes.validate_bonding(
                 &PublicKey::ed25519_from(KEY1),
-                U512::from(super::MAX_REL_INCREASE * total / 1_000_000),
+                U512::from(limits.max_rel_increase * total / 1_000_000),
+                &limits,
             ),
             "Failed to bond the maximum amount."
         );
@@ -232,52 +402,205 @@ mod tests {
 
     #[test]
     fn test_unbond() {
+        let limits = StakeLimits::default();
         let mut stakes = new_stakes(&[(KEY1, 5), (KEY2, 100)]);
         assert_eq!(
             Ok(U512::from(5)),
-            stakes.unbond(&PublicKey::ed25519_from(KEY1), None)
+            stakes.unbond(&PublicKey::ed25519_from(KEY1), None, &limits)
         );
         assert_eq!(new_stakes(&[(KEY2, 100)]), stakes);
     }
 
     #[test]
     fn test_unbond_last_validator() {
+        let limits = StakeLimits::default();
         let mut stakes = new_stakes(&[(KEY1, 5)]);
         assert_eq!(
             Err(Error::CannotUnbondLastValidator),
-            stakes.unbond(&PublicKey::ed25519_from(KEY1), None)
+            stakes.unbond(&PublicKey::ed25519_from(KEY1), None, &limits)
         );
     }
 
     #[test]
     fn test_partially_unbond() {
+        let limits = StakeLimits::default();
         let mut stakes = new_stakes(&[(KEY1, 50)]);
         assert_eq!(
             Ok(U512::from(4)),
-            stakes.unbond(&PublicKey::ed25519_from(KEY1), Some(U512::from(4)))
+            stakes.unbond(&PublicKey::ed25519_from(KEY1), Some(U512::from(4)), &limits)
         );
         assert_eq!(new_stakes(&[(KEY1, 46)]), stakes);
     }
 
+    #[test]
+    fn test_slash() {
+        let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
+        assert_eq!(
+            Ok(U512::from(20)),
+            stakes.slash(&PublicKey::ed25519_from(KEY2), U512::from(20))
+        );
+        assert_eq!(new_stakes(&[(KEY1, 50), (KEY2, 80)]), stakes);
+        assert_eq!(
+            Ok(U512::from(50)),
+            stakes.slash(&PublicKey::ed25519_from(KEY1), U512::from(70))
+        );
+        assert_eq!(new_stakes(&[(KEY2, 80)]), stakes);
+        assert_eq!(
+            Err(Error::NotBonded),
+            stakes.slash(&PublicKey::ed25519_from(KEY1), U512::from(1))
+        );
+    }
+
     #[test]
     fn test_unbond_too_much_rel() {
+        let limits = StakeLimits::default();
         let mut stakes = new_stakes(&[(KEY1, 999), (KEY2, 1)]);
         let total = 999 + 1;
         assert_eq!(
             Err(Error::UnbondTooLarge),
             stakes.unbond(
                 &PublicKey::ed25519_from(KEY1),
-                Some(U512::from(super::MAX_REL_DECREASE * total / 1_000_000 + 1)),
+                Some(U512::from(limits.max_rel_decrease * total / 1_000_000 + 1)),
+                &limits,
             ),
             "Successfully unbonded more than the maximum amount."
         );
         assert_eq!(
-            Ok(U512::from(super::MAX_REL_DECREASE * total / 1_000_000)),
+            Ok(U512::from(limits.max_rel_decrease * total / 1_000_000)),
             stakes.unbond(
                 &PublicKey::ed25519_from(KEY1),
-                Some(U512::from(super::MAX_REL_DECREASE * total / 1_000_000)),
+                Some(U512::from(limits.max_rel_decrease * total / 1_000_000)),
+                &limits,
             ),
             "Failed to unbond the maximum amount."
         );
     }
+
+    #[test]
+    fn test_min_bond() {
+        let limits = StakeLimits {
+            min_bond: U512::from(10),
+            ..Default::default()
+        };
+        let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
+        assert_eq!(
+            Err(Error::BelowMinimumBond),
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY3), U512::from(9), &limits)
+        );
+        assert_eq!(
+            Ok(()),
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY3), U512::from(10), &limits)
+        );
+        assert_eq!(
+            Err(Error::BelowMinimumBond),
+            stakes.unbond(
+                &PublicKey::ed25519_from(KEY1),
+                Some(U512::from(41)),
+                &limits
+            )
+        );
+        assert_eq!(
+            Ok(U512::from(40)),
+            stakes.unbond(
+                &PublicKey::ed25519_from(KEY1),
+                Some(U512::from(40)),
+                &limits
+            )
+        );
+        // Unbonding everything is still allowed.
+        assert_eq!(
+            Ok(U512::from(10)),
+            stakes.unbond(&PublicKey::ed25519_from(KEY1), None, &limits)
+        );
+    }
+
+    #[test]
+    fn test_max_validators() {
+        let limits = StakeLimits {
+            max_validators: 2,
+            ..Default::default()
+        };
+        let stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
+        assert_eq!(
+            Err(Error::TooManyValidators),
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY3), U512::from(50), &limits)
+        );
+        assert_eq!(
+            Ok(()),
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY2), U512::from(50), &limits)
+        );
+    }
+
+    #[test]
+    fn test_spread() {
+        let limits = StakeLimits {
+            max_spread: U512::from(60),
+            ..Default::default()
+        };
+        let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
+        assert_eq!(
+            Err(Error::SpreadTooHigh),
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY2), U512::from(11), &limits)
+        );
+        assert_eq!(
+            Ok(()),
+            stakes.validate_bonding(&PublicKey::ed25519_from(KEY2), U512::from(10), &limits)
+        );
+        assert_eq!(
+            Err(Error::SpreadTooHigh),
+            stakes.unbond(
+                &PublicKey::ed25519_from(KEY1),
+                Some(U512::from(11)),
+                &limits
+            )
+        );
+        assert_eq!(
+            Ok(U512::from(10)),
+            stakes.unbond(
+                &PublicKey::ed25519_from(KEY1),
+                Some(U512::from(10)),
+                &limits
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_rewards() {
+        let stakes = new_stakes(&[(KEY1, 1), (KEY2, 2), (KEY3, 2)]);
+        assert_eq!(
+            Ok(new_stakes(&[(KEY1, 2), (KEY2, 5), (KEY3, 5)]).0),
+            stakes.split_rewards(U512::from(12))
+        );
+        // The leftover mote goes to the largest stake with the lowest key.
+        assert_eq!(
+            Ok(new_stakes(&[(KEY1, 0), (KEY2, 2), (KEY3, 1)]).0),
+            stakes.split_rewards(U512::from(3))
+        );
+        assert_eq!(
+            Ok(new_stakes(&[(KEY1, 0), (KEY2, 0), (KEY3, 0)]).0),
+            stakes.split_rewards(U512::zero())
+        );
+        assert_eq!(
+            Ok(BTreeMap::new()),
+            new_stakes(&[]).split_rewards(U512::from(10))
+        );
+        assert_eq!(
+            Err(Error::RewardOverflow),
+            stakes.split_rewards(U512::max_value())
+        );
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&StakeLimits::default());
+        bytesrepr::test_serialization_roundtrip(&StakeLimits {
+            min_bond: U512::from(1),
+            max_validators: 2,
+            max_spread: U512::from(3),
+            max_increase: U512::from(4),
+            max_decrease: U512::from(5),
+            max_rel_increase: 6,
+            max_rel_decrease: 7,
+        });
+    }
 }
@@ -1,16 +1,21 @@
 //! Contains types and constants associated with user accounts.
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 use core::{
+    cmp::Ordering,
     convert::TryFrom,
     fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
 };
 
 use failure::Fail;
 use hex_fmt::HexFmt;
+#[cfg(feature = "serde-support")]
+use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    bytesrepr::{Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    key::FromStrError,
     CLType, CLTyped,
 };
 
@@ -107,6 +112,7 @@ pub const WEIGHT_SERIALIZED_LENGTH: usize = U8_SERIALIZED_LENGTH;
 
 /// The weight attributed to a given [`PublicKey`] in an account's associated keys.
 #[derive(PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct Weight(u8);
 
 impl Weight {
@@ -144,18 +150,41 @@ impl CLTyped for Weight {
     }
 }
 
-/// The length in bytes of a [`PublicKey`].
+/// The length in bytes of an [`Ed25519`] public key.
 pub const ED25519_LENGTH: usize = 32;
 
 /// The number of bytes in a serialized [`Ed25519`].
 pub const ED25519_SERIALIZED_LENGTH: usize = ED25519_LENGTH;
 
+/// The length in bytes of a compressed [`Secp256k1`] public key.
+pub const SECP256K1_LENGTH: usize = 33;
+
+/// The number of bytes in a serialized [`Secp256k1`].
+pub const SECP256K1_SERIALIZED_LENGTH: usize = SECP256K1_LENGTH;
+
+/// The length in bytes of an [`Ed25519`] signature.
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+/// The length in bytes of a recoverable [`Secp256k1`] signature: the 64-byte compact signature
+/// followed by the recovery ID.
+pub const SECP256K1_RECOVERABLE_SIGNATURE_LENGTH: usize = 65;
+
 /// The upper bound of bytes in a serialized [`PublicKey`].
-pub const PUBLIC_KEY_SERIALIZED_MAX_LENGTH: usize = ED25519_SERIALIZED_LENGTH;
+pub const PUBLIC_KEY_SERIALIZED_MAX_LENGTH: usize =
+    U8_SERIALIZED_LENGTH + SECP256K1_SERIALIZED_LENGTH;
+
+const ED25519_TAG: u8 = 0;
+const SECP256K1_TAG: u8 = 1;
+
+const ED25519_PREFIX: &str = "ed25519-";
+const SECP256K1_PREFIX: &str = "secp256k1-";
 
 /// A type alias for the raw bytes of an Ed25519 public key.
 pub type Ed25519Bytes = [u8; ED25519_LENGTH];
 
+/// A type alias for the raw bytes of a compressed Secp256k1 public key.
+pub type Secp256k1Bytes = [u8; SECP256K1_LENGTH];
+
 /// A newtype wrapping a [`Ed25519Bytes`] which is the raw bytes of
 /// the public key of an Ed25519 key pair.
 #[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
@@ -201,17 +230,97 @@ impl FromBytes for Ed25519 {
     }
 }
 
+/// A newtype wrapping a [`Secp256k1Bytes`] which is the raw bytes of
+/// the compressed public key of a Secp256k1 key pair.
+// Arrays longer than 32 elements don't implement the comparison and hashing traits, so those are
+// implemented manually via slices.
+#[derive(Clone, Copy)]
+pub struct Secp256k1(Secp256k1Bytes);
+
+impl Secp256k1 {
+    /// Constructs a new `Secp256k1` instance from the raw bytes of a compressed Secp256k1 public
+    /// key.
+    pub const fn new(value: Secp256k1Bytes) -> Secp256k1 {
+        Secp256k1(value)
+    }
+
+    /// Returns the raw bytes of the public key as an array.
+    pub fn value(&self) -> Secp256k1Bytes {
+        self.0
+    }
+
+    /// Returns the raw bytes of the public key as a `slice`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for Secp256k1 {
+    fn eq(&self, other: &Secp256k1) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+impl Eq for Secp256k1 {}
+
+impl PartialOrd for Secp256k1 {
+    fn partial_cmp(&self, other: &Secp256k1) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Secp256k1 {
+    fn cmp(&self, other: &Secp256k1) -> Ordering {
+        self.0[..].cmp(&other.0[..])
+    }
+}
+
+impl Hash for Secp256k1 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0[..].hash(state)
+    }
+}
+
+impl Display for Secp256k1 {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "Secp256k1({})", HexFmt(&self.0))
+    }
+}
+
+impl ToBytes for Secp256k1 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.to_vec())
+    }
+
+    fn serialized_length(&self) -> usize {
+        SECP256K1_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for Secp256k1 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (key_bytes, rem) = bytesrepr::safe_split_at(bytes, SECP256K1_LENGTH)?;
+        let mut value = [0u8; SECP256K1_LENGTH];
+        value.copy_from_slice(key_bytes);
+        Ok((Secp256k1::new(value), rem))
+    }
+}
+
 /// An enum of supported public key types.
 #[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum PublicKey {
     /// An Ed25519 public key type.
     Ed25519(Ed25519),
+    /// A compressed Secp256k1 public key type.
+    Secp256k1(Secp256k1),
 }
 
 impl Display for PublicKey {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
-        let PublicKey::Ed25519(ed25519) = self;
-        write!(f, "PublicKey({})", ed25519)
+        match self {
+            PublicKey::Ed25519(ed25519) => write!(f, "PublicKey({})", ed25519),
+            PublicKey::Secp256k1(secp256k1) => write!(f, "PublicKey({})", secp256k1),
+        }
     }
 }
 
@@ -229,17 +338,74 @@ impl PublicKey {
             .map_err(|_| TryFromSliceForPublicKeyError(()))
     }
 
-    /// Returns the raw bytes of the public key as an array.
-    #[doc(hidden)]
-    pub fn value(self) -> Ed25519Bytes {
-        let PublicKey::Ed25519(ed25519) = self;
-        ed25519.value()
+    /// Constructs a new `PublicKey` using compressed Secp256k1 bytes.
+    pub const fn secp256k1_from(key: Secp256k1Bytes) -> PublicKey {
+        let secp256k1 = Secp256k1::new(key);
+        PublicKey::Secp256k1(secp256k1)
+    }
+
+    /// Attemps a new Secp256k1 `PublicKey` creation using a slice of bytes.
+    pub fn secp256k1_try_from(bytes: &[u8]) -> Result<PublicKey, TryFromSliceForPublicKeyError> {
+        if bytes.len() != SECP256K1_LENGTH {
+            return Err(TryFromSliceForPublicKeyError(()));
+        }
+        let mut value = [0u8; SECP256K1_LENGTH];
+        value.copy_from_slice(bytes);
+        Ok(PublicKey::secp256k1_from(value))
     }
 
     /// Returns the raw bytes of the public key as a `slice`.
     pub fn as_bytes(&self) -> &[u8] {
-        let PublicKey::Ed25519(ed25519) = self;
-        ed25519.as_bytes()
+        match self {
+            PublicKey::Ed25519(ed25519) => ed25519.as_bytes(),
+            PublicKey::Secp256k1(secp256k1) => secp256k1.as_bytes(),
+        }
+    }
+
+    /// Formats the public key as its key type followed by its Base16-encoded raw bytes, e.g.
+    /// `ed25519-<hex>` or `secp256k1-<hex>`.
+    pub fn to_formatted_string(&self) -> String {
+        let prefix = match self {
+            PublicKey::Ed25519(_) => ED25519_PREFIX,
+            PublicKey::Secp256k1(_) => SECP256K1_PREFIX,
+        };
+        format!("{}{}", prefix, base16::encode_lower(self.as_bytes()))
+    }
+
+    /// Parses a string formatted as per
+    /// [`to_formatted_string`](PublicKey::to_formatted_string) into a `PublicKey`.
+    pub fn from_formatted_str(input: &str) -> Result<Self, FromStrError> {
+        let decode = |hex: &str| base16::decode(hex).map_err(|_| FromStrError::Hex);
+        let public_key = if input.starts_with(ED25519_PREFIX) {
+            PublicKey::ed25519_try_from(&decode(&input[ED25519_PREFIX.len()..])?)
+        } else if input.starts_with(SECP256K1_PREFIX) {
+            PublicKey::secp256k1_try_from(&decode(&input[SECP256K1_PREFIX.len()..])?)
+        } else {
+            return Err(FromStrError::InvalidPrefix);
+        };
+        public_key.map_err(|_| FromStrError::InvalidLength)
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            PublicKey::Ed25519(_) => ED25519_TAG,
+            PublicKey::Secp256k1(_) => SECP256K1_TAG,
+        }
+    }
+}
+
+#[cfg(feature = "serde-support")]
+impl Serialize for PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_formatted_string())
+    }
+}
+
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let formatted_public_key = String::deserialize(deserializer)?;
+        PublicKey::from_formatted_str(&formatted_public_key).map_err(SerdeError::custom)
     }
 }
 
@@ -251,7 +417,7 @@ impl Debug for PublicKey {
 
 impl CLTyped for PublicKey {
     fn cl_type() -> CLType {
-        CLType::FixedList(Box::new(CLType::U8), 32)
+        CLType::PublicKey
     }
 }
 
@@ -261,23 +427,60 @@ impl From<Ed25519> for PublicKey {
     }
 }
 
+impl From<Secp256k1> for PublicKey {
+    fn from(secp256k1: Secp256k1) -> PublicKey {
+        PublicKey::Secp256k1(secp256k1)
+    }
+}
+
+/// Creates a `PublicKey` from raw key bytes, inferring the key type from their length: 32 bytes
+/// for Ed25519 and 33 bytes for compressed Secp256k1.
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = TryFromSliceForPublicKeyError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes.len() {
+            ED25519_LENGTH => PublicKey::ed25519_try_from(bytes),
+            SECP256K1_LENGTH => PublicKey::secp256k1_try_from(bytes),
+            _ => Err(TryFromSliceForPublicKeyError(())),
+        }
+    }
+}
+
 impl ToBytes for PublicKey {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        let PublicKey::Ed25519(ed25519) = self;
-        let mut bytes = Vec::with_capacity(PUBLIC_KEY_SERIALIZED_MAX_LENGTH);
-        bytes.append(&mut ed25519.to_bytes()?);
+        let mut bytes = Vec::with_capacity(self.serialized_length());
+        bytes.push(self.tag());
+        match self {
+            PublicKey::Ed25519(ed25519) => bytes.append(&mut ed25519.to_bytes()?),
+            PublicKey::Secp256k1(secp256k1) => bytes.append(&mut secp256k1.to_bytes()?),
+        }
         Ok(bytes)
     }
 
     fn serialized_length(&self) -> usize {
-        PUBLIC_KEY_SERIALIZED_MAX_LENGTH
+        U8_SERIALIZED_LENGTH
+            + match self {
+                PublicKey::Ed25519(ed25519) => ed25519.serialized_length(),
+                PublicKey::Secp256k1(secp256k1) => secp256k1.serialized_length(),
+            }
     }
 }
 
 impl FromBytes for PublicKey {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (ed25519, rem) = Ed25519::from_bytes(bytes)?;
-        Ok((PublicKey::from(ed25519), rem))
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            ED25519_TAG => {
+                let (ed25519, rem) = Ed25519::from_bytes(rem)?;
+                Ok((PublicKey::from(ed25519), rem))
+            }
+            SECP256K1_TAG => {
+                let (secp256k1, rem) = Secp256k1::from_bytes(rem)?;
+                Ok((PublicKey::from(secp256k1), rem))
+            }
+            _ => Err(Error::Formatting),
+        }
     }
 }
 
@@ -410,6 +613,53 @@ mod tests {
             PublicKey::ed25519_try_from(&[0u8; 33][..]).expect_err("should not create public key");
     }
 
+    #[test]
+    fn secp256k1_public_key_from_slice() {
+        let bytes: Vec<u8> = (0..33).collect();
+        let public_key =
+            PublicKey::secp256k1_try_from(&bytes[..]).expect("should create public key");
+        assert_eq!(&bytes, &public_key.as_bytes());
+    }
+
+    #[test]
+    fn secp256k1_public_key_from_slice_wrong_length() {
+        let _public_key = PublicKey::secp256k1_try_from(&[0u8; 32][..])
+            .expect_err("should not create public key");
+        let _public_key = PublicKey::secp256k1_try_from(&[0u8; 34][..])
+            .expect_err("should not create public key");
+    }
+
+    #[test]
+    fn public_key_from_slice_infers_key_type() {
+        let ed25519 = PublicKey::try_from(&[1u8; 32][..]).expect("should create public key");
+        assert_eq!(ed25519, PublicKey::ed25519_from([1u8; 32]));
+
+        let secp256k1 = PublicKey::try_from(&[1u8; 33][..]).expect("should create public key");
+        assert_eq!(secp256k1, PublicKey::secp256k1_from([1u8; 33]));
+
+        let _public_key =
+            PublicKey::try_from(&[1u8; 31][..]).expect_err("should not create public key");
+    }
+
+    #[test]
+    fn public_key_serialization_roundtrip() {
+        let ed25519 = PublicKey::ed25519_from([1u8; 32]);
+        assert_eq!(ed25519.serialized_length(), 1 + ED25519_SERIALIZED_LENGTH);
+        bytesrepr::test_serialization_roundtrip(&ed25519);
+
+        let secp256k1 = PublicKey::secp256k1_from([2u8; 33]);
+        assert_eq!(
+            secp256k1.serialized_length(),
+            PUBLIC_KEY_SERIALIZED_MAX_LENGTH
+        );
+        bytesrepr::test_serialization_roundtrip(&secp256k1);
+
+        assert_ne!(
+            ed25519.to_bytes().unwrap()[0],
+            secp256k1.to_bytes().unwrap()[0]
+        );
+    }
+
     #[test]
     fn try_from_i32_for_set_threshold_failure() {
         let max_valid_value_for_variant = SetThresholdFailure::InsufficientTotalWeight as i32;
@@ -225,6 +225,18 @@ const HEADER_ERROR_MAX: u32 = HEADER_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 65_031 => MintError::PurseNotFound
 /// # );
+/// # show_and_check!(
+/// 65_032 => MintError::UnauthorizedRewardMinting
+/// # );
+/// # show_and_check!(
+/// 65_033 => MintError::UnauthorizedBurning
+/// # );
+/// # show_and_check!(
+/// 65_034 => MintError::TotalSupplyNotFound
+/// # );
+/// # show_and_check!(
+/// 65_035 => MintError::TotalSupplyUnderflow
+/// # );
 ///
 /// // Proof of stake errors:
 /// use casperlabs_types::system_contract_errors::pos::Error as PosError;
@@ -309,6 +321,57 @@ const HEADER_ERROR_MAX: u32 = HEADER_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 65_306 => PosError::SetRefundPurseCalledOutsidePayment
 /// # );
+/// # show_and_check!(
+/// 65_307 => PosError::RewardsPurseBalanceNotFound
+/// # );
+/// # show_and_check!(
+/// 65_308 => PosError::InsufficientRewardsPurseBalance
+/// # );
+/// # show_and_check!(
+/// 65_309 => PosError::RewardTransferFailed
+/// # );
+/// # show_and_check!(
+/// 65_310 => PosError::NotDelegated
+/// # );
+/// # show_and_check!(
+/// 65_311 => PosError::DelegationTooSmall
+/// # );
+/// # show_and_check!(
+/// 65_312 => PosError::UndelegateTooLarge
+/// # );
+/// # show_and_check!(
+/// 65_313 => PosError::SelfDelegation
+/// # );
+/// # show_and_check!(
+/// 65_314 => PosError::BelowMinimumBond
+/// # );
+/// # show_and_check!(
+/// 65_315 => PosError::TooManyValidators
+/// # );
+/// # show_and_check!(
+/// 65_316 => PosError::RewardMintingFailed
+/// # );
+/// # show_and_check!(
+/// 65_317 => PosError::QueueDelaysNotFound
+/// # );
+/// # show_and_check!(
+/// 65_318 => PosError::QueueDelaysDeserializationFailed
+/// # );
+/// # show_and_check!(
+/// 65_319 => PosError::StakeLimitsNotFound
+/// # );
+/// # show_and_check!(
+/// 65_320 => PosError::StakeLimitsDeserializationFailed
+/// # );
+/// # show_and_check!(
+/// 65_321 => PosError::RewardOverflow
+/// # );
+/// # show_and_check!(
+/// 65_322 => PosError::DelegationsNotFound
+/// # );
+/// # show_and_check!(
+/// 65_323 => PosError::DelegationsDeserializationFailed
+/// # );
 ///
 /// // User-defined errors:
 /// # show_and_check!(
@@ -716,12 +779,12 @@ mod tests {
 
     #[test]
     fn error_values() {
-        assert_eq!(65_024_u32, ApiError::Mint(0).into()); // MINT_ERROR_OFFSET == 65,024
-        assert_eq!(65_279_u32, ApiError::Mint(u8::MAX).into());
-        assert_eq!(65_280_u32, ApiError::ProofOfStake(0).into()); // POS_ERROR_OFFSET == 65,280
-        assert_eq!(65_535_u32, ApiError::ProofOfStake(u8::MAX).into());
-        assert_eq!(65_536_u32, ApiError::User(0).into()); // u16::MAX + 1
-        assert_eq!(131_071_u32, ApiError::User(u16::MAX).into()); // 2 * u16::MAX + 1
+        assert_eq!(65_024_u32, u32::from(ApiError::Mint(0))); // MINT_ERROR_OFFSET == 65,024
+        assert_eq!(65_279_u32, u32::from(ApiError::Mint(u8::MAX)));
+        assert_eq!(65_280_u32, u32::from(ApiError::ProofOfStake(0))); // POS_ERROR_OFFSET == 65,280
+        assert_eq!(65_535_u32, u32::from(ApiError::ProofOfStake(u8::MAX)));
+        assert_eq!(65_536_u32, u32::from(ApiError::User(0))); // u16::MAX + 1
+        assert_eq!(131_071_u32, u32::from(ApiError::User(u16::MAX))); // 2 * u16::MAX + 1
     }
 
     #[test]
@@ -6,6 +6,9 @@ use alloc::{
 };
 use core::mem;
 
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
     Key, URef, U128, U256, U512,
@@ -33,11 +36,13 @@ const CL_TYPE_TAG_TUPLE1: u8 = 18;
 const CL_TYPE_TAG_TUPLE2: u8 = 19;
 const CL_TYPE_TAG_TUPLE3: u8 = 20;
 const CL_TYPE_TAG_ANY: u8 = 21;
+const CL_TYPE_TAG_PUBLIC_KEY: u8 = 22;
 
 /// CasperLabs types, i.e. types which can be stored and manipulated by smart contracts.
 ///
 /// Provides a description of the underlying data type of a [`CLValue`](crate::CLValue).
 #[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub enum CLType {
     /// `bool` primitive.
     Bool,
@@ -65,6 +70,8 @@ pub enum CLType {
     Key,
     /// [`URef`] system type.
     URef,
+    /// [`PublicKey`](crate::account::PublicKey) system type.
+    PublicKey,
     /// `Option` of a `CLType`.
     Option(Box<CLType>),
     /// Variable-length list of a single `CLType` (comparable to a `Vec`).
@@ -108,6 +115,7 @@ impl CLType {
                 | CLType::String
                 | CLType::Key
                 | CLType::URef
+                | CLType::PublicKey
                 | CLType::Any => 0,
                 CLType::Option(cl_type) | CLType::List(cl_type) => cl_type.serialized_length(),
                 CLType::FixedList(cl_type, list_len) => {
@@ -143,6 +151,7 @@ impl CLType {
             CLType::String => stream.push(CL_TYPE_TAG_STRING),
             CLType::Key => stream.push(CL_TYPE_TAG_KEY),
             CLType::URef => stream.push(CL_TYPE_TAG_UREF),
+            CLType::PublicKey => stream.push(CL_TYPE_TAG_PUBLIC_KEY),
             CLType::Option(cl_type) => {
                 stream.push(CL_TYPE_TAG_OPTION);
                 cl_type.append_bytes(stream);
@@ -198,6 +207,7 @@ impl FromBytes for CLType {
             CL_TYPE_TAG_STRING => Ok((CLType::String, remainder)),
             CL_TYPE_TAG_KEY => Ok((CLType::Key, remainder)),
             CL_TYPE_TAG_UREF => Ok((CLType::URef, remainder)),
+            CL_TYPE_TAG_PUBLIC_KEY => Ok((CLType::PublicKey, remainder)),
             CL_TYPE_TAG_OPTION => {
                 let (inner_type, remainder) = CLType::from_bytes(remainder)?;
                 let cl_type = CLType::Option(Box::new(inner_type));
@@ -460,6 +470,7 @@ mod tests {
 
     use super::*;
     use crate::{
+        account::PublicKey,
         bytesrepr::{FromBytes, ToBytes},
         AccessRights, CLValue,
     };
@@ -544,6 +555,12 @@ mod tests {
         round_trip(&uref);
     }
 
+    #[test]
+    fn public_key_should_work() {
+        round_trip(&PublicKey::ed25519_from([1u8; 32]));
+        round_trip(&PublicKey::secp256k1_from([2u8; 33]));
+    }
+
     #[test]
     fn option_of_cl_type_should_work() {
         let x: Option<i32> = Some(-1);
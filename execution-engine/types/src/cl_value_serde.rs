@@ -0,0 +1,820 @@
+//! The `serde` representation of [`CLValue`].
+//!
+//! A `CLValue` is represented as a struct with two fields: `cl_type`, holding its [`CLType`], and
+//! `value`, holding the underlying data rendered according to that type:
+//!
+//! * `Bool`, `I32`, `I64`, `U8`, `U32` and `U64` as native numbers or booleans
+//! * `U128`, `U256` and `U512` as decimal strings
+//! * `Unit` as a unit (`null` in JSON)
+//! * `String` as a string
+//! * `Key`, `URef` and `PublicKey` as their formatted strings, e.g. `"hash-<hex>"`
+//! * `Option` as either a unit (`null`) or a single-entry map `{"Some": <value>}`, so that
+//!   `Some(())` and `Some(None)` remain distinct from `None`
+//! * `List` and `FixedList` as sequences
+//! * `Result` as a single-entry map, either `{"Ok": <value>}` or `{"Err": <value>}`
+//! * `Map` as a sequence of `{"key": <value>, "value": <value>}` maps, in serialized order
+//! * `Tuple1`, `Tuple2` and `Tuple3` as sequences
+//! * `Any` as the Base16-encoded serialized bytes
+//!
+//! Deserialization writes the value back out in `bytesrepr` form, so a value round-trips to the
+//! same bytes.  When deserializing, `cl_type` must precede `value`.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt::{self, Formatter};
+
+use serde::{
+    de::{
+        DeserializeSeed, Error as DeError, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor,
+    },
+    ser::{Error as SerError, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLValue, Key, URef, U128, U256, U512,
+};
+
+const OPTION_NONE_TAG: u8 = 0;
+const OPTION_SOME_TAG: u8 = 1;
+const RESULT_ERR_TAG: u8 = 0;
+const RESULT_OK_TAG: u8 = 1;
+
+const CL_TYPE_FIELD: &str = "cl_type";
+const VALUE_FIELD: &str = "value";
+const FIELDS: &[&str] = &[CL_TYPE_FIELD, VALUE_FIELD];
+
+const OPTION_VARIANTS: &[&str] = &["Some"];
+const RESULT_VARIANTS: &[&str] = &["Ok", "Err"];
+const MAP_ENTRY_FIELDS: &[&str] = &["key", "value"];
+
+impl Serialize for CLValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("CLValue", FIELDS.len())?;
+        state.serialize_field(CL_TYPE_FIELD, self.cl_type())?;
+        state.serialize_field(
+            VALUE_FIELD,
+            &TypedBytes {
+                cl_type: self.cl_type(),
+                bytes: self.inner_bytes(),
+            },
+        )?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CLValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("CLValue", FIELDS, CLValueVisitor)
+    }
+}
+
+struct CLValueVisitor;
+
+impl<'de> Visitor<'de> for CLValueVisitor {
+    type Value = CLValue;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a CLValue with `cl_type` and `value` fields")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CLValue, A::Error> {
+        let cl_type: CLType = seq
+            .next_element()?
+            .ok_or_else(|| DeError::invalid_length(0, &self))?;
+        let mut bytes = Vec::new();
+        seq.next_element_seed(TypedBytesSeed {
+            cl_type: &cl_type,
+            output: &mut bytes,
+        })?
+        .ok_or_else(|| DeError::invalid_length(1, &self))?;
+        Ok(CLValue::from_components(cl_type, bytes))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CLValue, A::Error> {
+        let mut cl_type: Option<CLType> = None;
+        let mut bytes: Option<Vec<u8>> = None;
+        while let Some(field) = map.next_key::<String>()? {
+            match field.as_str() {
+                CL_TYPE_FIELD => {
+                    if cl_type.is_some() {
+                        return Err(DeError::duplicate_field(CL_TYPE_FIELD));
+                    }
+                    cl_type = Some(map.next_value()?);
+                }
+                VALUE_FIELD => {
+                    if bytes.is_some() {
+                        return Err(DeError::duplicate_field(VALUE_FIELD));
+                    }
+                    let cl_type = cl_type.as_ref().ok_or_else(|| {
+                        DeError::custom("`cl_type` must precede `value` in a CLValue")
+                    })?;
+                    let mut output = Vec::new();
+                    map.next_value_seed(TypedBytesSeed {
+                        cl_type,
+                        output: &mut output,
+                    })?;
+                    bytes = Some(output);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let cl_type = cl_type.ok_or_else(|| DeError::missing_field(CL_TYPE_FIELD))?;
+        let bytes = bytes.ok_or_else(|| DeError::missing_field(VALUE_FIELD))?;
+        Ok(CLValue::from_components(cl_type, bytes))
+    }
+}
+
+/// Returns the serialized bytes of the leading value of type `cl_type` and the remainder.
+fn split_value<'a>(
+    cl_type: &CLType,
+    bytes: &'a [u8],
+) -> Result<(&'a [u8], &'a [u8]), bytesrepr::Error> {
+    fn skip<T: FromBytes>(bytes: &[u8]) -> Result<&[u8], bytesrepr::Error> {
+        T::from_bytes(bytes).map(|(_, remainder)| remainder)
+    }
+
+    fn skip_all<'a>(
+        cl_types: &[&CLType],
+        count: usize,
+        mut bytes: &'a [u8],
+    ) -> Result<&'a [u8], bytesrepr::Error> {
+        for _ in 0..count {
+            for cl_type in cl_types {
+                bytes = split_value(cl_type, bytes)?.1;
+            }
+        }
+        Ok(bytes)
+    }
+
+    let remainder = match cl_type {
+        CLType::Bool => skip::<bool>(bytes)?,
+        CLType::I32 => skip::<i32>(bytes)?,
+        CLType::I64 => skip::<i64>(bytes)?,
+        CLType::U8 => skip::<u8>(bytes)?,
+        CLType::U32 => skip::<u32>(bytes)?,
+        CLType::U64 => skip::<u64>(bytes)?,
+        CLType::U128 => skip::<U128>(bytes)?,
+        CLType::U256 => skip::<U256>(bytes)?,
+        CLType::U512 => skip::<U512>(bytes)?,
+        CLType::Unit => bytes,
+        CLType::String => skip::<String>(bytes)?,
+        CLType::Key => skip::<Key>(bytes)?,
+        CLType::URef => skip::<URef>(bytes)?,
+        CLType::PublicKey => skip::<PublicKey>(bytes)?,
+        CLType::Option(inner) => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                OPTION_NONE_TAG => remainder,
+                OPTION_SOME_TAG => split_value(inner, remainder)?.1,
+                _ => return Err(bytesrepr::Error::Formatting),
+            }
+        }
+        CLType::List(inner) => {
+            let (count, remainder) = u32::from_bytes(bytes)?;
+            skip_all(&[inner], count as usize, remainder)?
+        }
+        CLType::FixedList(inner, len) => skip_all(&[inner], *len as usize, bytes)?,
+        CLType::Result { ok, err } => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                RESULT_ERR_TAG => split_value(err, remainder)?.1,
+                RESULT_OK_TAG => split_value(ok, remainder)?.1,
+                _ => return Err(bytesrepr::Error::Formatting),
+            }
+        }
+        CLType::Map { key, value } => {
+            let (count, remainder) = u32::from_bytes(bytes)?;
+            skip_all(&[key, value], count as usize, remainder)?
+        }
+        CLType::Tuple1([t1]) => skip_all(&[t1], 1, bytes)?,
+        CLType::Tuple2([t1, t2]) => skip_all(&[t1, t2], 1, bytes)?,
+        CLType::Tuple3([t1, t2, t3]) => skip_all(&[t1, t2, t3], 1, bytes)?,
+        // The extent of a value of type `Any` is unknown, so it takes up all remaining bytes.
+        CLType::Any => &bytes[bytes.len()..],
+    };
+    let value_length = bytes.len() - remainder.len();
+    Ok(bytes.split_at(value_length))
+}
+
+/// The serialized bytes of exactly one value of the given `CLType`.
+struct TypedBytes<'a> {
+    cl_type: &'a CLType,
+    bytes: &'a [u8],
+}
+
+impl<'a> TypedBytes<'a> {
+    /// Splits the leading value of type `cl_type` off `bytes`.
+    fn split(cl_type: &'a CLType, bytes: &mut &'a [u8]) -> Result<Self, bytesrepr::Error> {
+        let (value_bytes, remainder) = split_value(cl_type, bytes)?;
+        *bytes = remainder;
+        Ok(TypedBytes {
+            cl_type,
+            bytes: value_bytes,
+        })
+    }
+
+    fn deserialize<T: FromBytes>(&self) -> Result<T, bytesrepr::Error> {
+        bytesrepr::deserialize(self.bytes.to_vec())
+    }
+}
+
+impl<'a> Serialize for TypedBytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let error = |error: bytesrepr::Error| S::Error::custom(error);
+        let mut bytes = self.bytes;
+        match self.cl_type {
+            CLType::Bool => serializer.serialize_bool(self.deserialize().map_err(error)?),
+            CLType::I32 => serializer.serialize_i32(self.deserialize().map_err(error)?),
+            CLType::I64 => serializer.serialize_i64(self.deserialize().map_err(error)?),
+            CLType::U8 => serializer.serialize_u8(self.deserialize().map_err(error)?),
+            CLType::U32 => serializer.serialize_u32(self.deserialize().map_err(error)?),
+            CLType::U64 => serializer.serialize_u64(self.deserialize().map_err(error)?),
+            CLType::U128 => self
+                .deserialize::<U128>()
+                .map_err(error)?
+                .serialize(serializer),
+            CLType::U256 => self
+                .deserialize::<U256>()
+                .map_err(error)?
+                .serialize(serializer),
+            CLType::U512 => self
+                .deserialize::<U512>()
+                .map_err(error)?
+                .serialize(serializer),
+            CLType::Unit => serializer.serialize_unit(),
+            CLType::String => {
+                serializer.serialize_str(&self.deserialize::<String>().map_err(error)?)
+            }
+            CLType::Key => self
+                .deserialize::<Key>()
+                .map_err(error)?
+                .serialize(serializer),
+            CLType::URef => self
+                .deserialize::<URef>()
+                .map_err(error)?
+                .serialize(serializer),
+            CLType::PublicKey => self
+                .deserialize::<PublicKey>()
+                .map_err(error)?
+                .serialize(serializer),
+            CLType::Option(inner) => {
+                let (tag, mut remainder) = u8::from_bytes(bytes).map_err(error)?;
+                match tag {
+                    OPTION_NONE_TAG => serializer.serialize_none(),
+                    _ => serializer.serialize_some(&SomeValue(
+                        TypedBytes::split(inner, &mut remainder).map_err(error)?,
+                    )),
+                }
+            }
+            CLType::List(inner) => {
+                let (count, mut remainder) = u32::from_bytes(bytes).map_err(error)?;
+                let mut seq = serializer.serialize_seq(Some(count as usize))?;
+                for _ in 0..count {
+                    seq.serialize_element(
+                        &TypedBytes::split(inner, &mut remainder).map_err(error)?,
+                    )?;
+                }
+                seq.end()
+            }
+            CLType::FixedList(inner, len) => {
+                let mut seq = serializer.serialize_seq(Some(*len as usize))?;
+                for _ in 0..*len {
+                    seq.serialize_element(&TypedBytes::split(inner, &mut bytes).map_err(error)?)?;
+                }
+                seq.end()
+            }
+            CLType::Result { ok, err } => {
+                let (tag, mut remainder) = u8::from_bytes(bytes).map_err(error)?;
+                let (variant, cl_type) = match tag {
+                    RESULT_ERR_TAG => ("Err", err),
+                    _ => ("Ok", ok),
+                };
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(
+                    variant,
+                    &TypedBytes::split(cl_type, &mut remainder).map_err(error)?,
+                )?;
+                map.end()
+            }
+            CLType::Map { key, value } => {
+                let (count, mut remainder) = u32::from_bytes(bytes).map_err(error)?;
+                let mut seq = serializer.serialize_seq(Some(count as usize))?;
+                for _ in 0..count {
+                    seq.serialize_element(&MapEntry {
+                        key: TypedBytes::split(key, &mut remainder).map_err(error)?,
+                        value: TypedBytes::split(value, &mut remainder).map_err(error)?,
+                    })?;
+                }
+                seq.end()
+            }
+            CLType::Tuple1(cl_types) => serialize_tuple(serializer, cl_types, bytes),
+            CLType::Tuple2(cl_types) => serialize_tuple(serializer, cl_types, bytes),
+            CLType::Tuple3(cl_types) => serialize_tuple(serializer, cl_types, bytes),
+            CLType::Any => serializer.serialize_str(&base16::encode_lower(bytes)),
+        }
+    }
+}
+
+fn serialize_tuple<'a, S: Serializer>(
+    serializer: S,
+    cl_types: &'a [Box<CLType>],
+    mut bytes: &'a [u8],
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(cl_types.len())?;
+    for cl_type in cl_types {
+        let element = TypedBytes::split(cl_type, &mut bytes).map_err(S::Error::custom)?;
+        tuple.serialize_element(&element)?;
+    }
+    tuple.end()
+}
+
+/// The inner value of an `Option` which is `Some`, serialized as a `{"Some": <value>}` map.
+struct SomeValue<'a>(TypedBytes<'a>);
+
+impl<'a> Serialize for SomeValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(OPTION_VARIANTS[0], &self.0)?;
+        map.end()
+    }
+}
+
+struct MapEntry<'a> {
+    key: TypedBytes<'a>,
+    value: TypedBytes<'a>,
+}
+
+impl<'a> Serialize for MapEntry<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(MAP_ENTRY_FIELDS.len()))?;
+        map.serialize_entry(MAP_ENTRY_FIELDS[0], &self.key)?;
+        map.serialize_entry(MAP_ENTRY_FIELDS[1], &self.value)?;
+        map.end()
+    }
+}
+
+/// Deserializes a value of the given `CLType`, appending its serialized bytes to `output`.
+struct TypedBytesSeed<'a> {
+    cl_type: &'a CLType,
+    output: &'a mut Vec<u8>,
+}
+
+impl<'a> TypedBytesSeed<'a> {
+    fn append<T: ToBytes, E: DeError>(self, value: T) -> Result<(), E> {
+        let mut bytes = value.into_bytes().map_err(E::custom)?;
+        self.output.append(&mut bytes);
+        Ok(())
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for TypedBytesSeed<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        match self.cl_type {
+            CLType::Bool => self.append(bool::deserialize(deserializer)?),
+            CLType::I32 => self.append(i32::deserialize(deserializer)?),
+            CLType::I64 => self.append(i64::deserialize(deserializer)?),
+            CLType::U8 => self.append(u8::deserialize(deserializer)?),
+            CLType::U32 => self.append(u32::deserialize(deserializer)?),
+            CLType::U64 => self.append(u64::deserialize(deserializer)?),
+            CLType::U128 => self.append(U128::deserialize(deserializer)?),
+            CLType::U256 => self.append(U256::deserialize(deserializer)?),
+            CLType::U512 => self.append(U512::deserialize(deserializer)?),
+            CLType::Unit => <()>::deserialize(deserializer),
+            CLType::String => self.append(String::deserialize(deserializer)?),
+            CLType::Key => self.append(Key::deserialize(deserializer)?),
+            CLType::URef => self.append(URef::deserialize(deserializer)?),
+            CLType::PublicKey => self.append(PublicKey::deserialize(deserializer)?),
+            CLType::Option(inner) => deserializer.deserialize_option(OptionVisitor {
+                inner,
+                output: self.output,
+            }),
+            CLType::List(inner) => deserializer.deserialize_seq(SeqVisitor {
+                cl_types: &[inner],
+                expected_len: None,
+                output: self.output,
+            }),
+            CLType::FixedList(inner, len) => deserializer.deserialize_seq(SeqVisitor {
+                cl_types: &[inner],
+                expected_len: Some(*len as usize),
+                output: self.output,
+            }),
+            CLType::Result { ok, err } => deserializer.deserialize_map(ResultVisitor {
+                ok,
+                err,
+                output: self.output,
+            }),
+            CLType::Map { key, value } => deserializer.deserialize_seq(SeqVisitor {
+                cl_types: &[key, value],
+                expected_len: None,
+                output: self.output,
+            }),
+            CLType::Tuple1([t1]) => deserializer.deserialize_tuple(
+                1,
+                SeqVisitor {
+                    cl_types: &[t1],
+                    expected_len: Some(1),
+                    output: self.output,
+                }
+                .tuple(),
+            ),
+            CLType::Tuple2([t1, t2]) => deserializer.deserialize_tuple(
+                2,
+                SeqVisitor {
+                    cl_types: &[t1, t2],
+                    expected_len: Some(1),
+                    output: self.output,
+                }
+                .tuple(),
+            ),
+            CLType::Tuple3([t1, t2, t3]) => deserializer.deserialize_tuple(
+                3,
+                SeqVisitor {
+                    cl_types: &[t1, t2, t3],
+                    expected_len: Some(1),
+                    output: self.output,
+                }
+                .tuple(),
+            ),
+            CLType::Any => {
+                let hex = String::deserialize(deserializer)?;
+                let mut bytes = base16::decode(&hex)
+                    .map_err(|_| DeError::invalid_value(Unexpected::Str(&hex), &"Base16 bytes"))?;
+                self.output.append(&mut bytes);
+                Ok(())
+            }
+        }
+    }
+}
+
+struct OptionVisitor<'a> {
+    inner: &'a CLType,
+    output: &'a mut Vec<u8>,
+}
+
+impl<'de, 'a> Visitor<'de> for OptionVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<(), E> {
+        self.output.push(OPTION_NONE_TAG);
+        Ok(())
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<(), E> {
+        self.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(SomeVisitor {
+            inner: self.inner,
+            output: self.output,
+        })
+    }
+}
+
+/// Visits the `{"Some": <value>}` map of an `Option` which is `Some`.
+struct SomeVisitor<'a> {
+    inner: &'a CLType,
+    output: &'a mut Vec<u8>,
+}
+
+impl<'de, 'a> Visitor<'de> for SomeVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map with a single `Some` entry")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let variant = map
+            .next_key::<String>()?
+            .ok_or_else(|| DeError::invalid_length(0, &self))?;
+        if variant != OPTION_VARIANTS[0] {
+            return Err(DeError::unknown_variant(&variant, OPTION_VARIANTS));
+        }
+        self.output.push(OPTION_SOME_TAG);
+        map.next_value_seed(TypedBytesSeed {
+            cl_type: self.inner,
+            output: self.output,
+        })?;
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(DeError::invalid_length(2, &self));
+        }
+        Ok(())
+    }
+}
+
+/// Visits a sequence of groups of values, where each group holds one value of each of
+/// `cl_types`.
+///
+/// If `expected_len` is `None`, the sequence is a `List` or `Map` and its serialized form is
+/// prefixed with the number of groups.  Map entries are visited as `{"key", "value"}` maps.
+struct SeqVisitor<'a, 'b> {
+    cl_types: &'b [&'a CLType],
+    expected_len: Option<usize>,
+    output: &'a mut Vec<u8>,
+}
+
+impl<'a, 'b> SeqVisitor<'a, 'b> {
+    /// Returns a visitor for a tuple, i.e. for a single group visited as a flat sequence.
+    fn tuple(self) -> TupleVisitor<'a, 'b> {
+        TupleVisitor(self)
+    }
+}
+
+impl<'de, 'a, 'b> Visitor<'de> for SeqVisitor<'a, 'b> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut elements = Vec::new();
+        let mut count = 0usize;
+        loop {
+            let found = match self.cl_types {
+                [cl_type] => seq
+                    .next_element_seed(TypedBytesSeed {
+                        cl_type,
+                        output: &mut elements,
+                    })?
+                    .is_some(),
+                [key, value] => {
+                    let entry = seq.next_element_seed(MapEntrySeed { key, value })?;
+                    match entry {
+                        Some(mut entry) => {
+                            elements.append(&mut entry);
+                            true
+                        }
+                        None => false,
+                    }
+                }
+                _ => unreachable!("sequences are made of single values or map entries"),
+            };
+            if !found {
+                break;
+            }
+            count += 1;
+        }
+        match self.expected_len {
+            Some(expected_len) if count != expected_len => {
+                return Err(DeError::invalid_length(count, &self));
+            }
+            Some(_) => (),
+            None => {
+                let mut count_bytes = (count as u32).into_bytes().map_err(DeError::custom)?;
+                self.output.append(&mut count_bytes);
+            }
+        }
+        self.output.append(&mut elements);
+        Ok(())
+    }
+}
+
+struct TupleVisitor<'a, 'b>(SeqVisitor<'a, 'b>);
+
+impl<'de, 'a, 'b> Visitor<'de> for TupleVisitor<'a, 'b> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a tuple of {} elements", self.0.cl_types.len())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for (index, cl_type) in self.0.cl_types.iter().enumerate() {
+            seq.next_element_seed(TypedBytesSeed {
+                cl_type,
+                output: self.0.output,
+            })?
+            .ok_or_else(|| DeError::invalid_length(index, &self))?;
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes a `{"key", "value"}` map entry, returning the serialized key followed by the
+/// serialized value.
+struct MapEntrySeed<'a> {
+    key: &'a CLType,
+    value: &'a CLType,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for MapEntrySeed<'a> {
+    type Value = Vec<u8>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_struct("MapEntry", MAP_ENTRY_FIELDS, self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for MapEntrySeed<'a> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map entry with `key` and `value` fields")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut output = Vec::new();
+        for (index, cl_type) in [self.key, self.value].iter().enumerate() {
+            seq.next_element_seed(TypedBytesSeed {
+                cl_type,
+                output: &mut output,
+            })?
+            .ok_or_else(|| DeError::invalid_length(index, &self))?;
+        }
+        Ok(output)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Vec<u8>, A::Error> {
+        // The key and value are collected separately, so they may appear in either order.
+        let mut key_bytes: Option<Vec<u8>> = None;
+        let mut value_bytes: Option<Vec<u8>> = None;
+        while let Some(field) = map.next_key::<String>()? {
+            let (slot, cl_type, name) = match field.as_str() {
+                "key" => (&mut key_bytes, self.key, MAP_ENTRY_FIELDS[0]),
+                "value" => (&mut value_bytes, self.value, MAP_ENTRY_FIELDS[1]),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                    continue;
+                }
+            };
+            if slot.is_some() {
+                return Err(DeError::duplicate_field(name));
+            }
+            let mut output = Vec::new();
+            map.next_value_seed(TypedBytesSeed {
+                cl_type,
+                output: &mut output,
+            })?;
+            *slot = Some(output);
+        }
+        let mut output = key_bytes.ok_or_else(|| DeError::missing_field(MAP_ENTRY_FIELDS[0]))?;
+        let mut value_bytes =
+            value_bytes.ok_or_else(|| DeError::missing_field(MAP_ENTRY_FIELDS[1]))?;
+        output.append(&mut value_bytes);
+        Ok(output)
+    }
+}
+
+struct ResultVisitor<'a> {
+    ok: &'a CLType,
+    err: &'a CLType,
+    output: &'a mut Vec<u8>,
+}
+
+impl<'de, 'a> Visitor<'de> for ResultVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map with a single `Ok` or `Err` entry")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let variant = map
+            .next_key::<String>()?
+            .ok_or_else(|| DeError::invalid_length(0, &self))?;
+        let (tag, cl_type) = match variant.as_str() {
+            "Ok" => (RESULT_OK_TAG, self.ok),
+            "Err" => (RESULT_ERR_TAG, self.err),
+            other => return Err(DeError::unknown_variant(other, RESULT_VARIANTS)),
+        };
+        self.output.push(tag);
+        map.next_value_seed(TypedBytesSeed {
+            cl_type,
+            output: self.output,
+        })?;
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(DeError::invalid_length(2, &self));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::ToString};
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{gens, AccessRights, CLTyped};
+
+    fn to_json<T: CLTyped + ToBytes>(value: T) -> serde_json::Value {
+        let cl_value = CLValue::from_t(value).unwrap();
+        serde_json::to_value(&cl_value).unwrap()["value"].clone()
+    }
+
+    fn json_round_trip(cl_value: &CLValue) {
+        let json = serde_json::to_string(cl_value).unwrap();
+        let parsed: CLValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, cl_value, "{}", json);
+    }
+
+    #[test]
+    fn should_render_typed_json() {
+        assert_eq!(to_json(true), serde_json::json!(true));
+        assert_eq!(to_json(-1i32), serde_json::json!(-1));
+        assert_eq!(
+            to_json(U512::from(u64::max_value()) * 2),
+            serde_json::json!("36893488147419103230")
+        );
+        assert_eq!(to_json(()), serde_json::Value::Null);
+        assert_eq!(to_json("abc".to_string()), serde_json::json!("abc"));
+        assert_eq!(
+            to_json(Key::Hash([1; 32])),
+            serde_json::json!(Key::Hash([1; 32]).as_string())
+        );
+        assert_eq!(
+            to_json(URef::new([2; 32], AccessRights::READ)),
+            serde_json::json!(URef::new([2; 32], AccessRights::READ).as_string())
+        );
+        assert_eq!(to_json(Some(1u8)), serde_json::json!({ "Some": 1 }));
+        assert_eq!(to_json(Option::<u8>::None), serde_json::Value::Null);
+        assert_eq!(to_json(vec![1u32, 2, 3]), serde_json::json!([1, 2, 3]));
+        assert_eq!(to_json([1u64, 2]), serde_json::json!([1, 2]));
+        assert_eq!(
+            to_json(Result::<u8, String>::Ok(1)),
+            serde_json::json!({ "Ok": 1 })
+        );
+        assert_eq!(
+            to_json(Result::<u8, String>::Err("bad".to_string())),
+            serde_json::json!({ "Err": "bad" })
+        );
+        let mut map = BTreeMap::new();
+        map.insert(1u8, "one".to_string());
+        assert_eq!(
+            to_json(map),
+            serde_json::json!([{ "key": 1, "value": "one" }])
+        );
+        assert_eq!(
+            to_json((1u8, "a".to_string(), false)),
+            serde_json::json!([1, "a", false])
+        );
+    }
+
+    #[test]
+    fn should_render_cl_type() {
+        let cl_value = CLValue::from_t(Some(vec![1u8])).unwrap();
+        let json = serde_json::to_value(&cl_value).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "cl_type": { "Option": { "List": "U8" } }, "value": { "Some": [1] } })
+        );
+    }
+
+    #[test]
+    fn should_distinguish_some_unit_and_nested_none_from_none() {
+        let some_unit = CLValue::from_t(Some(())).unwrap();
+        let none_unit = CLValue::from_t(Option::<()>::None).unwrap();
+        assert_eq!(
+            serde_json::to_value(&some_unit).unwrap()["value"],
+            serde_json::json!({ "Some": null })
+        );
+        json_round_trip(&some_unit);
+        json_round_trip(&none_unit);
+
+        let some_none = CLValue::from_t(Some(Option::<u8>::None)).unwrap();
+        let none_none = CLValue::from_t(Option::<Option<u8>>::None).unwrap();
+        assert_eq!(
+            serde_json::to_value(&some_none).unwrap()["value"],
+            serde_json::json!({ "Some": null })
+        );
+        json_round_trip(&some_none);
+        json_round_trip(&none_none);
+
+        let json = r#"{ "cl_type": { "Option": { "Option": "U8" } }, "value": null }"#;
+        assert_eq!(serde_json::from_str::<CLValue>(json).unwrap(), none_none);
+    }
+
+    #[test]
+    fn should_require_cl_type_before_value() {
+        let json = r#"{ "value": 1, "cl_type": "U8" }"#;
+        assert!(serde_json::from_str::<CLValue>(json).is_err());
+    }
+
+    #[test]
+    fn should_reject_value_of_wrong_type() {
+        let json = r#"{ "cl_type": { "FixedList": ["U8", 2] }, "value": [1, 2, 3] }"#;
+        assert!(serde_json::from_str::<CLValue>(json).is_err());
+        let json = r#"{ "cl_type": "U8", "value": "1" }"#;
+        assert!(serde_json::from_str::<CLValue>(json).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn json_round_trip_preserves_bytes(cl_value in gens::cl_value_arb()) {
+            json_round_trip(&cl_value);
+        }
+    }
+}
@@ -1,12 +1,19 @@
 use crate::bytesrepr::{Error, FromBytes, ToBytes};
+#[cfg(feature = "serde-support")]
+use crate::serde_helpers;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
 const CONTRACT_WASM_MAX_DISPLAY_LEN: usize = 16;
 
 /// A container for contract's WASM bytes.
 #[derive(PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct ContractWasm {
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_helpers::hex_bytes"))]
     bytes: Vec<u8>,
 }
 
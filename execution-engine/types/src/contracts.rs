@@ -14,6 +14,12 @@ use alloc::{
 };
 use core::fmt;
 
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde-support")]
+use crate::serde_helpers;
+
 /// Maximum number of distinct user groups.
 pub const MAX_GROUPS: u8 = 10;
 /// Maximum number of URefs which can be assigned across all user groups.
@@ -52,6 +58,7 @@ pub enum Error {
 /// A (labelled) "user group". Each method of a versioned contract may be
 /// assoicated with one or more user groups which are allowed to call it.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct Group(String);
 
 impl Group {
@@ -99,6 +106,7 @@ pub type ProtocolVersionMajor = u32;
 
 /// Major element of `ProtocolVersion` combined with `ContractVersion`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct ContractVersionKey(ProtocolVersionMajor, ContractVersion);
 
 impl ContractVersionKey {
@@ -170,10 +178,15 @@ pub type Groups = BTreeMap<Group, BTreeSet<URef>>;
 
 /// Contract definition, metadata, and security container.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct ContractPackage {
     /// Key used to add or disable versions
     access_key: URef,
     /// All versions (enabled & disabled)
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(with = "serde_helpers::contract_versions")
+    )]
     versions: ContractVersions,
     /// Disabled versions
     disabled_versions: DisabledVersions,
@@ -385,6 +398,7 @@ pub type EntryPointsMap = BTreeMap<String, EntryPoint>;
 
 /// Collection of named entry points
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct EntryPoints(EntryPointsMap);
 
 impl Default for EntryPoints {
@@ -459,8 +473,11 @@ pub type NamedKeys = BTreeMap<String, Key>;
 
 /// Methods and type signatures supported by a contract.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct Contract {
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_helpers::hash"))]
     contract_package_hash: ContractPackageHash,
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_helpers::hash"))]
     contract_wasm_hash: ContractWasmHash,
     named_keys: NamedKeys,
     entry_points: EntryPoints,
@@ -626,6 +643,7 @@ impl Default for Contract {
 /// Context of method execution
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub enum EntryPointType {
     /// Runs as session code
     Session = 0,
@@ -669,6 +687,7 @@ pub type Parameters = Vec<Parameter>;
 /// Type signature of a method. Order of arguments matter since can be
 /// referenced by index as well as name.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct EntryPoint {
     name: String,
     args: Parameters,
@@ -799,6 +818,7 @@ impl FromBytes for EntryPoint {
 /// Enum describing the possible access control options for a contract entry
 /// point (method).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub enum EntryPointAccess {
     /// Anyone can call this method (no access controls).
     Public,
@@ -861,6 +881,7 @@ impl FromBytes for EntryPointAccess {
 
 /// Parameter to a method
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct Parameter {
     name: String,
     cl_type: CLType,
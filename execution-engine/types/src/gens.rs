@@ -13,7 +13,7 @@ use proptest::{
 };
 
 use crate::{
-    account::{PublicKey, Weight},
+    account::{PublicKey, Weight, SECP256K1_LENGTH},
     contracts::{ContractVersions, DisabledVersions, Groups, NamedKeys, Parameters},
     AccessRights, CLType, CLValue, Contract, ContractPackage, ContractVersionKey, ContractWasm,
     EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, NamedArg, Parameter,
@@ -67,7 +67,12 @@ pub fn key_arb() -> impl Strategy<Value = Key> {
 }
 
 pub fn public_key_arb() -> impl Strategy<Value = PublicKey> {
-    u8_slice_32().prop_map(PublicKey::ed25519_from)
+    prop_oneof![
+        u8_slice_32().prop_map(PublicKey::ed25519_from),
+        vec(any::<u8>(), SECP256K1_LENGTH).prop_map(|bytes| {
+            PublicKey::secp256k1_try_from(&bytes).expect("should create public key")
+        }),
+    ]
 }
 
 pub fn weight_arb() -> impl Strategy<Value = Weight> {
@@ -110,6 +115,7 @@ pub fn cl_simple_type_arb() -> impl Strategy<Value = CLType> {
         Just(CLType::String),
         Just(CLType::Key),
         Just(CLType::URef),
+        Just(CLType::PublicKey),
     ]
 }
 
@@ -174,6 +180,7 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             | CLType::String
             | CLType::Key
             | CLType::URef
+            | CLType::PublicKey
             | CLType::Option(_)
             | CLType::List(_)
             | CLType::FixedList(..)
@@ -199,6 +206,7 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
         u512_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         key_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         uref_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        public_key_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         ".*".prop_map(|x: String| CLValue::from_t(x).expect("should create CLValue")),
         option::of(any::<u64>()).prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         vec(uref_arb(), 0..100).prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
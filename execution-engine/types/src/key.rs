@@ -1,11 +1,21 @@
 use alloc::{format, string::String, vec::Vec};
-use core::fmt::{self, Debug, Display, Formatter};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter},
+};
 
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
 use hex_fmt::HexFmt;
+#[cfg(feature = "serde-support")]
+use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    account::PublicKey,
+    account::{PublicKey, PUBLIC_KEY_SERIALIZED_MAX_LENGTH},
     bytesrepr::{self, Error, FromBytes, ToBytes},
+    uref::UREF_PREFIX,
     URef, UREF_SERIALIZED_LENGTH,
 };
 
@@ -13,6 +23,9 @@ const ACCOUNT_ID: u8 = 0;
 const HASH_ID: u8 = 1;
 const UREF_ID: u8 = 2;
 
+const ACCOUNT_PREFIX: &str = "account-";
+const HASH_PREFIX: &str = "hash-";
+
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
 /// The number of bytes in a [`Key::Hash`].
@@ -22,10 +35,36 @@ const KEY_ID_SERIALIZED_LENGTH: usize = 1;
 // u8 used to determine the ID
 const KEY_HASH_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
 const KEY_UREF_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + UREF_SERIALIZED_LENGTH;
+const KEY_ACCOUNT_MAX_SERIALIZED_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + PUBLIC_KEY_SERIALIZED_MAX_LENGTH;
 
 /// An alias for [`Key`]s hash variant.
 pub type HashAddr = [u8; KEY_HASH_LENGTH];
 
+/// Error while parsing a formatted [`Key`], [`URef`] or [`PublicKey`] string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FromStrError {
+    /// The string doesn't start with a recognized prefix.
+    InvalidPrefix,
+    /// The Base16-encoded part of the string couldn't be decoded.
+    Hex,
+    /// The decoded bytes have the wrong length.
+    InvalidLength,
+    /// The access rights of a [`URef`] couldn't be parsed.
+    InvalidAccessRights,
+}
+
+impl Display for FromStrError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FromStrError::InvalidPrefix => write!(f, "invalid prefix"),
+            FromStrError::Hex => write!(f, "invalid Base16 encoding"),
+            FromStrError::InvalidLength => write!(f, "invalid length"),
+            FromStrError::InvalidAccessRights => write!(f, "invalid access rights"),
+        }
+    }
+}
+
 impl From<HashAddr> for Key {
     fn from(addr: HashAddr) -> Self {
         Key::Hash(addr)
@@ -39,6 +78,18 @@ pub type ContractWasmHash = HashAddr;
 /// An alias for [`Key`]s hash variant.
 pub type ContractPackageHash = HashAddr;
 
+/// The variant of a [`Key`], which is serialized as its first byte.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum KeyTag {
+    /// The tag of [`Key::Account`].
+    Account = ACCOUNT_ID,
+    /// The tag of [`Key::Hash`].
+    Hash = HASH_ID,
+    /// The tag of [`Key::URef`].
+    URef = UREF_ID,
+}
+
 /// The type under which data (e.g. [`CLValue`](crate::CLValue)s, smart contracts, user accounts)
 /// are indexed on the network.
 #[repr(C)]
@@ -63,9 +114,18 @@ impl Key {
         }
     }
 
+    /// Returns the [`KeyTag`] of `self`.
+    pub fn tag(&self) -> KeyTag {
+        match self {
+            Key::Account(_) => KeyTag::Account,
+            Key::Hash(_) => KeyTag::Hash,
+            Key::URef(_) => KeyTag::URef,
+        }
+    }
+
     /// Returns the maximum size a [`Key`] can be serialized into.
     pub const fn max_serialized_length() -> usize {
-        KEY_UREF_SERIALIZED_LENGTH
+        KEY_ACCOUNT_MAX_SERIALIZED_LENGTH
     }
 
     /// If `self` is of type [`Key::URef`], returns `self` with the [`AccessRights`] stripped from
@@ -80,14 +140,32 @@ impl Key {
     /// Returns a human-readable version of `self`, with the inner bytes encoded to Base16.
     pub fn as_string(&self) -> String {
         match self {
-            Key::Account(PublicKey::Ed25519(addr)) => {
-                format!("account-ed25519-{}", base16::encode_lower(&addr.value()))
+            Key::Account(public_key) => {
+                format!("{}{}", ACCOUNT_PREFIX, public_key.to_formatted_string())
             }
-            Key::Hash(addr) => format!("hash-{}", base16::encode_lower(addr)),
+            Key::Hash(addr) => format!("{}{}", HASH_PREFIX, base16::encode_lower(addr)),
             Key::URef(uref) => uref.as_string(),
         }
     }
 
+    /// Parses a string formatted as per [`as_string`](Key::as_string) into a `Key`.
+    pub fn from_formatted_str(input: &str) -> Result<Key, FromStrError> {
+        if input.starts_with(ACCOUNT_PREFIX) {
+            let public_key = PublicKey::from_formatted_str(&input[ACCOUNT_PREFIX.len()..])?;
+            Ok(Key::Account(public_key))
+        } else if input.starts_with(HASH_PREFIX) {
+            let bytes =
+                base16::decode(&input[HASH_PREFIX.len()..]).map_err(|_| FromStrError::Hex)?;
+            let hash_addr =
+                HashAddr::try_from(bytes.as_slice()).map_err(|_| FromStrError::InvalidLength)?;
+            Ok(Key::Hash(hash_addr))
+        } else if input.starts_with(UREF_PREFIX) {
+            URef::from_formatted_str(input).map(Key::URef)
+        } else {
+            Err(FromStrError::InvalidPrefix)
+        }
+    }
+
     /// Returns the inner bytes of `self` if `self` is of type [`Key::Account`], otherwise returns
     /// `None`.
     pub fn into_account(self) -> Option<PublicKey> {
@@ -124,19 +202,34 @@ impl Key {
     }
 
     /// Creates the seed of a local key for a context with the given base key.
+    ///
+    /// Secp256k1 account keys are longer than the seed, so their Blake2b hash is used instead.
     pub fn into_seed(self) -> [u8; BLAKE2B_DIGEST_LENGTH] {
         match self {
             Key::Account(PublicKey::Ed25519(bytes)) => bytes.value(),
+            Key::Account(PublicKey::Secp256k1(bytes)) => blake2b_hash(bytes.as_bytes()),
             Key::Hash(bytes) => bytes,
             Key::URef(uref) => uref.addr(),
         }
     }
 }
 
+fn blake2b_hash(data: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let mut ret = [0u8; BLAKE2B_DIGEST_LENGTH];
+    // NOTE: Assumed safe as `BLAKE2B_DIGEST_LENGTH` is a valid value for a hasher
+    let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).expect("should create hasher");
+    hasher.input(data);
+    hasher.variable_result(|hash| ret.clone_from_slice(hash));
+    ret
+}
+
 impl Display for Key {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Key::Account(PublicKey::Ed25519(ed25519)) => write!(f, "Key::Account({})", ed25519),
+            Key::Account(PublicKey::Secp256k1(secp256k1)) => {
+                write!(f, "Key::Account({})", secp256k1)
+            }
             Key::Hash(addr) => write!(f, "Key::Hash({})", HexFmt(addr)),
             Key::URef(uref) => write!(f, "Key::{}", uref), /* Display impl for URef will append */
         }
@@ -155,6 +248,21 @@ impl From<URef> for Key {
     }
 }
 
+#[cfg(feature = "serde-support")]
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_string())
+    }
+}
+
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let formatted_key = String::deserialize(deserializer)?;
+        Key::from_formatted_str(&formatted_key).map_err(SerdeError::custom)
+    }
+}
+
 impl From<PublicKey> for Key {
     fn from(public_key: PublicKey) -> Key {
         Key::Account(public_key)
@@ -215,6 +323,7 @@ impl FromBytes for Key {
 mod tests {
     use super::*;
     use crate::{
+        account::SECP256K1_LENGTH,
         bytesrepr::{Error, FromBytes},
         AccessRights, URef,
     };
@@ -286,6 +395,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_display_secp256k1_account_key() {
+        let expected_hash = "01".repeat(33);
+        let account_key = Key::Account(PublicKey::secp256k1_from([1u8; SECP256K1_LENGTH]));
+        assert_eq!(
+            format!("{}", account_key),
+            format!("Key::Account(Secp256k1({}))", expected_hash)
+        );
+        assert_eq!(
+            account_key.as_string(),
+            format!("account-secp256k1-{}", expected_hash)
+        );
+    }
+
+    #[test]
+    fn secp256k1_account_seeds_should_differ() {
+        let seed_1 = Key::Account(PublicKey::secp256k1_from([1u8; SECP256K1_LENGTH])).into_seed();
+        let seed_2 = Key::Account(PublicKey::secp256k1_from([2u8; SECP256K1_LENGTH])).into_seed();
+        assert_ne!(seed_1, seed_2);
+    }
+
     #[test]
     fn abuse_vec_key() {
         // Prefix is 2^32-1 = shouldn't allocate that much
@@ -330,10 +460,57 @@ mod tests {
         let key_account = Key::Account(PublicKey::ed25519_from([42; BLAKE2B_DIGEST_LENGTH]));
         assert!(key_account.serialized_length() <= Key::max_serialized_length());
 
+        let key_account = Key::Account(PublicKey::secp256k1_from([42; SECP256K1_LENGTH]));
+        assert!(key_account.serialized_length() <= Key::max_serialized_length());
+
         let key_hash = Key::Hash([42; KEY_HASH_LENGTH]);
         assert!(key_hash.serialized_length() <= Key::max_serialized_length());
 
         let key_uref = Key::URef(URef::new([42; BLAKE2B_DIGEST_LENGTH], AccessRights::READ));
         assert!(key_uref.serialized_length() <= Key::max_serialized_length());
     }
+
+    #[test]
+    fn key_tag_is_first_serialized_byte() {
+        let keys = [
+            Key::Account(PublicKey::ed25519_from([42; BLAKE2B_DIGEST_LENGTH])),
+            Key::Account(PublicKey::secp256k1_from([42; SECP256K1_LENGTH])),
+            Key::Hash([42; KEY_HASH_LENGTH]),
+            Key::URef(URef::new([42; BLAKE2B_DIGEST_LENGTH], AccessRights::READ)),
+        ];
+        for key in &keys {
+            assert_eq!(key.to_bytes().unwrap()[0], key.tag() as u8);
+        }
+    }
+
+    #[test]
+    fn key_from_formatted_str() {
+        let keys = [
+            Key::Account(PublicKey::ed25519_from([42; BLAKE2B_DIGEST_LENGTH])),
+            Key::Account(PublicKey::secp256k1_from([42; SECP256K1_LENGTH])),
+            Key::Hash([42; KEY_HASH_LENGTH]),
+            Key::URef(URef::new(
+                [42; BLAKE2B_DIGEST_LENGTH],
+                AccessRights::READ_ADD,
+            )),
+        ];
+        for key in &keys {
+            assert_eq!(Key::from_formatted_str(&key.as_string()), Ok(*key));
+        }
+
+        let hex = "2a".repeat(KEY_HASH_LENGTH);
+        assert_eq!(
+            Key::from_formatted_str(&format!("contract-{}", hex)),
+            Err(FromStrError::InvalidPrefix)
+        );
+        assert_eq!(
+            Key::from_formatted_str("hash-2a"),
+            Err(FromStrError::InvalidLength)
+        );
+        assert_eq!(Key::from_formatted_str("hash-zz"), Err(FromStrError::Hex));
+        assert_eq!(
+            Key::from_formatted_str(&format!("uref-{}-010", hex)),
+            Err(FromStrError::InvalidAccessRights)
+        );
+    }
 }
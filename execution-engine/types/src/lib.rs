@@ -4,6 +4,16 @@
 //!
 //! By default, the library is `no_std`, however you can enable full `std` functionality by enabling
 //! the crate's `std` feature.
+//!
+//! # `serde`
+//!
+//! Enabling the crate's `serde-support` feature implements `Serialize` and `Deserialize` for
+//! [`Key`], [`URef`], [`CLType`], [`CLValue`], [`RuntimeArgs`], [`Contract`], [`ContractPackage`]
+//! and the types they are made of.  Keys, URefs and public keys are represented by their formatted
+//! strings (e.g. `"hash-<hex>"` or `"uref-<hex>-007"`), large unsigned integers by decimal strings
+//! and raw bytes by Base16 strings.  A `CLValue` is represented by its `cl_type` and its `value`
+//! rendered according to that type, and deserializing it yields the same bytes it was serialized
+//! from.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(
@@ -30,6 +40,8 @@ mod block_time;
 pub mod bytesrepr;
 mod cl_type;
 mod cl_value;
+#[cfg(feature = "serde-support")]
+mod cl_value_serde;
 mod contract_wasm;
 pub mod contracts;
 #[cfg(any(feature = "gens", test))]
@@ -39,6 +51,8 @@ mod phase;
 mod protocol_version;
 pub mod runtime_args;
 mod semver;
+#[cfg(feature = "serde-support")]
+mod serde_helpers;
 pub mod system_contract_errors;
 pub mod system_contract_type;
 mod transfer_result;
@@ -60,8 +74,8 @@ pub use contracts::{
 pub use contract_wasm::ContractWasm;
 #[doc(inline)]
 pub use key::{
-    ContractHash, ContractPackageHash, ContractWasmHash, HashAddr, Key, BLAKE2B_DIGEST_LENGTH,
-    KEY_HASH_LENGTH,
+    ContractHash, ContractPackageHash, ContractWasmHash, FromStrError, HashAddr, Key, KeyTag,
+    BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH,
 };
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
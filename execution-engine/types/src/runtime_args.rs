@@ -2,6 +2,9 @@
 
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
 use crate::{
     bytesrepr::{self, Error, FromBytes, ToBytes},
     CLTyped, CLValue,
@@ -9,6 +12,7 @@ use crate::{
 
 /// Named arguments to a contract
 #[derive(PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct NamedArg(String, CLValue);
 
 impl NamedArg {
@@ -55,6 +59,7 @@ impl FromBytes for NamedArg {
 
 /// Represents a collection of arguments passed to a smart contract.
 #[derive(PartialEq, Eq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct RuntimeArgs(Vec<NamedArg>);
 
 impl RuntimeArgs {
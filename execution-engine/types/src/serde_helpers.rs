@@ -0,0 +1,159 @@
+//! Helpers for the `serde` representations of the types in this crate, for use with
+//! `#[serde(with = "...")]`.
+
+/// Serializes byte containers as Base16-encoded strings.
+pub(crate) mod hex_bytes {
+    use alloc::{string::String, vec::Vec};
+
+    use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        bytes: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base16::encode_lower(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        base16::decode(&hex).map_err(|_| SerdeError::custom("invalid Base16 encoding"))
+    }
+}
+
+/// Serializes 32-byte hashes as Base16-encoded strings.
+pub(crate) mod hash {
+    use core::convert::TryFrom;
+
+    use serde::{de::Error as SerdeError, Deserializer, Serializer};
+
+    use crate::HashAddr;
+
+    pub(crate) fn serialize<S: Serializer>(
+        hash: &HashAddr,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::hex_bytes::serialize(hash, serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashAddr, D::Error> {
+        let bytes = super::hex_bytes::deserialize(deserializer)?;
+        HashAddr::try_from(bytes.as_slice())
+            .map_err(|_| SerdeError::invalid_length(bytes.len(), &"32 bytes"))
+    }
+}
+
+/// Serializes [`ContractVersions`] as a list of entries, since the keys of the map aren't strings.
+pub(crate) mod contract_versions {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{contracts::ContractVersions, ContractHash, ContractVersionKey};
+
+    #[derive(Serialize, Deserialize)]
+    struct Entry {
+        contract_version_key: ContractVersionKey,
+        #[serde(with = "super::hash")]
+        contract_hash: ContractHash,
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        versions: &ContractVersions,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(versions.iter().map(|(key, hash)| Entry {
+            contract_version_key: *key,
+            contract_hash: *hash,
+        }))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ContractVersions, D::Error> {
+        let entries = Vec::<Entry>::deserialize(deserializer)?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| (entry.contract_version_key, entry.contract_hash))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+
+    use proptest::prelude::*;
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use crate::{account::PublicKey, gens, Key, RuntimeArgs, URef, U512};
+
+    fn json_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+        let json = serde_json::to_string(value).unwrap();
+        let parsed: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, value, "{}", json);
+    }
+
+    #[test]
+    fn should_use_formatted_strings() {
+        let public_key = PublicKey::ed25519_from([1; 32]);
+        assert_eq!(
+            serde_json::to_value(Key::Account(public_key)).unwrap(),
+            serde_json::json!(format!("account-ed25519-{}", "01".repeat(32)))
+        );
+        assert_eq!(
+            serde_json::to_value(U512::from(1_000_000u64)).unwrap(),
+            serde_json::json!("1000000")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn key_json_round_trip(key in gens::key_arb()) {
+            json_round_trip(&key);
+        }
+
+        #[test]
+        fn uref_json_round_trip(uref in gens::uref_arb()) {
+            json_round_trip::<URef>(&uref);
+        }
+
+        #[test]
+        fn public_key_json_round_trip(public_key in gens::public_key_arb()) {
+            json_round_trip(&public_key);
+        }
+
+        #[test]
+        fn u512_json_round_trip(value in gens::u512_arb()) {
+            json_round_trip(&value);
+        }
+
+        #[test]
+        fn cl_type_json_round_trip(cl_type in gens::cl_type_arb()) {
+            json_round_trip(&cl_type);
+        }
+
+        #[test]
+        fn runtime_args_json_round_trip(named_args in proptest::collection::vec(gens::named_args_arb(), 0..5)) {
+            json_round_trip(&RuntimeArgs::from(named_args));
+        }
+
+        #[test]
+        fn contract_json_round_trip(contract in gens::contract_arb()) {
+            json_round_trip(&contract);
+        }
+
+        #[test]
+        fn contract_package_json_round_trip(contract_package in gens::contract_package_arb()) {
+            json_round_trip(&contract_package);
+        }
+
+        #[test]
+        fn contract_wasm_json_round_trip(contract_wasm in gens::contract_wasm_arb()) {
+            json_round_trip(&contract_wasm);
+        }
+    }
+}
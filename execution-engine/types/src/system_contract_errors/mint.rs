@@ -38,6 +38,18 @@ pub enum Error {
     /// Purse not found while trying to get balance.
     #[fail(display = "Purse not found")]
     PurseNotFound = 7,
+    /// Tried to mint rewards from an account other than the system account.
+    #[fail(display = "Unauthorized reward minting")]
+    UnauthorizedRewardMinting = 8,
+    /// Tried to burn motes from an account other than the system account.
+    #[fail(display = "Unauthorized burning")]
+    UnauthorizedBurning = 9,
+    /// The total supply is not recorded in the Mint contract's named keys.
+    #[fail(display = "Total supply not found")]
+    TotalSupplyNotFound = 10,
+    /// Tried to burn more motes than the recorded total supply.
+    #[fail(display = "Total supply underflow")]
+    TotalSupplyUnderflow = 11,
 }
 
 impl From<PurseError> for Error {
@@ -79,6 +91,12 @@ impl TryFrom<u8> for Error {
             d if d == Error::InvalidNonEmptyPurseCreation as u8 => {
                 Ok(Error::InvalidNonEmptyPurseCreation)
             }
+            d if d == Error::UnauthorizedRewardMinting as u8 => {
+                Ok(Error::UnauthorizedRewardMinting)
+            }
+            d if d == Error::UnauthorizedBurning as u8 => Ok(Error::UnauthorizedBurning),
+            d if d == Error::TotalSupplyNotFound as u8 => Ok(Error::TotalSupplyNotFound),
+            d if d == Error::TotalSupplyUnderflow as u8 => Ok(Error::TotalSupplyUnderflow),
             _ => Err(TryFromU8ForError(())),
         }
     }
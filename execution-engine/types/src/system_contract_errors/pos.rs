@@ -105,6 +105,61 @@ pub enum Error {
     /// deploy, but was called by the session code.
     #[fail(display = "Set refund purse was called outside payment")]
     SetRefundPurseCalledOutsidePayment,
+    /// Internal error: couldn't retrieve the balance for the PoS contract's rewards purse.
+    #[fail(display = "Rewards purse balance not found")]
+    RewardsPurseBalanceNotFound,
+    /// While distributing rewards, the requested total exceeded the balance of the PoS contract's
+    /// rewards purse.
+    #[fail(display = "Insufficient rewards purse balance")]
+    InsufficientRewardsPurseBalance,
+    /// While distributing rewards, the transfer from the PoS contract's rewards purse to a
+    /// validator's main purse failed.
+    #[fail(display = "Reward transfer failed")]
+    RewardTransferFailed,
+    /// The given delegator has no delegation to the given validator.
+    #[fail(display = "Not delegated")]
+    NotDelegated,
+    /// Attempted to delegate a stake which was too small.
+    #[fail(display = "Delegation is too small")]
+    DelegationTooSmall,
+    /// Attempted to undelegate an amount larger than the delegation.
+    #[fail(display = "Undelegate is too large")]
+    UndelegateTooLarge,
+    /// A validator attempted to delegate to itself rather than bonding.
+    #[fail(display = "Cannot delegate to self")]
+    SelfDelegation,
+    /// Attempted to bond or unbond such that the validator's stake would be below the minimum
+    /// bond.
+    #[fail(display = "Stake is below the minimum bond")]
+    BelowMinimumBond,
+    /// Attempted to bond a new validator while the maximum number of validators is bonded.
+    #[fail(display = "Too many validators")]
+    TooManyValidators,
+    /// While distributing seigniorage, minting the rewards into the PoS contract's rewards purse
+    /// failed.
+    #[fail(display = "Reward minting failed")]
+    RewardMintingFailed,
+    /// Internal error: the PoS contract's queue delays were not found.
+    #[fail(display = "Queue delays not found")]
+    QueueDelaysNotFound,
+    /// Internal error: the PoS contract's queue delays could not be deserialized.
+    #[fail(display = "Queue delays deserialization failed")]
+    QueueDelaysDeserializationFailed,
+    /// Internal error: the PoS contract's stake limits were not found.
+    #[fail(display = "Stake limits not found")]
+    StakeLimitsNotFound,
+    /// Internal error: the PoS contract's stake limits could not be deserialized.
+    #[fail(display = "Stake limits deserialization failed")]
+    StakeLimitsDeserializationFailed,
+    /// While distributing seigniorage, computing the rewards overflowed.
+    #[fail(display = "Reward overflow")]
+    RewardOverflow,
+    /// Internal error: the PoS contract's delegations were not found.
+    #[fail(display = "Delegations not found")]
+    DelegationsNotFound,
+    /// Internal error: the PoS contract's delegations could not be deserialized.
+    #[fail(display = "Delegations deserialization failed")]
+    DelegationsDeserializationFailed,
 }
 
 impl CLTyped for Error {
@@ -1,7 +1,11 @@
+#[cfg(feature = "serde-support")]
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use num_integer::Integer;
 use num_traits::{AsPrimitive, Bounded, Num, One, Unsigned, WrappingAdd, WrappingSub, Zero};
+#[cfg(feature = "serde-support")]
+use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
 
@@ -40,6 +44,23 @@ pub enum UIntParseError {
 
 macro_rules! impl_traits_for_uint {
     ($type:ident, $total_bytes:expr, $test_mod:ident) => {
+        // Serialized as a decimal string, since JSON numbers can't represent the full range.
+        #[cfg(feature = "serde-support")]
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde-support")]
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let decimal = String::deserialize(deserializer)?;
+                $type::from_dec_str(&decimal)
+                    .map_err(|_| SerdeError::custom(concat!("invalid ", stringify!($type))))
+            }
+        }
+
         impl ToBytes for $type {
             fn to_bytes(&self) -> Result<Vec<u8>, Error> {
                 let mut buf = [0u8; $total_bytes];
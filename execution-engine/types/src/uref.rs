@@ -5,8 +5,12 @@ use core::{
 };
 
 use hex_fmt::HexFmt;
+#[cfg(feature = "serde-support")]
+use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{bytesrepr, AccessRights, ApiError, Key, ACCESS_RIGHTS_SERIALIZED_LENGTH};
+use crate::{
+    bytesrepr, key::FromStrError, AccessRights, ApiError, Key, ACCESS_RIGHTS_SERIALIZED_LENGTH,
+};
 
 /// The number of bytes in a [`URef`] address.
 pub const UREF_ADDR_LENGTH: usize = 32;
@@ -17,6 +21,8 @@ pub const UREF_SERIALIZED_LENGTH: usize = UREF_ADDR_LENGTH + ACCESS_RIGHTS_SERIA
 /// The address of a [`URef`](types::URef) (unforgeable reference) on the network.
 pub type URefAddr = [u8; UREF_ADDR_LENGTH];
 
+pub(crate) const UREF_PREFIX: &str = "uref-";
+
 /// Represents an unforgeable reference, containing an address in the network's global storage and
 /// the [`AccessRights`] of the reference.
 ///
@@ -87,11 +93,43 @@ impl URef {
         // Access rights is represented as octal, which means that max value of u8 can
         // be represented as maximum of 3 octal digits.
         format!(
-            "uref-{}-{:03o}",
+            "{}{}-{:03o}",
+            UREF_PREFIX,
             base16::encode_lower(&self.addr()),
             access_rights_bits
         )
     }
+
+    /// Parses a string formatted as per [`as_string`](URef::as_string) into a `URef`.
+    pub fn from_formatted_str(input: &str) -> Result<Self, FromStrError> {
+        if !input.starts_with(UREF_PREFIX) {
+            return Err(FromStrError::InvalidPrefix);
+        }
+        let parts = &input[UREF_PREFIX.len()..];
+        let separator = parts.rfind('-').ok_or(FromStrError::InvalidAccessRights)?;
+        let bytes = base16::decode(&parts[..separator]).map_err(|_| FromStrError::Hex)?;
+        let addr = URefAddr::try_from(bytes.as_slice()).map_err(|_| FromStrError::InvalidLength)?;
+        let access_rights_bits = u8::from_str_radix(&parts[separator + 1..], 8)
+            .map_err(|_| FromStrError::InvalidAccessRights)?;
+        let access_rights =
+            AccessRights::from_bits(access_rights_bits).ok_or(FromStrError::InvalidAccessRights)?;
+        Ok(URef(addr, access_rights))
+    }
+}
+
+#[cfg(feature = "serde-support")]
+impl Serialize for URef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_string())
+    }
+}
+
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for URef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let formatted_uref = String::deserialize(deserializer)?;
+        URef::from_formatted_str(&formatted_uref).map_err(SerdeError::custom)
+    }
 }
 
 impl Display for URef {
@@ -168,4 +206,26 @@ mod tests {
             "uref-0000000000000000000000000000000000000000000000000000000000000000-000"
         );
     }
+
+    #[test]
+    fn uref_from_formatted_str() {
+        let uref = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        assert_eq!(URef::from_formatted_str(&uref.as_string()), Ok(uref));
+
+        let invalid_access_rights =
+            "uref-0000000000000000000000000000000000000000000000000000000000000000-010";
+        assert_eq!(
+            URef::from_formatted_str(invalid_access_rights),
+            Err(FromStrError::InvalidAccessRights)
+        );
+        let short_addr = "uref-00-007";
+        assert_eq!(
+            URef::from_formatted_str(short_addr),
+            Err(FromStrError::InvalidLength)
+        );
+        assert_eq!(
+            URef::from_formatted_str("hash-00"),
+            Err(FromStrError::InvalidPrefix)
+        );
+    }
 }